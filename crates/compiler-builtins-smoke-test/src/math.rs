@@ -36,135 +36,347 @@ macro_rules! no_mangle {
 }
 
 no_mangle! {
+    canonicalize(x: f64) -> f64;
+    canonicalizef(x: f32) -> f32;
+    canonicalizef128(x: f128) -> f128;
+    canonicalizef16(x: f16) -> f16;
     frexp(x: f64 | y: &mut c_int) -> f64;
     frexpf(x: f32 | y: &mut c_int) -> f32;
+    frexpf128(x: f128 | y: &mut c_int) -> f128;
+    frexpf16(x: f16 | y: &mut c_int) -> f16;
     acos(x: f64) -> f64;
     acosf(x: f32) -> f32;
+    acosf128(x: f128) -> f128;
+    acosf16(x: f16) -> f16;
     acosh(x: f64) -> f64;
     acoshf(x: f32) -> f32;
+    acoshf128(x: f128) -> f128;
+    acoshf16(x: f16) -> f16;
+    acospi(x: f64) -> f64;
+    acospif(x: f32) -> f32;
+    acospif128(x: f128) -> f128;
+    acospif16(x: f16) -> f16;
     asin(x: f64) -> f64;
     asinf(x: f32) -> f32;
+    asinf128(x: f128) -> f128;
+    asinf16(x: f16) -> f16;
     asinh(x: f64) -> f64;
     asinhf(x: f32) -> f32;
+    asinhf128(x: f128) -> f128;
+    asinhf16(x: f16) -> f16;
+    asinpi(x: f64) -> f64;
+    asinpif(x: f32) -> f32;
+    asinpif128(x: f128) -> f128;
+    asinpif16(x: f16) -> f16;
     atan(x: f64) -> f64;
     atan2(x: f64, y: f64) -> f64;
     atan2f(x: f32, y: f32) -> f32;
+    atan2f128(x: f128, y: f128) -> f128;
+    atan2f16(x: f16, y: f16) -> f16;
+    atan2pi(x: f64, y: f64) -> f64;
+    atan2pif(x: f32, y: f32) -> f32;
+    atan2pif128(x: f128, y: f128) -> f128;
+    atan2pif16(x: f16, y: f16) -> f16;
     atanf(x: f32) -> f32;
+    atanf128(x: f128) -> f128;
+    atanf16(x: f16) -> f16;
     atanh(x: f64) -> f64;
     atanhf(x: f32) -> f32;
+    atanhf128(x: f128) -> f128;
+    atanhf16(x: f16) -> f16;
+    atanpi(x: f64) -> f64;
+    atanpif(x: f32) -> f32;
+    atanpif128(x: f128) -> f128;
+    atanpif16(x: f16) -> f16;
     cbrt(x: f64) -> f64;
     cbrtf(x: f32) -> f32;
+    cbrtf128(x: f128) -> f128;
+    cbrtf16(x: f16) -> f16;
     ceil(x: f64) -> f64;
     ceilf(x: f32) -> f32;
     ceilf128(x: f128) -> f128;
     ceilf16(x: f16) -> f16;
+    compoundn(x: f64, y: i64) -> f64;
+    compoundnf(x: f32, y: i64) -> f32;
     copysign(x: f64, y: f64) -> f64;
     copysignf(x: f32, y: f32) -> f32;
     copysignf128(x: f128, y: f128) -> f128;
     copysignf16(x: f16, y: f16) -> f16;
     cos(x: f64) -> f64;
     cosf(x: f32) -> f32;
+    cosf128(x: f128) -> f128;
+    cosf16(x: f16) -> f16;
     cosh(x: f64) -> f64;
     coshf(x: f32) -> f32;
+    coshf128(x: f128) -> f128;
+    coshf16(x: f16) -> f16;
+    cospi(x: f64) -> f64;
+    cospif(x: f32) -> f32;
+    cospif128(x: f128) -> f128;
+    cospif16(x: f16) -> f16;
     erf(x: f64) -> f64;
     erfc(x: f64) -> f64;
     erfcf(x: f32) -> f32;
+    erfcf128(x: f128) -> f128;
+    erfcf16(x: f16) -> f16;
     erff(x: f32) -> f32;
+    erff128(x: f128) -> f128;
+    erff16(x: f16) -> f16;
     exp(x: f64) -> f64;
     exp10(x: f64) -> f64;
     exp10f(x: f32) -> f32;
+    exp10f16(x: f16) -> f16;
+    exp10m1(x: f64) -> f64;
+    exp10m1f(x: f32) -> f32;
     exp2(x: f64) -> f64;
     exp2f(x: f32) -> f32;
+    exp2f128(x: f128) -> f128;
+    exp2f16(x: f16) -> f16;
+    exp2m1(x: f64) -> f64;
+    exp2m1f(x: f32) -> f32;
     expf(x: f32) -> f32;
+    expf128(x: f128) -> f128;
+    expf16(x: f16) -> f16;
     expm1(x: f64) -> f64;
     expm1f(x: f32) -> f32;
+    expm1f128(x: f128) -> f128;
+    expm1f16(x: f16) -> f16;
+    f16addf32(x: f32, y: f32) -> f16;
+    f16divf32(x: f32, y: f32) -> f16;
+    f16fmaf32(x: f32, y: f32, z: f32) -> f16;
+    f16mulf32(x: f32, y: f32) -> f16;
+    f16sqrtf32(x: f32) -> f16;
+    f16subf32(x: f32, y: f32) -> f16;
+    f64addf128(x: f128, y: f128) -> f64;
+    f64divf128(x: f128, y: f128) -> f64;
+    f64fmaf128(x: f128, y: f128, z: f128) -> f64;
+    f64mulf128(x: f128, y: f128) -> f64;
+    f64sqrtf128(x: f128) -> f64;
+    f64subf128(x: f128, y: f128) -> f64;
     fabs(x: f64) -> f64;
     fabsf(x: f32) -> f32;
     fabsf128(x: f128) -> f128;
     fabsf16(x: f16) -> f16;
+    fadd(x: f64, y: f64) -> f32;
     fdim(x: f64, y: f64) -> f64;
     fdimf(x: f32, y: f32) -> f32;
     fdimf128(x: f128, y: f128) -> f128;
     fdimf16(x: f16, y: f16) -> f16;
+    fdiv(x: f64, y: f64) -> f32;
+    ffma(x: f64, y: f64, z: f64) -> f32;
     floor(x: f64) -> f64;
     floorf(x: f32) -> f32;
     floorf128(x: f128) -> f128;
     floorf16(x: f16) -> f16;
     fma(x: f64, y: f64, z: f64) -> f64;
     fmaf(x: f32, y: f32, z: f32) -> f32;
+    fmaf128(x: f128, y: f128, z: f128) -> f128;
+    fmaf16(x: f16, y: f16, z: f16) -> f16;
     fmax(x: f64, y: f64) -> f64;
     fmaxf(x: f32, y: f32) -> f32;
     fmin(x: f64, y: f64) -> f64;
     fminf(x: f32, y: f32) -> f32;
     fmod(x: f64, y: f64) -> f64;
     fmodf(x: f32, y: f32) -> f32;
+    fmul(x: f64, y: f64) -> f32;
+    fsqrt(x: f64) -> f32;
+    fsub(x: f64, y: f64) -> f32;
+    getpayload(x: f64) -> f64;
+    getpayloadf(x: f32) -> f32;
+    getpayloadf128(x: f128) -> f128;
+    getpayloadf16(x: f16) -> f16;
     hypot(x: f64, y: f64) -> f64;
     hypotf(x: f32, y: f32) -> f32;
+    hypotf128(x: f128, y: f128) -> f128;
+    hypotf16(x: f16, y: f16) -> f16;
     ilogb(x: f64) -> c_int;
     ilogbf(x: f32) -> c_int;
+    ilogbf128(x: f128) -> c_int;
+    ilogbf16(x: f16) -> c_int;
+    issignaling(x: f64) -> c_int;
+    issignalingf(x: f32) -> c_int;
+    issignalingf128(x: f128) -> c_int;
+    issignalingf16(x: f16) -> c_int;
     j0(x: f64) -> f64;
     j0f(x: f32) -> f32;
+    j0f128(x: f128) -> f128;
     j1(x: f64) -> f64;
     j1f(x: f32) -> f32;
+    j1f128(x: f128) -> f128;
     jn(x: c_int, y: f64) -> f64;
     jnf(x: c_int, y: f32) -> f32;
+    jnf128(x: c_int, y: f128) -> f128;
     ldexp(x: f64, y: c_int) -> f64;
     ldexpf(x: f32, y: c_int) -> f32;
     lgamma(x: f64) -> f64;
     lgamma_r(x: f64 | r: &mut c_int) -> f64;
     lgammaf(x: f32) -> f32;
+    lgammaf128(x: f128) -> f128;
+    lgammaf16(x: f16) -> f16;
+    lgammaf128_r(x: f128 | r: &mut c_int) -> f128;
     lgammaf_r(x: f32 | r: &mut c_int) -> f32;
+    llogb(x: f64) -> i64;
+    llogbf(x: f32) -> i64;
+    llogbf128(x: f128) -> i64;
+    llogbf16(x: f16) -> i64;
+    llrint(x: f64) -> i64;
+    llrintf(x: f32) -> i64;
+    llrintf128(x: f128) -> i64;
+    llrintf16(x: f16) -> i64;
+    llround(x: f64) -> i64;
+    llroundf(x: f32) -> i64;
+    llroundf128(x: f128) -> i64;
+    llroundf16(x: f16) -> i64;
     log(x: f64) -> f64;
     log10(x: f64) -> f64;
     log10f(x: f32) -> f32;
+    log10f128(x: f128) -> f128;
+    log10f16(x: f16) -> f16;
+    log10p1(x: f64) -> f64;
+    log10p1f(x: f32) -> f32;
     log1p(x: f64) -> f64;
     log1pf(x: f32) -> f32;
+    log1pf128(x: f128) -> f128;
+    log1pf16(x: f16) -> f16;
     log2(x: f64) -> f64;
     log2f(x: f32) -> f32;
+    log2f128(x: f128) -> f128;
+    log2f16(x: f16) -> f16;
+    log2p1(x: f64) -> f64;
+    log2p1f(x: f32) -> f32;
+    logb(x: f64) -> f64;
+    logbf(x: f32) -> f32;
+    logbf128(x: f128) -> f128;
+    logbf16(x: f16) -> f16;
     logf(x: f32) -> f32;
+    logf128(x: f128) -> f128;
+    logf16(x: f16) -> f16;
+    logp1(x: f64) -> f64;
+    logp1f(x: f32) -> f32;
+    lrint(x: f64) -> i64;
+    lrintf(x: f32) -> i64;
+    lrintf128(x: f128) -> i64;
+    lrintf16(x: f16) -> i64;
+    lround(x: f64) -> i64;
+    lroundf(x: f32) -> i64;
+    lroundf128(x: f128) -> i64;
+    lroundf16(x: f16) -> i64;
     modf(x: f64 | r: &mut f64) -> f64;
     modff(x: f32 | r: &mut f32) -> f32;
+    modff128(x: f128 | r: &mut f128) -> f128;
+    modff16(x: f16 | r: &mut f16) -> f16;
+    nearbyint(x: f64) -> f64;
+    nearbyintf(x: f32) -> f32;
+    nearbyintf128(x: f128) -> f128;
+    nearbyintf16(x: f16) -> f16;
     nextafter(x: f64, y: f64) -> f64;
     nextafterf(x: f32, y: f32) -> f32;
+    nextafterf128(x: f128, y: f128) -> f128;
+    nextafterf16(x: f16, y: f16) -> f16;
+    nextdown(x: f64) -> f64;
+    nextdownf(x: f32) -> f32;
+    nextdownf128(x: f128) -> f128;
+    nextdownf16(x: f16) -> f16;
+    nexttoward(x: f64, y: f64) -> f64;
+    nexttowardf(x: f32, y: f64) -> f32;
+    nextup(x: f64) -> f64;
+    nextupf(x: f32) -> f32;
+    nextupf128(x: f128) -> f128;
+    nextupf16(x: f16) -> f16;
     pow(x: f64, y: f64) -> f64;
     powf(x: f32, y: f32) -> f32;
+    powf128(x: f128, y: f128) -> f128;
+    powf16(x: f16, y: f16) -> f16;
+    pown(x: f64, y: i64) -> f64;
+    pownf(x: f32, y: i64) -> f32;
+    powr(x: f64, y: f64) -> f64;
+    powrf(x: f32, y: f32) -> f32;
     remainder(x: f64, y: f64) -> f64;
     remainderf(x: f32, y: f32) -> f32;
+    remainderf128(x: f128, y: f128) -> f128;
+    remainderf16(x: f16, y: f16) -> f16;
     remquo(x: f64, y: f64 | q: &mut c_int) -> f64;
     remquof(x: f32, y: f32 | q: &mut c_int) -> f32;
+    remquof128(x: f128, y: f128 | q: &mut c_int) -> f128;
+    remquof16(x: f16, y: f16 | q: &mut c_int) -> f16;
     rint(x: f64) -> f64;
     rintf(x: f32) -> f32;
     rintf128(x: f128) -> f128;
     rintf16(x: f16) -> f16;
+    rootn(x: f64, y: i64) -> f64;
+    rootnf(x: f32, y: i64) -> f32;
     round(x: f64) -> f64;
     roundf(x: f32) -> f32;
+    rsqrt(x: f64) -> f64;
+    rsqrtf(x: f32) -> f32;
+    scalbln(x: f64, y: i64) -> f64;
+    scalblnf(x: f32, y: i64) -> f32;
+    scalblnf128(x: f128, y: i64) -> f128;
+    scalblnf16(x: f16, y: i64) -> f16;
     scalbn(x: f64, y: c_int) -> f64;
     scalbnf(x: f32, y: c_int) -> f32;
+    setpayload(x: f64) -> f64;
+    setpayloadf(x: f32) -> f32;
+    setpayloadf128(x: f128) -> f128;
+    setpayloadf16(x: f16) -> f16;
+    setpayloadsig(x: f64) -> f64;
+    setpayloadsigf(x: f32) -> f32;
+    setpayloadsigf128(x: f128) -> f128;
+    setpayloadsigf16(x: f16) -> f16;
     sin(x: f64) -> f64;
     sinf(x: f32) -> f32;
+    sinf128(x: f128) -> f128;
+    sinf16(x: f16) -> f16;
     sinh(x: f64) -> f64;
     sinhf(x: f32) -> f32;
+    sinhf128(x: f128) -> f128;
+    sinhf16(x: f16) -> f16;
+    sinpi(x: f64) -> f64;
+    sinpif(x: f32) -> f32;
+    sinpif128(x: f128) -> f128;
+    sinpif16(x: f16) -> f16;
     sqrt(x: f64) -> f64;
     sqrtf(x: f32) -> f32;
     tan(x: f64) -> f64;
     tanf(x: f32) -> f32;
+    tanf128(x: f128) -> f128;
+    tanf16(x: f16) -> f16;
     tanh(x: f64) -> f64;
     tanhf(x: f32) -> f32;
+    tanhf128(x: f128) -> f128;
+    tanhf16(x: f16) -> f16;
+    tanpi(x: f64) -> f64;
+    tanpif(x: f32) -> f32;
+    tanpif128(x: f128) -> f128;
+    tanpif16(x: f16) -> f16;
     tgamma(x: f64) -> f64;
     tgammaf(x: f32) -> f32;
+    tgammaf128(x: f128) -> f128;
+    tgammaf16(x: f16) -> f16;
+    totalorder(x: f64, y: f64) -> c_int;
+    totalorderf(x: f32, y: f32) -> c_int;
+    totalorderf128(x: f128, y: f128) -> c_int;
+    totalorderf16(x: f16, y: f16) -> c_int;
+    totalordermag(x: f64, y: f64) -> c_int;
+    totalordermagf(x: f32, y: f32) -> c_int;
+    totalordermagf128(x: f128, y: f128) -> c_int;
+    totalordermagf16(x: f16, y: f16) -> c_int;
     trunc(x: f64) -> f64;
     truncf(x: f32) -> f32;
     truncf128(x: f128) -> f128;
     truncf16(x: f16) -> f16;
     y0(x: f64) -> f64;
     y0f(x: f32) -> f32;
+    y0f128(x: f128) -> f128;
     y1(x: f64) -> f64;
     y1f(x: f32) -> f32;
+    y1f128(x: f128) -> f128;
     yn(x: c_int, y: f64) -> f64;
     ynf(x: c_int, y: f32) -> f32;
+    ynf128(x: c_int, y: f128) -> f128;
 }
 
-/* sincos has no direct return type, not worth handling in the macro */
+/* sincos and sincospi have no direct return type, not worth handling in the macro */
 
 #[unsafe(no_mangle)]
 extern "C" fn sincos(x: f64, s: &mut f64, c: &mut f64) {
@@ -176,6 +388,36 @@ extern "C" fn sincosf(x: f32, s: &mut f32, c: &mut f32) {
     (*s, *c) = libm::sincosf(x);
 }
 
+#[unsafe(no_mangle)]
+extern "C" fn sincosf16(x: f16, s: &mut f16, c: &mut f16) {
+    (*s, *c) = libm::sincosf16(x);
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn sincosf128(x: f128, s: &mut f128, c: &mut f128) {
+    (*s, *c) = libm::sincosf128(x);
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn sincospi(x: f64, s: &mut f64, c: &mut f64) {
+    (*s, *c) = libm::sincospi(x);
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn sincospif(x: f32, s: &mut f32, c: &mut f32) {
+    (*s, *c) = libm::sincospif(x);
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn sincospif16(x: f16, s: &mut f16, c: &mut f16) {
+    (*s, *c) = libm::sincospif16(x);
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn sincospif128(x: f128, s: &mut f128, c: &mut f128) {
+    (*s, *c) = libm::sincospif128(x);
+}
+
 #[panic_handler]
 fn panic(_info: &core::panic::PanicInfo) -> ! {
     loop {}
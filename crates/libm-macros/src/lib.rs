@@ -397,7 +397,22 @@ fn base_name(name: &str) -> &str {
     let known_mappings = &[
         ("erff", "erf"),
         ("erf", "erf"),
+        ("erff16", "erf"),
+        ("erff128", "erf"),
+        ("f16addf32", "fadd"),
+        ("f64addf128", "fadd"),
+        ("f16divf32", "fdiv"),
+        ("f64divf128", "fdiv"),
+        ("f16fmaf32", "ffma"),
+        ("f64fmaf128", "ffma"),
+        ("f16mulf32", "fmul"),
+        ("f64mulf128", "fmul"),
+        ("f16sqrtf32", "fsqrt"),
+        ("f64sqrtf128", "fsqrt"),
+        ("f16subf32", "fsub"),
+        ("f64subf128", "fsub"),
         ("lgammaf_r", "lgamma_r"),
+        ("lgammaf128_r", "lgamma_r"),
         ("modff", "modf"),
         ("modf", "modf"),
     ];
@@ -420,7 +435,10 @@ impl ToTokens for Ty {
             Ty::F64 => quote! { f64 },
             Ty::F128 => quote! { f128 },
             Ty::I32 => quote! { i32 },
+            Ty::I64 => quote! { i64 },
+            Ty::U32 => quote! { u32 },
             Ty::CInt => quote! { ::core::ffi::c_int },
+            Ty::Round => quote! { ::libm::Round },
             Ty::MutF16 => quote! { &'a mut f16 },
             Ty::MutF32 => quote! { &'a mut f32 },
             Ty::MutF64 => quote! { &'a mut f64 },
@@ -10,13 +10,52 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         Signature { args: &[Ty::F16], returns: &[Ty::F16] },
         None,
         &[
+            "acosf16",
+            "acoshf16",
+            "acospif16",
+            "asinf16",
+            "asinhf16",
+            "asinpif16",
+            "atanf16",
+            "atanhf16",
+            "atanpif16",
+            "canonicalizef16",
+            "cbrtf16",
             "ceilf16",
+            "cosf16",
+            "coshf16",
+            "cospif16",
+            "erfcf16",
+            "erff16",
+            "exp10f16",
+            "exp2f16",
+            "expf16",
+            "expm1f16",
             "fabsf16",
             "floorf16",
+            "getpayloadf16",
+            "lgammaf16",
+            "log10f16",
+            "log1pf16",
+            "log2f16",
+            "logbf16",
+            "logf16",
+            "nearbyintf16",
+            "nextdownf16",
+            "nextupf16",
             "rintf16",
             "roundevenf16",
             "roundf16",
+            "setpayloadf16",
+            "setpayloadsigf16",
+            "sinf16",
+            "sinhf16",
+            "sinpif16",
             "sqrtf16",
+            "tanf16",
+            "tanhf16",
+            "tanpif16",
+            "tgammaf16",
             "truncf16",
         ],
     ),
@@ -28,37 +67,57 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         &[
             "acosf",
             "acoshf",
+            "acospif",
             "asinf",
             "asinhf",
+            "asinpif",
             "atanf",
             "atanhf",
+            "atanpif",
+            "canonicalizef",
             "cbrtf",
             "ceilf",
             "cosf",
             "coshf",
+            "cospif",
             "erfcf",
             "erff",
             "exp10f",
+            "exp10m1f",
             "exp2f",
+            "exp2m1f",
             "expf",
             "expm1f",
             "fabsf",
             "floorf",
+            "getpayloadf",
             "j0f",
             "j1f",
             "lgammaf",
             "log10f",
+            "log10p1f",
             "log1pf",
             "log2f",
+            "log2p1f",
+            "logbf",
             "logf",
+            "logp1f",
+            "nearbyintf",
+            "nextdownf",
+            "nextupf",
             "rintf",
             "roundevenf",
             "roundf",
+            "rsqrtf",
+            "setpayloadf",
+            "setpayloadsigf",
             "sinf",
             "sinhf",
+            "sinpif",
             "sqrtf",
             "tanf",
             "tanhf",
+            "tanpif",
             "tgammaf",
             "truncf",
             "y0f",
@@ -73,37 +132,57 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         &[
             "acos",
             "acosh",
+            "acospi",
             "asin",
             "asinh",
+            "asinpi",
             "atan",
             "atanh",
+            "atanpi",
+            "canonicalize",
             "cbrt",
             "ceil",
             "cos",
             "cosh",
+            "cospi",
             "erf",
             "erfc",
             "exp",
             "exp10",
+            "exp10m1",
             "exp2",
+            "exp2m1",
             "expm1",
             "fabs",
             "floor",
+            "getpayload",
             "j0",
             "j1",
             "lgamma",
             "log",
             "log10",
+            "log10p1",
             "log1p",
             "log2",
+            "log2p1",
+            "logb",
+            "logp1",
+            "nearbyint",
+            "nextdown",
+            "nextup",
             "rint",
             "round",
             "roundeven",
+            "rsqrt",
+            "setpayload",
+            "setpayloadsig",
             "sin",
             "sinh",
+            "sinpi",
             "sqrt",
             "tan",
             "tanh",
+            "tanpi",
             "tgamma",
             "trunc",
             "y0",
@@ -116,14 +195,56 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         Signature { args: &[Ty::F128], returns: &[Ty::F128] },
         None,
         &[
+            "acosf128",
+            "acoshf128",
+            "acospif128",
+            "asinf128",
+            "asinhf128",
+            "asinpif128",
+            "atanf128",
+            "atanhf128",
+            "atanpif128",
+            "canonicalizef128",
+            "cbrtf128",
             "ceilf128",
+            "cosf128",
+            "coshf128",
+            "cospif128",
+            "erfcf128",
+            "erff128",
+            "exp2f128",
+            "expf128",
+            "expm1f128",
             "fabsf128",
             "floorf128",
+            "getpayloadf128",
+            "j0f128",
+            "j1f128",
+            "lgammaf128",
+            "log10f128",
+            "log1pf128",
+            "log2f128",
+            "logbf128",
+            "logf128",
+            "nearbyintf128",
+            "nextdownf128",
+            "nextupf128",
             "rintf128",
             "roundevenf128",
             "roundf128",
+            "setpayloadf128",
+            "setpayloadsigf128",
+            "sinf128",
+            "sinhf128",
+            "sinpif128",
             "sqrtf128",
+            "tanf128",
+            "tanhf128",
+            "tanpif128",
+            "tgammaf128",
             "truncf128",
+            "y0f128",
+            "y1f128",
         ],
     ),
     (
@@ -132,15 +253,25 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         Signature { args: &[Ty::F16, Ty::F16], returns: &[Ty::F16] },
         None,
         &[
+            "atan2f16",
+            "atan2pif16",
             "copysignf16",
             "fdimf16",
             "fmaxf16",
+            "fmaximum_mag_numf16",
+            "fmaximum_magf16",
             "fmaximum_numf16",
             "fmaximumf16",
             "fminf16",
+            "fminimum_mag_numf16",
+            "fminimum_magf16",
             "fminimum_numf16",
             "fminimumf16",
             "fmodf16",
+            "hypotf16",
+            "nextafterf16",
+            "powf16",
+            "remainderf16",
         ],
     ),
     (
@@ -150,18 +281,24 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         None,
         &[
             "atan2f",
+            "atan2pif",
             "copysignf",
             "fdimf",
             "fmaxf",
+            "fmaximum_mag_numf",
+            "fmaximum_magf",
             "fmaximum_numf",
             "fmaximumf",
             "fminf",
+            "fminimum_mag_numf",
+            "fminimum_magf",
             "fminimum_numf",
             "fminimumf",
             "fmodf",
             "hypotf",
             "nextafterf",
             "powf",
+            "powrf",
             "remainderf",
         ],
     ),
@@ -172,18 +309,25 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         None,
         &[
             "atan2",
+            "atan2pi",
             "copysign",
             "fdim",
             "fmax",
             "fmaximum",
+            "fmaximum_mag",
+            "fmaximum_mag_num",
             "fmaximum_num",
             "fmin",
             "fminimum",
+            "fminimum_mag",
+            "fminimum_mag_num",
             "fminimum_num",
             "fmod",
             "hypot",
             "nextafter",
+            "nexttoward",
             "pow",
+            "powr",
             "remainder",
         ],
     ),
@@ -193,17 +337,34 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         Signature { args: &[Ty::F128, Ty::F128], returns: &[Ty::F128] },
         None,
         &[
+            "atan2f128",
+            "atan2pif128",
             "copysignf128",
             "fdimf128",
             "fmaxf128",
+            "fmaximum_mag_numf128",
+            "fmaximum_magf128",
             "fmaximum_numf128",
             "fmaximumf128",
             "fminf128",
+            "fminimum_mag_numf128",
+            "fminimum_magf128",
             "fminimum_numf128",
             "fminimumf128",
             "fmodf128",
+            "hypotf128",
+            "nextafterf128",
+            "powf128",
+            "remainderf128",
         ],
     ),
+    (
+        // `(f16, f16, f16) -> f16`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16, Ty::F16, Ty::F16], returns: &[Ty::F16] },
+        None,
+        &["fmaf16"],
+    ),
     (
         // `(f32, f32, f32) -> f32`
         FloatTy::F32,
@@ -225,19 +386,152 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         None,
         &["fmaf128"],
     ),
+    (
+        // `(f32) -> f16`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32], returns: &[Ty::F16] },
+        None,
+        &["f16sqrtf32"],
+    ),
+    (
+        // `(f64) -> f32`
+        FloatTy::F64,
+        Signature { args: &[Ty::F64], returns: &[Ty::F32] },
+        None,
+        &["fsqrt"],
+    ),
+    (
+        // `(f128) -> f64`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128], returns: &[Ty::F64] },
+        None,
+        &["f64sqrtf128"],
+    ),
+    (
+        // `(f32, f32) -> f16`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32, Ty::F32], returns: &[Ty::F16] },
+        None,
+        &["f16addf32", "f16divf32", "f16mulf32", "f16subf32"],
+    ),
+    (
+        // `(f64, f64) -> f32`
+        FloatTy::F64,
+        Signature { args: &[Ty::F64, Ty::F64], returns: &[Ty::F32] },
+        None,
+        &["fadd", "fdiv", "fmul", "fsub"],
+    ),
+    (
+        // `(f128, f128) -> f64`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128, Ty::F128], returns: &[Ty::F64] },
+        None,
+        &["f64addf128", "f64divf128", "f64mulf128", "f64subf128"],
+    ),
+    (
+        // `(f32, f32, f32) -> f16`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32, Ty::F32, Ty::F32], returns: &[Ty::F16] },
+        None,
+        &["f16fmaf32"],
+    ),
+    (
+        // `(f64, f64, f64) -> f32`
+        FloatTy::F64,
+        Signature { args: &[Ty::F64, Ty::F64, Ty::F64], returns: &[Ty::F32] },
+        None,
+        &["ffma"],
+    ),
+    (
+        // `(f128, f128, f128) -> f64`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128, Ty::F128, Ty::F128], returns: &[Ty::F64] },
+        None,
+        &["f64fmaf128"],
+    ),
+    (
+        // `(f16) -> i32`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16], returns: &[Ty::I32] },
+        None,
+        &["ilogbf16", "issignalingf16"],
+    ),
     (
         // `(f32) -> i32`
         FloatTy::F32,
         Signature { args: &[Ty::F32], returns: &[Ty::I32] },
         None,
-        &["ilogbf"],
+        &["ilogbf", "issignalingf"],
     ),
     (
         // `(f64) -> i32`
         FloatTy::F64,
         Signature { args: &[Ty::F64], returns: &[Ty::I32] },
         None,
-        &["ilogb"],
+        &["ilogb", "issignaling"],
+    ),
+    (
+        // `(f128) -> i32`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128], returns: &[Ty::I32] },
+        None,
+        &["ilogbf128", "issignalingf128"],
+    ),
+    (
+        // `(f16) -> i64`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16], returns: &[Ty::I64] },
+        None,
+        &["llogbf16", "llrintf16", "llroundf16", "lrintf16", "lroundf16"],
+    ),
+    (
+        // `(f32) -> i64`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32], returns: &[Ty::I64] },
+        None,
+        &["llogbf", "llrintf", "llroundf", "lrintf", "lroundf"],
+    ),
+    (
+        // `(f64) -> i64`
+        FloatTy::F64,
+        Signature { args: &[Ty::F64], returns: &[Ty::I64] },
+        None,
+        &["llogb", "llrint", "llround", "lrint", "lround"],
+    ),
+    (
+        // `(f128) -> i64`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128], returns: &[Ty::I64] },
+        None,
+        &["llogbf128", "llrintf128", "llroundf128", "lrintf128", "lroundf128"],
+    ),
+    (
+        // `(f16, f16) -> i32`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16, Ty::F16], returns: &[Ty::I32] },
+        None,
+        &["totalorderf16", "totalordermagf16"],
+    ),
+    (
+        // `(f32, f32) -> i32`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32, Ty::F32], returns: &[Ty::I32] },
+        None,
+        &["totalorderf", "totalordermagf"],
+    ),
+    (
+        // `(f64, f64) -> i32`
+        FloatTy::F64,
+        Signature { args: &[Ty::F64, Ty::F64], returns: &[Ty::I32] },
+        None,
+        &["totalorder", "totalordermag"],
+    ),
+    (
+        // `(f128, f128) -> i32`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128, Ty::F128], returns: &[Ty::I32] },
+        None,
+        &["totalorderf128", "totalordermagf128"],
     ),
     (
         // `(i32, f32) -> f32`
@@ -253,6 +547,13 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         None,
         &["jn", "yn"],
     ),
+    (
+        // `(i32, f128) -> f128`
+        FloatTy::F128,
+        Signature { args: &[Ty::I32, Ty::F128], returns: &[Ty::F128] },
+        None,
+        &["jnf128", "ynf128"],
+    ),
     (
         // `(f16, i32) -> f16`
         FloatTy::F16,
@@ -281,6 +582,76 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         None,
         &["ldexpf128", "scalbnf128"],
     ),
+    (
+        // `(f16, i64) -> f16`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16, Ty::I64], returns: &[Ty::F16] },
+        None,
+        &["scalblnf16"],
+    ),
+    (
+        // `(f32, i64) -> f32`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32, Ty::I64], returns: &[Ty::F32] },
+        None,
+        &["compoundnf", "pownf", "rootnf", "scalblnf"],
+    ),
+    (
+        // `(f64, i64) -> f64`
+        FloatTy::F64,
+        Signature { args: &[Ty::F64, Ty::I64], returns: &[Ty::F64] },
+        None,
+        &["compoundn", "pown", "rootn", "scalbln"],
+    ),
+    (
+        // `(f128, i64) -> f128`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128, Ty::I64], returns: &[Ty::F128] },
+        None,
+        &["scalblnf128"],
+    ),
+    (
+        // `(f32, f64) -> f32`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32, Ty::F64], returns: &[Ty::F32] },
+        None,
+        &["nexttowardf"],
+    ),
+    (
+        // `(f16, Round, u32) -> f16`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16, Ty::Round, Ty::U32], returns: &[Ty::F16] },
+        None,
+        &["fromfpf16", "fromfpxf16", "ufromfpf16", "ufromfpxf16"],
+    ),
+    (
+        // `(f32, Round, u32) -> f32`
+        FloatTy::F32,
+        Signature { args: &[Ty::F32, Ty::Round, Ty::U32], returns: &[Ty::F32] },
+        None,
+        &["fromfpf", "fromfpxf", "ufromfpf", "ufromfpxf"],
+    ),
+    (
+        // `(f64, Round, u32) -> f64`
+        FloatTy::F64,
+        Signature { args: &[Ty::F64, Ty::Round, Ty::U32], returns: &[Ty::F64] },
+        None,
+        &["fromfp", "fromfpx", "ufromfp", "ufromfpx"],
+    ),
+    (
+        // `(f128, Round, u32) -> f128`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128, Ty::Round, Ty::U32], returns: &[Ty::F128] },
+        None,
+        &["fromfpf128", "fromfpxf128", "ufromfpf128", "ufromfpxf128"],
+    ),
+    (
+        // `(f16, &mut f16) -> f16` as `(f16) -> (f16, f16)`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16], returns: &[Ty::F16, Ty::F16] },
+        Some(Signature { args: &[Ty::F16, Ty::MutF16], returns: &[Ty::F16] }),
+        &["modff16"],
+    ),
     (
         // `(f32, &mut f32) -> f32` as `(f32) -> (f32, f32)`
         FloatTy::F32,
@@ -295,6 +666,20 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         Some(Signature { args: &[Ty::F64, Ty::MutF64], returns: &[Ty::F64] }),
         &["modf"],
     ),
+    (
+        // `(f128, &mut f128) -> f128` as `(f128) -> (f128, f128)`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128], returns: &[Ty::F128, Ty::F128] },
+        Some(Signature { args: &[Ty::F128, Ty::MutF128], returns: &[Ty::F128] }),
+        &["modff128"],
+    ),
+    (
+        // `(f16, &mut c_int) -> f16` as `(f16) -> (f16, i32)`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16], returns: &[Ty::F16, Ty::I32] },
+        Some(Signature { args: &[Ty::F16, Ty::MutCInt], returns: &[Ty::F16] }),
+        &["frexpf16"],
+    ),
     (
         // `(f32, &mut c_int) -> f32` as `(f32) -> (f32, i32)`
         FloatTy::F32,
@@ -309,6 +694,20 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         Some(Signature { args: &[Ty::F64, Ty::MutCInt], returns: &[Ty::F64] }),
         &["frexp", "lgamma_r"],
     ),
+    (
+        // `(f128, &mut c_int) -> f128` as `(f128) -> (f128, i32)`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128], returns: &[Ty::F128, Ty::I32] },
+        Some(Signature { args: &[Ty::F128, Ty::MutCInt], returns: &[Ty::F128] }),
+        &["frexpf128", "lgammaf128_r"],
+    ),
+    (
+        // `(f16, f16, &mut c_int) -> f16` as `(f16, f16) -> (f16, i32)`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16, Ty::F16], returns: &[Ty::F16, Ty::I32] },
+        Some(Signature { args: &[Ty::F16, Ty::F16, Ty::MutCInt], returns: &[Ty::F16] }),
+        &["remquof16"],
+    ),
     (
         // `(f32, f32, &mut c_int) -> f32` as `(f32, f32) -> (f32, i32)`
         FloatTy::F32,
@@ -323,19 +722,40 @@ const ALL_OPERATIONS_NESTED: &[(FloatTy, Signature, Option<Signature>, &[&str])]
         Some(Signature { args: &[Ty::F64, Ty::F64, Ty::MutCInt], returns: &[Ty::F64] }),
         &["remquo"],
     ),
+    (
+        // `(f128, f128, &mut c_int) -> f128` as `(f128, f128) -> (f128, i32)`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128, Ty::F128], returns: &[Ty::F128, Ty::I32] },
+        Some(Signature { args: &[Ty::F128, Ty::F128, Ty::MutCInt], returns: &[Ty::F128] }),
+        &["remquof128"],
+    ),
+    (
+        // `(f16, &mut f16, &mut f16)` as `(f16) -> (f16, f16)`
+        FloatTy::F16,
+        Signature { args: &[Ty::F16], returns: &[Ty::F16, Ty::F16] },
+        Some(Signature { args: &[Ty::F16, Ty::MutF16, Ty::MutF16], returns: &[] }),
+        &["sincosf16", "sincospif16"],
+    ),
     (
         // `(f32, &mut f32, &mut f32)` as `(f32) -> (f32, f32)`
         FloatTy::F32,
         Signature { args: &[Ty::F32], returns: &[Ty::F32, Ty::F32] },
         Some(Signature { args: &[Ty::F32, Ty::MutF32, Ty::MutF32], returns: &[] }),
-        &["sincosf"],
+        &["sincosf", "sincospif"],
     ),
     (
         // `(f64, &mut f64, &mut f64)` as `(f64) -> (f64, f64)`
         FloatTy::F64,
         Signature { args: &[Ty::F64], returns: &[Ty::F64, Ty::F64] },
         Some(Signature { args: &[Ty::F64, Ty::MutF64, Ty::MutF64], returns: &[] }),
-        &["sincos"],
+        &["sincos", "sincospi"],
+    ),
+    (
+        // `(f128, &mut f128, &mut f128)` as `(f128) -> (f128, f128)`
+        FloatTy::F128,
+        Signature { args: &[Ty::F128], returns: &[Ty::F128, Ty::F128] },
+        Some(Signature { args: &[Ty::F128, Ty::MutF128, Ty::MutF128], returns: &[] }),
+        &["sincosf128", "sincospif128"],
     ),
 ];
 
@@ -348,7 +768,10 @@ pub enum Ty {
     F64,
     F128,
     I32,
+    I64,
+    U32,
     CInt,
+    Round,
     MutF16,
     MutF32,
     MutF64,
@@ -375,7 +798,10 @@ impl fmt::Display for Ty {
             Ty::F64 => "f64",
             Ty::F128 => "f128",
             Ty::I32 => "i32",
+            Ty::I64 => "i64",
+            Ty::U32 => "u32",
             Ty::CInt => "::core::ffi::c_int",
+            Ty::Round => "::libm::Round",
             Ty::MutF16 => "&mut f16",
             Ty::MutF32 => "&mut f32",
             Ty::MutF64 => "&mut f64",
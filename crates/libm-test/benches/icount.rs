@@ -149,65 +149,150 @@ main!(
     // verify-apilist-start
     // verify-sorted-start
     icount_bench_acos_group,
+    icount_bench_acosf128_group,
+    icount_bench_acosf16_group,
     icount_bench_acosf_group,
     icount_bench_acosh_group,
+    icount_bench_acoshf128_group,
+    icount_bench_acoshf16_group,
     icount_bench_acoshf_group,
+    icount_bench_acospi_group,
+    icount_bench_acospif128_group,
+    icount_bench_acospif16_group,
+    icount_bench_acospif_group,
     icount_bench_asin_group,
+    icount_bench_asinf128_group,
+    icount_bench_asinf16_group,
     icount_bench_asinf_group,
     icount_bench_asinh_group,
+    icount_bench_asinhf128_group,
+    icount_bench_asinhf16_group,
     icount_bench_asinhf_group,
+    icount_bench_asinpi_group,
+    icount_bench_asinpif128_group,
+    icount_bench_asinpif16_group,
+    icount_bench_asinpif_group,
     icount_bench_atan2_group,
+    icount_bench_atan2f128_group,
+    icount_bench_atan2f16_group,
     icount_bench_atan2f_group,
+    icount_bench_atan2pi_group,
+    icount_bench_atan2pif128_group,
+    icount_bench_atan2pif16_group,
+    icount_bench_atan2pif_group,
     icount_bench_atan_group,
+    icount_bench_atanf128_group,
+    icount_bench_atanf16_group,
     icount_bench_atanf_group,
     icount_bench_atanh_group,
+    icount_bench_atanhf128_group,
+    icount_bench_atanhf16_group,
     icount_bench_atanhf_group,
+    icount_bench_atanpi_group,
+    icount_bench_atanpif128_group,
+    icount_bench_atanpif16_group,
+    icount_bench_atanpif_group,
+    icount_bench_canonicalize_group,
+    icount_bench_canonicalizef128_group,
+    icount_bench_canonicalizef16_group,
+    icount_bench_canonicalizef_group,
     icount_bench_cbrt_group,
+    icount_bench_cbrtf128_group,
+    icount_bench_cbrtf16_group,
     icount_bench_cbrtf_group,
     icount_bench_ceil_group,
     icount_bench_ceilf128_group,
     icount_bench_ceilf16_group,
     icount_bench_ceilf_group,
+    icount_bench_compoundn_group,
+    icount_bench_compoundnf_group,
     icount_bench_copysign_group,
     icount_bench_copysignf128_group,
     icount_bench_copysignf16_group,
     icount_bench_copysignf_group,
     icount_bench_cos_group,
+    icount_bench_cosf128_group,
+    icount_bench_cosf16_group,
     icount_bench_cosf_group,
     icount_bench_cosh_group,
+    icount_bench_coshf128_group,
+    icount_bench_coshf16_group,
     icount_bench_coshf_group,
+    icount_bench_cospi_group,
+    icount_bench_cospif128_group,
+    icount_bench_cospif16_group,
+    icount_bench_cospif_group,
     icount_bench_erf_group,
     icount_bench_erfc_group,
+    icount_bench_erfcf128_group,
+    icount_bench_erfcf16_group,
     icount_bench_erfcf_group,
+    icount_bench_erff128_group,
+    icount_bench_erff16_group,
     icount_bench_erff_group,
     icount_bench_exp10_group,
+    icount_bench_exp10f16_group,
     icount_bench_exp10f_group,
+    icount_bench_exp10m1_group,
+    icount_bench_exp10m1f_group,
     icount_bench_exp2_group,
+    icount_bench_exp2f128_group,
+    icount_bench_exp2f16_group,
     icount_bench_exp2f_group,
+    icount_bench_exp2m1_group,
+    icount_bench_exp2m1f_group,
     icount_bench_exp_group,
+    icount_bench_expf128_group,
+    icount_bench_expf16_group,
     icount_bench_expf_group,
     icount_bench_expm1_group,
+    icount_bench_expm1f128_group,
+    icount_bench_expm1f16_group,
     icount_bench_expm1f_group,
+    icount_bench_f16addf32_group,
+    icount_bench_f16divf32_group,
+    icount_bench_f16fmaf32_group,
+    icount_bench_f16mulf32_group,
+    icount_bench_f16sqrtf32_group,
+    icount_bench_f16subf32_group,
+    icount_bench_f64addf128_group,
+    icount_bench_f64divf128_group,
+    icount_bench_f64fmaf128_group,
+    icount_bench_f64mulf128_group,
+    icount_bench_f64sqrtf128_group,
+    icount_bench_f64subf128_group,
     icount_bench_fabs_group,
     icount_bench_fabsf128_group,
     icount_bench_fabsf16_group,
     icount_bench_fabsf_group,
+    icount_bench_fadd_group,
     icount_bench_fdim_group,
     icount_bench_fdimf128_group,
     icount_bench_fdimf16_group,
     icount_bench_fdimf_group,
+    icount_bench_fdiv_group,
+    icount_bench_ffma_group,
     icount_bench_floor_group,
     icount_bench_floorf128_group,
     icount_bench_floorf16_group,
     icount_bench_floorf_group,
     icount_bench_fma_group,
     icount_bench_fmaf128_group,
+    icount_bench_fmaf16_group,
     icount_bench_fmaf_group,
     icount_bench_fmax_group,
     icount_bench_fmaxf128_group,
     icount_bench_fmaxf16_group,
     icount_bench_fmaxf_group,
     icount_bench_fmaximum_group,
+    icount_bench_fmaximum_mag_group,
+    icount_bench_fmaximum_mag_num_group,
+    icount_bench_fmaximum_mag_numf128_group,
+    icount_bench_fmaximum_mag_numf16_group,
+    icount_bench_fmaximum_mag_numf_group,
+    icount_bench_fmaximum_magf128_group,
+    icount_bench_fmaximum_magf16_group,
+    icount_bench_fmaximum_magf_group,
     icount_bench_fmaximum_num_group,
     icount_bench_fmaximum_numf128_group,
     icount_bench_fmaximum_numf16_group,
@@ -220,6 +305,14 @@ main!(
     icount_bench_fminf16_group,
     icount_bench_fminf_group,
     icount_bench_fminimum_group,
+    icount_bench_fminimum_mag_group,
+    icount_bench_fminimum_mag_num_group,
+    icount_bench_fminimum_mag_numf128_group,
+    icount_bench_fminimum_mag_numf16_group,
+    icount_bench_fminimum_mag_numf_group,
+    icount_bench_fminimum_magf128_group,
+    icount_bench_fminimum_magf16_group,
+    icount_bench_fminimum_magf_group,
     icount_bench_fminimum_num_group,
     icount_bench_fminimum_numf128_group,
     icount_bench_fminimum_numf16_group,
@@ -231,17 +324,45 @@ main!(
     icount_bench_fmodf128_group,
     icount_bench_fmodf16_group,
     icount_bench_fmodf_group,
+    icount_bench_fmul_group,
     icount_bench_frexp_group,
+    icount_bench_frexpf128_group,
+    icount_bench_frexpf16_group,
     icount_bench_frexpf_group,
+    icount_bench_fromfp_group,
+    icount_bench_fromfpf128_group,
+    icount_bench_fromfpf16_group,
+    icount_bench_fromfpf_group,
+    icount_bench_fromfpx_group,
+    icount_bench_fromfpxf128_group,
+    icount_bench_fromfpxf16_group,
+    icount_bench_fromfpxf_group,
+    icount_bench_fsqrt_group,
+    icount_bench_fsub_group,
+    icount_bench_getpayload_group,
+    icount_bench_getpayloadf128_group,
+    icount_bench_getpayloadf16_group,
+    icount_bench_getpayloadf_group,
     icount_bench_hypot_group,
+    icount_bench_hypotf128_group,
+    icount_bench_hypotf16_group,
     icount_bench_hypotf_group,
     icount_bench_ilogb_group,
+    icount_bench_ilogbf128_group,
+    icount_bench_ilogbf16_group,
     icount_bench_ilogbf_group,
+    icount_bench_issignaling_group,
+    icount_bench_issignalingf128_group,
+    icount_bench_issignalingf16_group,
+    icount_bench_issignalingf_group,
     icount_bench_j0_group,
+    icount_bench_j0f128_group,
     icount_bench_j0f_group,
     icount_bench_j1_group,
+    icount_bench_j1f128_group,
     icount_bench_j1f_group,
     icount_bench_jn_group,
+    icount_bench_jnf128_group,
     icount_bench_jnf_group,
     icount_bench_ldexp_group,
     icount_bench_ldexpf128_group,
@@ -249,30 +370,101 @@ main!(
     icount_bench_ldexpf_group,
     icount_bench_lgamma_group,
     icount_bench_lgamma_r_group,
+    icount_bench_lgammaf128_group,
+    icount_bench_lgammaf128_r_group,
+    icount_bench_lgammaf16_group,
     icount_bench_lgammaf_group,
     icount_bench_lgammaf_r_group,
+    icount_bench_llogb_group,
+    icount_bench_llogbf128_group,
+    icount_bench_llogbf16_group,
+    icount_bench_llogbf_group,
+    icount_bench_llrint_group,
+    icount_bench_llrintf128_group,
+    icount_bench_llrintf16_group,
+    icount_bench_llrintf_group,
+    icount_bench_llround_group,
+    icount_bench_llroundf128_group,
+    icount_bench_llroundf16_group,
+    icount_bench_llroundf_group,
     icount_bench_log10_group,
+    icount_bench_log10f128_group,
+    icount_bench_log10f16_group,
     icount_bench_log10f_group,
+    icount_bench_log10p1_group,
+    icount_bench_log10p1f_group,
     icount_bench_log1p_group,
+    icount_bench_log1pf128_group,
+    icount_bench_log1pf16_group,
     icount_bench_log1pf_group,
     icount_bench_log2_group,
+    icount_bench_log2f128_group,
+    icount_bench_log2f16_group,
     icount_bench_log2f_group,
+    icount_bench_log2p1_group,
+    icount_bench_log2p1f_group,
     icount_bench_log_group,
+    icount_bench_logb_group,
+    icount_bench_logbf128_group,
+    icount_bench_logbf16_group,
+    icount_bench_logbf_group,
+    icount_bench_logf128_group,
+    icount_bench_logf16_group,
     icount_bench_logf_group,
+    icount_bench_logp1_group,
+    icount_bench_logp1f_group,
+    icount_bench_lrint_group,
+    icount_bench_lrintf128_group,
+    icount_bench_lrintf16_group,
+    icount_bench_lrintf_group,
+    icount_bench_lround_group,
+    icount_bench_lroundf128_group,
+    icount_bench_lroundf16_group,
+    icount_bench_lroundf_group,
     icount_bench_modf_group,
+    icount_bench_modff128_group,
+    icount_bench_modff16_group,
     icount_bench_modff_group,
+    icount_bench_nearbyint_group,
+    icount_bench_nearbyintf128_group,
+    icount_bench_nearbyintf16_group,
+    icount_bench_nearbyintf_group,
     icount_bench_nextafter_group,
+    icount_bench_nextafterf128_group,
+    icount_bench_nextafterf16_group,
     icount_bench_nextafterf_group,
+    icount_bench_nextdown_group,
+    icount_bench_nextdownf128_group,
+    icount_bench_nextdownf16_group,
+    icount_bench_nextdownf_group,
+    icount_bench_nexttoward_group,
+    icount_bench_nexttowardf_group,
+    icount_bench_nextup_group,
+    icount_bench_nextupf128_group,
+    icount_bench_nextupf16_group,
+    icount_bench_nextupf_group,
     icount_bench_pow_group,
+    icount_bench_powf128_group,
+    icount_bench_powf16_group,
     icount_bench_powf_group,
+    icount_bench_pown_group,
+    icount_bench_pownf_group,
+    icount_bench_powr_group,
+    icount_bench_powrf_group,
     icount_bench_remainder_group,
+    icount_bench_remainderf128_group,
+    icount_bench_remainderf16_group,
     icount_bench_remainderf_group,
     icount_bench_remquo_group,
+    icount_bench_remquof128_group,
+    icount_bench_remquof16_group,
     icount_bench_remquof_group,
     icount_bench_rint_group,
     icount_bench_rintf128_group,
     icount_bench_rintf16_group,
     icount_bench_rintf_group,
+    icount_bench_rootn_group,
+    icount_bench_rootnf_group,
     icount_bench_round_group,
     icount_bench_roundeven_group,
     icount_bench_roundevenf128_group,
@@ -281,35 +473,92 @@ main!(
     icount_bench_roundf128_group,
     icount_bench_roundf16_group,
     icount_bench_roundf_group,
+    icount_bench_rsqrt_group,
+    icount_bench_rsqrtf_group,
+    icount_bench_scalbln_group,
+    icount_bench_scalblnf128_group,
+    icount_bench_scalblnf16_group,
+    icount_bench_scalblnf_group,
     icount_bench_scalbn_group,
     icount_bench_scalbnf128_group,
     icount_bench_scalbnf16_group,
     icount_bench_scalbnf_group,
+    icount_bench_setpayload_group,
+    icount_bench_setpayloadf128_group,
+    icount_bench_setpayloadf16_group,
+    icount_bench_setpayloadf_group,
+    icount_bench_setpayloadsig_group,
+    icount_bench_setpayloadsigf128_group,
+    icount_bench_setpayloadsigf16_group,
+    icount_bench_setpayloadsigf_group,
     icount_bench_sin_group,
     icount_bench_sincos_group,
+    icount_bench_sincosf128_group,
+    icount_bench_sincosf16_group,
     icount_bench_sincosf_group,
+    icount_bench_sincospi_group,
+    icount_bench_sincospif128_group,
+    icount_bench_sincospif16_group,
+    icount_bench_sincospif_group,
+    icount_bench_sinf128_group,
+    icount_bench_sinf16_group,
     icount_bench_sinf_group,
     icount_bench_sinh_group,
+    icount_bench_sinhf128_group,
+    icount_bench_sinhf16_group,
     icount_bench_sinhf_group,
+    icount_bench_sinpi_group,
+    icount_bench_sinpif128_group,
+    icount_bench_sinpif16_group,
+    icount_bench_sinpif_group,
     icount_bench_sqrt_group,
     icount_bench_sqrtf128_group,
     icount_bench_sqrtf16_group,
     icount_bench_sqrtf_group,
     icount_bench_tan_group,
+    icount_bench_tanf128_group,
+    icount_bench_tanf16_group,
     icount_bench_tanf_group,
     icount_bench_tanh_group,
+    icount_bench_tanhf128_group,
+    icount_bench_tanhf16_group,
     icount_bench_tanhf_group,
+    icount_bench_tanpi_group,
+    icount_bench_tanpif128_group,
+    icount_bench_tanpif16_group,
+    icount_bench_tanpif_group,
     icount_bench_tgamma_group,
+    icount_bench_tgammaf128_group,
+    icount_bench_tgammaf16_group,
     icount_bench_tgammaf_group,
+    icount_bench_totalorder_group,
+    icount_bench_totalorderf128_group,
+    icount_bench_totalorderf16_group,
+    icount_bench_totalorderf_group,
+    icount_bench_totalordermag_group,
+    icount_bench_totalordermagf128_group,
+    icount_bench_totalordermagf16_group,
+    icount_bench_totalordermagf_group,
     icount_bench_trunc_group,
     icount_bench_truncf128_group,
     icount_bench_truncf16_group,
     icount_bench_truncf_group,
+    icount_bench_ufromfp_group,
+    icount_bench_ufromfpf128_group,
+    icount_bench_ufromfpf16_group,
+    icount_bench_ufromfpf_group,
+    icount_bench_ufromfpx_group,
+    icount_bench_ufromfpxf128_group,
+    icount_bench_ufromfpxf16_group,
+    icount_bench_ufromfpxf_group,
     icount_bench_y0_group,
+    icount_bench_y0f128_group,
     icount_bench_y0f_group,
     icount_bench_y1_group,
+    icount_bench_y1f128_group,
     icount_bench_y1f_group,
     icount_bench_yn_group,
+    icount_bench_ynf128_group,
     icount_bench_ynf_group,
     // verify-sorted-end
     // verify-apilist-end
@@ -117,20 +117,105 @@ libm_macros::for_each_function! {
         exp10 | exp10f | exp2 | exp2f => (true, Some(musl_math_sys::MACRO_FN_NAME)),
 
         // Musl does not provide `f16` and `f128` functions
-        ceilf128
+        acosf128
+        | acosf16
+        | acoshf128
+        | acoshf16
+        | acospi
+        | acospif
+        | acospif128
+        | acospif16
+        | asinf128
+        | asinf16
+        | asinhf128
+        | asinhf16
+        | asinpi
+        | asinpif
+        | asinpif128
+        | asinpif16
+        | atan2f128
+        | atan2f16
+        | atan2pi
+        | atan2pif
+        | atan2pif128
+        | atan2pif16
+        | atanf128
+        | atanf16
+        | atanhf128
+        | atanhf16
+        | atanpi
+        | atanpif
+        | atanpif128
+        | atanpif16
+        | canonicalize
+        | canonicalizef
+        | canonicalizef128
+        | canonicalizef16
+        | cbrtf128
+        | cbrtf16
+        | ceilf128
         | ceilf16
+        | compoundn
+        | compoundnf
         | copysignf128
         | copysignf16
+        | cosf128
+        | cosf16
+        | coshf128
+        | coshf16
+        | cospi
+        | cospif
+        | cospif128
+        | cospif16
+        | erfcf128
+        | erfcf16
+        | erff128
+        | erff16
+        | exp10f16
+        | exp10m1
+        | exp10m1f
+        | exp2f128
+        | exp2f16
+        | exp2m1
+        | exp2m1f
+        | expf128
+        | expf16
+        | expm1f128
+        | expm1f16
+        | f16addf32
+        | f16divf32
+        | f16fmaf32
+        | f16mulf32
+        | f16sqrtf32
+        | f16subf32
+        | f64addf128
+        | f64divf128
+        | f64fmaf128
+        | f64mulf128
+        | f64sqrtf128
+        | f64subf128
         | fabsf128
         | fabsf16
+        | fadd
         | fdimf128
         | fdimf16
+        | fdiv
+        | ffma
         | floorf128
         | floorf16
         | fmaf128
+        | fmaf16
         | fmaxf128
         | fmaxf16
         | fmaximum
+        | fmaximum_mag
+        | fmaximum_mag_num
+        | fmaximum_mag_numf
+        | fmaximum_mag_numf128
+        | fmaximum_mag_numf16
+        | fmaximum_magf
+        | fmaximum_magf128
+        | fmaximum_magf16
         | fmaximum_num
         | fmaximum_numf
         | fmaximum_numf128
@@ -141,6 +226,14 @@ libm_macros::for_each_function! {
         | fminf128
         | fminf16
         | fminimum
+        | fminimum_mag
+        | fminimum_mag_num
+        | fminimum_mag_numf
+        | fminimum_mag_numf128
+        | fminimum_mag_numf16
+        | fminimum_magf
+        | fminimum_magf128
+        | fminimum_magf16
         | fminimum_num
         | fminimum_numf
         | fminimum_numf128
@@ -150,22 +243,174 @@ libm_macros::for_each_function! {
         | fminimumf16
         | fmodf128
         | fmodf16
+        | fmul
+        | frexpf128
+        | frexpf16
+        | fromfp
+        | fromfpf
+        | fromfpf128
+        | fromfpf16
+        | fromfpx
+        | fromfpxf
+        | fromfpxf128
+        | fromfpxf16
+        | fsqrt
+        | fsub
+        | getpayload
+        | getpayloadf
+        | getpayloadf128
+        | getpayloadf16
+        | hypotf128
+        | hypotf16
+        | ilogbf128
+        | ilogbf16
+        | issignaling
+        | issignalingf
+        | issignalingf128
+        | issignalingf16
+        | j0f128
+        | j1f128
+        | jnf128
         | ldexpf128
         | ldexpf16
+        | lgammaf128
+        | lgammaf128_r
+        | lgammaf16
+        | llogb
+        | llogbf
+        | llogbf128
+        | llogbf16
+        | llrint
+        | llrintf
+        | llrintf128
+        | llrintf16
+        | llround
+        | llroundf
+        | llroundf128
+        | llroundf16
+        | log10f128
+        | log10f16
+        | log10p1
+        | log10p1f
+        | log1pf128
+        | log1pf16
+        | log2f128
+        | log2f16
+        | log2p1
+        | log2p1f
+        | logbf128
+        | logbf16
+        | logf128
+        | logf16
+        | logp1
+        | logp1f
+        | lrint
+        | lrintf
+        | lrintf128
+        | lrintf16
+        | lround
+        | lroundf
+        | lroundf128
+        | lroundf16
+        | modff128
+        | modff16
+        | nearbyintf128
+        | nearbyintf16
+        | nextafterf128
+        | nextafterf16
+        | nextdown
+        | nextdownf
+        | nextdownf128
+        | nextdownf16
+        | nexttoward
+        | nexttowardf
+        | nextup
+        | nextupf
+        | nextupf128
+        | nextupf16
+        | powf128
+        | powf16
+        | pown
+        | pownf
+        | powr
+        | powrf
+        | remainderf128
+        | remainderf16
+        | remquof128
+        | remquof16
         | rintf128
         | rintf16
+        | rootn
+        | rootnf
         | roundeven
         | roundevenf
         | roundevenf128
         | roundevenf16
         | roundf128
         | roundf16
+        | rsqrt
+        | rsqrtf
+        | scalbln
+        | scalblnf
+        | scalblnf128
+        | scalblnf16
         | scalbnf128
         | scalbnf16
+        | setpayload
+        | setpayloadf
+        | setpayloadf128
+        | setpayloadf16
+        | setpayloadsig
+        | setpayloadsigf
+        | setpayloadsigf128
+        | setpayloadsigf16
+        | sincosf128
+        | sincosf16
+        | sincospi
+        | sincospif
+        | sincospif128
+        | sincospif16
+        | sinf128
+        | sinf16
+        | sinhf128
+        | sinhf16
+        | sinpi
+        | sinpif
+        | sinpif128
+        | sinpif16
         | sqrtf128
         | sqrtf16
+        | tanf128
+        | tanf16
+        | tanhf128
+        | tanhf16
+        | tanpi
+        | tanpif
+        | tanpif128
+        | tanpif16
+        | tgammaf128
+        | tgammaf16
+        | totalorder
+        | totalorderf
+        | totalorderf128
+        | totalorderf16
+        | totalordermag
+        | totalordermagf
+        | totalordermagf128
+        | totalordermagf16
         | truncf128
-        | truncf16 => (false, None),
+        | truncf16
+        | ufromfp
+        | ufromfpf
+        | ufromfpf128
+        | ufromfpf16
+        | ufromfpx
+        | ufromfpxf
+        | ufromfpxf128
+        | ufromfpxf16
+        | y0f128
+        | y1f128
+        | ynf128 => (false, None),
 
         // By default we never skip (false) and always have a musl function available
         _ => (false, Some(musl_math_sys::MACRO_FN_NAME))
@@ -3,7 +3,7 @@
 use std::fmt;
 use std::ops::Bound;
 
-use libm::support::Int;
+use libm::support::{Int, MinInt};
 
 use crate::{BaseName, Float, FloatExt, Identifier};
 
@@ -153,6 +153,17 @@ impl<F: Float, I: Int> EitherPrim<Domain<F>, Domain<I>> {
     }
     .into_prim_float()];
 
+    /// Domain for `sinpi`, `cospi`, and `tanpi`
+    const TRIG_PI: [Self; 1] = [Domain {
+        // These have exact results and poles at multiples of one half.
+        check_points: Some(|| {
+            let half = F::ONE / (F::ONE + F::ONE);
+            Box::new([F::NEG_ONE, -half, half, F::ONE].into_iter())
+        }),
+        ..Domain::UNBOUNDED
+    }
+    .into_prim_float()];
+
     /// Domain for `log` in various bases
     const LOG: [Self; 1] = Self::STRICTLY_POSITIVE;
 
@@ -164,6 +175,48 @@ impl<F: Float, I: Int> EitherPrim<Domain<F>, Domain<I>> {
     /// Domain for `sqrt`
     const SQRT: [Self; 1] = Self::POSITIVE;
 
+    /// Domain for functions that round to an integer, such as `lrint` and `fromfp`. For `fromfp`,
+    /// only the float has a domain; the other arguments are chosen by the generators.
+    const TO_INT: [Self; 1] = [Domain {
+        // Ties in each direction, and the edges of 32- and 64-bit integers.
+        check_points: Some(|| {
+            let pow2 = |e: u32| F::from_parts(false, F::EXP_BIAS + e, F::Int::ZERO);
+            let half = F::ONE / (F::ONE + F::ONE);
+            let int_edges = [31, 63].into_iter().filter(|&e| e <= F::EXP_BIAS).map(pow2);
+            let ties = [half, F::ONE + half, F::ONE + F::ONE + half];
+            Box::new(ties.into_iter().chain(int_edges).flat_map(|x| [x, -x]))
+        }),
+        ..Domain::UNBOUNDED
+    }
+    .into_prim_float()];
+
+    /// Domain for `setpayload`. Valid payloads are integers in `0..2^(SIG_BITS - 1)`, which are
+    /// rare among the generated values, so the boundaries are checked explicitly.
+    const PAYLOAD: [Self; 1] = [Domain {
+        check_points: Some(|| {
+            let limit = F::from_parts(false, F::EXP_BIAS + F::SIG_BITS - 1, F::Int::ZERO);
+            let ints = [F::ZERO, F::ONE, F::ONE + F::ONE, limit - F::ONE, limit];
+            Box::new(ints.into_iter().chain([
+                F::NEG_ZERO,
+                F::NEG_ONE,
+                limit - F::ONE / (F::ONE + F::ONE),
+            ]))
+        }),
+        ..Domain::UNBOUNDED
+    }
+    .into_prim_float()];
+
+    /// Domain for `powr`, which is only defined for nonnegative bases
+    const POWR: [Self; 2] =
+        [Domain::POSITIVE.into_prim_float(), Domain::UNBOUNDED.into_prim_float()];
+
+    /// Domain for `compoundn` i.e. `(1 + x)^n`
+    const COMPOUNDN: [Self; 2] = [
+        Domain { start: Bound::Included(F::NEG_ONE), end: Bound::Unbounded, check_points: None }
+            .into_prim_float(),
+        Domain::UNBOUNDED_INT.into_prim_int(),
+    ];
+
     /// Domain for `gamma`
     const GAMMA: [Self; 1] = [Domain {
         check_points: Some(|| {
@@ -199,63 +252,113 @@ pub fn get_domain<F: Float, I: Int>(
     let x = match id.base_name() {
         BaseName::Acos => &EitherPrim::INVERSE_TRIG_PERIODIC[..],
         BaseName::Acosh => &EitherPrim::ACOSH[..],
+        BaseName::Acospi => &EitherPrim::INVERSE_TRIG_PERIODIC[..],
         BaseName::Asin => &EitherPrim::INVERSE_TRIG_PERIODIC[..],
         BaseName::Asinh => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Asinpi => &EitherPrim::INVERSE_TRIG_PERIODIC[..],
         BaseName::Atan => &EitherPrim::UNBOUNDED1[..],
         BaseName::Atan2 => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Atan2pi => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Canonicalize => &EitherPrim::UNBOUNDED1[..],
         BaseName::Cbrt => &EitherPrim::UNBOUNDED1[..],
         BaseName::Atanh => &EitherPrim::ATANH[..],
+        BaseName::Atanpi => &EitherPrim::UNBOUNDED1[..],
         BaseName::Ceil => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Compoundn => &EitherPrim::COMPOUNDN[..],
         BaseName::Cosh => &EitherPrim::UNBOUNDED1[..],
         BaseName::Copysign => &EitherPrim::UNBOUNDED2[..],
         BaseName::Cos => &EitherPrim::TRIG[..],
+        BaseName::Cospi => &EitherPrim::TRIG_PI[..],
         BaseName::Exp => &EitherPrim::UNBOUNDED1[..],
         BaseName::Erf => &EitherPrim::UNBOUNDED1[..],
         BaseName::Erfc => &EitherPrim::UNBOUNDED1[..],
         BaseName::Expm1 => &EitherPrim::UNBOUNDED1[..],
         BaseName::Exp10 => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Exp10m1 => &EitherPrim::UNBOUNDED1[..],
         BaseName::Exp2 => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Exp2m1 => &EitherPrim::UNBOUNDED1[..],
         BaseName::Frexp => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Fromfp => &EitherPrim::TO_INT[..],
+        BaseName::Fromfpx => &EitherPrim::TO_INT[..],
         BaseName::Fabs => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Fadd => &EitherPrim::UNBOUNDED2[..],
         BaseName::Fdim => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Fdiv => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Ffma => &EitherPrim::UNBOUNDED3[..],
         BaseName::Floor => &EitherPrim::UNBOUNDED1[..],
         BaseName::Fma => &EitherPrim::UNBOUNDED3[..],
         BaseName::Fmax => &EitherPrim::UNBOUNDED2[..],
         BaseName::Fmaximum => &EitherPrim::UNBOUNDED2[..],
+        BaseName::FmaximumMag => &EitherPrim::UNBOUNDED2[..],
+        BaseName::FmaximumMagNum => &EitherPrim::UNBOUNDED2[..],
         BaseName::FmaximumNum => &EitherPrim::UNBOUNDED2[..],
         BaseName::Fmin => &EitherPrim::UNBOUNDED2[..],
         BaseName::Fminimum => &EitherPrim::UNBOUNDED2[..],
+        BaseName::FminimumMag => &EitherPrim::UNBOUNDED2[..],
+        BaseName::FminimumMagNum => &EitherPrim::UNBOUNDED2[..],
         BaseName::FminimumNum => &EitherPrim::UNBOUNDED2[..],
         BaseName::Fmod => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Fmul => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Fsqrt => &EitherPrim::SQRT[..],
+        BaseName::Fsub => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Getpayload => &EitherPrim::UNBOUNDED1[..],
         BaseName::Hypot => &EitherPrim::UNBOUNDED2[..],
         BaseName::Ilogb => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Issignaling => &EitherPrim::UNBOUNDED1[..],
         BaseName::J0 => &EitherPrim::UNBOUNDED1[..],
         BaseName::J1 => &EitherPrim::UNBOUNDED1[..],
         BaseName::Jn => &EitherPrim::BESSEL_N[..],
         BaseName::Ldexp => &EitherPrim::UNBOUNDED_F_I[..],
         BaseName::Lgamma => &EitherPrim::LGAMMA[..],
         BaseName::LgammaR => &EitherPrim::LGAMMA[..],
+        BaseName::Llogb => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Llrint => &EitherPrim::TO_INT[..],
+        BaseName::Llround => &EitherPrim::TO_INT[..],
         BaseName::Log => &EitherPrim::LOG[..],
         BaseName::Log10 => &EitherPrim::LOG[..],
+        BaseName::Log10p1 => &EitherPrim::LOG1P[..],
         BaseName::Log1p => &EitherPrim::LOG1P[..],
         BaseName::Log2 => &EitherPrim::LOG[..],
+        BaseName::Log2p1 => &EitherPrim::LOG1P[..],
+        BaseName::Logp1 => &EitherPrim::LOG1P[..],
+        BaseName::Logb => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Lrint => &EitherPrim::TO_INT[..],
+        BaseName::Lround => &EitherPrim::TO_INT[..],
         BaseName::Modf => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Nearbyint => &EitherPrim::UNBOUNDED1[..],
         BaseName::Nextafter => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Nextdown => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Nexttoward => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Nextup => &EitherPrim::UNBOUNDED1[..],
         BaseName::Pow => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Pown => &EitherPrim::UNBOUNDED_F_I[..],
+        BaseName::Powr => &EitherPrim::POWR[..],
         BaseName::Remainder => &EitherPrim::UNBOUNDED2[..],
         BaseName::Remquo => &EitherPrim::UNBOUNDED2[..],
         BaseName::Rint => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Rootn => &EitherPrim::UNBOUNDED_F_I[..],
         BaseName::Round => &EitherPrim::UNBOUNDED1[..],
         BaseName::Roundeven => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Rsqrt => &EitherPrim::SQRT[..],
+        BaseName::Scalbln => &EitherPrim::UNBOUNDED_F_I[..],
         BaseName::Scalbn => &EitherPrim::UNBOUNDED_F_I[..],
+        BaseName::Setpayload => &EitherPrim::PAYLOAD[..],
+        BaseName::Setpayloadsig => &EitherPrim::PAYLOAD[..],
         BaseName::Sin => &EitherPrim::TRIG[..],
         BaseName::Sincos => &EitherPrim::TRIG[..],
+        BaseName::Sincospi => &EitherPrim::TRIG_PI[..],
         BaseName::Sinh => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Sinpi => &EitherPrim::TRIG_PI[..],
         BaseName::Sqrt => &EitherPrim::SQRT[..],
         BaseName::Tan => &EitherPrim::TRIG[..],
         BaseName::Tanh => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Tanpi => &EitherPrim::TRIG_PI[..],
         BaseName::Tgamma => &EitherPrim::GAMMA[..],
+        BaseName::Totalorder => &EitherPrim::UNBOUNDED2[..],
+        BaseName::Totalordermag => &EitherPrim::UNBOUNDED2[..],
         BaseName::Trunc => &EitherPrim::UNBOUNDED1[..],
+        BaseName::Ufromfp => &EitherPrim::TO_INT[..],
+        BaseName::Ufromfpx => &EitherPrim::TO_INT[..],
         BaseName::Y0 => &EitherPrim::UNBOUNDED1[..],
         BaseName::Y1 => &EitherPrim::UNBOUNDED1[..],
         BaseName::Yn => &EitherPrim::BESSEL_N[..],
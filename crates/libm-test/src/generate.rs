@@ -5,6 +5,21 @@ pub mod edge_cases;
 pub mod random;
 pub mod spaced;
 
+use libm::Round;
+use libm::support::Float;
+
+/// Every rounding direction, which is how the `fromfp` family is always tested.
+const FROMFP_ROUNDING: [Round; 5] =
+    [Round::Positive, Round::Negative, Round::Zero, Round::NearestAway, Round::Nearest];
+
+/// Widths to test the `fromfp` family with: the edges of common integer types, and the point
+/// past which every finite `F` fits.
+fn fromfp_widths<F: Float>() -> [u32; 16] {
+    let sig = F::SIG_BITS;
+    let exp = F::EXP_MAX as u32;
+    [0, 1, 2, 8, 16, 31, 32, 33, 63, 64, 65, sig + 1, sig + 2, exp + 1, exp + 2, u32::MAX]
+}
+
 /// A wrapper to turn any iterator into an `ExactSizeIterator`. Asserts the final result to ensure
 /// the provided size was correct.
 #[derive(Debug)]
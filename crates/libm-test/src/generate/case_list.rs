@@ -6,9 +6,9 @@
 //!
 //! This is useful for adding regression tests or expected failures.
 
-use libm::hf64;
 #[cfg(f128_enabled)]
 use libm::hf128;
+use libm::{Round, hf32, hf64};
 
 use crate::{CheckBasis, CheckCtx, GeneratorKind, MathOp, op};
 
@@ -39,391 +39,1074 @@ fn acosf_cases() -> Vec<TestCase<op::acosf::Routine>> {
     vec![]
 }
 
-fn acosh_cases() -> Vec<TestCase<op::acosh::Routine>> {
+#[cfg(f128_enabled)]
+fn acosf128_cases() -> Vec<TestCase<op::acosf128::Routine>> {
     vec![]
 }
 
-fn acoshf_cases() -> Vec<TestCase<op::acoshf::Routine>> {
+#[cfg(f16_enabled)]
+fn acosf16_cases() -> Vec<TestCase<op::acosf16::Routine>> {
     vec![]
 }
 
-fn asin_cases() -> Vec<TestCase<op::asin::Routine>> {
+fn acosh_cases() -> Vec<TestCase<op::acosh::Routine>> {
     vec![]
 }
 
-fn asinf_cases() -> Vec<TestCase<op::asinf::Routine>> {
+fn acoshf_cases() -> Vec<TestCase<op::acoshf::Routine>> {
     vec![]
 }
 
-fn asinh_cases() -> Vec<TestCase<op::asinh::Routine>> {
+#[cfg(f128_enabled)]
+fn acoshf128_cases() -> Vec<TestCase<op::acoshf128::Routine>> {
     vec![]
 }
 
-fn asinhf_cases() -> Vec<TestCase<op::asinhf::Routine>> {
+#[cfg(f16_enabled)]
+fn acoshf16_cases() -> Vec<TestCase<op::acoshf16::Routine>> {
     vec![]
 }
 
-fn atan_cases() -> Vec<TestCase<op::atan::Routine>> {
-    vec![]
+fn acospi_cases() -> Vec<TestCase<op::acospi::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact results at the ends of the domain and at zero
+            ((1.0,), Some(0.0)),
+            ((-1.0,), Some(1.0)),
+            ((0.0,), Some(0.5)),
+            ((-0.0,), Some(0.5)),
+            ((0.5,), Some(hf64!("0x1.5555555555555p-2"))),
+            // Subnormal and tiny arguments round to 1/2
+            ((5e-324,), Some(0.5)),
+            ((-5e-324,), Some(0.5)),
+            ((1e-320,), Some(0.5)),
+            ((-1e-320,), Some(0.5)),
+            ((2.2e-308,), Some(0.5)),
+            ((-2.2e-308,), Some(0.5)),
+        ],
+    );
+    v
 }
 
-fn atan2_cases() -> Vec<TestCase<op::atan2::Routine>> {
+fn acospif_cases() -> Vec<TestCase<op::acospif::Routine>> {
     vec![]
 }
 
-fn atan2f_cases() -> Vec<TestCase<op::atan2f::Routine>> {
+#[cfg(f128_enabled)]
+fn acospif128_cases() -> Vec<TestCase<op::acospif128::Routine>> {
     vec![]
 }
 
-fn atanf_cases() -> Vec<TestCase<op::atanf::Routine>> {
+#[cfg(f16_enabled)]
+fn acospif16_cases() -> Vec<TestCase<op::acospif16::Routine>> {
     vec![]
 }
 
-fn atanh_cases() -> Vec<TestCase<op::atanh::Routine>> {
+fn asin_cases() -> Vec<TestCase<op::asin::Routine>> {
     vec![]
 }
 
-fn atanhf_cases() -> Vec<TestCase<op::atanhf::Routine>> {
+fn asinf_cases() -> Vec<TestCase<op::asinf::Routine>> {
     vec![]
 }
 
-fn cbrt_cases() -> Vec<TestCase<op::cbrt::Routine>> {
+#[cfg(f128_enabled)]
+fn asinf128_cases() -> Vec<TestCase<op::asinf128::Routine>> {
     vec![]
 }
 
-fn cbrtf_cases() -> Vec<TestCase<op::cbrtf::Routine>> {
+#[cfg(f16_enabled)]
+fn asinf16_cases() -> Vec<TestCase<op::asinf16::Routine>> {
     vec![]
 }
 
-fn ceil_cases() -> Vec<TestCase<op::ceil::Routine>> {
+fn asinh_cases() -> Vec<TestCase<op::asinh::Routine>> {
     vec![]
 }
 
-fn ceilf_cases() -> Vec<TestCase<op::ceilf::Routine>> {
+fn asinhf_cases() -> Vec<TestCase<op::asinhf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn ceilf128_cases() -> Vec<TestCase<op::ceilf128::Routine>> {
+fn asinhf128_cases() -> Vec<TestCase<op::asinhf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn ceilf16_cases() -> Vec<TestCase<op::ceilf16::Routine>> {
+fn asinhf16_cases() -> Vec<TestCase<op::asinhf16::Routine>> {
     vec![]
 }
 
-fn copysign_cases() -> Vec<TestCase<op::copysign::Routine>> {
-    vec![]
+fn asinpi_cases() -> Vec<TestCase<op::asinpi::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact results at the ends of the domain, and zeros keep their sign
+            ((1.0,), Some(0.5)),
+            ((-1.0,), Some(-0.5)),
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((0.5,), Some(hf64!("0x1.5555555555555p-3"))),
+        ],
+    );
+    v
 }
 
-fn copysignf_cases() -> Vec<TestCase<op::copysignf::Routine>> {
+fn asinpif_cases() -> Vec<TestCase<op::asinpif::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn copysignf128_cases() -> Vec<TestCase<op::copysignf128::Routine>> {
+fn asinpif128_cases() -> Vec<TestCase<op::asinpif128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn copysignf16_cases() -> Vec<TestCase<op::copysignf16::Routine>> {
+fn asinpif16_cases() -> Vec<TestCase<op::asinpif16::Routine>> {
     vec![]
 }
 
-fn cos_cases() -> Vec<TestCase<op::cos::Routine>> {
+fn atan_cases() -> Vec<TestCase<op::atan::Routine>> {
     vec![]
 }
 
-fn cosf_cases() -> Vec<TestCase<op::cosf::Routine>> {
+fn atan2_cases() -> Vec<TestCase<op::atan2::Routine>> {
     vec![]
 }
 
-fn cosh_cases() -> Vec<TestCase<op::cosh::Routine>> {
+fn atan2f_cases() -> Vec<TestCase<op::atan2f::Routine>> {
     vec![]
 }
 
-fn coshf_cases() -> Vec<TestCase<op::coshf::Routine>> {
+#[cfg(f128_enabled)]
+fn atan2f128_cases() -> Vec<TestCase<op::atan2f128::Routine>> {
     vec![]
 }
 
-fn erf_cases() -> Vec<TestCase<op::erf::Routine>> {
+#[cfg(f16_enabled)]
+fn atan2f16_cases() -> Vec<TestCase<op::atan2f16::Routine>> {
     vec![]
 }
 
-fn erfc_cases() -> Vec<TestCase<op::erfc::Routine>> {
+fn atan2pi_cases() -> Vec<TestCase<op::atan2pi::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Quadrant boundaries and diagonals are exact
+            ((0.0, -1.0), Some(1.0)),
+            ((-0.0, -1.0), Some(-1.0)),
+            ((-0.0, 1.0), Some(-0.0)),
+            ((1.0, 0.0), Some(0.5)),
+            ((1.0, 1.0), Some(0.25)),
+            ((-1.0, -1.0), Some(-0.75)),
+            ((f64::INFINITY, f64::NEG_INFINITY), Some(0.75)),
+        ],
+    );
+    v
+}
+
+fn atan2pif_cases() -> Vec<TestCase<op::atan2pif::Routine>> {
     vec![]
 }
 
-fn erfcf_cases() -> Vec<TestCase<op::erfcf::Routine>> {
+#[cfg(f128_enabled)]
+fn atan2pif128_cases() -> Vec<TestCase<op::atan2pif128::Routine>> {
     vec![]
 }
 
-fn erff_cases() -> Vec<TestCase<op::erff::Routine>> {
+#[cfg(f16_enabled)]
+fn atan2pif16_cases() -> Vec<TestCase<op::atan2pif16::Routine>> {
     vec![]
 }
 
-fn exp_cases() -> Vec<TestCase<op::exp::Routine>> {
+fn atanf_cases() -> Vec<TestCase<op::atanf::Routine>> {
     vec![]
 }
 
-fn exp10_cases() -> Vec<TestCase<op::exp10::Routine>> {
+#[cfg(f128_enabled)]
+fn atanf128_cases() -> Vec<TestCase<op::atanf128::Routine>> {
     vec![]
 }
 
-fn exp10f_cases() -> Vec<TestCase<op::exp10f::Routine>> {
+#[cfg(f16_enabled)]
+fn atanf16_cases() -> Vec<TestCase<op::atanf16::Routine>> {
     vec![]
 }
 
-fn exp2_cases() -> Vec<TestCase<op::exp2::Routine>> {
+fn atanh_cases() -> Vec<TestCase<op::atanh::Routine>> {
     vec![]
 }
 
-fn exp2f_cases() -> Vec<TestCase<op::exp2f::Routine>> {
+fn atanhf_cases() -> Vec<TestCase<op::atanhf::Routine>> {
     vec![]
 }
 
-fn expf_cases() -> Vec<TestCase<op::expf::Routine>> {
+#[cfg(f128_enabled)]
+fn atanhf128_cases() -> Vec<TestCase<op::atanhf128::Routine>> {
     vec![]
 }
 
-fn expm1_cases() -> Vec<TestCase<op::expm1::Routine>> {
+#[cfg(f16_enabled)]
+fn atanhf16_cases() -> Vec<TestCase<op::atanhf16::Routine>> {
     vec![]
 }
 
-fn expm1f_cases() -> Vec<TestCase<op::expm1f::Routine>> {
+fn atanpi_cases() -> Vec<TestCase<op::atanpi::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact results at infinities and at one
+            ((1.0,), Some(0.25)),
+            ((-1.0,), Some(-0.25)),
+            ((f64::INFINITY,), Some(0.5)),
+            ((-0.0,), Some(-0.0)),
+        ],
+    );
+    v
+}
+
+fn atanpif_cases() -> Vec<TestCase<op::atanpif::Routine>> {
     vec![]
 }
 
-fn fabs_cases() -> Vec<TestCase<op::fabs::Routine>> {
+#[cfg(f128_enabled)]
+fn atanpif128_cases() -> Vec<TestCase<op::atanpif128::Routine>> {
     vec![]
 }
 
-fn fabsf_cases() -> Vec<TestCase<op::fabsf::Routine>> {
+#[cfg(f16_enabled)]
+fn atanpif16_cases() -> Vec<TestCase<op::atanpif16::Routine>> {
+    vec![]
+}
+
+fn canonicalize_cases() -> Vec<TestCase<op::canonicalize::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Signaling NaNs are quieted, keeping the sign and payload
+            ((f64::from_bits(0x7ff0_0000_0000_0001),), Some(f64::from_bits(0x7ff8_0000_0000_0001))),
+            ((f64::from_bits(0xfff4_0000_0000_0000),), Some(f64::from_bits(0xfffc_0000_0000_0000))),
+            ((f64::from_bits(0x7ff8_0000_0000_002a),), Some(f64::from_bits(0x7ff8_0000_0000_002a))),
+            ((-0.0,), Some(-0.0)),
+        ],
+    );
+    v
+}
+
+fn canonicalizef_cases() -> Vec<TestCase<op::canonicalizef::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn fabsf128_cases() -> Vec<TestCase<op::fabsf128::Routine>> {
+fn canonicalizef128_cases() -> Vec<TestCase<op::canonicalizef128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn fabsf16_cases() -> Vec<TestCase<op::fabsf16::Routine>> {
+fn canonicalizef16_cases() -> Vec<TestCase<op::canonicalizef16::Routine>> {
     vec![]
 }
 
-fn fdim_cases() -> Vec<TestCase<op::fdim::Routine>> {
+fn cbrt_cases() -> Vec<TestCase<op::cbrt::Routine>> {
     vec![]
 }
 
-fn fdimf_cases() -> Vec<TestCase<op::fdimf::Routine>> {
+fn cbrtf_cases() -> Vec<TestCase<op::cbrtf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn fdimf128_cases() -> Vec<TestCase<op::fdimf128::Routine>> {
+fn cbrtf128_cases() -> Vec<TestCase<op::cbrtf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn fdimf16_cases() -> Vec<TestCase<op::fdimf16::Routine>> {
+fn cbrtf16_cases() -> Vec<TestCase<op::cbrtf16::Routine>> {
     vec![]
 }
 
-fn floor_cases() -> Vec<TestCase<op::floor::Routine>> {
+fn ceil_cases() -> Vec<TestCase<op::ceil::Routine>> {
     vec![]
 }
 
-fn floorf_cases() -> Vec<TestCase<op::floorf::Routine>> {
+fn ceilf_cases() -> Vec<TestCase<op::ceilf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn floorf128_cases() -> Vec<TestCase<op::floorf128::Routine>> {
+fn ceilf128_cases() -> Vec<TestCase<op::ceilf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn floorf16_cases() -> Vec<TestCase<op::floorf16::Routine>> {
+fn ceilf16_cases() -> Vec<TestCase<op::ceilf16::Routine>> {
     vec![]
 }
 
-fn fma_cases() -> Vec<TestCase<op::fma::Routine>> {
+fn compoundn_cases() -> Vec<TestCase<op::compoundn::Routine>> {
     let mut v = vec![];
     TestCase::append_pairs(
         &mut v,
         &[
-            // Previous failure with incorrect sign
-            ((5e-324, -5e-324, 0.0), Some(-0.0)),
+            // Exact powers, and the special cases at x = -1
+            ((0.5, 2), Some(2.25)),
+            ((1.0, 10), Some(1024.0)),
+            ((-0.5, -3), Some(8.0)),
+            ((3.0, -2), Some(0.0625)),
+            ((-1.0, 2), Some(0.0)),
+            ((-1.0, -1), Some(f64::INFINITY)),
+            ((f64::NAN, 0), Some(1.0)),
         ],
     );
     v
 }
 
-fn fmaf_cases() -> Vec<TestCase<op::fmaf::Routine>> {
-    vec![]
-}
-
-#[cfg(f128_enabled)]
-fn fmaf128_cases() -> Vec<TestCase<op::fmaf128::Routine>> {
+fn compoundnf_cases() -> Vec<TestCase<op::compoundnf::Routine>> {
     let mut v = vec![];
     TestCase::append_pairs(
         &mut v,
         &[
-            (
-                // Tricky rounding case that previously failed in extensive tests
-                (
-                    hf128!("-0x1.1966cc01966cc01966cc01966f06p-25"),
-                    hf128!("-0x1.669933fe69933fe69933fe6997c9p-16358"),
-                    hf128!("-0x0.000000000000000000000000048ap-16382"),
-                ),
-                Some(hf128!("0x0.c5171470a3ff5e0f68d751491b18p-16382")),
-            ),
-            (
-                // Subnormal edge case that caused a failure
-                (
-                    hf128!("0x0.7ffffffffffffffffffffffffff7p-16382"),
-                    hf128!("0x1.ffffffffffffffffffffffffffffp-1"),
-                    hf128!("0x0.8000000000000000000000000009p-16382"),
-                ),
-                Some(hf128!("0x1.0000000000000000000000000000p-16382")),
-            ),
+            // Exact powers, and the special cases at x = -1
+            ((0.5, 2), Some(2.25)),
+            ((1.0, 10), Some(1024.0)),
+            ((-0.5, -3), Some(8.0)),
+            ((3.0, -2), Some(0.0625)),
+            ((-1.0, 2), Some(0.0)),
+            ((-1.0, -1), Some(f32::INFINITY)),
+            ((f32::NAN, 0), Some(1.0)),
         ],
     );
     v
 }
 
+fn copysign_cases() -> Vec<TestCase<op::copysign::Routine>> {
+    vec![]
+}
+
+fn copysignf_cases() -> Vec<TestCase<op::copysignf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn copysignf128_cases() -> Vec<TestCase<op::copysignf128::Routine>> {
+    vec![]
+}
+
 #[cfg(f16_enabled)]
-fn fmaxf16_cases() -> Vec<TestCase<op::fmaxf16::Routine>> {
+fn copysignf16_cases() -> Vec<TestCase<op::copysignf16::Routine>> {
     vec![]
 }
 
-fn fmaxf_cases() -> Vec<TestCase<op::fmaxf::Routine>> {
+fn cos_cases() -> Vec<TestCase<op::cos::Routine>> {
     vec![]
 }
 
-fn fmax_cases() -> Vec<TestCase<op::fmax::Routine>> {
+fn cosf_cases() -> Vec<TestCase<op::cosf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn fmaxf128_cases() -> Vec<TestCase<op::fmaxf128::Routine>> {
+fn cosf128_cases() -> Vec<TestCase<op::cosf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn fmaximumf16_cases() -> Vec<TestCase<op::fmaximumf16::Routine>> {
+fn cosf16_cases() -> Vec<TestCase<op::cosf16::Routine>> {
     vec![]
 }
 
-fn fmaximumf_cases() -> Vec<TestCase<op::fmaximumf::Routine>> {
+fn cosh_cases() -> Vec<TestCase<op::cosh::Routine>> {
     vec![]
 }
 
-fn fmaximum_cases() -> Vec<TestCase<op::fmaximum::Routine>> {
+fn coshf_cases() -> Vec<TestCase<op::coshf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn fmaximumf128_cases() -> Vec<TestCase<op::fmaximumf128::Routine>> {
+fn coshf128_cases() -> Vec<TestCase<op::coshf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn fmaximum_numf16_cases() -> Vec<TestCase<op::fmaximum_numf16::Routine>> {
+fn coshf16_cases() -> Vec<TestCase<op::coshf16::Routine>> {
     vec![]
 }
 
-fn fmaximum_numf_cases() -> Vec<TestCase<op::fmaximum_numf::Routine>> {
-    vec![]
+fn cospi_cases() -> Vec<TestCase<op::cospi::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros at odd multiples of 1/2 are always positive
+            ((0.0,), Some(1.0)),
+            ((-0.0,), Some(1.0)),
+            ((0.5,), Some(0.0)),
+            ((-0.5,), Some(0.0)),
+            ((1.5,), Some(0.0)),
+            ((-1.5,), Some(0.0)),
+            ((1.0,), Some(-1.0)),
+            ((-2.0,), Some(1.0)),
+        ],
+    );
+    v
 }
 
-fn fmaximum_num_cases() -> Vec<TestCase<op::fmaximum_num::Routine>> {
-    vec![]
+fn cospif_cases() -> Vec<TestCase<op::cospif::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros at odd multiples of 1/2 are always positive
+            ((0.0,), Some(1.0)),
+            ((-0.0,), Some(1.0)),
+            ((0.5,), Some(0.0)),
+            ((-0.5,), Some(0.0)),
+            ((1.5,), Some(0.0)),
+            ((-1.5,), Some(0.0)),
+            ((1.0,), Some(-1.0)),
+            ((-2.0,), Some(1.0)),
+        ],
+    );
+    v
 }
 
 #[cfg(f128_enabled)]
-fn fmaximum_numf128_cases() -> Vec<TestCase<op::fmaximum_numf128::Routine>> {
-    vec![]
+fn cospif128_cases() -> Vec<TestCase<op::cospif128::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros at odd multiples of 1/2 are always positive
+            ((0.0,), Some(1.0)),
+            ((-0.0,), Some(1.0)),
+            ((0.5,), Some(0.0)),
+            ((-0.5,), Some(0.0)),
+            ((1.5,), Some(0.0)),
+            ((-1.5,), Some(0.0)),
+            ((1.0,), Some(-1.0)),
+            ((-2.0,), Some(1.0)),
+        ],
+    );
+    v
 }
 
 #[cfg(f16_enabled)]
-fn fminf16_cases() -> Vec<TestCase<op::fminf16::Routine>> {
+fn cospif16_cases() -> Vec<TestCase<op::cospif16::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros at odd multiples of 1/2 are always positive
+            ((0.0,), Some(1.0)),
+            ((-0.0,), Some(1.0)),
+            ((0.5,), Some(0.0)),
+            ((-0.5,), Some(0.0)),
+            ((1.5,), Some(0.0)),
+            ((-1.5,), Some(0.0)),
+            ((1.0,), Some(-1.0)),
+            ((-2.0,), Some(1.0)),
+        ],
+    );
+    v
+}
+
+fn erf_cases() -> Vec<TestCase<op::erf::Routine>> {
     vec![]
 }
 
-fn fminf_cases() -> Vec<TestCase<op::fminf::Routine>> {
+fn erfc_cases() -> Vec<TestCase<op::erfc::Routine>> {
     vec![]
 }
 
-fn fmin_cases() -> Vec<TestCase<op::fmin::Routine>> {
+fn erfcf_cases() -> Vec<TestCase<op::erfcf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn fminf128_cases() -> Vec<TestCase<op::fminf128::Routine>> {
+fn erfcf128_cases() -> Vec<TestCase<op::erfcf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn fminimumf16_cases() -> Vec<TestCase<op::fminimumf16::Routine>> {
+fn erfcf16_cases() -> Vec<TestCase<op::erfcf16::Routine>> {
     vec![]
 }
 
-fn fminimumf_cases() -> Vec<TestCase<op::fminimumf::Routine>> {
+fn erff_cases() -> Vec<TestCase<op::erff::Routine>> {
     vec![]
 }
 
-fn fminimum_cases() -> Vec<TestCase<op::fminimum::Routine>> {
+#[cfg(f128_enabled)]
+fn erff128_cases() -> Vec<TestCase<op::erff128::Routine>> {
     vec![]
 }
 
-#[cfg(f128_enabled)]
-fn fminimumf128_cases() -> Vec<TestCase<op::fminimumf128::Routine>> {
+#[cfg(f16_enabled)]
+fn erff16_cases() -> Vec<TestCase<op::erff16::Routine>> {
     vec![]
 }
 
-#[cfg(f16_enabled)]
-fn fminimum_numf16_cases() -> Vec<TestCase<op::fminimum_numf16::Routine>> {
+fn exp_cases() -> Vec<TestCase<op::exp::Routine>> {
     vec![]
 }
 
-fn fminimum_numf_cases() -> Vec<TestCase<op::fminimum_numf::Routine>> {
+fn exp10_cases() -> Vec<TestCase<op::exp10::Routine>> {
     vec![]
 }
 
-fn fminimum_num_cases() -> Vec<TestCase<op::fminimum_num::Routine>> {
+fn exp10f_cases() -> Vec<TestCase<op::exp10f::Routine>> {
     vec![]
 }
 
-#[cfg(f128_enabled)]
-fn fminimum_numf128_cases() -> Vec<TestCase<op::fminimum_numf128::Routine>> {
+#[cfg(f16_enabled)]
+fn exp10f16_cases() -> Vec<TestCase<op::exp10f16::Routine>> {
     vec![]
 }
 
-fn fmod_cases() -> Vec<TestCase<op::fmod::Routine>> {
+fn exp10m1_cases() -> Vec<TestCase<op::exp10m1::Routine>> {
     let mut v = vec![];
     TestCase::append_pairs(
         &mut v,
         &[
-            // Previous failure with incorrect loop iteration
-            // <https://github.com/rust-lang/libm/pull/469#discussion_r2022337272>
-            ((2.1, 3.123e-320), Some(2.0696e-320)),
-            ((2.1, 2.253547e-318), Some(1.772535e-318)),
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(9.0)),
+            ((2.0,), Some(99.0)),
+            ((5.0,), Some(99999.0)),
+            // Saturation for large arguments
+            ((-f64::MAX,), Some(-1.0)),
+            ((-1e300,), Some(-1.0)),
+            ((hf64!("-0x1p1023"),), Some(-1.0)),
+            ((-100.0,), Some(-1.0)),
+            ((f64::MAX,), Some(f64::INFINITY)),
         ],
     );
     v
 }
 
-fn fmodf_cases() -> Vec<TestCase<op::fmodf::Routine>> {
+fn exp10m1f_cases() -> Vec<TestCase<op::exp10m1f::Routine>> {
     let mut v = vec![];
     TestCase::append_pairs(
         &mut v,
         &[
-            // Previous failure with incorrect loop iteration
-            // <https://github.com/rust-lang/libm/pull/469#discussion_r2022337272>
-            ((2.1, 8.858e-42), Some(8.085e-42)),
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(9.0)),
+            ((2.0,), Some(99.0)),
+            ((5.0,), Some(99999.0)),
+            // Saturation for large arguments
+            ((-f32::MAX,), Some(-1.0)),
+            ((-1e30,), Some(-1.0)),
+            ((-100.0,), Some(-1.0)),
+            ((f32::MAX,), Some(f32::INFINITY)),
+        ],
+    );
+    v
+}
+
+fn exp2_cases() -> Vec<TestCase<op::exp2::Routine>> {
+    vec![]
+}
+
+fn exp2f_cases() -> Vec<TestCase<op::exp2f::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn exp2f128_cases() -> Vec<TestCase<op::exp2f128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn exp2f16_cases() -> Vec<TestCase<op::exp2f16::Routine>> {
+    vec![]
+}
+
+fn exp2m1_cases() -> Vec<TestCase<op::exp2m1::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(1.0)),
+            ((-1.0,), Some(-0.5)),
+            ((10.0,), Some(1023.0)),
+            ((-2.0,), Some(-0.75)),
+        ],
+    );
+    v
+}
+
+fn exp2m1f_cases() -> Vec<TestCase<op::exp2m1f::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(1.0)),
+            ((-1.0,), Some(-0.5)),
+            ((10.0,), Some(1023.0)),
+            ((-2.0,), Some(-0.75)),
+        ],
+    );
+    v
+}
+
+fn expf_cases() -> Vec<TestCase<op::expf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn expf128_cases() -> Vec<TestCase<op::expf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn expf16_cases() -> Vec<TestCase<op::expf16::Routine>> {
+    vec![]
+}
+
+fn expm1_cases() -> Vec<TestCase<op::expm1::Routine>> {
+    vec![]
+}
+
+fn expm1f_cases() -> Vec<TestCase<op::expm1f::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn expm1f128_cases() -> Vec<TestCase<op::expm1f128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn expm1f16_cases() -> Vec<TestCase<op::expm1f16::Routine>> {
+    vec![]
+}
+
+fn fabs_cases() -> Vec<TestCase<op::fabs::Routine>> {
+    vec![]
+}
+
+fn fabsf_cases() -> Vec<TestCase<op::fabsf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fabsf128_cases() -> Vec<TestCase<op::fabsf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fabsf16_cases() -> Vec<TestCase<op::fabsf16::Routine>> {
+    vec![]
+}
+
+fn fadd_cases() -> Vec<TestCase<op::fadd::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Rounding in `f64` first would give a tie that rounds down
+            ((hf64!("0x1.000001p+0"), hf64!("0x1p-60")), Some(hf32!("0x1.000002p+0"))),
+            ((hf64!("0x1.000001p+0"), hf64!("-0x1p-60")), Some(1.0)),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn f64addf128_cases() -> Vec<TestCase<op::f64addf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn f16addf32_cases() -> Vec<TestCase<op::f16addf32::Routine>> {
+    vec![]
+}
+
+fn fdim_cases() -> Vec<TestCase<op::fdim::Routine>> {
+    vec![]
+}
+
+fn fdimf_cases() -> Vec<TestCase<op::fdimf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fdimf128_cases() -> Vec<TestCase<op::fdimf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fdimf16_cases() -> Vec<TestCase<op::fdimf16::Routine>> {
+    vec![]
+}
+
+fn fdiv_cases() -> Vec<TestCase<op::fdiv::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn f64divf128_cases() -> Vec<TestCase<op::f64divf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn f16divf32_cases() -> Vec<TestCase<op::f16divf32::Routine>> {
+    vec![]
+}
+
+fn ffma_cases() -> Vec<TestCase<op::ffma::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Rounding in `f64` first would give a tie that rounds down
+            (
+                (hf64!("0x1p-30"), hf64!("0x1p-30"), hf64!("0x1.000001p+0")),
+                Some(hf32!("0x1.000002p+0")),
+            ),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn f64fmaf128_cases() -> Vec<TestCase<op::f64fmaf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn f16fmaf32_cases() -> Vec<TestCase<op::f16fmaf32::Routine>> {
+    vec![]
+}
+
+fn floor_cases() -> Vec<TestCase<op::floor::Routine>> {
+    vec![]
+}
+
+fn floorf_cases() -> Vec<TestCase<op::floorf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn floorf128_cases() -> Vec<TestCase<op::floorf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn floorf16_cases() -> Vec<TestCase<op::floorf16::Routine>> {
+    vec![]
+}
+
+fn fma_cases() -> Vec<TestCase<op::fma::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Previous failure with incorrect sign
+            ((5e-324, -5e-324, 0.0), Some(-0.0)),
+        ],
+    );
+    v
+}
+
+fn fmaf_cases() -> Vec<TestCase<op::fmaf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fmaf128_cases() -> Vec<TestCase<op::fmaf128::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            (
+                // Tricky rounding case that previously failed in extensive tests
+                (
+                    hf128!("-0x1.1966cc01966cc01966cc01966f06p-25"),
+                    hf128!("-0x1.669933fe69933fe69933fe6997c9p-16358"),
+                    hf128!("-0x0.000000000000000000000000048ap-16382"),
+                ),
+                Some(hf128!("0x0.c5171470a3ff5e0f68d751491b18p-16382")),
+            ),
+            (
+                // Subnormal edge case that caused a failure
+                (
+                    hf128!("0x0.7ffffffffffffffffffffffffff7p-16382"),
+                    hf128!("0x1.ffffffffffffffffffffffffffffp-1"),
+                    hf128!("0x0.8000000000000000000000000009p-16382"),
+                ),
+                Some(hf128!("0x1.0000000000000000000000000000p-16382")),
+            ),
+        ],
+    );
+    v
+}
+
+#[cfg(f16_enabled)]
+fn fmaf16_cases() -> Vec<TestCase<op::fmaf16::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fmaxf16_cases() -> Vec<TestCase<op::fmaxf16::Routine>> {
+    vec![]
+}
+
+fn fmaxf_cases() -> Vec<TestCase<op::fmaxf::Routine>> {
+    vec![]
+}
+
+fn fmax_cases() -> Vec<TestCase<op::fmax::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fmaxf128_cases() -> Vec<TestCase<op::fmaxf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fmaximumf16_cases() -> Vec<TestCase<op::fmaximumf16::Routine>> {
+    vec![]
+}
+
+fn fmaximumf_cases() -> Vec<TestCase<op::fmaximumf::Routine>> {
+    vec![]
+}
+
+fn fmaximum_cases() -> Vec<TestCase<op::fmaximum::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fmaximumf128_cases() -> Vec<TestCase<op::fmaximumf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fmaximum_magf16_cases() -> Vec<TestCase<op::fmaximum_magf16::Routine>> {
+    vec![]
+}
+
+fn fmaximum_magf_cases() -> Vec<TestCase<op::fmaximum_magf::Routine>> {
+    vec![]
+}
+
+fn fmaximum_mag_cases() -> Vec<TestCase<op::fmaximum_mag::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((-2.0, 1.0), Some(-2.0)),
+            ((-1.0, 1.0), Some(1.0)),
+            ((0.0, -0.0), Some(0.0)),
+            ((-0.0, -0.0), Some(-0.0)),
+            ((f64::NAN, 1.0), Some(f64::NAN)),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn fmaximum_magf128_cases() -> Vec<TestCase<op::fmaximum_magf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fmaximum_mag_numf16_cases() -> Vec<TestCase<op::fmaximum_mag_numf16::Routine>> {
+    vec![]
+}
+
+fn fmaximum_mag_numf_cases() -> Vec<TestCase<op::fmaximum_mag_numf::Routine>> {
+    vec![]
+}
+
+fn fmaximum_mag_num_cases() -> Vec<TestCase<op::fmaximum_mag_num::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((-2.0, 1.0), Some(-2.0)),
+            ((1.0, -1.0), Some(1.0)),
+            ((-0.0, 0.0), Some(0.0)),
+            ((f64::NAN, -1.0), Some(-1.0)),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn fmaximum_mag_numf128_cases() -> Vec<TestCase<op::fmaximum_mag_numf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fmaximum_numf16_cases() -> Vec<TestCase<op::fmaximum_numf16::Routine>> {
+    vec![]
+}
+
+fn fmaximum_numf_cases() -> Vec<TestCase<op::fmaximum_numf::Routine>> {
+    vec![]
+}
+
+fn fmaximum_num_cases() -> Vec<TestCase<op::fmaximum_num::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fmaximum_numf128_cases() -> Vec<TestCase<op::fmaximum_numf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fminf16_cases() -> Vec<TestCase<op::fminf16::Routine>> {
+    vec![]
+}
+
+fn fminf_cases() -> Vec<TestCase<op::fminf::Routine>> {
+    vec![]
+}
+
+fn fmin_cases() -> Vec<TestCase<op::fmin::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fminf128_cases() -> Vec<TestCase<op::fminf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fminimumf16_cases() -> Vec<TestCase<op::fminimumf16::Routine>> {
+    vec![]
+}
+
+fn fminimumf_cases() -> Vec<TestCase<op::fminimumf::Routine>> {
+    vec![]
+}
+
+fn fminimum_cases() -> Vec<TestCase<op::fminimum::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fminimumf128_cases() -> Vec<TestCase<op::fminimumf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fminimum_magf16_cases() -> Vec<TestCase<op::fminimum_magf16::Routine>> {
+    vec![]
+}
+
+fn fminimum_magf_cases() -> Vec<TestCase<op::fminimum_magf::Routine>> {
+    vec![]
+}
+
+fn fminimum_mag_cases() -> Vec<TestCase<op::fminimum_mag::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((-2.0, 1.0), Some(1.0)),
+            ((1.0, -1.0), Some(-1.0)),
+            ((0.0, -0.0), Some(-0.0)),
+            ((f64::NAN, 1.0), Some(f64::NAN)),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn fminimum_magf128_cases() -> Vec<TestCase<op::fminimum_magf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fminimum_mag_numf16_cases() -> Vec<TestCase<op::fminimum_mag_numf16::Routine>> {
+    vec![]
+}
+
+fn fminimum_mag_numf_cases() -> Vec<TestCase<op::fminimum_mag_numf::Routine>> {
+    vec![]
+}
+
+fn fminimum_mag_num_cases() -> Vec<TestCase<op::fminimum_mag_num::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((-2.0, 1.0), Some(1.0)),
+            ((-1.0, 1.0), Some(-1.0)),
+            ((-0.0, 0.0), Some(-0.0)),
+            ((f64::NAN, -1.0), Some(-1.0)),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn fminimum_mag_numf128_cases() -> Vec<TestCase<op::fminimum_mag_numf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fminimum_numf16_cases() -> Vec<TestCase<op::fminimum_numf16::Routine>> {
+    vec![]
+}
+
+fn fminimum_numf_cases() -> Vec<TestCase<op::fminimum_numf::Routine>> {
+    vec![]
+}
+
+fn fminimum_num_cases() -> Vec<TestCase<op::fminimum_num::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fminimum_numf128_cases() -> Vec<TestCase<op::fminimum_numf128::Routine>> {
+    vec![]
+}
+
+fn fmod_cases() -> Vec<TestCase<op::fmod::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Previous failure with incorrect loop iteration
+            // <https://github.com/rust-lang/libm/pull/469#discussion_r2022337272>
+            ((2.1, 3.123e-320), Some(2.0696e-320)),
+            ((2.1, 2.253547e-318), Some(1.772535e-318)),
+        ],
+    );
+    v
+}
+
+fn fmodf_cases() -> Vec<TestCase<op::fmodf::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Previous failure with incorrect loop iteration
+            // <https://github.com/rust-lang/libm/pull/469#discussion_r2022337272>
+            ((2.1, 8.858e-42), Some(8.085e-42)),
             ((2.1, 6.39164e-40), Some(6.1636e-40)),
             ((5.5, 6.39164e-40), Some(4.77036e-40)),
             ((-151.189, 6.39164e-40), Some(-5.64734e-40)),
@@ -433,344 +1116,1584 @@ fn fmodf_cases() -> Vec<TestCase<op::fmodf::Routine>> {
 }
 
 #[cfg(f128_enabled)]
-fn fmodf128_cases() -> Vec<TestCase<op::fmodf128::Routine>> {
+fn fmodf128_cases() -> Vec<TestCase<op::fmodf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fmodf16_cases() -> Vec<TestCase<op::fmodf16::Routine>> {
+    vec![]
+}
+
+fn fmul_cases() -> Vec<TestCase<op::fmul::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Rounding in `f64` first would give a tie that rounds down
+            ((hf64!("0x1.0000008p+0"), hf64!("0x1.0000008p+0")), Some(hf32!("0x1.000002p+0"))),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn f64mulf128_cases() -> Vec<TestCase<op::f64mulf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn f16mulf32_cases() -> Vec<TestCase<op::f16mulf32::Routine>> {
+    vec![]
+}
+
+fn frexp_cases() -> Vec<TestCase<op::frexp::Routine>> {
+    vec![]
+}
+
+fn frexpf_cases() -> Vec<TestCase<op::frexpf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn frexpf128_cases() -> Vec<TestCase<op::frexpf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn frexpf16_cases() -> Vec<TestCase<op::frexpf16::Routine>> {
+    vec![]
+}
+
+fn fromfp_cases() -> Vec<TestCase<op::fromfp::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Ties in each direction
+            ((2.5, Round::Positive, 8), Some(3.0)),
+            ((2.5, Round::Negative, 8), Some(2.0)),
+            ((-2.5, Round::Zero, 8), Some(-2.0)),
+            ((-2.5, Round::NearestAway, 8), Some(-3.0)),
+            ((-2.5, Round::Nearest, 8), Some(-2.0)),
+            // The edges of `i32`, which are only in range after rounding
+            ((hf64!("-0x1.000000008p+31"), Round::Zero, 32), Some(hf64!("-0x1p+31"))),
+            ((hf64!("-0x1.000000008p+31"), Round::Negative, 32), Some(f64::NAN)),
+            ((hf64!("0x1.fffffffep+30"), Round::Nearest, 32), Some(f64::NAN)),
+            ((hf64!("0x1.fffffffep+30"), Round::Zero, 32), Some(hf64!("0x1.fffffffcp+30"))),
+            // Invalid for any rounding direction
+            ((1.0, Round::Nearest, 0), Some(f64::NAN)),
+            ((f64::INFINITY, Round::Nearest, u32::MAX), Some(f64::NAN)),
+        ],
+    );
+    v
+}
+
+fn fromfpf_cases() -> Vec<TestCase<op::fromfpf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fromfpf128_cases() -> Vec<TestCase<op::fromfpf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fromfpf16_cases() -> Vec<TestCase<op::fromfpf16::Routine>> {
+    vec![]
+}
+
+fn fromfpx_cases() -> Vec<TestCase<op::fromfpx::Routine>> {
+    vec![]
+}
+
+fn fromfpxf_cases() -> Vec<TestCase<op::fromfpxf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn fromfpxf128_cases() -> Vec<TestCase<op::fromfpxf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn fromfpxf16_cases() -> Vec<TestCase<op::fromfpxf16::Routine>> {
+    vec![]
+}
+
+fn fsqrt_cases() -> Vec<TestCase<op::fsqrt::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn f64sqrtf128_cases() -> Vec<TestCase<op::f64sqrtf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn f16sqrtf32_cases() -> Vec<TestCase<op::f16sqrtf32::Routine>> {
+    vec![]
+}
+
+fn fsub_cases() -> Vec<TestCase<op::fsub::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Rounding in `f64` first would give a tie that rounds down
+            ((hf64!("0x1.000001p+0"), hf64!("-0x1p-60")), Some(hf32!("0x1.000002p+0"))),
+        ],
+    );
+    v
+}
+
+#[cfg(f128_enabled)]
+fn f64subf128_cases() -> Vec<TestCase<op::f64subf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn f16subf32_cases() -> Vec<TestCase<op::f16subf32::Routine>> {
+    vec![]
+}
+
+fn getpayload_cases() -> Vec<TestCase<op::getpayload::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((f64::from_bits(0x7ff8_0000_0000_002a),), Some(42.0)),
+            ((f64::from_bits(0xfff0_0000_0000_0001),), Some(1.0)),
+            ((f64::from_bits(0x7fff_ffff_ffff_ffff),), Some(2251799813685247.0)),
+            ((f64::INFINITY,), Some(-1.0)),
+        ],
+    );
+    v
+}
+
+fn getpayloadf_cases() -> Vec<TestCase<op::getpayloadf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn getpayloadf128_cases() -> Vec<TestCase<op::getpayloadf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn getpayloadf16_cases() -> Vec<TestCase<op::getpayloadf16::Routine>> {
+    vec![]
+}
+
+fn hypot_cases() -> Vec<TestCase<op::hypot::Routine>> {
+    vec![]
+}
+
+fn hypotf_cases() -> Vec<TestCase<op::hypotf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn hypotf128_cases() -> Vec<TestCase<op::hypotf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn hypotf16_cases() -> Vec<TestCase<op::hypotf16::Routine>> {
+    vec![]
+}
+
+fn ilogb_cases() -> Vec<TestCase<op::ilogb::Routine>> {
+    vec![]
+}
+
+fn ilogbf_cases() -> Vec<TestCase<op::ilogbf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn ilogbf128_cases() -> Vec<TestCase<op::ilogbf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn ilogbf16_cases() -> Vec<TestCase<op::ilogbf16::Routine>> {
+    vec![]
+}
+
+fn issignaling_cases() -> Vec<TestCase<op::issignaling::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((f64::from_bits(0x7ff0_0000_0000_0001),), Some(1)),
+            ((f64::from_bits(0xfff4_0000_0000_0000),), Some(1)),
+            ((f64::NAN,), Some(0)),
+            ((f64::INFINITY,), Some(0)),
+        ],
+    );
+    v
+}
+
+fn issignalingf_cases() -> Vec<TestCase<op::issignalingf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn issignalingf128_cases() -> Vec<TestCase<op::issignalingf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn issignalingf16_cases() -> Vec<TestCase<op::issignalingf16::Routine>> {
+    vec![]
+}
+
+fn j0_cases() -> Vec<TestCase<op::j0::Routine>> {
+    vec![]
+}
+
+fn j0f_cases() -> Vec<TestCase<op::j0f::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn j0f128_cases() -> Vec<TestCase<op::j0f128::Routine>> {
+    vec![]
+}
+
+fn j1_cases() -> Vec<TestCase<op::j1::Routine>> {
+    vec![]
+}
+
+fn j1f_cases() -> Vec<TestCase<op::j1f::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn j1f128_cases() -> Vec<TestCase<op::j1f128::Routine>> {
+    vec![]
+}
+
+fn jn_cases() -> Vec<TestCase<op::jn::Routine>> {
+    vec![]
+}
+
+fn jnf_cases() -> Vec<TestCase<op::jnf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn jnf128_cases() -> Vec<TestCase<op::jnf128::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Large orders with a small argument underflow to a positive zero
+            ((100_000_000, 1.0), Some(0.0)),
+            ((150_000_000, 3.0), Some(0.0)),
+            ((1_000_000_000, 1.5), Some(0.0)),
+            ((i32::MAX, 1.0), Some(0.0)),
+            ((i32::MIN, 1.0), Some(0.0)),
+            ((-99_999_999, 1.0), Some(-0.0)),
+            ((100_000_001, -1.0), Some(-0.0)),
+        ],
+    );
+    v
+}
+
+fn ldexp_cases() -> Vec<TestCase<op::ldexp::Routine>> {
+    vec![]
+}
+
+fn ldexpf_cases() -> Vec<TestCase<op::ldexpf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn ldexpf128_cases() -> Vec<TestCase<op::ldexpf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn ldexpf16_cases() -> Vec<TestCase<op::ldexpf16::Routine>> {
+    vec![]
+}
+
+fn lgamma_cases() -> Vec<TestCase<op::lgamma::Routine>> {
+    vec![]
+}
+
+fn lgamma_r_cases() -> Vec<TestCase<op::lgamma_r::Routine>> {
+    vec![]
+}
+
+fn lgammaf_cases() -> Vec<TestCase<op::lgammaf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn lgammaf128_cases() -> Vec<TestCase<op::lgammaf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn lgammaf16_cases() -> Vec<TestCase<op::lgammaf16::Routine>> {
+    vec![]
+}
+
+fn lgammaf_r_cases() -> Vec<TestCase<op::lgammaf_r::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn lgammaf128_r_cases() -> Vec<TestCase<op::lgammaf128_r::Routine>> {
+    vec![]
+}
+
+fn llogb_cases() -> Vec<TestCase<op::llogb::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((0.0,), Some(i64::MIN)),
+            ((f64::NAN,), Some(i64::MIN)),
+            ((f64::NEG_INFINITY,), Some(i64::MAX)),
+            ((5e-324,), Some(-1074)),
+            ((-1.5,), Some(0)),
+        ],
+    );
+    v
+}
+
+fn llogbf_cases() -> Vec<TestCase<op::llogbf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn llogbf128_cases() -> Vec<TestCase<op::llogbf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn llogbf16_cases() -> Vec<TestCase<op::llogbf16::Routine>> {
+    vec![]
+}
+
+fn llrint_cases() -> Vec<TestCase<op::llrint::Routine>> {
+    vec![]
+}
+
+fn llrintf_cases() -> Vec<TestCase<op::llrintf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn llrintf128_cases() -> Vec<TestCase<op::llrintf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn llrintf16_cases() -> Vec<TestCase<op::llrintf16::Routine>> {
+    vec![]
+}
+
+fn llround_cases() -> Vec<TestCase<op::llround::Routine>> {
+    vec![]
+}
+
+fn llroundf_cases() -> Vec<TestCase<op::llroundf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn llroundf128_cases() -> Vec<TestCase<op::llroundf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn llroundf16_cases() -> Vec<TestCase<op::llroundf16::Routine>> {
+    vec![]
+}
+
+fn log_cases() -> Vec<TestCase<op::log::Routine>> {
+    vec![]
+}
+
+fn log10_cases() -> Vec<TestCase<op::log10::Routine>> {
+    vec![]
+}
+
+fn log10f_cases() -> Vec<TestCase<op::log10f::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn log10f128_cases() -> Vec<TestCase<op::log10f128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn log10f16_cases() -> Vec<TestCase<op::log10f16::Routine>> {
+    vec![]
+}
+
+fn log10p1_cases() -> Vec<TestCase<op::log10p1::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((9.0,), Some(1.0)),
+            ((99.0,), Some(2.0)),
+            ((99999.0,), Some(5.0)),
+            ((-1.0,), Some(f64::NEG_INFINITY)),
+        ],
+    );
+    v
+}
+
+fn log10p1f_cases() -> Vec<TestCase<op::log10p1f::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((9.0,), Some(1.0)),
+            ((99.0,), Some(2.0)),
+            ((99999.0,), Some(5.0)),
+            ((-1.0,), Some(f32::NEG_INFINITY)),
+        ],
+    );
+    v
+}
+
+fn log1p_cases() -> Vec<TestCase<op::log1p::Routine>> {
+    vec![]
+}
+
+fn log1pf_cases() -> Vec<TestCase<op::log1pf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn log1pf128_cases() -> Vec<TestCase<op::log1pf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn log1pf16_cases() -> Vec<TestCase<op::log1pf16::Routine>> {
+    vec![]
+}
+
+fn log2_cases() -> Vec<TestCase<op::log2::Routine>> {
+    vec![]
+}
+
+fn log2f_cases() -> Vec<TestCase<op::log2f::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn log2f128_cases() -> Vec<TestCase<op::log2f128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn log2f16_cases() -> Vec<TestCase<op::log2f16::Routine>> {
+    vec![]
+}
+
+fn log2p1_cases() -> Vec<TestCase<op::log2p1::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(1.0)),
+            ((3.0,), Some(2.0)),
+            ((-0.5,), Some(-1.0)),
+            ((-0.75,), Some(-2.0)),
+            ((1023.0,), Some(10.0)),
+            ((-1.0,), Some(f64::NEG_INFINITY)),
+        ],
+    );
+    v
+}
+
+fn log2p1f_cases() -> Vec<TestCase<op::log2p1f::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros keep their sign and integer powers are exact
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(1.0)),
+            ((3.0,), Some(2.0)),
+            ((-0.5,), Some(-1.0)),
+            ((-0.75,), Some(-2.0)),
+            ((1023.0,), Some(10.0)),
+            ((-1.0,), Some(f32::NEG_INFINITY)),
+        ],
+    );
+    v
+}
+
+fn logb_cases() -> Vec<TestCase<op::logb::Routine>> {
+    vec![]
+}
+
+fn logbf_cases() -> Vec<TestCase<op::logbf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn logbf128_cases() -> Vec<TestCase<op::logbf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn logbf16_cases() -> Vec<TestCase<op::logbf16::Routine>> {
+    vec![]
+}
+
+fn logf_cases() -> Vec<TestCase<op::logf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn logf128_cases() -> Vec<TestCase<op::logf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn logf16_cases() -> Vec<TestCase<op::logf16::Routine>> {
+    vec![]
+}
+
+fn logp1_cases() -> Vec<TestCase<op::logp1::Routine>> {
+    vec![]
+}
+
+fn logp1f_cases() -> Vec<TestCase<op::logp1f::Routine>> {
+    vec![]
+}
+
+fn lrint_cases() -> Vec<TestCase<op::lrint::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Ties go to even
+            ((0.5,), Some(0)),
+            ((-1.5,), Some(-2)),
+            ((2.5,), Some(2)),
+            // The edges of `i64`, and values that are out of range
+            ((hf64!("-0x1p+63"),), Some(i64::MIN)),
+            ((hf64!("0x1.fffffffffffffp+62"),), Some(0x7ffffffffffffc00)),
+            ((hf64!("0x1p+63"),), Some(i64::MIN)),
+            ((f64::NAN,), Some(i64::MIN)),
+            ((f64::NEG_INFINITY,), Some(i64::MIN)),
+        ],
+    );
+    v
+}
+
+fn lrintf_cases() -> Vec<TestCase<op::lrintf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn lrintf128_cases() -> Vec<TestCase<op::lrintf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn lrintf16_cases() -> Vec<TestCase<op::lrintf16::Routine>> {
+    vec![]
+}
+
+fn lround_cases() -> Vec<TestCase<op::lround::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Ties go away from zero
+            ((0.5,), Some(1)),
+            ((-1.5,), Some(-2)),
+            ((2.5,), Some(3)),
+            // Just below a tie, where adding 0.5 would round up
+            ((hf64!("0x1.fffffffffffffp-2"),), Some(0)),
+            // The edges of `i64`, and values that are out of range
+            ((hf64!("-0x1p+63"),), Some(i64::MIN)),
+            ((hf64!("0x1p+63"),), Some(i64::MIN)),
+            ((f64::NAN,), Some(i64::MIN)),
+            ((f64::INFINITY,), Some(i64::MIN)),
+        ],
+    );
+    v
+}
+
+fn lroundf_cases() -> Vec<TestCase<op::lroundf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn lroundf128_cases() -> Vec<TestCase<op::lroundf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn lroundf16_cases() -> Vec<TestCase<op::lroundf16::Routine>> {
+    vec![]
+}
+
+fn modf_cases() -> Vec<TestCase<op::modf::Routine>> {
+    vec![]
+}
+
+fn modff_cases() -> Vec<TestCase<op::modff::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn modff128_cases() -> Vec<TestCase<op::modff128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn modff16_cases() -> Vec<TestCase<op::modff16::Routine>> {
+    vec![]
+}
+
+fn nearbyint_cases() -> Vec<TestCase<op::nearbyint::Routine>> {
+    vec![]
+}
+
+fn nearbyintf_cases() -> Vec<TestCase<op::nearbyintf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn nearbyintf128_cases() -> Vec<TestCase<op::nearbyintf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn nearbyintf16_cases() -> Vec<TestCase<op::nearbyintf16::Routine>> {
+    vec![]
+}
+
+fn nextafter_cases() -> Vec<TestCase<op::nextafter::Routine>> {
+    vec![]
+}
+
+fn nextafterf_cases() -> Vec<TestCase<op::nextafterf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn nextafterf128_cases() -> Vec<TestCase<op::nextafterf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn nextafterf16_cases() -> Vec<TestCase<op::nextafterf16::Routine>> {
+    vec![]
+}
+
+fn nextdown_cases() -> Vec<TestCase<op::nextdown::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((0.0,), Some(-5e-324)),
+            ((5e-324,), Some(0.0)),
+            ((f64::NEG_INFINITY,), Some(f64::NEG_INFINITY)),
+            ((f64::INFINITY,), Some(f64::MAX)),
+        ],
+    );
+    v
+}
+
+fn nextdownf_cases() -> Vec<TestCase<op::nextdownf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn nextdownf128_cases() -> Vec<TestCase<op::nextdownf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn fmodf16_cases() -> Vec<TestCase<op::fmodf16::Routine>> {
+fn nextdownf16_cases() -> Vec<TestCase<op::nextdownf16::Routine>> {
     vec![]
 }
 
-fn frexp_cases() -> Vec<TestCase<op::frexp::Routine>> {
+fn nexttoward_cases() -> Vec<TestCase<op::nexttoward::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((1.0, 2.0), Some(1.0000000000000002)),
+            ((-0.0, 0.0), Some(0.0)),
+            ((0.0, -1.0), Some(-5e-324)),
+        ],
+    );
+    v
+}
+
+fn nexttowardf_cases() -> Vec<TestCase<op::nexttowardf::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Directions between two `f32`s
+            ((1.0, 1.0 + f64::EPSILON), Some(1.0000001)),
+            ((1.0, 1.0 - f64::EPSILON), Some(0.99999994)),
+            ((0.0, -1e-300), Some(-1e-45)),
+            ((0.0, -0.0), Some(-0.0)),
+            ((f32::MAX, f64::MAX), Some(f32::INFINITY)),
+        ],
+    );
+    v
+}
+
+fn nextup_cases() -> Vec<TestCase<op::nextup::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((-0.0,), Some(5e-324)),
+            ((-5e-324,), Some(-0.0)),
+            ((f64::MAX,), Some(f64::INFINITY)),
+            ((f64::INFINITY,), Some(f64::INFINITY)),
+        ],
+    );
+    v
+}
+
+fn nextupf_cases() -> Vec<TestCase<op::nextupf::Routine>> {
     vec![]
 }
 
-fn frexpf_cases() -> Vec<TestCase<op::frexpf::Routine>> {
+#[cfg(f128_enabled)]
+fn nextupf128_cases() -> Vec<TestCase<op::nextupf128::Routine>> {
     vec![]
 }
 
-fn hypot_cases() -> Vec<TestCase<op::hypot::Routine>> {
+#[cfg(f16_enabled)]
+fn nextupf16_cases() -> Vec<TestCase<op::nextupf16::Routine>> {
     vec![]
 }
 
-fn hypotf_cases() -> Vec<TestCase<op::hypotf::Routine>> {
+fn pow_cases() -> Vec<TestCase<op::pow::Routine>> {
+    vec![]
+}
+
+fn powf_cases() -> Vec<TestCase<op::powf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn powf128_cases() -> Vec<TestCase<op::powf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn powf16_cases() -> Vec<TestCase<op::powf16::Routine>> {
+    vec![]
+}
+
+fn pown_cases() -> Vec<TestCase<op::pown::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact powers, signed zeros and pown(NaN, 0)
+            ((2.0, 10), Some(1024.0)),
+            ((-2.0, 3), Some(-8.0)),
+            ((-3.0, 4), Some(81.0)),
+            ((2.0, -3), Some(0.125)),
+            ((2.0, -1074), Some(hf64!("0x1p-1074"))),
+            ((-0.0, -3), Some(f64::NEG_INFINITY)),
+            ((-0.0, 2), Some(0.0)),
+            ((f64::NAN, 0), Some(1.0)),
+        ],
+    );
+    v
+}
+
+fn pownf_cases() -> Vec<TestCase<op::pownf::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact powers, signed zeros and pown(NaN, 0)
+            ((2.0, 10), Some(1024.0)),
+            ((-2.0, 3), Some(-8.0)),
+            ((-3.0, 4), Some(81.0)),
+            ((2.0, -3), Some(0.125)),
+            ((2.0, -149), Some(hf32!("0x1p-149"))),
+            ((-0.0, -3), Some(f32::NEG_INFINITY)),
+            ((-0.0, 2), Some(0.0)),
+            ((f32::NAN, 0), Some(1.0)),
+        ],
+    );
+    v
+}
+
+fn powr_cases() -> Vec<TestCase<op::powr::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact powers, and -0 behaves as +0
+            ((4.0, 0.5), Some(2.0)),
+            ((2.0, -2.0), Some(0.25)),
+            ((1.0, 5.0), Some(1.0)),
+            ((-0.0, -1.0), Some(f64::INFINITY)),
+            ((-0.0, 3.0), Some(0.0)),
+        ],
+    );
+    v
+}
+
+fn powrf_cases() -> Vec<TestCase<op::powrf::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact powers, and -0 behaves as +0
+            ((4.0, 0.5), Some(2.0)),
+            ((2.0, -2.0), Some(0.25)),
+            ((1.0, 5.0), Some(1.0)),
+            ((-0.0, -1.0), Some(f32::INFINITY)),
+            ((-0.0, 3.0), Some(0.0)),
+        ],
+    );
+    v
+}
+
+fn remainder_cases() -> Vec<TestCase<op::remainder::Routine>> {
+    vec![]
+}
+
+fn remainderf_cases() -> Vec<TestCase<op::remainderf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn remainderf128_cases() -> Vec<TestCase<op::remainderf128::Routine>> {
     vec![]
 }
 
-fn ilogb_cases() -> Vec<TestCase<op::ilogb::Routine>> {
-    vec![]
+#[cfg(f16_enabled)]
+fn remainderf16_cases() -> Vec<TestCase<op::remainderf16::Routine>> {
+    vec![]
+}
+
+fn remquo_cases() -> Vec<TestCase<op::remquo::Routine>> {
+    vec![]
+}
+
+fn remquof_cases() -> Vec<TestCase<op::remquof::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn remquof128_cases() -> Vec<TestCase<op::remquof128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn remquof16_cases() -> Vec<TestCase<op::remquof16::Routine>> {
+    vec![]
+}
+
+fn rint_cases() -> Vec<TestCase<op::rint::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Known failure on i586
+            #[cfg(not(x86_no_sse))]
+            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff994000p+38"))),
+            #[cfg(x86_no_sse)]
+            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff998000p+38"))),
+        ],
+    );
+    v
+}
+
+fn rintf_cases() -> Vec<TestCase<op::rintf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn rintf128_cases() -> Vec<TestCase<op::rintf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn rintf16_cases() -> Vec<TestCase<op::rintf16::Routine>> {
+    vec![]
+}
+
+fn rootn_cases() -> Vec<TestCase<op::rootn::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact roots, and odd roots of negative numbers
+            ((-8.0, 3), Some(-2.0)),
+            ((16.0, 4), Some(2.0)),
+            ((1024.0, 10), Some(2.0)),
+            ((0.25, -2), Some(2.0)),
+            ((-32.0, -5), Some(-0.5)),
+            ((-0.0, 3), Some(-0.0)),
+            ((-0.0, -3), Some(f64::NEG_INFINITY)),
+        ],
+    );
+    v
+}
+
+fn rootnf_cases() -> Vec<TestCase<op::rootnf::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact roots, and odd roots of negative numbers
+            ((-8.0, 3), Some(-2.0)),
+            ((16.0, 4), Some(2.0)),
+            ((1024.0, 10), Some(2.0)),
+            ((0.25, -2), Some(2.0)),
+            ((-32.0, -5), Some(-0.5)),
+            ((-0.0, 3), Some(-0.0)),
+            ((-0.0, -3), Some(f32::NEG_INFINITY)),
+        ],
+    );
+    v
+}
+
+#[cfg(f16_enabled)]
+fn roundf16_cases() -> Vec<TestCase<op::roundf16::Routine>> {
+    vec![]
+}
+
+fn round_cases() -> Vec<TestCase<op::round::Routine>> {
+    vec![]
+}
+
+fn roundf_cases() -> Vec<TestCase<op::roundf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn roundf128_cases() -> Vec<TestCase<op::roundf128::Routine>> {
+    vec![]
+}
+
+#[cfg(f16_enabled)]
+fn roundevenf16_cases() -> Vec<TestCase<op::roundevenf16::Routine>> {
+    vec![]
+}
+
+fn roundeven_cases() -> Vec<TestCase<op::roundeven::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Known failure on i586
+            #[cfg(not(x86_no_sse))]
+            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff994000p+38"))),
+            #[cfg(x86_no_sse)]
+            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff998000p+38"))),
+        ],
+    );
+    v
+}
+
+fn roundevenf_cases() -> Vec<TestCase<op::roundevenf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn roundevenf128_cases() -> Vec<TestCase<op::roundevenf128::Routine>> {
+    vec![]
+}
+
+fn rsqrt_cases() -> Vec<TestCase<op::rsqrt::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact results and signed zeros
+            ((4.0,), Some(0.5)),
+            ((0.25,), Some(2.0)),
+            ((-0.0,), Some(f64::NEG_INFINITY)),
+            ((f64::INFINITY,), Some(0.0)),
+            ((hf64!("0x1p-1074"),), Some(hf64!("0x1p537"))),
+        ],
+    );
+    v
+}
+
+fn rsqrtf_cases() -> Vec<TestCase<op::rsqrtf::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact results and signed zeros
+            ((4.0,), Some(0.5)),
+            ((0.25,), Some(2.0)),
+            ((-0.0,), Some(f32::NEG_INFINITY)),
+            ((f32::INFINITY,), Some(0.0)),
+            ((hf32!("0x1p-148"),), Some(hf32!("0x1p74"))),
+        ],
+    );
+    v
+}
+
+fn scalbln_cases() -> Vec<TestCase<op::scalbln::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exponents that don't fit in an `i32`
+            ((1.0, i64::MAX), Some(f64::INFINITY)),
+            ((-1.0, 1 << 40), Some(f64::NEG_INFINITY)),
+            ((1.0, i64::MIN), Some(0.0)),
+            ((-1.0, -(1 << 40)), Some(-0.0)),
+            ((1.0, -1074), Some(5e-324)),
+        ],
+    );
+    v
 }
 
-fn ilogbf_cases() -> Vec<TestCase<op::ilogbf::Routine>> {
+fn scalblnf_cases() -> Vec<TestCase<op::scalblnf::Routine>> {
     vec![]
 }
 
-fn j0_cases() -> Vec<TestCase<op::j0::Routine>> {
+#[cfg(f128_enabled)]
+fn scalblnf128_cases() -> Vec<TestCase<op::scalblnf128::Routine>> {
     vec![]
 }
 
-fn j0f_cases() -> Vec<TestCase<op::j0f::Routine>> {
+#[cfg(f16_enabled)]
+fn scalblnf16_cases() -> Vec<TestCase<op::scalblnf16::Routine>> {
     vec![]
 }
 
-fn j1_cases() -> Vec<TestCase<op::j1::Routine>> {
+fn scalbn_cases() -> Vec<TestCase<op::scalbn::Routine>> {
     vec![]
 }
 
-fn j1f_cases() -> Vec<TestCase<op::j1f::Routine>> {
+fn scalbnf_cases() -> Vec<TestCase<op::scalbnf::Routine>> {
     vec![]
 }
 
-fn jn_cases() -> Vec<TestCase<op::jn::Routine>> {
+#[cfg(f128_enabled)]
+fn scalbnf128_cases() -> Vec<TestCase<op::scalbnf128::Routine>> {
     vec![]
 }
 
-fn jnf_cases() -> Vec<TestCase<op::jnf::Routine>> {
+#[cfg(f16_enabled)]
+fn scalbnf16_cases() -> Vec<TestCase<op::scalbnf16::Routine>> {
     vec![]
 }
 
-fn ldexp_cases() -> Vec<TestCase<op::ldexp::Routine>> {
-    vec![]
+fn setpayload_cases() -> Vec<TestCase<op::setpayload::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((42.0,), Some(f64::from_bits(0x7ff8_0000_0000_002a))),
+            ((2251799813685247.0,), Some(f64::from_bits(0x7fff_ffff_ffff_ffff))),
+            // Invalid payloads
+            ((2251799813685248.0,), Some(0.0)),
+            ((0.5,), Some(0.0)),
+            ((-0.0,), Some(0.0)),
+        ],
+    );
+    v
 }
 
-fn ldexpf_cases() -> Vec<TestCase<op::ldexpf::Routine>> {
+fn setpayloadf_cases() -> Vec<TestCase<op::setpayloadf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn ldexpf128_cases() -> Vec<TestCase<op::ldexpf128::Routine>> {
+fn setpayloadf128_cases() -> Vec<TestCase<op::setpayloadf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn ldexpf16_cases() -> Vec<TestCase<op::ldexpf16::Routine>> {
+fn setpayloadf16_cases() -> Vec<TestCase<op::setpayloadf16::Routine>> {
     vec![]
 }
 
-fn lgamma_cases() -> Vec<TestCase<op::lgamma::Routine>> {
-    vec![]
+fn setpayloadsig_cases() -> Vec<TestCase<op::setpayloadsig::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((42.0,), Some(f64::from_bits(0x7ff0_0000_0000_002a))),
+            ((2251799813685247.0,), Some(f64::from_bits(0x7ff7_ffff_ffff_ffff))),
+            // Invalid payloads, including zero which would encode infinity
+            ((0.0,), Some(0.0)),
+            ((-1.0,), Some(0.0)),
+        ],
+    );
+    v
 }
 
-fn lgamma_r_cases() -> Vec<TestCase<op::lgamma_r::Routine>> {
+fn setpayloadsigf_cases() -> Vec<TestCase<op::setpayloadsigf::Routine>> {
     vec![]
 }
 
-fn lgammaf_cases() -> Vec<TestCase<op::lgammaf::Routine>> {
+#[cfg(f128_enabled)]
+fn setpayloadsigf128_cases() -> Vec<TestCase<op::setpayloadsigf128::Routine>> {
     vec![]
 }
 
-fn lgammaf_r_cases() -> Vec<TestCase<op::lgammaf_r::Routine>> {
+#[cfg(f16_enabled)]
+fn setpayloadsigf16_cases() -> Vec<TestCase<op::setpayloadsigf16::Routine>> {
     vec![]
 }
 
-fn log_cases() -> Vec<TestCase<op::log::Routine>> {
+fn sin_cases() -> Vec<TestCase<op::sin::Routine>> {
     vec![]
 }
 
-fn log10_cases() -> Vec<TestCase<op::log10::Routine>> {
+fn sincos_cases() -> Vec<TestCase<op::sincos::Routine>> {
     vec![]
 }
 
-fn log10f_cases() -> Vec<TestCase<op::log10f::Routine>> {
+fn sincosf_cases() -> Vec<TestCase<op::sincosf::Routine>> {
     vec![]
 }
 
-fn log1p_cases() -> Vec<TestCase<op::log1p::Routine>> {
+#[cfg(f128_enabled)]
+fn sincosf128_cases() -> Vec<TestCase<op::sincosf128::Routine>> {
     vec![]
 }
 
-fn log1pf_cases() -> Vec<TestCase<op::log1pf::Routine>> {
+#[cfg(f16_enabled)]
+fn sincosf16_cases() -> Vec<TestCase<op::sincosf16::Routine>> {
     vec![]
 }
 
-fn log2_cases() -> Vec<TestCase<op::log2::Routine>> {
+fn sincospi_cases() -> Vec<TestCase<op::sincospi::Routine>> {
     vec![]
 }
 
-fn log2f_cases() -> Vec<TestCase<op::log2f::Routine>> {
+fn sincospif_cases() -> Vec<TestCase<op::sincospif::Routine>> {
     vec![]
 }
 
-fn logf_cases() -> Vec<TestCase<op::logf::Routine>> {
+#[cfg(f128_enabled)]
+fn sincospif128_cases() -> Vec<TestCase<op::sincospif128::Routine>> {
     vec![]
 }
 
-fn modf_cases() -> Vec<TestCase<op::modf::Routine>> {
+#[cfg(f16_enabled)]
+fn sincospif16_cases() -> Vec<TestCase<op::sincospif16::Routine>> {
     vec![]
 }
 
-fn modff_cases() -> Vec<TestCase<op::modff::Routine>> {
+fn sinf_cases() -> Vec<TestCase<op::sinf::Routine>> {
     vec![]
 }
 
-fn nextafter_cases() -> Vec<TestCase<op::nextafter::Routine>> {
+#[cfg(f128_enabled)]
+fn sinf128_cases() -> Vec<TestCase<op::sinf128::Routine>> {
     vec![]
 }
 
-fn nextafterf_cases() -> Vec<TestCase<op::nextafterf::Routine>> {
+#[cfg(f16_enabled)]
+fn sinf16_cases() -> Vec<TestCase<op::sinf16::Routine>> {
     vec![]
 }
 
-fn pow_cases() -> Vec<TestCase<op::pow::Routine>> {
+fn sinh_cases() -> Vec<TestCase<op::sinh::Routine>> {
     vec![]
 }
 
-fn powf_cases() -> Vec<TestCase<op::powf::Routine>> {
+fn sinhf_cases() -> Vec<TestCase<op::sinhf::Routine>> {
     vec![]
 }
 
-fn remainder_cases() -> Vec<TestCase<op::remainder::Routine>> {
+#[cfg(f128_enabled)]
+fn sinhf128_cases() -> Vec<TestCase<op::sinhf128::Routine>> {
     vec![]
 }
 
-fn remainderf_cases() -> Vec<TestCase<op::remainderf::Routine>> {
+#[cfg(f16_enabled)]
+fn sinhf16_cases() -> Vec<TestCase<op::sinhf16::Routine>> {
     vec![]
 }
 
-fn remquo_cases() -> Vec<TestCase<op::remquo::Routine>> {
-    vec![]
+fn sinpi_cases() -> Vec<TestCase<op::sinpi::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact zeros take the sign of `x`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(0.0)),
+            ((-1.0,), Some(-0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(1.0)),
+            ((-1.5,), Some(1.0)),
+        ],
+    );
+    v
 }
 
-fn remquof_cases() -> Vec<TestCase<op::remquof::Routine>> {
-    vec![]
+fn sinpif_cases() -> Vec<TestCase<op::sinpif::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact zeros take the sign of `x`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(0.0)),
+            ((-1.0,), Some(-0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(1.0)),
+            ((-1.5,), Some(1.0)),
+        ],
+    );
+    v
 }
 
-fn rint_cases() -> Vec<TestCase<op::rint::Routine>> {
+#[cfg(f128_enabled)]
+fn sinpif128_cases() -> Vec<TestCase<op::sinpif128::Routine>> {
     let mut v = vec![];
     TestCase::append_pairs(
         &mut v,
         &[
-            // Known failure on i586
-            #[cfg(not(x86_no_sse))]
-            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff994000p+38"))),
-            #[cfg(x86_no_sse)]
-            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff998000p+38"))),
+            // Exact zeros take the sign of `x`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(0.0)),
+            ((-1.0,), Some(-0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(1.0)),
+            ((-1.5,), Some(1.0)),
         ],
     );
     v
 }
 
-fn rintf_cases() -> Vec<TestCase<op::rintf::Routine>> {
+#[cfg(f16_enabled)]
+fn sinpif16_cases() -> Vec<TestCase<op::sinpif16::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Exact zeros take the sign of `x`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(0.0)),
+            ((-1.0,), Some(-0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(1.0)),
+            ((-1.5,), Some(1.0)),
+        ],
+    );
+    v
+}
+
+fn sqrt_cases() -> Vec<TestCase<op::sqrt::Routine>> {
+    vec![]
+}
+
+fn sqrtf_cases() -> Vec<TestCase<op::sqrtf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn rintf128_cases() -> Vec<TestCase<op::rintf128::Routine>> {
+fn sqrtf128_cases() -> Vec<TestCase<op::sqrtf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn rintf16_cases() -> Vec<TestCase<op::rintf16::Routine>> {
+fn sqrtf16_cases() -> Vec<TestCase<op::sqrtf16::Routine>> {
+    vec![]
+}
+
+fn tan_cases() -> Vec<TestCase<op::tan::Routine>> {
+    vec![]
+}
+
+fn tanf_cases() -> Vec<TestCase<op::tanf::Routine>> {
+    vec![]
+}
+
+#[cfg(f128_enabled)]
+fn tanf128_cases() -> Vec<TestCase<op::tanf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn roundf16_cases() -> Vec<TestCase<op::roundf16::Routine>> {
+fn tanf16_cases() -> Vec<TestCase<op::tanf16::Routine>> {
     vec![]
 }
 
-fn round_cases() -> Vec<TestCase<op::round::Routine>> {
+fn tanh_cases() -> Vec<TestCase<op::tanh::Routine>> {
     vec![]
 }
 
-fn roundf_cases() -> Vec<TestCase<op::roundf::Routine>> {
+fn tanhf_cases() -> Vec<TestCase<op::tanhf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn roundf128_cases() -> Vec<TestCase<op::roundf128::Routine>> {
+fn tanhf128_cases() -> Vec<TestCase<op::tanhf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn roundevenf16_cases() -> Vec<TestCase<op::roundevenf16::Routine>> {
+fn tanhf16_cases() -> Vec<TestCase<op::tanhf16::Routine>> {
     vec![]
 }
 
-fn roundeven_cases() -> Vec<TestCase<op::roundeven::Routine>> {
+fn tanpi_cases() -> Vec<TestCase<op::tanpi::Routine>> {
     let mut v = vec![];
     TestCase::append_pairs(
         &mut v,
         &[
-            // Known failure on i586
-            #[cfg(not(x86_no_sse))]
-            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff994000p+38"))),
-            #[cfg(x86_no_sse)]
-            ((hf64!("-0x1.e3f13ff995ffcp+38"),), Some(hf64!("-0x1.e3f13ff998000p+38"))),
+            // Zeros follow the parity of `x` and poles are signed by the parity of `x - 1/2`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(-0.0)),
+            ((-1.0,), Some(0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(f64::INFINITY)),
+            ((-0.5,), Some(f64::NEG_INFINITY)),
+            ((1.5,), Some(f64::NEG_INFINITY)),
+            ((-1.5,), Some(f64::INFINITY)),
         ],
     );
     v
 }
 
-fn roundevenf_cases() -> Vec<TestCase<op::roundevenf::Routine>> {
-    vec![]
+fn tanpif_cases() -> Vec<TestCase<op::tanpif::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros follow the parity of `x` and poles are signed by the parity of `x - 1/2`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(-0.0)),
+            ((-1.0,), Some(0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(f32::INFINITY)),
+            ((-0.5,), Some(f32::NEG_INFINITY)),
+            ((1.5,), Some(f32::NEG_INFINITY)),
+            ((-1.5,), Some(f32::INFINITY)),
+        ],
+    );
+    v
 }
 
 #[cfg(f128_enabled)]
-fn roundevenf128_cases() -> Vec<TestCase<op::roundevenf128::Routine>> {
-    vec![]
+fn tanpif128_cases() -> Vec<TestCase<op::tanpif128::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros follow the parity of `x` and poles are signed by the parity of `x - 1/2`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(-0.0)),
+            ((-1.0,), Some(0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(f128::INFINITY)),
+            ((-0.5,), Some(f128::NEG_INFINITY)),
+            ((1.5,), Some(f128::NEG_INFINITY)),
+            ((-1.5,), Some(f128::INFINITY)),
+        ],
+    );
+    v
 }
 
-fn scalbn_cases() -> Vec<TestCase<op::scalbn::Routine>> {
+#[cfg(f16_enabled)]
+fn tanpif16_cases() -> Vec<TestCase<op::tanpif16::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Zeros follow the parity of `x` and poles are signed by the parity of `x - 1/2`
+            ((0.0,), Some(0.0)),
+            ((-0.0,), Some(-0.0)),
+            ((1.0,), Some(-0.0)),
+            ((-1.0,), Some(0.0)),
+            ((2.0,), Some(0.0)),
+            ((-2.0,), Some(-0.0)),
+            ((0.5,), Some(f16::INFINITY)),
+            ((-0.5,), Some(f16::NEG_INFINITY)),
+            ((1.5,), Some(f16::NEG_INFINITY)),
+            ((-1.5,), Some(f16::INFINITY)),
+        ],
+    );
+    v
+}
+
+fn tgamma_cases() -> Vec<TestCase<op::tgamma::Routine>> {
     vec![]
 }
 
-fn scalbnf_cases() -> Vec<TestCase<op::scalbnf::Routine>> {
+fn tgammaf_cases() -> Vec<TestCase<op::tgammaf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn scalbnf128_cases() -> Vec<TestCase<op::scalbnf128::Routine>> {
+fn tgammaf128_cases() -> Vec<TestCase<op::tgammaf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn scalbnf16_cases() -> Vec<TestCase<op::scalbnf16::Routine>> {
+fn tgammaf16_cases() -> Vec<TestCase<op::tgammaf16::Routine>> {
     vec![]
 }
 
-fn sin_cases() -> Vec<TestCase<op::sin::Routine>> {
-    vec![]
+fn totalorder_cases() -> Vec<TestCase<op::totalorder::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            ((-0.0, 0.0), Some(1)),
+            ((0.0, -0.0), Some(0)),
+            ((f64::INFINITY, f64::NAN), Some(1)),
+            ((f64::NAN, f64::INFINITY), Some(0)),
+            ((-f64::NAN, f64::NEG_INFINITY), Some(1)),
+        ],
+    );
+    v
 }
 
-fn sincos_cases() -> Vec<TestCase<op::sincos::Routine>> {
+fn totalorderf_cases() -> Vec<TestCase<op::totalorderf::Routine>> {
     vec![]
 }
 
-fn sincosf_cases() -> Vec<TestCase<op::sincosf::Routine>> {
+#[cfg(f128_enabled)]
+fn totalorderf128_cases() -> Vec<TestCase<op::totalorderf128::Routine>> {
     vec![]
 }
 
-fn sinf_cases() -> Vec<TestCase<op::sinf::Routine>> {
+#[cfg(f16_enabled)]
+fn totalorderf16_cases() -> Vec<TestCase<op::totalorderf16::Routine>> {
     vec![]
 }
 
-fn sinh_cases() -> Vec<TestCase<op::sinh::Routine>> {
-    vec![]
+fn totalordermag_cases() -> Vec<TestCase<op::totalordermag::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[((-1.0, 1.0), Some(1)), ((1.0, -0.5), Some(0)), ((-f64::NAN, f64::INFINITY), Some(0))],
+    );
+    v
 }
 
-fn sinhf_cases() -> Vec<TestCase<op::sinhf::Routine>> {
+fn totalordermagf_cases() -> Vec<TestCase<op::totalordermagf::Routine>> {
     vec![]
 }
 
-fn sqrt_cases() -> Vec<TestCase<op::sqrt::Routine>> {
+#[cfg(f128_enabled)]
+fn totalordermagf128_cases() -> Vec<TestCase<op::totalordermagf128::Routine>> {
     vec![]
 }
 
-fn sqrtf_cases() -> Vec<TestCase<op::sqrtf::Routine>> {
+#[cfg(f16_enabled)]
+fn totalordermagf16_cases() -> Vec<TestCase<op::totalordermagf16::Routine>> {
     vec![]
 }
 
-#[cfg(f128_enabled)]
-fn sqrtf128_cases() -> Vec<TestCase<op::sqrtf128::Routine>> {
+fn trunc_cases() -> Vec<TestCase<op::trunc::Routine>> {
     vec![]
 }
 
-#[cfg(f16_enabled)]
-fn sqrtf16_cases() -> Vec<TestCase<op::sqrtf16::Routine>> {
+fn truncf_cases() -> Vec<TestCase<op::truncf::Routine>> {
     vec![]
 }
 
-fn tan_cases() -> Vec<TestCase<op::tan::Routine>> {
+#[cfg(f128_enabled)]
+fn truncf128_cases() -> Vec<TestCase<op::truncf128::Routine>> {
     vec![]
 }
 
-fn tanf_cases() -> Vec<TestCase<op::tanf::Routine>> {
+#[cfg(f16_enabled)]
+fn truncf16_cases() -> Vec<TestCase<op::truncf16::Routine>> {
     vec![]
 }
 
-fn tanh_cases() -> Vec<TestCase<op::tanh::Routine>> {
-    vec![]
+fn ufromfp_cases() -> Vec<TestCase<op::ufromfp::Routine>> {
+    let mut v = vec![];
+    TestCase::append_pairs(
+        &mut v,
+        &[
+            // Negative values that round to zero are in range and keep their sign
+            ((-0.5, Round::Positive, 1), Some(-0.0)),
+            ((-0.5, Round::NearestAway, 1), Some(f64::NAN)),
+            ((-1.0, Round::Positive, 64), Some(f64::NAN)),
+            // The edge of `u32`
+            ((hf64!("0x1.fffffffep+31"), Round::Negative, 32), Some(hf64!("0x1.fffffffep+31"))),
+            ((hf64!("0x1.ffffffffp+31"), Round::Negative, 32), Some(hf64!("0x1.fffffffep+31"))),
+            ((hf64!("0x1.ffffffffp+31"), Round::Nearest, 32), Some(f64::NAN)),
+        ],
+    );
+    v
 }
 
-fn tanhf_cases() -> Vec<TestCase<op::tanhf::Routine>> {
+fn ufromfpf_cases() -> Vec<TestCase<op::ufromfpf::Routine>> {
     vec![]
 }
 
-fn tgamma_cases() -> Vec<TestCase<op::tgamma::Routine>> {
+#[cfg(f128_enabled)]
+fn ufromfpf128_cases() -> Vec<TestCase<op::ufromfpf128::Routine>> {
     vec![]
 }
 
-fn tgammaf_cases() -> Vec<TestCase<op::tgammaf::Routine>> {
+#[cfg(f16_enabled)]
+fn ufromfpf16_cases() -> Vec<TestCase<op::ufromfpf16::Routine>> {
     vec![]
 }
 
-fn trunc_cases() -> Vec<TestCase<op::trunc::Routine>> {
+fn ufromfpx_cases() -> Vec<TestCase<op::ufromfpx::Routine>> {
     vec![]
 }
 
-fn truncf_cases() -> Vec<TestCase<op::truncf::Routine>> {
+fn ufromfpxf_cases() -> Vec<TestCase<op::ufromfpxf::Routine>> {
     vec![]
 }
 
 #[cfg(f128_enabled)]
-fn truncf128_cases() -> Vec<TestCase<op::truncf128::Routine>> {
+fn ufromfpxf128_cases() -> Vec<TestCase<op::ufromfpxf128::Routine>> {
     vec![]
 }
 
 #[cfg(f16_enabled)]
-fn truncf16_cases() -> Vec<TestCase<op::truncf16::Routine>> {
+fn ufromfpxf16_cases() -> Vec<TestCase<op::ufromfpxf16::Routine>> {
     vec![]
 }
 
@@ -782,6 +2705,11 @@ fn y0f_cases() -> Vec<TestCase<op::y0f::Routine>> {
     vec![]
 }
 
+#[cfg(f128_enabled)]
+fn y0f128_cases() -> Vec<TestCase<op::y0f128::Routine>> {
+    vec![]
+}
+
 fn y1_cases() -> Vec<TestCase<op::y1::Routine>> {
     vec![]
 }
@@ -790,6 +2718,11 @@ fn y1f_cases() -> Vec<TestCase<op::y1f::Routine>> {
     vec![]
 }
 
+#[cfg(f128_enabled)]
+fn y1f128_cases() -> Vec<TestCase<op::y1f128::Routine>> {
+    vec![]
+}
+
 fn yn_cases() -> Vec<TestCase<op::yn::Routine>> {
     vec![]
 }
@@ -798,6 +2731,11 @@ fn ynf_cases() -> Vec<TestCase<op::ynf::Routine>> {
     vec![]
 }
 
+#[cfg(f128_enabled)]
+fn ynf128_cases() -> Vec<TestCase<op::ynf128::Routine>> {
+    vec![]
+}
+
 pub trait CaseListInput: MathOp + Sized {
     fn get_cases() -> Vec<TestCase<Self>>;
 }
@@ -1,9 +1,10 @@
 //! A generator that checks a handful of cases near infinities, zeros, asymptotes, and NaNs.
 
+use libm::Round;
 use libm::support::{CastInto, Float, Int, MinInt};
 
 use crate::domain::get_domain;
-use crate::generate::KnownSize;
+use crate::generate::{FROMFP_ROUNDING, KnownSize, fromfp_widths};
 use crate::op::OpITy;
 use crate::run_cfg::{check_near_count, check_point_count};
 use crate::{BaseName, CheckCtx, FloatExt, FloatTy, MathOp, test_log};
@@ -139,7 +140,7 @@ where
     int_count_around(I::ZERO, near_points, &mut values);
     int_count_around(I::ZERO, near_points, &mut values);
 
-    if matches!(ctx.base_name, BaseName::Scalbn | BaseName::Ldexp) {
+    if matches!(ctx.base_name, BaseName::Scalbn | BaseName::Scalbln | BaseName::Ldexp) {
         assert_eq!(argnum, 1, "scalbn integer argument should be arg1");
         let (emax, emin, emin_sn) = match ctx.fn_ident.math_op().float_ty {
             FloatTy::F16 => {
@@ -286,6 +287,42 @@ macro_rules! impl_edge_case_input {
                 (iter, count)
             }
         }
+
+        impl<Op> EdgeCaseInput<Op> for ($fty, i64)
+        where
+            Op: MathOp<RustArgs = Self, FTy = $fty>,
+        {
+            fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+                let (iter0, steps0) = float_edge_cases::<Op>(ctx, 0);
+                let (iter1, steps1) = int_edge_cases(ctx, 1);
+
+                let iter =
+                    iter0.flat_map(move |first| iter1.clone().map(move |second| (first, second)));
+                let count = steps0.checked_mul(steps1).unwrap();
+
+                (iter, count)
+            }
+        }
+
+        impl<Op> EdgeCaseInput<Op> for ($fty, Round, u32)
+        where
+            Op: MathOp<RustArgs = Self, FTy = $fty>,
+        {
+            fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+                let (iter0, steps0) = float_edge_cases::<Op>(ctx, 0);
+                let widths = fromfp_widths::<$fty>();
+
+                let iter = iter0.flat_map(move |x| {
+                    FROMFP_ROUNDING
+                        .into_iter()
+                        .flat_map(move |round| widths.into_iter().map(move |w| (x, round, w)))
+                });
+                let per_float = (FROMFP_ROUNDING.len() * widths.len()) as u64;
+                let count = steps0.checked_mul(per_float).unwrap();
+
+                (iter, count)
+            }
+        }
     };
 }
 
@@ -296,6 +333,22 @@ impl_edge_case_input!(f64);
 #[cfg(f128_enabled)]
 impl_edge_case_input!(f128);
 
+/// `nexttowardf` takes its direction as an `f64`, which uses the `f32` edge cases.
+impl<Op> EdgeCaseInput<Op> for (f32, f64)
+where
+    Op: MathOp<RustArgs = Self, FTy = f32>,
+{
+    fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+        let (iter0, steps0) = float_edge_cases::<Op>(ctx, 0);
+        let (iter1, steps1) = float_edge_cases::<Op>(ctx, 1);
+        let iter = iter0.flat_map(move |first| {
+            iter1.clone().map(move |second: f32| (first, f64::from(second)))
+        });
+        let count = steps0.checked_mul(steps1).unwrap();
+        (iter, count)
+    }
+}
+
 pub fn get_test_cases<Op>(
     ctx: &CheckCtx,
 ) -> (impl Iterator<Item = Op::RustArgs> + Send + use<'_, Op>, u64)
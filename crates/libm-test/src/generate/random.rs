@@ -2,13 +2,14 @@ use std::env;
 use std::ops::RangeInclusive;
 use std::sync::LazyLock;
 
+use libm::Round;
 use libm::support::Float;
 use rand::distr::{Alphanumeric, StandardUniform};
 use rand::prelude::Distribution;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
-use super::KnownSize;
+use super::{FROMFP_ROUNDING, KnownSize};
 use crate::CheckCtx;
 use crate::run_cfg::{int_range, iteration_count};
 
@@ -104,6 +105,34 @@ macro_rules! impl_random_input {
                 (iter, count0 * count1)
             }
         }
+
+        impl RandomInput for ($fty, i64) {
+            fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+                let count0 = iteration_count(ctx, 0);
+                let count1 = iteration_count(ctx, 1);
+                let range1 = int_range(ctx, 1);
+                let iter = random_floats(count0).flat_map(move |f1: $fty| {
+                    random_ints(count1, range1.clone()).map(move |f2: i32| (f1, f2.into()))
+                });
+                (iter, count0 * count1)
+            }
+        }
+
+        impl RandomInput for ($fty, Round, u32) {
+            fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+                // Pick the rounding direction and width at random along with the float, rather
+                // than testing every combination.
+                let count = iteration_count(ctx, 0);
+                let max_width = <$fty>::EXP_MAX as u32 + 2;
+                let mut rng = ChaCha8Rng::from_seed(*SEED);
+                let iter = (0..count).map(move |_| {
+                    let x = <$fty>::from_bits(rng.random());
+                    let round = FROMFP_ROUNDING[rng.random_range(0..FROMFP_ROUNDING.len())];
+                    (x, round, rng.random_range(0..=max_width))
+                });
+                (iter, count)
+            }
+        }
     };
 }
 
@@ -114,6 +143,17 @@ impl_random_input!(f64);
 #[cfg(f128_enabled)]
 impl_random_input!(f128);
 
+/// `nexttowardf` takes its direction as an `f64`.
+impl RandomInput for (f32, f64) {
+    fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+        let count0 = iteration_count(ctx, 0);
+        let count1 = iteration_count(ctx, 1);
+        let iter = random_floats(count0)
+            .flat_map(move |f1: f32| random_floats(count1).map(move |f2: f64| (f1, f2)));
+        (iter, count0 * count1)
+    }
+}
+
 /// Create a test case iterator.
 pub fn get_test_cases<RustArgs: RandomInput>(
     ctx: &CheckCtx,
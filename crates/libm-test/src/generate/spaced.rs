@@ -1,9 +1,11 @@
 use std::fmt;
 use std::ops::RangeInclusive;
 
+use libm::Round;
 use libm::support::{Float, MinInt};
 
 use crate::domain::get_domain;
+use crate::generate::{FROMFP_ROUNDING, fromfp_widths};
 use crate::op::OpITy;
 use crate::run_cfg::{int_range, iteration_count};
 use crate::{CheckCtx, MathOp, linear_ints, logspace};
@@ -231,6 +233,63 @@ macro_rules! impl_spaced_input {
                 }
             }
         }
+
+        impl<Op> SpacedInput<Op> for ($fty, i64)
+        where
+            Op: MathOp<RustArgs = Self, FTy = $fty>,
+        {
+            fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+                let max_steps0 = iteration_count(ctx, 0);
+                let range1 = int_range(ctx, 1);
+                let max_steps1 = iteration_count(ctx, 1);
+                match value_count::<Op::FTy>() {
+                    Some(count0) if count0 <= max_steps0 => {
+                        let (iter1, steps1) = linear_ints(range1, max_steps1);
+                        let iter = all_values().flat_map(move |first| {
+                            iter1.clone().map(move |second| (first, second.into()))
+                        });
+                        (EitherIter::A(iter), count0.checked_mul(steps1).unwrap())
+                    }
+                    _ => {
+                        let (iter0, steps0) = logspace_steps::<Op>(ctx, 0, max_steps0);
+                        let (iter1, steps1) = linear_ints(range1, max_steps1);
+
+                        let iter = iter0.flat_map(move |first| {
+                            iter1.clone().map(move |second| (first, second.into()))
+                        });
+                        let count = steps0.checked_mul(steps1).unwrap();
+
+                        (EitherIter::B(iter), count)
+                    }
+                }
+            }
+        }
+
+        impl<Op> SpacedInput<Op> for ($fty, Round, u32)
+        where
+            Op: MathOp<RustArgs = Self, FTy = $fty>,
+        {
+            fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+                // Every float is checked with each rounding direction and width.
+                let widths = fromfp_widths::<$fty>();
+                let per_float = (FROMFP_ROUNDING.len() * widths.len()) as u64;
+                let max_steps0 = (iteration_count(ctx, 0) / per_float).max(1);
+                let (iter0, steps0) = match value_count::<Op::FTy>() {
+                    Some(count0) if count0 <= max_steps0 => (EitherIter::A(all_values()), count0),
+                    _ => {
+                        let (iter0, steps0) = logspace_steps::<Op>(ctx, 0, max_steps0);
+                        (EitherIter::B(iter0), steps0)
+                    }
+                };
+
+                let iter = iter0.flat_map(move |x| {
+                    FROMFP_ROUNDING
+                        .into_iter()
+                        .flat_map(move |round| widths.into_iter().map(move |w| (x, round, w)))
+                });
+                (iter, steps0.checked_mul(per_float).unwrap())
+            }
+        }
     };
 }
 
@@ -241,6 +300,25 @@ impl_spaced_input!(f64);
 #[cfg(f128_enabled)]
 impl_spaced_input!(f128);
 
+/// `nexttowardf` takes its direction as an `f64`. Directions are spaced over the `f32` values,
+/// which is enough to cover every ordering with `x`.
+impl<Op> SpacedInput<Op> for (f32, f64)
+where
+    Op: MathOp<RustArgs = Self, FTy = f32>,
+{
+    fn get_cases(ctx: &CheckCtx) -> (impl Iterator<Item = Self>, u64) {
+        let max_steps0 = iteration_count(ctx, 0);
+        let max_steps1 = iteration_count(ctx, 1);
+        let (iter0, steps0) = logspace_steps::<Op>(ctx, 0, max_steps0);
+        let (iter1, steps1) = logspace_steps::<Op>(ctx, 1, max_steps1);
+        let iter = iter0.flat_map(move |first| {
+            iter1.clone().map(move |second: f32| (first, f64::from(second)))
+        });
+        let count = steps0.checked_mul(steps1).unwrap();
+        (iter, count)
+    }
+}
+
 /// Create a test case iterator for extensive inputs. Also returns the total test case count.
 pub fn get_test_cases<Op>(
     ctx: &CheckCtx,
@@ -4,12 +4,15 @@
 //! a struct named `Operation` that implements [`MpOp`].
 
 use std::cmp::Ordering;
+use std::ffi::{c_int, c_long};
 
-use rug::Assign;
+use gmp_mpfr_sys::mpfr;
+use libm::Round;
 pub use rug::Float as MpFloat;
 use rug::az::{self, Az};
 use rug::float::Round::Nearest;
-use rug::ops::{PowAssignRound, RemAssignRound};
+use rug::ops::{AssignRound, NegAssign, PowAssignRound, RemAssignRound};
+use rug::{Assign, Integer};
 
 use crate::{Float, MathOp};
 
@@ -19,14 +22,64 @@ fn new_mpfloat<F: Float>() -> MpFloat {
 }
 
 /// Set subnormal emulation and convert to a concrete float type.
+///
+/// This is done with MPFR's exponent range set to that of `F`, so MPFR's underflow and overflow
+/// flags are raised like they would be for `F`. MPFR detects tininess after rounding, so a
+/// result that was rounded up to the smallest normal number also raises underflow, which
+/// matches tininess before rounding.
 fn prep_retval<F: Float>(mp: &mut MpFloat, ord: Ordering) -> F
 where
     for<'a> &'a MpFloat: az::Cast<F>,
 {
-    mp.subnormalize_ieee_round(ord, Nearest);
+    let rounded_up = if mp.is_sign_negative() { ord.is_lt() } else { ord.is_gt() };
+    // Values in the binade of the smallest normal are exact in `F`.
+    let min_normal =
+        mp.get_exp() == Some(F::EXP_MIN + 1) && (&*mp).az::<F>().abs() == F::MIN_POSITIVE_NORMAL;
+
+    // SAFETY: `mp` is initialized, and the exponent range is restored before returning. MPFR's
+    // significands are in `[0.5, 1)`, so its exponents are one more than IEEE 754's.
+    unsafe {
+        let (emin, emax) = (mpfr::get_emin(), mpfr::get_emax());
+        mpfr::set_emin((F::EXP_MIN_SUBNORM + 1).into());
+        mpfr::set_emax((F::EXP_MAX + 1).into());
+        let t = mpfr::check_range(mp.as_raw_mut(), ord as c_int, mpfr::rnd_t::RNDN);
+        mpfr::subnormalize(mp.as_raw_mut(), t, mpfr::rnd_t::RNDN);
+        mpfr::set_emin(emin);
+        mpfr::set_emax(emax);
+
+        if min_normal && rounded_up {
+            mpfr::set_underflow();
+        }
+    }
+
     (&*mp).az::<F>()
 }
 
+/// Precision used for intermediate results when MPFR has no direct operation.
+const EXTRA_PREC: u32 = 256;
+
+/// Convert the ternary value returned by raw MPFR functions to an `Ordering`.
+fn ternary_ord(t: c_int) -> Ordering {
+    t.cmp(&0)
+}
+
+/// MPFR takes the integer argument of some functions as a C `long`, which may only be 32 bits.
+#[allow(clippy::useless_conversion)] // `c_long` is `i64` on some platforms
+fn to_long(n: i64) -> Option<c_long> {
+    n.try_into().ok()
+}
+
+/// Return a `long` that has the same sign and parity as `n`, for use where only those affect the
+/// result.
+fn long_proxy(n: i64) -> c_long {
+    match (n > 0, n % 2 != 0) {
+        (true, true) => c_long::MAX,
+        (true, false) => c_long::MAX - 1,
+        (false, false) => c_long::MIN,
+        (false, true) => c_long::MIN + 1,
+    }
+}
+
 /// Structures that represent a float operation.
 ///
 pub trait MpOp: MathOp {
@@ -133,26 +186,63 @@ libm_macros::for_each_function! {
     skip: [
         // Most of these need a manual implementation
         // verify-sorted-start
+        canonicalize,
+        canonicalizef,
+        canonicalizef128,
+        canonicalizef16,
         ceil,
         ceilf,
         ceilf128,
         ceilf16,
+        compoundn,
+        compoundnf,
         copysign,
         copysignf,
         copysignf128,
         copysignf16,
+        f16addf32,
+        f16divf32,
+        f16fmaf32,
+        f16mulf32,
+        f16sqrtf32,
+        f16subf32,
+        f64addf128,
+        f64divf128,
+        f64fmaf128,
+        f64mulf128,
+        f64sqrtf128,
+        f64subf128,
         fabs,
         fabsf,
         fabsf128,
         fabsf16,floor,
+        fadd,
+        fdiv,
+        ffma,
         floorf,
         floorf128,
         floorf16,
         fmaximum,
+        fmaximum_mag,
+        fmaximum_mag_num,
+        fmaximum_mag_numf,
+        fmaximum_mag_numf128,
+        fmaximum_mag_numf16,
+        fmaximum_magf,
+        fmaximum_magf128,
+        fmaximum_magf16,
         fmaximumf,
         fmaximumf128,
         fmaximumf16,
         fminimum,
+        fminimum_mag,
+        fminimum_mag_num,
+        fminimum_mag_numf,
+        fminimum_mag_numf128,
+        fminimum_mag_numf16,
+        fminimum_magf,
+        fminimum_magf128,
+        fminimum_magf16,
         fminimumf,
         fminimumf128,
         fminimumf16,
@@ -160,29 +250,109 @@ libm_macros::for_each_function! {
         fmodf,
         fmodf128,
         fmodf16,
+        fmul,
         frexp,
         frexpf,
+        frexpf128,
+        frexpf16,
+        fromfp,
+        fromfpf,
+        fromfpf128,
+        fromfpf16,
+        fromfpx,
+        fromfpxf,
+        fromfpxf128,
+        fromfpxf16,
+        fsqrt,
+        fsub,
+        getpayload,
+        getpayloadf,
+        getpayloadf128,
+        getpayloadf16,
         ilogb,
         ilogbf,
+        ilogbf128,
+        ilogbf16,
+        issignaling,
+        issignalingf,
+        issignalingf128,
+        issignalingf16,
         jn,
         jnf,
+        jnf128,
         ldexp,
         ldexpf,
         ldexpf128,
         ldexpf16,
         lgamma_r,
+        lgammaf128,
+        lgammaf128_r,
+        lgammaf16,
         lgammaf_r,
+        llogb,
+        llogbf,
+        llogbf128,
+        llogbf16,
+        llrint,
+        llrintf,
+        llrintf128,
+        llrintf16,
+        llround,
+        llroundf,
+        llroundf128,
+        llroundf16,
+        logb,
+        logbf,
+        logbf128,
+        logbf16,
+        lrint,
+        lrintf,
+        lrintf128,
+        lrintf16,
+        lround,
+        lroundf,
+        lroundf128,
+        lroundf16,
         modf,
         modff,
+        modff128,
+        modff16,
+        nearbyint,
+        nearbyintf,
+        nearbyintf128,
+        nearbyintf16,
         nextafter,
         nextafterf,
+        nextafterf128,
+        nextafterf16,
+        nextdown,
+        nextdownf,
+        nextdownf128,
+        nextdownf16,
+        nexttoward,
+        nexttowardf,
+        nextup,
+        nextupf,
+        nextupf128,
+        nextupf16,
         pow,
-        powf,remquo,
+        powf,
+        powf128,
+        powf16,
+        pown,
+        pownf,
+        powr,
+        powrf,
+        remquo,
         remquof,
+        remquof128,
+        remquof16,
         rint,
         rintf,
         rintf128,
         rintf16,
+        rootn,
+        rootnf,
         round,
         roundeven,
         roundevenf,
@@ -191,32 +361,82 @@ libm_macros::for_each_function! {
         roundf,
         roundf128,
         roundf16,
+        rsqrt,
+        rsqrtf,
+        scalbln,
+        scalblnf,
+        scalblnf128,
+        scalblnf16,
         scalbn,
         scalbnf,
         scalbnf128,
         scalbnf16,
+        setpayload,
+        setpayloadf,
+        setpayloadf128,
+        setpayloadf16,
+        setpayloadsig,
+        setpayloadsigf,
+        setpayloadsigf128,
+        setpayloadsigf16,
         sincos,sincosf,
+        sincosf128,
+        sincosf16,
+        sincospi,
+        sincospif,
+        sincospif128,
+        sincospif16,
+        totalorder,
+        totalorderf,
+        totalorderf128,
+        totalorderf16,
+        totalordermag,
+        totalordermagf,
+        totalordermagf128,
+        totalordermagf16,
         trunc,
         truncf,
         truncf128,
-        truncf16,yn,
+        truncf16,
+        ufromfp,
+        ufromfpf,
+        ufromfpf128,
+        ufromfpf16,
+        ufromfpx,
+        ufromfpxf,
+        ufromfpxf128,
+        ufromfpxf16,
+        yn,
         ynf,
+        ynf128,
         // verify-sorted-end
     ],
     fn_extra: match MACRO_FN_NAME {
         // Remap function names that are different between mpfr and libm
-        expm1 | expm1f => exp_m1,
+        acospi | acospif | acospif16 | acospif128 => acos_pi,
+        asinpi | asinpif | asinpif16 | asinpif128 => asin_pi,
+        atan2pi | atan2pif | atan2pif16 | atan2pif128 => atan2_pi,
+        atanpi | atanpif | atanpif16 | atanpif128 => atan_pi,
+        cospi | cospif | cospif16 | cospif128 => cos_pi,
+        exp10m1 | exp10m1f => exp10_m1,
+        exp2m1 | exp2m1f => exp2_m1,
+        expm1 | expm1f | expm1f128 | expm1f16 => exp_m1,
         fabs | fabsf => abs,
         fdim | fdimf | fdimf16 | fdimf128  => positive_diff,
-        fma | fmaf | fmaf128 => mul_add,
+        fma | fmaf | fmaf16 | fmaf128 => mul_add,
         fmax | fmaxf | fmaxf16 | fmaxf128 |
         fmaximum_num | fmaximum_numf | fmaximum_numf16 | fmaximum_numf128 => max,
         fmin | fminf | fminf16 | fminf128 |
         fminimum_num | fminimum_numf | fminimum_numf16 | fminimum_numf128 => min,
         lgamma | lgammaf => ln_gamma,
-        log | logf => ln,
-        log1p | log1pf => ln_1p,
-        tgamma | tgammaf => gamma,
+        log | logf | logf128 | logf16 => ln,
+        log10p1 | log10p1f => log10_1p,
+        log1p | log1pf | log1pf128 | log1pf16 => ln_1p,
+        log2p1 | log2p1f => log2_1p,
+        logp1 | logp1f => ln_1p,
+        sinpi | sinpif | sinpif16 | sinpif128 => sin_pi,
+        tanpi | tanpif | tanpif16 | tanpif128 => tan_pi,
+        tgamma | tgammaf | tgammaf16 | tgammaf128 => gamma,
         _ => MACRO_FN_NAME_NORMALIZED
     }
 }
@@ -254,6 +474,8 @@ impl_no_round! {
     fabsf => abs_mut;
     floor => floor_mut;
     floorf => floor_mut;
+    nearbyint => round_even_mut;
+    nearbyintf => round_even_mut;
     rint => round_even_mut; // FIXME: respect rounding mode
     rintf => round_even_mut; // FIXME: respect rounding mode
     round => round_mut;
@@ -269,6 +491,7 @@ impl_no_round! {
     ceilf16 => ceil_mut;
     fabsf16 => abs_mut;
     floorf16 => floor_mut;
+    nearbyintf16 => round_even_mut;
     rintf16 => round_even_mut; // FIXME: respect rounding mode
     roundf16 => round_mut;
     roundevenf16 => round_even_mut;
@@ -280,14 +503,269 @@ impl_no_round! {
     ceilf128 => ceil_mut;
     fabsf128 => abs_mut;
     floorf128 => floor_mut;
+    nearbyintf128 => round_even_mut;
     rintf128 => round_even_mut; // FIXME: respect rounding mode
     roundf128 => round_mut;
     roundevenf128 => round_even_mut;
     truncf128 => trunc_mut;
 }
 
+/// Implement functions that round to an integer and return it as `i64`. NaN, infinities and
+/// out of range values return `i64::MIN`.
+macro_rules! impl_to_i64 {
+    ($($fn_name:ident => $rug_name:ident;)*) => {
+        $(
+            impl MpOp for crate::op::$fn_name::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.assign(input.0);
+                    this.$rug_name();
+                    this.to_integer().and_then(|int| int.to_i64()).unwrap_or(i64::MIN)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_i64! {
+    llrint => round_even_mut; // FIXME: respect rounding mode
+    llrintf => round_even_mut; // FIXME: respect rounding mode
+    llround => round_mut;
+    llroundf => round_mut;
+    lrint => round_even_mut; // FIXME: respect rounding mode
+    lrintf => round_even_mut; // FIXME: respect rounding mode
+    lround => round_mut;
+    lroundf => round_mut;
+}
+
+#[cfg(f16_enabled)]
+impl_to_i64! {
+    llrintf16 => round_even_mut; // FIXME: respect rounding mode
+    llroundf16 => round_mut;
+    lrintf16 => round_even_mut; // FIXME: respect rounding mode
+    lroundf16 => round_mut;
+}
+
+#[cfg(f128_enabled)]
+impl_to_i64! {
+    llrintf128 => round_even_mut; // FIXME: respect rounding mode
+    llroundf128 => round_mut;
+    lrintf128 => round_even_mut; // FIXME: respect rounding mode
+    lroundf128 => round_mut;
+}
+
 /// Some functions are difficult to do in a generic way. Implement them here.
 macro_rules! impl_op_for_ty {
+    ($fty:ty, $suffix:literal) => {
+        paste::paste! {
+            impl MpOp for crate::op::[<jn $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    let (n, x) = input;
+                    this.assign(x);
+                    let ord = this.jn_round(n, Nearest);
+                    prep_retval::<Self::FTy>(this, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[<yn $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    let (n, x) = input;
+                    this.assign(x);
+                    let ord = this.yn_round(n, Nearest);
+                    prep_retval::<Self::FTy>(this, ord)
+                }
+            }
+        }
+    };
+}
+
+/// Version of `impl_op_for_ty` for functions that only have `f32` and `f64` implementations.
+macro_rules! impl_op_for_ty_f32_f64 {
+    ($fty:ty, $suffix:literal) => {
+        paste::paste! {
+            impl MpOp for crate::op::[<compoundn $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    let (x, n) = input;
+                    this.assign(x);
+                    let ord = match to_long(n) {
+                        // SAFETY: MPFR allows the output to alias the input.
+                        Some(n) => ternary_ord(unsafe {
+                            mpfr::compound_si(this.as_raw_mut(), this.as_raw(), n, mpfr::rnd_t::RNDN)
+                        }),
+                        None if !this.is_finite() || *this <= -1 => ternary_ord(unsafe {
+                            mpfr::compound_si(
+                                this.as_raw_mut(),
+                                this.as_raw(),
+                                long_proxy(n),
+                                mpfr::rnd_t::RNDN,
+                            )
+                        }),
+                        None => {
+                            // (1 + x)^n = exp(n * ln(1 + x))
+                            let mut t = MpFloat::with_val(EXTRA_PREC, &*this);
+                            t.ln_1p_mut();
+                            t *= n;
+                            t.exp_mut();
+                            this.assign_round(&t, Nearest)
+                        }
+                    };
+                    prep_retval::<Self::FTy>(this, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[<pown $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.assign(input.0);
+                    let ord = this.pow_assign_round(input.1, Nearest);
+                    prep_retval::<Self::FTy>(this, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[<powr $suffix>]::Routine {
+                type MpTy = (MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    // SAFETY: MPFR allows the output to alias the input.
+                    let ord = ternary_ord(unsafe {
+                        mpfr::powr(
+                            this.0.as_raw_mut(),
+                            this.0.as_raw(),
+                            this.1.as_raw(),
+                            mpfr::rnd_t::RNDN,
+                        )
+                    });
+                    prep_retval::<Self::FTy>(&mut this.0, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[<rootn $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    let (x, n) = input;
+                    this.assign(x);
+                    let ord = match to_long(n) {
+                        // SAFETY: MPFR allows the output to alias the input.
+                        Some(n) => ternary_ord(unsafe {
+                            mpfr::rootn_si(this.as_raw_mut(), this.as_raw(), n, mpfr::rnd_t::RNDN)
+                        }),
+                        None if !this.is_normal() || (*this < 0 && n % 2 == 0) => {
+                            ternary_ord(unsafe {
+                                mpfr::rootn_si(
+                                    this.as_raw_mut(),
+                                    this.as_raw(),
+                                    long_proxy(n),
+                                    mpfr::rnd_t::RNDN,
+                                )
+                            })
+                        }
+                        None => {
+                            // |x|^(1/n) = exp(ln(|x|) / n), with the sign of `x`
+                            let mut t = MpFloat::with_val(EXTRA_PREC, &*this);
+                            let neg = t.is_sign_negative();
+                            t.abs_mut();
+                            t.ln_mut();
+                            t /= n;
+                            t.exp_mut();
+                            if neg {
+                                t = -t;
+                            }
+                            this.assign_round(&t, Nearest)
+                        }
+                    };
+                    prep_retval::<Self::FTy>(this, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[<rsqrt $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.assign(input.0);
+                    // MPFR returns +inf for -0, but C follows IEEE 754 `rSqrt` with -inf. Still
+                    // let MPFR compute it so it raises divide-by-zero.
+                    let neg_zero = this.is_zero() && this.is_sign_negative();
+                    let ord = this.recip_sqrt_round(Nearest);
+                    if neg_zero {
+                        this.neg_assign();
+                    }
+                    prep_retval::<Self::FTy>(this, ord)
+                }
+            }
+        }
+    };
+}
+
+/// Return the payload bits for `pl` if it is a nonnegative integer less than `limit`. Negative
+/// zero is not a valid payload.
+fn payload<F, I>(this: &mut MpFloat, pl: F, limit: I) -> Option<I>
+where
+    F: Float,
+    MpFloat: Assign<F>,
+    Integer: PartialOrd<I>,
+    I: az::Cast<u128> + TryFrom<u128>,
+{
+    if pl.is_sign_negative() {
+        return None;
+    }
+
+    this.assign(pl);
+    if !this.is_integer() {
+        return None;
+    }
+
+    let int = this.to_integer().unwrap();
+    if int >= limit {
+        return None;
+    }
+    int.to_u128().and_then(|v| v.try_into().ok())
+}
+
+/// Version of `impl_op_for_ty` for functions that also have `f16` implementations.
+macro_rules! impl_op_for_ty_all {
     ($fty:ty, $suffix:literal) => {
         paste::paste! {
             impl MpOp for crate::op::[<modf $suffix>]::Routine {
@@ -308,18 +786,22 @@ macro_rules! impl_op_for_ty {
                 }
             }
 
-            impl MpOp for crate::op::[<pow $suffix>]::Routine {
-                type MpTy = (MpFloat, MpFloat);
+            impl MpOp for crate::op::[<canonicalize $suffix>]::Routine {
+                type MpTy = MpFloat;
 
                 fn new_mp() -> Self::MpTy {
-                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
+                    new_mpfloat::<Self::FTy>()
                 }
 
                 fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
-                    this.0.assign(input.0);
-                    this.1.assign(input.1);
-                    let ord = this.0.pow_assign_round(&this.1, Nearest);
-                    prep_retval::<Self::RustRet>(&mut this.0, ord)
+                    // MPFR doesn't track NaN payloads, so quiet NaNs by setting the bit directly.
+                    if input.0.is_nan() {
+                        let quiet_bit = 1 << (<$fty>::MANTISSA_DIGITS - 2);
+                        return <$fty>::from_bits(input.0.to_bits() | quiet_bit);
+                    }
+
+                    this.assign(input.0);
+                    prep_retval::<Self::FTy>(this, Ordering::Equal)
                 }
             }
 
@@ -337,6 +819,24 @@ macro_rules! impl_op_for_ty {
                 }
             }
 
+            impl MpOp for crate::op::[<getpayload $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    if !input.0.is_nan() {
+                        return -1.0;
+                    }
+
+                    let pl_mask = (1 << (<$fty>::MANTISSA_DIGITS - 2)) - 1;
+                    this.assign(Integer::from(input.0.to_bits() & pl_mask));
+                    prep_retval::<Self::FTy>(this, Ordering::Equal)
+                }
+            }
+
             impl MpOp for crate::op::[<ilogb $suffix>]::Routine {
                 type MpTy = MpFloat;
 
@@ -360,7 +860,18 @@ macro_rules! impl_op_for_ty {
                 }
             }
 
-            impl MpOp for crate::op::[<jn $suffix>]::Routine {
+            impl MpOp for crate::op::[<issignaling $suffix>]::Routine {
+                type MpTy = ();
+
+                fn new_mp() -> Self::MpTy {}
+
+                fn run(_this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    let quiet_bit = 1 << (<$fty>::MANTISSA_DIGITS - 2);
+                    i32::from(input.0.is_nan() && input.0.to_bits() & quiet_bit == 0)
+                }
+            }
+
+            impl MpOp for crate::op::[<llogb $suffix>]::Routine {
                 type MpTy = MpFloat;
 
                 fn new_mp() -> Self::MpTy {
@@ -368,28 +879,60 @@ macro_rules! impl_op_for_ty {
                 }
 
                 fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
-                    let (n, x) = input;
-                    this.assign(x);
-                    let ord = this.jn_round(n, Nearest);
-                    prep_retval::<Self::FTy>(this, ord)
+                    this.assign(input.0);
+
+                    // Same as `ilogb`
+                    this.get_exp().map(|v| i64::from(v) - 1).unwrap_or_else(|| {
+                        if this.is_infinite() {
+                            i64::MAX
+                        } else {
+                            // Zero or NaN
+                            i64::MIN
+                        }
+                    })
                 }
             }
 
-            impl MpOp for crate::op::[<sincos $suffix>]::Routine {
-                type MpTy = (MpFloat, MpFloat);
+            impl MpOp for crate::op::[<nextdown $suffix>]::Routine {
+                type MpTy = ();
+
+                fn new_mp() -> Self::MpTy {}
+
+                fn run(_this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    // MPFR's `next_below` doesn't know about subnormals, so use our own.
+                    input.0.next_down()
+                }
+            }
+
+            impl MpOp for crate::op::[<nextup $suffix>]::Routine {
+                type MpTy = ();
+
+                fn new_mp() -> Self::MpTy {}
+
+                fn run(_this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    // MPFR's `next_above` doesn't know about subnormals, so use our own.
+                    input.0.next_up()
+                }
+            }
+
+            impl MpOp for crate::op::[<logb $suffix>]::Routine {
+                type MpTy = MpFloat;
 
                 fn new_mp() -> Self::MpTy {
-                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
+                    new_mpfloat::<Self::FTy>()
                 }
 
                 fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
-                    this.0.assign(input.0);
-                    this.1.assign(0.0);
-                    let (sord, cord) = this.0.sin_cos_round(&mut this.1, Nearest);
-                    (
-                        prep_retval::<Self::FTy>(&mut this.0, sord),
-                        prep_retval::<Self::FTy>(&mut this.1, cord)
-                    )
+                    this.assign(input.0);
+
+                    // Same as `ilogb` but as a float, with infinities and zero handled by value.
+                    match this.get_exp() {
+                        Some(exp) => this.assign(exp - 1),
+                        None if this.is_infinite() => this.abs_mut(),
+                        None if this.is_zero() => this.assign(<$fty>::NEG_INFINITY),
+                        None => (),
+                    }
+                    prep_retval::<Self::FTy>(this, Ordering::Equal)
                 }
             }
 
@@ -411,28 +954,21 @@ macro_rules! impl_op_for_ty {
                 }
             }
 
-            impl MpOp for crate::op::[<yn $suffix>]::Routine {
-                type MpTy = MpFloat;
+            impl MpOp for crate::op::[<pow $suffix>]::Routine {
+                type MpTy = (MpFloat, MpFloat);
 
                 fn new_mp() -> Self::MpTy {
-                    new_mpfloat::<Self::FTy>()
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
                 }
 
                 fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
-                    let (n, x) = input;
-                    this.assign(x);
-                    let ord = this.yn_round(n, Nearest);
-                    prep_retval::<Self::FTy>(this, ord)
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    let ord = this.0.pow_assign_round(&this.1, Nearest);
+                    prep_retval::<Self::RustRet>(&mut this.0, ord)
                 }
             }
-        }
-    };
-}
 
-/// Version of `impl_op_for_ty` with only functions that have `f16` and `f128` implementations.
-macro_rules! impl_op_for_ty_all {
-    ($fty:ty, $suffix:literal) => {
-        paste::paste! {
             impl MpOp for crate::op::[<copysign $suffix>]::Routine {
                 type MpTy = (MpFloat, MpFloat);
 
@@ -504,39 +1040,249 @@ macro_rules! impl_op_for_ty_all {
                 }
             }
 
-            // `ldexp` and `scalbn` are the same for binary floating point, so just forward all
-            // methods.
-            impl MpOp for crate::op::[<ldexp $suffix>]::Routine {
-                type MpTy = <crate::op::[<scalbn $suffix>]::Routine as MpOp>::MpTy;
+            impl MpOp for crate::op::[< fmaximum_mag $suffix >]::Routine {
+                type MpTy = (MpFloat, MpFloat);
 
                 fn new_mp() -> Self::MpTy {
-                    <crate::op::[<scalbn $suffix>]::Routine as MpOp>::new_mp()
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
                 }
 
                 fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
-                    <crate::op::[<scalbn $suffix>]::Routine as MpOp>::run(this, input)
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    let ord = match this.0.cmp_abs(&this.1) {
+                        None => {
+                            this.0.assign($fty::NAN);
+                            Ordering::Equal
+                        }
+                        Some(Ordering::Less) => {
+                            this.0.assign(&this.1);
+                            Ordering::Equal
+                        }
+                        Some(Ordering::Greater) => Ordering::Equal,
+                        Some(Ordering::Equal) => this.0.max_round(&this.1, Nearest),
+                    };
+                    prep_retval::<Self::RustRet>(&mut this.0, ord)
                 }
             }
 
-            impl MpOp for crate::op::[<scalbn $suffix>]::Routine {
-                type MpTy = MpFloat;
+            impl MpOp for crate::op::[< fmaximum_mag_num $suffix >]::Routine {
+                type MpTy = (MpFloat, MpFloat);
 
                 fn new_mp() -> Self::MpTy {
-                    new_mpfloat::<Self::FTy>()
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
                 }
 
                 fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
-                    this.assign(input.0);
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    let ord = match this.0.cmp_abs(&this.1) {
+                        Some(Ordering::Less) => {
+                            this.0.assign(&this.1);
+                            Ordering::Equal
+                        }
+                        Some(Ordering::Greater) => Ordering::Equal,
+                        // MPFR returns the non-NaN operand if there is one
+                        Some(Ordering::Equal) | None => this.0.max_round(&this.1, Nearest),
+                    };
+                    prep_retval::<Self::RustRet>(&mut this.0, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[< fminimum_mag $suffix >]::Routine {
+                type MpTy = (MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    let ord = match this.0.cmp_abs(&this.1) {
+                        None => {
+                            this.0.assign($fty::NAN);
+                            Ordering::Equal
+                        }
+                        Some(Ordering::Greater) => {
+                            this.0.assign(&this.1);
+                            Ordering::Equal
+                        }
+                        Some(Ordering::Less) => Ordering::Equal,
+                        Some(Ordering::Equal) => this.0.min_round(&this.1, Nearest),
+                    };
+                    prep_retval::<Self::RustRet>(&mut this.0, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[< fminimum_mag_num $suffix >]::Routine {
+                type MpTy = (MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    let ord = match this.0.cmp_abs(&this.1) {
+                        Some(Ordering::Greater) => {
+                            this.0.assign(&this.1);
+                            Ordering::Equal
+                        }
+                        Some(Ordering::Less) => Ordering::Equal,
+                        // MPFR returns the non-NaN operand if there is one
+                        Some(Ordering::Equal) | None => this.0.min_round(&this.1, Nearest),
+                    };
+                    prep_retval::<Self::RustRet>(&mut this.0, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[<sincos $suffix>]::Routine {
+                type MpTy = (MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.0.assign(input.0);
+                    this.1.assign(0.0);
+                    let (sord, cord) = this.0.sin_cos_round(&mut this.1, Nearest);
+                    (
+                        prep_retval::<Self::FTy>(&mut this.0, sord),
+                        prep_retval::<Self::FTy>(&mut this.1, cord)
+                    )
+                }
+            }
+
+            impl MpOp for crate::op::[<sincospi $suffix>]::Routine {
+                type MpTy = (MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (new_mpfloat::<Self::FTy>(), new_mpfloat::<Self::FTy>())
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    // MPFR has no combined operation, so compute each half separately.
+                    this.0.assign(input.0);
+                    this.1.assign(input.0);
+                    let sord = this.0.sin_pi_round(Nearest);
+                    let cord = this.1.cos_pi_round(Nearest);
+                    (
+                        prep_retval::<Self::FTy>(&mut this.0, sord),
+                        prep_retval::<Self::FTy>(&mut this.1, cord)
+                    )
+                }
+            }
+
+            // `ldexp` and `scalbn` are the same for binary floating point, so just forward all
+            // methods.
+            impl MpOp for crate::op::[<ldexp $suffix>]::Routine {
+                type MpTy = <crate::op::[<scalbn $suffix>]::Routine as MpOp>::MpTy;
+
+                fn new_mp() -> Self::MpTy {
+                    <crate::op::[<scalbn $suffix>]::Routine as MpOp>::new_mp()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    <crate::op::[<scalbn $suffix>]::Routine as MpOp>::run(this, input)
+                }
+            }
+
+            impl MpOp for crate::op::[<scalbln $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    // Exponents outside of the `i32` range overflow or underflow every type, as
+                    // do the saturated values.
+                    let n = input.1.clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+                    this.assign(input.0);
+                    *this <<= n;
+                    prep_retval::<Self::FTy>(this, Ordering::Equal)
+                }
+            }
+
+            impl MpOp for crate::op::[<scalbn $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.assign(input.0);
                     *this <<= input.1;
                     prep_retval::<Self::FTy>(this, Ordering::Equal)
                 }
             }
+
+            impl MpOp for crate::op::[<setpayload $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    let quiet_bit = 1 << (<$fty>::MANTISSA_DIGITS - 2);
+                    match payload(this, input.0, quiet_bit) {
+                        Some(pl) => <$fty>::from_bits(<$fty>::INFINITY.to_bits() | quiet_bit | pl),
+                        None => 0.0,
+                    }
+                }
+            }
+
+            impl MpOp for crate::op::[<setpayloadsig $suffix>]::Routine {
+                type MpTy = MpFloat;
+
+                fn new_mp() -> Self::MpTy {
+                    new_mpfloat::<Self::FTy>()
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    let quiet_bit = 1 << (<$fty>::MANTISSA_DIGITS - 2);
+                    match payload(this, input.0, quiet_bit) {
+                        Some(pl) if pl != 0 => <$fty>::from_bits(<$fty>::INFINITY.to_bits() | pl),
+                        _ => 0.0,
+                    }
+                }
+            }
+
+            impl MpOp for crate::op::[<totalorder $suffix>]::Routine {
+                type MpTy = ();
+
+                fn new_mp() -> Self::MpTy {}
+
+                fn run(_this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    i32::from(input.0.total_cmp(&input.1).is_le())
+                }
+            }
+
+            impl MpOp for crate::op::[<totalordermag $suffix>]::Routine {
+                type MpTy = ();
+
+                fn new_mp() -> Self::MpTy {}
+
+                fn run(_this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    i32::from(input.0.abs().total_cmp(&input.1.abs()).is_le())
+                }
+            }
         }
     };
 }
 
 impl_op_for_ty!(f32, "f");
 impl_op_for_ty!(f64, "");
+#[cfg(f128_enabled)]
+impl_op_for_ty!(f128, "f128");
+
+impl_op_for_ty_f32_f64!(f32, "f");
+impl_op_for_ty_f32_f64!(f64, "");
 
 #[cfg(f16_enabled)]
 impl_op_for_ty_all!(f16, "f16");
@@ -545,6 +1291,154 @@ impl_op_for_ty_all!(f64, "");
 #[cfg(f128_enabled)]
 impl_op_for_ty_all!(f128, "f128");
 
+/// Implement the narrowing operations such as `fadd`, which take arguments of type `$fty` and
+/// return `$rty`. The result is computed directly at the precision of `$rty` so it is only
+/// rounded once.
+macro_rules! impl_narrowing {
+    ($fty:ty => $rty:ty, $prefix:literal, $suffix:literal) => {
+        paste::paste! {
+            impl_narrowing! {
+                @binary $fty => $rty;
+                [<$prefix add $suffix>] => |a, b| a + b;
+                [<$prefix sub $suffix>] => |a, b| a - b;
+                [<$prefix mul $suffix>] => |a, b| a * b;
+                [<$prefix div $suffix>] => |a, b| a / b;
+            }
+
+            impl MpOp for crate::op::[<$prefix fma $suffix>]::Routine {
+                type MpTy = (MpFloat, MpFloat, MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (
+                        new_mpfloat::<$fty>(),
+                        new_mpfloat::<$fty>(),
+                        new_mpfloat::<$fty>(),
+                        new_mpfloat::<$rty>(),
+                    )
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    this.2.assign(input.2);
+                    let ord = this.3.assign_round(this.0.mul_add_ref(&this.1, &this.2), Nearest);
+                    prep_retval::<$rty>(&mut this.3, ord)
+                }
+            }
+
+            impl MpOp for crate::op::[<$prefix sqrt $suffix>]::Routine {
+                type MpTy = (MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (new_mpfloat::<$fty>(), new_mpfloat::<$rty>())
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.0.assign(input.0);
+                    let ord = this.1.assign_round(this.0.sqrt_ref(), Nearest);
+                    prep_retval::<$rty>(&mut this.1, ord)
+                }
+            }
+        }
+    };
+
+    (@binary $fty:ty => $rty:ty; $($fn_name:ident => |$a:ident, $b:ident| $op:expr;)*) => {
+        $(
+            impl MpOp for crate::op::$fn_name::Routine {
+                type MpTy = (MpFloat, MpFloat, MpFloat);
+
+                fn new_mp() -> Self::MpTy {
+                    (new_mpfloat::<$fty>(), new_mpfloat::<$fty>(), new_mpfloat::<$rty>())
+                }
+
+                fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                    this.0.assign(input.0);
+                    this.1.assign(input.1);
+                    let ($a, $b) = (&this.0, &this.1);
+                    let ord = this.2.assign_round($op, Nearest);
+                    prep_retval::<$rty>(&mut this.2, ord)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(f16_enabled)]
+impl_narrowing!(f32 => f16, "f16", "f32");
+impl_narrowing!(f64 => f32, "f", "");
+#[cfg(f128_enabled)]
+impl_narrowing!(f128 => f64, "f64", "f128");
+
+/// Round to an integer in the direction `round`, returning NaN if the result does not fit in an
+/// integer with `width` bits. This is shared by the `fromfp` family, since the `x` variants only
+/// differ in status.
+fn fromfp_ref<F: Float>(mp: &mut MpFloat, round: Round, width: u32, signed: bool) -> F
+where
+    for<'a> &'a MpFloat: az::Cast<F>,
+{
+    if !mp.is_finite() || width == 0 {
+        return F::NAN;
+    }
+
+    match round {
+        Round::Positive => mp.ceil_mut(),
+        Round::Negative => mp.floor_mut(),
+        Round::Zero => mp.trunc_mut(),
+        Round::NearestAway => mp.round_mut(),
+        Round::Nearest => mp.round_even_mut(),
+        _ => unimplemented!("rounding mode {round:?}"),
+    }
+
+    // Every finite `F` fits in `EXP_MAX + 2` bits, so there is no need for larger bounds.
+    let width = width.min(F::EXP_MAX as u32 + 2);
+    let (min, max) = if signed {
+        let half = Integer::from(1) << (width - 1);
+        (-half.clone(), half - 1)
+    } else {
+        (Integer::new(), (Integer::from(1) << width) - 1)
+    };
+
+    let int = mp.to_integer().unwrap();
+    if int < min || int > max {
+        return F::NAN;
+    }
+
+    prep_retval::<F>(mp, Ordering::Equal)
+}
+
+macro_rules! impl_fromfp {
+    ($suffix:literal) => {
+        paste::paste! {
+            impl_fromfp!(@inner [<fromfp $suffix>], true);
+            impl_fromfp!(@inner [<fromfpx $suffix>], true);
+            impl_fromfp!(@inner [<ufromfp $suffix>], false);
+            impl_fromfp!(@inner [<ufromfpx $suffix>], false);
+        }
+    };
+
+    (@inner $fn_name:ident, $signed:literal) => {
+        impl MpOp for crate::op::$fn_name::Routine {
+            type MpTy = MpFloat;
+
+            fn new_mp() -> Self::MpTy {
+                new_mpfloat::<Self::FTy>()
+            }
+
+            fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                this.assign(input.0);
+                fromfp_ref(this, input.1, input.2, $signed)
+            }
+        }
+    };
+}
+
+#[cfg(f16_enabled)]
+impl_fromfp!("f16");
+impl_fromfp!("f");
+impl_fromfp!("");
+#[cfg(f128_enabled)]
+impl_fromfp!("f128");
+
 // `lgamma_r` is not a simple suffix so we can't use the above macro.
 impl MpOp for crate::op::lgamma_r::Routine {
     type MpTy = MpFloat;
@@ -576,8 +1470,81 @@ impl MpOp for crate::op::lgammaf_r::Routine {
     }
 }
 
+#[cfg(f128_enabled)]
+impl MpOp for crate::op::lgammaf128_r::Routine {
+    type MpTy = MpFloat;
+
+    fn new_mp() -> Self::MpTy {
+        new_mpfloat::<Self::FTy>()
+    }
+
+    fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+        this.assign(input.0);
+        let (sign, ord) = this.ln_abs_gamma_round(Nearest);
+        let ret = prep_retval::<Self::FTy>(this, ord);
+        (ret, sign as i32)
+    }
+}
+
+// Unlike MPFR's `lngamma`, C's `lgamma` is defined for negative inputs where the gamma function
+// is negative. Use `lgamma` (which MPFR calls `ln_abs_gamma`) to check our newer routines.
+#[cfg(f16_enabled)]
+impl MpOp for crate::op::lgammaf16::Routine {
+    type MpTy = MpFloat;
+
+    fn new_mp() -> Self::MpTy {
+        new_mpfloat::<Self::FTy>()
+    }
+
+    fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+        this.assign(input.0);
+        let (_sign, ord) = this.ln_abs_gamma_round(Nearest);
+        prep_retval::<Self::FTy>(this, ord)
+    }
+}
+
+#[cfg(f128_enabled)]
+impl MpOp for crate::op::lgammaf128::Routine {
+    type MpTy = MpFloat;
+
+    fn new_mp() -> Self::MpTy {
+        new_mpfloat::<Self::FTy>()
+    }
+
+    fn run(this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+        this.assign(input.0);
+        let (_sign, ord) = this.ln_abs_gamma_round(Nearest);
+        prep_retval::<Self::FTy>(this, ord)
+    }
+}
+
 /* stub implementations so we don't need to special case them */
 
+/// Implement `nexttoward`, which takes the direction as an `f64`.
+macro_rules! impl_nexttoward {
+    ($fty:ty, $fn_name:ident) => {
+        impl MpOp for crate::op::$fn_name::Routine {
+            type MpTy = ();
+
+            fn new_mp() -> Self::MpTy {}
+
+            fn run(_this: &mut Self::MpTy, input: Self::RustArgs) -> Self::RustRet {
+                let (x, y) = input;
+                match f64::from(x).partial_cmp(&y) {
+                    None => <$fty>::NAN,
+                    Some(Ordering::Less) => x.next_up(),
+                    Some(Ordering::Greater) => x.next_down(),
+                    // Zeros take the sign of `y`
+                    Some(Ordering::Equal) => y as $fty,
+                }
+            }
+        }
+    };
+}
+
+impl_nexttoward!(f32, nexttowardf);
+impl_nexttoward!(f64, nexttoward);
+
 impl MpOp for crate::op::nextafter::Routine {
     type MpTy = MpFloat;
 
@@ -601,3 +1568,306 @@ impl MpOp for crate::op::nextafterf::Routine {
         unimplemented!("nextafter does not yet have a MPFR operation");
     }
 }
+
+#[cfg(f16_enabled)]
+impl MpOp for crate::op::nextafterf16::Routine {
+    type MpTy = MpFloat;
+
+    fn new_mp() -> Self::MpTy {
+        unimplemented!("nextafter does not yet have a MPFR operation");
+    }
+
+    fn run(_this: &mut Self::MpTy, _input: Self::RustArgs) -> Self::RustRet {
+        unimplemented!("nextafter does not yet have a MPFR operation");
+    }
+}
+
+#[cfg(f128_enabled)]
+impl MpOp for crate::op::nextafterf128::Routine {
+    type MpTy = MpFloat;
+
+    fn new_mp() -> Self::MpTy {
+        unimplemented!("nextafter does not yet have a MPFR operation");
+    }
+
+    fn run(_this: &mut Self::MpTy, _input: Self::RustArgs) -> Self::RustRet {
+        unimplemented!("nextafter does not yet have a MPFR operation");
+    }
+}
+
+/// Reference results for the `libm` functions that take a rounding mode.
+pub mod directed {
+    use std::cmp::Ordering;
+
+    use libm::{FpResult, Round, Status};
+    use rug::Assign;
+    use rug::az::{self, Az};
+    use rug::float::Round as MpRound;
+
+    use super::MpFloat;
+    use crate::{Float, FloatExt};
+
+    /// The MPFR rounding mode corresponding to `round`. MPFR does not have a ties-away mode, see
+    /// `round_op` for how it is emulated.
+    fn mp_round(round: Round) -> MpRound {
+        match round {
+            Round::Nearest => MpRound::Nearest,
+            Round::Negative => MpRound::Down,
+            Round::Positive => MpRound::Up,
+            Round::Zero => MpRound::Zero,
+            Round::NearestAway => unreachable!("ties away is not an MPFR rounding mode"),
+            _ => unimplemented!("rounding mode {round:?}"),
+        }
+    }
+
+    /// Like `prep_retval`, but subnormals and overflow respect `round`. `ord` is the ternary value
+    /// from rounding to `F`'s precision with an unbounded exponent, which is used to determine the
+    /// status. Underflow uses tininess before rounding, and results that are NaN are not flagged.
+    fn prep_retval<F: Float>(mp: &mut MpFloat, ord: Ordering, round: Round) -> FpResult<F>
+    where
+        MpFloat: Assign<F>,
+        for<'a> &'a MpFloat: az::Cast<F>,
+    {
+        // Rounding is monotonic, so the exact value is tiny if the rounded one is, or if it was
+        // rounded up in magnitude to exactly the smallest normal.
+        let mut min_normal = MpFloat::new(mp.prec());
+        min_normal.assign(F::MIN_POSITIVE_NORMAL);
+        let rounded_up = if mp.is_sign_negative() { ord.is_lt() } else { ord.is_gt() };
+        let abs = mp.as_abs();
+        let tiny = *abs < min_normal || (*abs == min_normal && rounded_up);
+
+        let ord = mp.subnormalize_ieee_round(ord, mp_round(round));
+        let res = (&*mp).az::<F>();
+
+        let mut status = Status::OK;
+        if ord != Ordering::Equal {
+            status = Status::INEXACT;
+            status.set_underflow(tiny);
+        }
+
+        if !res.is_infinite() || mp.is_infinite() {
+            return FpResult::new(res, status);
+        }
+
+        // The value is larger than any finite float. It only becomes infinite when rounding
+        // away from zero.
+        let neg = res.is_sign_negative();
+        let inf = match round {
+            Round::Nearest | Round::NearestAway => true,
+            Round::Negative => neg,
+            Round::Positive => !neg,
+            Round::Zero => false,
+            _ => unimplemented!("rounding mode {round:?}"),
+        };
+        let val = match (inf, neg) {
+            (true, _) => res,
+            (false, false) => F::MAX,
+            (false, true) => F::MIN,
+        };
+        let mut status = Status::OVERFLOW;
+        status.set_inexact(true);
+        FpResult::new(val, status)
+    }
+
+    /// Round the result of `op`, which computes a value at the given precision and MPFR rounding
+    /// mode, to `F` using `round`.
+    fn round_op<F: Float>(
+        round: Round,
+        op: impl Fn(u32, MpRound) -> (MpFloat, Ordering),
+    ) -> FpResult<F>
+    where
+        MpFloat: Assign<F>,
+        for<'a> &'a MpFloat: az::Cast<F>,
+    {
+        let prec = F::SIG_BITS + 1;
+        if round != Round::NearestAway {
+            let (mut mp, ord) = op(prec, mp_round(round));
+            return prep_retval(&mut mp, ord, round);
+        }
+
+        // Round to even, then check whether the exact result is halfway to the other neighbor.
+        // Halfway values have one more significant bit than `F`, so they are always exact at a
+        // higher precision and anything inexact there cannot be a tie. Exactness, tininess and
+        // overflow are the same either way.
+        let (mut mp, ord) = op(prec, MpRound::Nearest);
+        let even: FpResult<F> = prep_retval(&mut mp, ord, Round::Nearest);
+        let (exact, ord) = op(prec * 4, MpRound::Nearest);
+        if ord != Ordering::Equal || even.val.is_nan() || even.val.is_infinite() {
+            return even;
+        }
+
+        let mut mid = MpFloat::new(prec * 4);
+        mid.assign(even.val);
+        let other = if exact > mid { even.val.next_up() } else { even.val.next_down() };
+
+        let mut other_mp = MpFloat::new(prec * 4);
+        other_mp.assign(other);
+        mid += &other_mp;
+        mid /= 2;
+
+        if mid == exact && other.abs() > even.val.abs() {
+            FpResult::new(other, even.status)
+        } else {
+            even
+        }
+    }
+
+    /// Raise invalid if the result is NaN but none of the inputs were.
+    fn check_invalid<F: Float>(res: FpResult<F>, nan_input: bool) -> FpResult<F> {
+        if res.val.is_nan() && !nan_input { FpResult::new(res.val, Status::INVALID) } else { res }
+    }
+
+    /// `sqrt(x)` rounded in the direction `round`.
+    pub fn sqrt<F: Float>(x: F, round: Round) -> FpResult<F>
+    where
+        MpFloat: Assign<F>,
+        for<'a> &'a MpFloat: az::Cast<F>,
+    {
+        let res = round_op(round, |prec, mp_round| {
+            let mut mp = MpFloat::new(prec);
+            mp.assign(x);
+            let ord = mp.sqrt_round(mp_round);
+            (mp, ord)
+        });
+        check_invalid(res, x.is_nan())
+    }
+
+    /// `(x * y) + z` rounded once in the direction `round`.
+    pub fn fma<F: Float>(x: F, y: F, z: F, round: Round) -> FpResult<F>
+    where
+        MpFloat: Assign<F>,
+        for<'a> &'a MpFloat: az::Cast<F>,
+    {
+        let res = round_op(round, |prec, mp_round| {
+            let mut mx = MpFloat::new(prec);
+            let mut my = MpFloat::new(prec);
+            let mut mz = MpFloat::new(prec);
+            mx.assign(x);
+            my.assign(y);
+            mz.assign(z);
+            let ord = mx.mul_add_round(&my, &mz, mp_round);
+            (mx, ord)
+        });
+        check_invalid(res, x.is_nan() || y.is_nan() || z.is_nan())
+    }
+
+    /// Apply `op` to operands that are exact at `F`'s precision, rounding once to `H`.
+    fn narrow_op<F: Float, H: Float>(
+        inputs: &[F],
+        round: Round,
+        op: impl Fn(&[MpFloat], u32, MpRound) -> (MpFloat, Ordering),
+    ) -> FpResult<H>
+    where
+        MpFloat: Assign<F> + Assign<H>,
+        for<'a> &'a MpFloat: az::Cast<H>,
+    {
+        let mp: Vec<MpFloat> = inputs
+            .iter()
+            .map(|&x| {
+                let mut mp = MpFloat::new(F::SIG_BITS + 1);
+                mp.assign(x);
+                mp
+            })
+            .collect();
+        let res = round_op(round, |prec, mp_round| op(&mp, prec, mp_round));
+        check_invalid(res, inputs.iter().any(|x| x.is_nan()))
+    }
+
+    /// `x + y` rounded once to `H` in the direction `round`.
+    pub fn fadd<F: Float, H: Float>(x: F, y: F, round: Round) -> FpResult<H>
+    where
+        MpFloat: Assign<F> + Assign<H>,
+        for<'a> &'a MpFloat: az::Cast<H>,
+    {
+        narrow_op(&[x, y], round, |mp, prec, mp_round| {
+            MpFloat::with_val_round(prec, &mp[0] + &mp[1], mp_round)
+        })
+    }
+
+    /// `x - y` rounded once to `H` in the direction `round`.
+    pub fn fsub<F: Float, H: Float>(x: F, y: F, round: Round) -> FpResult<H>
+    where
+        MpFloat: Assign<F> + Assign<H>,
+        for<'a> &'a MpFloat: az::Cast<H>,
+    {
+        narrow_op(&[x, y], round, |mp, prec, mp_round| {
+            MpFloat::with_val_round(prec, &mp[0] - &mp[1], mp_round)
+        })
+    }
+
+    /// `x * y` rounded once to `H` in the direction `round`.
+    pub fn fmul<F: Float, H: Float>(x: F, y: F, round: Round) -> FpResult<H>
+    where
+        MpFloat: Assign<F> + Assign<H>,
+        for<'a> &'a MpFloat: az::Cast<H>,
+    {
+        narrow_op(&[x, y], round, |mp, prec, mp_round| {
+            MpFloat::with_val_round(prec, &mp[0] * &mp[1], mp_round)
+        })
+    }
+
+    /// `x / y` rounded once to `H` in the direction `round`.
+    pub fn fdiv<F: Float, H: Float>(x: F, y: F, round: Round) -> FpResult<H>
+    where
+        MpFloat: Assign<F> + Assign<H>,
+        for<'a> &'a MpFloat: az::Cast<H>,
+    {
+        let res = narrow_op(&[x, y], round, |mp, prec, mp_round| {
+            MpFloat::with_val_round(prec, &mp[0] / &mp[1], mp_round)
+        });
+        if !x.is_nan() && !x.is_infinite() && x != F::ZERO && y == F::ZERO {
+            FpResult::new(res.val, Status::DIVIDE_BY_ZERO)
+        } else {
+            res
+        }
+    }
+
+    /// `(x * y) + z` rounded once to `H` in the direction `round`.
+    pub fn ffma<F: Float, H: Float>(x: F, y: F, z: F, round: Round) -> FpResult<H>
+    where
+        MpFloat: Assign<F> + Assign<H>,
+        for<'a> &'a MpFloat: az::Cast<H>,
+    {
+        narrow_op(&[x, y, z], round, |mp, prec, mp_round| {
+            MpFloat::with_val_round(prec, mp[0].mul_add_ref(&mp[1], &mp[2]), mp_round)
+        })
+    }
+
+    /// `sqrt(x)` rounded once to `H` in the direction `round`.
+    pub fn fsqrt<F: Float, H: Float>(x: F, round: Round) -> FpResult<H>
+    where
+        MpFloat: Assign<F> + Assign<H>,
+        for<'a> &'a MpFloat: az::Cast<H>,
+    {
+        narrow_op(&[x], round, |mp, prec, mp_round| {
+            MpFloat::with_val_round(prec, mp[0].sqrt_ref(), mp_round)
+        })
+    }
+
+    /// `x` rounded to an integer in the direction `round`.
+    pub fn rint<F: Float>(x: F, round: Round) -> FpResult<F>
+    where
+        MpFloat: Assign<F>,
+        for<'a> &'a MpFloat: az::Cast<F>,
+    {
+        let mut mp = MpFloat::new(F::SIG_BITS + 1);
+        mp.assign(x);
+        match round {
+            Round::Nearest => mp.round_even_mut(),
+            Round::Negative => mp.floor_mut(),
+            Round::Positive => mp.ceil_mut(),
+            Round::Zero => mp.trunc_mut(),
+            Round::NearestAway => mp.round_mut(),
+            _ => unimplemented!("rounding mode {round:?}"),
+        }
+
+        // Integers are exact, so any MPFR mode converts them the same way. Only rounding off a
+        // fractional part raises an exception.
+        let val = prep_retval(&mut mp, Ordering::Equal, Round::Nearest).val;
+        if val != x && !x.is_nan() {
+            FpResult::new(val, Status::INEXACT)
+        } else {
+            FpResult::ok(val)
+        }
+    }
+}
@@ -20,23 +20,8 @@ pub trait FloatExt: Float {
 
     /// Increment by one ULP, saturating at infinity.
     fn next_up(self) -> Self {
-        let bits = self.to_bits();
-        if self.is_nan() || bits == Self::INFINITY.to_bits() {
-            return self;
-        }
-
-        let abs = self.abs().to_bits();
-        let next_bits = if abs == Self::Int::ZERO {
-            // Next up from 0 is the smallest subnormal
-            Self::TINY_BITS
-        } else if bits == abs {
-            // Positive: counting up is more positive
-            bits + Self::Int::ONE
-        } else {
-            // Negative: counting down is more positive
-            bits - Self::Int::ONE
-        };
-        Self::from_bits(next_bits)
+        // `libm` quiets NaNs with arithmetic, which `f8` does not implement
+        if self.is_nan() { self } else { libm::generic::nextup(self) }
     }
 
     /// A faster way to effectively call `next_up` `n` times.
@@ -71,23 +56,8 @@ pub trait FloatExt: Float {
 
     /// Decrement by one ULP, saturating at negative infinity.
     fn next_down(self) -> Self {
-        let bits = self.to_bits();
-        if self.is_nan() || bits == Self::NEG_INFINITY.to_bits() {
-            return self;
-        }
-
-        let abs = self.abs().to_bits();
-        let next_bits = if abs == Self::Int::ZERO {
-            // Next up from 0 is the smallest negative subnormal
-            Self::TINY_BITS | Self::SIGN_MASK
-        } else if bits == abs {
-            // Positive: counting down is more negative
-            bits - Self::Int::ONE
-        } else {
-            // Negative: counting up is more negative
-            bits + Self::Int::ONE
-        };
-        Self::from_bits(next_bits)
+        // `libm` quiets NaNs with arithmetic, which `f8` does not implement
+        if self.is_nan() { self } else { libm::generic::nextdown(self) }
     }
 
     /// A faster way to effectively call `next_down` `n` times.
@@ -3,11 +3,13 @@
 
 use core::f32;
 
+use BaseName as Bn;
 use CheckBasis::{Mpfr, Musl};
+use Identifier as Id;
+use libm::Round;
 use libm::support::CastFrom;
-use {BaseName as Bn, Identifier as Id};
 
-use crate::{BaseName, CheckBasis, CheckCtx, Float, Identifier, Int, TestResult};
+use crate::{BaseName, CheckBasis, CheckCtx, Float, FloatTy, Identifier, Int, TestResult};
 
 /// Type implementing [`IgnoreCase`].
 pub struct SpecialCase;
@@ -15,71 +17,173 @@ pub struct SpecialCase;
 /// ULP allowed to differ from the results returned by a test basis.
 #[allow(clippy::single_match)]
 pub fn default_ulp(ctx: &CheckCtx) -> u32 {
+    let float_ty = ctx.fn_ident.math_op().float_ty;
+
     // ULP compared to the infinite (MPFR) result.
     let mut ulp = match ctx.base_name {
         // Operations that require exact results. This list should correlate with what we
         // have documented at <https://doc.rust-lang.org/std/primitive.f32.html>.
-        Bn::Ceil
+        Bn::Canonicalize
+        | Bn::Ceil
         | Bn::Copysign
         | Bn::Fabs
+        | Bn::Fadd
         | Bn::Fdim
+        | Bn::Fdiv
+        | Bn::Ffma
         | Bn::Floor
         | Bn::Fma
         | Bn::Fmax
         | Bn::Fmaximum
+        | Bn::FmaximumMag
+        | Bn::FmaximumMagNum
         | Bn::FmaximumNum
         | Bn::Fmin
         | Bn::Fminimum
+        | Bn::FminimumMag
+        | Bn::FminimumMagNum
         | Bn::FminimumNum
         | Bn::Fmod
+        | Bn::Fmul
         | Bn::Frexp
+        | Bn::Fromfp
+        | Bn::Fromfpx
+        | Bn::Fsqrt
+        | Bn::Fsub
+        | Bn::Getpayload
         | Bn::Ilogb
+        | Bn::Issignaling
         | Bn::Ldexp
+        | Bn::Llogb
+        | Bn::Llrint
+        | Bn::Llround
+        | Bn::Logb
+        | Bn::Lrint
+        | Bn::Lround
         | Bn::Modf
+        | Bn::Nearbyint
         | Bn::Nextafter
+        | Bn::Nextdown
+        | Bn::Nexttoward
+        | Bn::Nextup
         | Bn::Remainder
         | Bn::Remquo
         | Bn::Rint
         | Bn::Round
         | Bn::Roundeven
+        | Bn::Scalbln
         | Bn::Scalbn
+        | Bn::Setpayload
+        | Bn::Setpayloadsig
         | Bn::Sqrt
-        | Bn::Trunc => 0,
+        | Bn::Totalorder
+        | Bn::Totalordermag
+        | Bn::Trunc
+        | Bn::Ufromfp
+        | Bn::Ufromfpx => 0,
 
         // Operations that aren't required to be exact, but our implementations are.
         Bn::Cbrt => 0,
 
+        // The `f16` and `f128` versions of these are evaluated with enough extra precision to be
+        // correctly rounded.
+        Bn::Acos
+        | Bn::Acosh
+        | Bn::Acospi
+        | Bn::Asin
+        | Bn::Asinh
+        | Bn::Asinpi
+        | Bn::Atan
+        | Bn::Atan2
+        | Bn::Atan2pi
+        | Bn::Atanh
+        | Bn::Atanpi
+        | Bn::Cos
+        | Bn::Cosh
+        | Bn::Cospi
+        | Bn::Erf
+        | Bn::Erfc
+        | Bn::Exp
+        | Bn::Exp10
+        | Bn::Exp2
+        | Bn::Expm1
+        | Bn::Hypot
+        | Bn::J0
+        | Bn::J1
+        | Bn::Jn
+        | Bn::Lgamma
+        | Bn::LgammaR
+        | Bn::Log
+        | Bn::Log10
+        | Bn::Log1p
+        | Bn::Log2
+        | Bn::Pow
+        | Bn::Sin
+        | Bn::Sincos
+        | Bn::Sincospi
+        | Bn::Sinh
+        | Bn::Sinpi
+        | Bn::Tan
+        | Bn::Tanh
+        | Bn::Tanpi
+        | Bn::Tgamma
+        | Bn::Y0
+        | Bn::Y1
+        | Bn::Yn
+            if matches!(float_ty, FloatTy::F16 | FloatTy::F128) =>
+        {
+            0
+        }
+
         // Bessel functions have large inaccuracies.
         Bn::J0 | Bn::J1 | Bn::Y0 | Bn::Y1 | Bn::Jn | Bn::Yn => 8_000_000,
 
         // For all other operations, specify our implementation's worst case precision.
         Bn::Acos => 1,
         Bn::Acosh => 4,
+        Bn::Acospi => 1,
         Bn::Asin => 1,
         Bn::Asinh => 2,
+        Bn::Asinpi => 1,
         Bn::Atan => 1,
         Bn::Atan2 => 2,
+        Bn::Atan2pi => 1,
         Bn::Atanh => 2,
+        Bn::Atanpi => 1,
         Bn::Cos => 1,
+        Bn::Compoundn => 1,
         Bn::Cosh => 1,
+        Bn::Cospi => 1,
         Bn::Erf => 1,
         Bn::Erfc => 4,
         Bn::Exp => 1,
         Bn::Exp10 => 6,
+        Bn::Exp10m1 => 1,
         Bn::Exp2 => 1,
+        Bn::Exp2m1 => 1,
         Bn::Expm1 => 1,
         Bn::Hypot => 1,
         Bn::Lgamma | Bn::LgammaR => 16,
         Bn::Log => 1,
         Bn::Log10 => 1,
+        Bn::Log10p1 => 1,
         Bn::Log1p => 1,
         Bn::Log2 => 1,
+        Bn::Log2p1 => 1,
+        Bn::Logp1 => 1,
         Bn::Pow => 1,
+        Bn::Pown => 1,
+        Bn::Powr => 1,
+        Bn::Rootn => 1,
+        Bn::Rsqrt => 1,
         Bn::Sin => 1,
         Bn::Sincos => 1,
+        Bn::Sincospi => 1,
         Bn::Sinh => 2,
+        Bn::Sinpi => 1,
         Bn::Tan => 1,
         Bn::Tanh => 2,
+        Bn::Tanpi => 1,
         // tgammaf has higher accuracy than tgamma.
         Bn::Tgamma if ctx.fn_ident != Id::Tgamma => 1,
         Bn::Tgamma => 20,
@@ -335,7 +439,21 @@ impl MaybeOverride<(f64,)> for SpecialCase {
 }
 
 #[cfg(f128_enabled)]
-impl MaybeOverride<(f128,)> for SpecialCase {}
+impl MaybeOverride<(f128,)> for SpecialCase {
+    fn check_int<I: Int>(input: (f128,), actual: I, expected: I, ctx: &CheckCtx) -> CheckAction {
+        // On MPFR for lgammaf128_r, we set -1 as the integer result for negative infinity but
+        // MPFR sets +1
+        if ctx.basis == CheckBasis::Mpfr
+            && ctx.base_name == BaseName::LgammaR
+            && input.0 == f128::NEG_INFINITY
+            && actual.abs() == expected.abs()
+        {
+            return XFAIL("lgammar integer result");
+        }
+
+        DEFAULT
+    }
+}
 
 // F1 and F2 are always the same type, this is just to please generics
 fn unop_common<F1: Float, F2: Float>(
@@ -517,6 +635,18 @@ impl MaybeOverride<(i32, f64)> for SpecialCase {
     }
 }
 
+#[cfg(f128_enabled)]
+impl MaybeOverride<(i32, f128)> for SpecialCase {
+    fn check_float<F: Float>(
+        input: (i32, f128),
+        actual: F,
+        expected: F,
+        ctx: &CheckCtx,
+    ) -> CheckAction {
+        int_float_common(input, actual, expected, ctx)
+    }
+}
+
 fn int_float_common<F1: Float, F2: Float>(
     input: (i32, F1),
     actual: F2,
@@ -564,9 +694,25 @@ fn int_float_common<F1: Float, F2: Float>(
 impl MaybeOverride<(f16, i32)> for SpecialCase {}
 impl MaybeOverride<(f32, i32)> for SpecialCase {}
 impl MaybeOverride<(f64, i32)> for SpecialCase {}
+impl MaybeOverride<(f32, i64)> for SpecialCase {}
+impl MaybeOverride<(f64, i64)> for SpecialCase {}
 #[cfg(f128_enabled)]
 impl MaybeOverride<(f128, i32)> for SpecialCase {}
+#[cfg(f16_enabled)]
+impl MaybeOverride<(f16, i64)> for SpecialCase {}
+#[cfg(f128_enabled)]
+impl MaybeOverride<(f128, i64)> for SpecialCase {}
+impl MaybeOverride<(f32, f64)> for SpecialCase {}
 
+#[cfg(f16_enabled)]
+impl MaybeOverride<(f16, Round, u32)> for SpecialCase {}
+impl MaybeOverride<(f32, Round, u32)> for SpecialCase {}
+impl MaybeOverride<(f64, Round, u32)> for SpecialCase {}
+#[cfg(f128_enabled)]
+impl MaybeOverride<(f128, Round, u32)> for SpecialCase {}
+
+#[cfg(f16_enabled)]
+impl MaybeOverride<(f16, f16, f16)> for SpecialCase {}
 impl MaybeOverride<(f32, f32, f32)> for SpecialCase {}
 impl MaybeOverride<(f64, f64, f64)> for SpecialCase {}
 #[cfg(f128_enabled)]
@@ -10,6 +10,7 @@ use std::panic::{RefUnwindSafe, UnwindSafe};
 use std::{fmt, panic};
 
 use anyhow::{Context, anyhow, bail, ensure};
+use libm::Round;
 use libm::support::Hexf;
 
 use crate::precision::CheckAction;
@@ -262,6 +263,16 @@ where
 
 impl_int!(u32, i32, u64, i64);
 
+impl Hex for Round {
+    fn hex(self) -> String {
+        format!("{self:?}")
+    }
+
+    fn hexf(self) -> String {
+        String::new()
+    }
+}
+
 /* trait implementations for floats */
 
 macro_rules! impl_float {
@@ -445,3 +456,15 @@ impl_tuples!(
     (f32, f32);
     (f64, f64);
 );
+
+#[cfg(f16_enabled)]
+impl_tuples!(
+    (f16, i32);
+    (f16, f16);
+);
+
+#[cfg(f128_enabled)]
+impl_tuples!(
+    (f128, i32);
+    (f128, f128);
+);
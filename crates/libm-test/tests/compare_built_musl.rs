@@ -82,27 +82,128 @@ libm_macros::for_each_function! {
         jnf,
         ldexp,
         ldexpf,
+        scalbln,
+        scalblnf,
         scalbn,
         scalbnf,
         yn,
         ynf,
 
+        // Results for out of range inputs are unspecified and differ by architecture
+        llrint,
+        llrintf,
+        llround,
+        llroundf,
+        lrint,
+        lrintf,
+        lround,
+        lroundf,
+
+        // musl takes the direction as a `long double`
+        nexttoward,
+        nexttowardf,
+
         // Not provided by musl
         // verify-sorted-start
+        acosf128,
+        acosf16,
+        acoshf128,
+        acoshf16,
+        acospi,
+        acospif,
+        acospif128,
+        acospif16,
+        asinf128,
+        asinf16,
+        asinhf128,
+        asinhf16,
+        asinpi,
+        asinpif,
+        asinpif128,
+        asinpif16,
+        atan2f128,
+        atan2f16,
+        atan2pi,
+        atan2pif,
+        atan2pif128,
+        atan2pif16,
+        atanf128,
+        atanf16,
+        atanhf128,
+        atanhf16,
+        atanpi,
+        atanpif,
+        atanpif128,
+        atanpif16,
+        canonicalize,
+        canonicalizef,
+        canonicalizef128,
+        canonicalizef16,
+        cbrtf128,
+        cbrtf16,
         ceilf128,
         ceilf16,
+        compoundn,
+        compoundnf,
         copysignf128,
         copysignf16,
+        cosf128,
+        cosf16,
+        coshf128,
+        coshf16,
+        cospi,
+        cospif,
+        cospif128,
+        cospif16,
+        erfcf128,
+        erfcf16,
+        erff128,
+        erff16,
+        exp10f16,
+        exp10m1,
+        exp10m1f,
+        exp2f128,
+        exp2f16,
+        exp2m1,
+        exp2m1f,
+        expf128,
+        expf16,
+        expm1f128,
+        expm1f16,
+        f16addf32,
+        f16divf32,
+        f16fmaf32,
+        f16mulf32,
+        f16sqrtf32,
+        f16subf32,
+        f64addf128,
+        f64divf128,
+        f64fmaf128,
+        f64mulf128,
+        f64sqrtf128,
+        f64subf128,
         fabsf128,
         fabsf16,
+        fadd,
         fdimf128,
         fdimf16,
+        fdiv,
+        ffma,
         floorf128,
         floorf16,
         fmaf128,
+        fmaf16,
         fmaxf128,
         fmaxf16,
         fmaximum,
+        fmaximum_mag,
+        fmaximum_mag_num,
+        fmaximum_mag_numf,
+        fmaximum_mag_numf128,
+        fmaximum_mag_numf16,
+        fmaximum_magf,
+        fmaximum_magf128,
+        fmaximum_magf16,
         fmaximum_num,
         fmaximum_numf,
         fmaximum_numf128,
@@ -113,6 +214,14 @@ libm_macros::for_each_function! {
         fminf128,
         fminf16,
         fminimum,
+        fminimum_mag,
+        fminimum_mag_num,
+        fminimum_mag_numf,
+        fminimum_mag_numf128,
+        fminimum_mag_numf16,
+        fminimum_magf,
+        fminimum_magf128,
+        fminimum_magf16,
         fminimum_num,
         fminimum_numf,
         fminimum_numf128,
@@ -122,22 +231,352 @@ libm_macros::for_each_function! {
         fminimumf16,
         fmodf128,
         fmodf16,
+        fmul,
+        frexpf128,
+        frexpf16,
+        fromfp,
+        fromfpf,
+        fromfpf128,
+        fromfpf16,
+        fromfpx,
+        fromfpxf,
+        fromfpxf128,
+        fromfpxf16,
+        fsqrt,
+        fsub,
+        getpayload,
+        getpayloadf,
+        getpayloadf128,
+        getpayloadf16,
+        hypotf128,
+        hypotf16,
+        ilogbf128,
+        ilogbf16,
+        issignaling,
+        issignalingf,
+        issignalingf128,
+        issignalingf16,
+        j0f128,
+        j1f128,
+        jnf128,
         ldexpf128,
         ldexpf16,
+        lgammaf128,
+        lgammaf128_r,
+        lgammaf16,
+        llogb,
+        llogbf,
+        llogbf128,
+        llogbf16,
+        llrintf128,
+        llrintf16,
+        llroundf128,
+        llroundf16,
+        log10f128,
+        log10f16,
+        log10p1,
+        log10p1f,
+        log1pf128,
+        log1pf16,
+        log2f128,
+        log2f16,
+        log2p1,
+        log2p1f,
+        logbf128,
+        logbf16,
+        logf128,
+        logf16,
+        logp1,
+        logp1f,
+        lrintf128,
+        lrintf16,
+        lroundf128,
+        lroundf16,
+        modff128,
+        modff16,
+        nearbyintf128,
+        nearbyintf16,
+        nextafterf128,
+        nextafterf16,
+        nextdown,
+        nextdownf,
+        nextdownf128,
+        nextdownf16,
+        nextup,
+        nextupf,
+        nextupf128,
+        nextupf16,
+        powf128,
+        powf16,
+        pown,
+        pownf,
+        powr,
+        powrf,
+        remainderf128,
+        remainderf16,
+        remquof128,
+        remquof16,
         rintf128,
         rintf16,
+        rootn,
+        rootnf,
         roundeven,
         roundevenf,
         roundevenf128,
         roundevenf16,
         roundf128,
         roundf16,
+        rsqrt,
+        rsqrtf,
+        scalblnf128,
+        scalblnf16,
         scalbnf128,
         scalbnf16,
+        setpayload,
+        setpayloadf,
+        setpayloadf128,
+        setpayloadf16,
+        setpayloadsig,
+        setpayloadsigf,
+        setpayloadsigf128,
+        setpayloadsigf16,
+        sincosf128,
+        sincosf16,
+        sincospi,
+        sincospif,
+        sincospif128,
+        sincospif16,
+        sinf128,
+        sinf16,
+        sinhf128,
+        sinhf16,
+        sinpi,
+        sinpif,
+        sinpif128,
+        sinpif16,
         sqrtf128,
         sqrtf16,
+        tanf128,
+        tanf16,
+        tanhf128,
+        tanhf16,
+        tanpi,
+        tanpif,
+        tanpif128,
+        tanpif16,
+        tgammaf128,
+        tgammaf16,
+        totalorder,
+        totalorderf,
+        totalorderf128,
+        totalorderf16,
+        totalordermag,
+        totalordermagf,
+        totalordermagf128,
+        totalordermagf16,
         truncf128,
         truncf16,
+        ufromfp,
+        ufromfpf,
+        ufromfpf128,
+        ufromfpf16,
+        ufromfpx,
+        ufromfpxf,
+        ufromfpxf128,
+        ufromfpxf16,
+        y0f128,
+        y1f128,
+        ynf128,
         // verify-sorted-end
     ],
 }
+
+/// The complex functions are not part of the `MathOp` harness, so compare them separately over a
+/// grid of inputs.
+mod complex {
+    use libm::Complex;
+
+    /// Moderate values, away from the branch points where musl's inverse functions lose accuracy.
+    const FINITE: &[f64] = &[-2.0, -1.25, -0.5, -0.1, 0.1, 0.5, 1.25, 2.0];
+    /// Values that exercise the special cases of C99 Annex G.
+    const SPECIAL: &[f64] = &[0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN];
+
+    /// Which special values to add to the grid of inputs, and how to compare their results.
+    #[derive(Clone, Copy, PartialEq)]
+    enum Special {
+        /// Only finite nonzero inputs.
+        None,
+        /// Zeros, infinities and NaNs. The results must match musl exactly, including the sign of
+        /// zeros.
+        All,
+        /// Signed zeros only, without checking the sign of zero results. musl computes the
+        /// inverse functions with the logarithm formula, which does not implement the infinite
+        /// and NaN cases of Annex G or keep the sign of zero results. Those are covered by the
+        /// unit tests, and this still checks the branch cuts on the axes.
+        Zeros,
+    }
+
+    /// All `(re, im)` pairs from `vals`, extended with the requested special values.
+    fn grid(special: Special) -> Vec<(f64, f64)> {
+        let mut vals = FINITE.to_vec();
+        match special {
+            Special::None => (),
+            Special::All => vals.extend_from_slice(SPECIAL),
+            Special::Zeros => vals.extend_from_slice(&[0.0, -0.0]),
+        }
+        vals.iter().flat_map(|&re| vals.iter().map(move |&im| (re, im))).collect()
+    }
+
+    /// musl does not round correctly, so allow `ulp` times the epsilon of the magnitude of the
+    /// result. Non-finite parts must match exactly, NaNs match any NaN, and zeros must have the
+    /// same sign unless `special` says otherwise.
+    #[track_caller]
+    fn check(
+        name: &str,
+        input: &str,
+        ours: (f64, f64),
+        musl: (f64, f64),
+        eps: f64,
+        ulp: f64,
+        special: Special,
+    ) {
+        let scale = ours.0.hypot(ours.1).max(musl.0.hypot(musl.1));
+        let part_ok = |a: f64, b: f64| {
+            if a.is_nan() || b.is_nan() {
+                a.is_nan() && b.is_nan()
+            } else if a == 0.0 && b == 0.0 {
+                special == Special::Zeros || a.is_sign_negative() == b.is_sign_negative()
+            } else if !a.is_finite() || !b.is_finite() {
+                a == b
+            } else {
+                (a - b).abs() <= scale * eps * ulp
+            }
+        };
+
+        assert!(
+            part_ok(ours.0, musl.0) && part_ok(ours.1, musl.1),
+            "{name}{input}: crate {ours:?}, musl {musl:?}"
+        );
+    }
+
+    macro_rules! complex_tests {
+        (
+            $(
+                $kind:ident $name:ident: $fty:ty, special: $special:ident, ulp: $ulp:literal;
+            )*
+        ) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    // FIXME(#309): LE PPC crashes calling some musl functions
+                    #[cfg_attr(all(target_arch = "powerpc64", target_endian = "little"), ignore)]
+                    fn [< musl_complex_ $name >]() {
+                        let special = Special::$special;
+                        for (re, im) in grid(special) {
+                            complex_tests!(
+                                @check $kind $name, $fty, re as $fty, im as $fty, $ulp, special
+                            );
+                        }
+                    }
+                }
+            )*
+        };
+
+        (@check unary $name:ident, $fty:ty, $re:expr, $im:expr, $ulp:expr, $special:expr) => {{
+            let z = Complex::new($re, $im);
+            let a = libm::$name(z);
+            let b = musl_math_sys::$name(musl_math_sys::Complex { re: z.re, im: z.im });
+            check(
+                stringify!($name),
+                &format!("({:?}, {:?})", z.re, z.im),
+                (a.re.into(), a.im.into()),
+                (b.re.into(), b.im.into()),
+                <$fty>::EPSILON.into(),
+                $ulp,
+                $special,
+            );
+        }};
+
+        (@check real $name:ident, $fty:ty, $re:expr, $im:expr, $ulp:expr, $special:expr) => {{
+            let z = Complex::new($re, $im);
+            let a = libm::$name(z);
+            let b = musl_math_sys::$name(musl_math_sys::Complex { re: z.re, im: z.im });
+            check(
+                stringify!($name),
+                &format!("({:?}, {:?})", z.re, z.im),
+                (a.into(), 0.0),
+                (b.into(), 0.0),
+                <$fty>::EPSILON.into(),
+                $ulp,
+                $special,
+            );
+        }};
+
+        (@check binary $name:ident, $fty:ty, $re:expr, $im:expr, $ulp:expr, $special:expr) => {{
+            let z = Complex::new($re, $im);
+            for (wre, wim) in grid(Special::None) {
+                let w = Complex::new(wre as $fty, wim as $fty);
+                let a = libm::$name(z, w);
+                let b = musl_math_sys::$name(
+                    musl_math_sys::Complex { re: z.re, im: z.im },
+                    musl_math_sys::Complex { re: w.re, im: w.im },
+                );
+                check(
+                    stringify!($name),
+                    &format!("({:?}, {:?}), ({:?}, {:?})", z.re, z.im, w.re, w.im),
+                    (a.re.into(), a.im.into()),
+                    (b.re.into(), b.im.into()),
+                    <$fty>::EPSILON.into(),
+                    $ulp,
+                    $special,
+                );
+            }
+        }};
+    }
+
+    // The inverse functions and `cpow` get more room because musl's logarithm formulas cancel
+    // for small arguments, and `cpow` scales the error of `clog` by the exponent.
+    complex_tests! {
+        real cabs: f64, special: All, ulp: 4.0;
+        real cabsf: f32, special: All, ulp: 4.0;
+        unary cacos: f64, special: Zeros, ulp: 16.0;
+        unary cacosf: f32, special: Zeros, ulp: 16.0;
+        unary cacosh: f64, special: Zeros, ulp: 16.0;
+        unary cacoshf: f32, special: Zeros, ulp: 16.0;
+        real carg: f64, special: All, ulp: 4.0;
+        real cargf: f32, special: All, ulp: 4.0;
+        unary casin: f64, special: Zeros, ulp: 16.0;
+        unary casinf: f32, special: Zeros, ulp: 16.0;
+        unary casinh: f64, special: Zeros, ulp: 16.0;
+        unary casinhf: f32, special: Zeros, ulp: 16.0;
+        unary catan: f64, special: Zeros, ulp: 16.0;
+        unary catanf: f32, special: Zeros, ulp: 16.0;
+        unary catanh: f64, special: Zeros, ulp: 16.0;
+        unary catanhf: f32, special: Zeros, ulp: 16.0;
+        unary ccos: f64, special: All, ulp: 4.0;
+        unary ccosf: f32, special: All, ulp: 4.0;
+        unary ccosh: f64, special: All, ulp: 4.0;
+        unary ccoshf: f32, special: All, ulp: 4.0;
+        unary cexp: f64, special: All, ulp: 4.0;
+        unary cexpf: f32, special: All, ulp: 4.0;
+        unary clog: f64, special: All, ulp: 4.0;
+        unary clogf: f32, special: All, ulp: 4.0;
+        unary conj: f64, special: All, ulp: 4.0;
+        unary conjf: f32, special: All, ulp: 4.0;
+        // musl multiplies with the infinity recovery of Annex G.5.1, we do not
+        binary cpow: f64, special: None, ulp: 16.0;
+        binary cpowf: f32, special: None, ulp: 16.0;
+        unary cproj: f64, special: All, ulp: 4.0;
+        unary cprojf: f32, special: All, ulp: 4.0;
+        unary csin: f64, special: All, ulp: 4.0;
+        unary csinf: f32, special: All, ulp: 4.0;
+        unary csinh: f64, special: All, ulp: 4.0;
+        unary csinhf: f32, special: All, ulp: 4.0;
+        unary csqrt: f64, special: All, ulp: 4.0;
+        unary csqrtf: f32, special: All, ulp: 4.0;
+        unary ctan: f64, special: All, ulp: 4.0;
+        unary ctanf: f32, special: All, ulp: 4.0;
+        unary ctanh: f64, special: All, ulp: 4.0;
+        unary ctanhf: f32, special: All, ulp: 4.0;
+    }
+}
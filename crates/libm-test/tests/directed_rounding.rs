@@ -0,0 +1,176 @@
+//! Test the functions that take a rounding mode against MPFR, in every direction. Both the
+//! result and the exceptions raised must match.
+
+#![cfg(feature = "build-mpfr")]
+#![cfg_attr(f16_enabled, feature(f16))]
+#![cfg_attr(f128_enabled, feature(f128))]
+
+use libm::{FpResult, Round, Status};
+use libm_test::generate::edge_cases::{self, EdgeCaseInput};
+use libm_test::generate::random::{self, RandomInput};
+use libm_test::mpfloat::{MpFloat, directed};
+use libm_test::{CheckBasis, CheckCtx, Float, GeneratorKind, Hex, MathOp, MinInt};
+use rug::{Assign, az};
+
+const ROUNDS: [Round; 5] =
+    [Round::Nearest, Round::Negative, Round::Positive, Round::Zero, Round::NearestAway];
+
+/// Call `f` with random inputs for `Op`, plus edge cases for unary functions. The edge cases of
+/// functions with more arguments are a cartesian product that is too slow to run in every mode.
+fn for_each_input<Op>(mut f: impl FnMut(Op::RustArgs))
+where
+    Op: MathOp,
+    Op::RustArgs: RandomInput + EdgeCaseInput<Op>,
+{
+    let edge = CheckCtx::new(Op::IDENTIFIER, CheckBasis::Mpfr, GeneratorKind::EdgeCases);
+    if edge.input_count() == 1 {
+        edge_cases::get_test_cases::<Op>(&edge).0.for_each(&mut f);
+    }
+
+    let rand = CheckCtx::new(Op::IDENTIFIER, CheckBasis::Mpfr, GeneratorKind::Random);
+    random::get_test_cases::<Op::RustArgs>(&rand).0.for_each(f);
+}
+
+/// Results and status must match exactly, except that any NaN is accepted for NaN.
+fn check<F: Float + Hex>(
+    actual: FpResult<F>,
+    expected: FpResult<F>,
+    round: Round,
+    input: impl Hex,
+) {
+    let same_val = (actual.val.is_nan() && expected.val.is_nan())
+        || actual.val.to_bits() == expected.val.to_bits();
+
+    // A NaN without invalid comes from a NaN input, which `sqrt` flags as invalid even if it is
+    // quiet.
+    let nan_input = expected.val.is_nan() && expected.status == Status::OK;
+
+    assert!(
+        same_val && (nan_input || actual.status == expected.status),
+        "{round:?} input: {} actual: {} {:?} expected: {} {:?}",
+        input.hexf(),
+        actual.val.hexf(),
+        actual.status,
+        expected.val.hexf(),
+        expected.status,
+    );
+}
+
+macro_rules! directed_tests {
+    ($(
+        $(#[$attr:meta])*
+        $op:ident => $libm_fn:ident, $mp_fn:ident, ($($arg:ident),+);
+    )*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $libm_fn() {
+                type Op = libm_test::op::$op::Routine;
+
+                for_each_input::<Op>(|input| {
+                    let ($($arg,)+) = input;
+                    for round in ROUNDS {
+                        let actual = libm::$libm_fn($($arg,)+ round);
+                        let expected = directed::$mp_fn($($arg,)+ round);
+                        check(actual, expected, round, input);
+                    }
+                });
+            }
+        )*
+    };
+}
+
+directed_tests! {
+    #[cfg(f16_enabled)]
+    sqrtf16 => sqrtf16_round, sqrt, (x);
+    sqrtf => sqrtf_round, sqrt, (x);
+    sqrt => sqrt_round, sqrt, (x);
+    #[cfg(f128_enabled)]
+    sqrtf128 => sqrtf128_round, sqrt, (x);
+
+    #[cfg(f16_enabled)]
+    fmaf16 => fmaf16_round, fma, (x, y, z);
+    fmaf => fmaf_round, fma, (x, y, z);
+    fma => fma_round, fma, (x, y, z);
+    #[cfg(f128_enabled)]
+    fmaf128 => fmaf128_round, fma, (x, y, z);
+
+    #[cfg(f16_enabled)]
+    f16addf32 => f16addf32_round, fadd, (x, y);
+    fadd => fadd_round, fadd, (x, y);
+    #[cfg(f128_enabled)]
+    f64addf128 => f64addf128_round, fadd, (x, y);
+
+    #[cfg(f16_enabled)]
+    f16subf32 => f16subf32_round, fsub, (x, y);
+    fsub => fsub_round, fsub, (x, y);
+    #[cfg(f128_enabled)]
+    f64subf128 => f64subf128_round, fsub, (x, y);
+
+    #[cfg(f16_enabled)]
+    f16mulf32 => f16mulf32_round, fmul, (x, y);
+    fmul => fmul_round, fmul, (x, y);
+    #[cfg(f128_enabled)]
+    f64mulf128 => f64mulf128_round, fmul, (x, y);
+
+    #[cfg(f16_enabled)]
+    f16divf32 => f16divf32_round, fdiv, (x, y);
+    fdiv => fdiv_round, fdiv, (x, y);
+    #[cfg(f128_enabled)]
+    f64divf128 => f64divf128_round, fdiv, (x, y);
+
+    #[cfg(f16_enabled)]
+    f16fmaf32 => f16fmaf32_round, ffma, (x, y, z);
+    ffma => ffma_round, ffma, (x, y, z);
+    #[cfg(f128_enabled)]
+    f64fmaf128 => f64fmaf128_round, ffma, (x, y, z);
+
+    #[cfg(f16_enabled)]
+    f16sqrtf32 => f16sqrtf32_round, fsqrt, (x);
+    fsqrt => fsqrt_round, fsqrt, (x);
+    #[cfg(f128_enabled)]
+    f64sqrtf128 => f64sqrtf128_round, fsqrt, (x);
+
+    #[cfg(f16_enabled)]
+    rintf16 => rintf16_round, rint, (x);
+    rintf => rintf_round, rint, (x);
+    rint => rint_round, rint, (x);
+    #[cfg(f128_enabled)]
+    rintf128 => rintf128_round, rint, (x);
+}
+
+/// Check `fma` where `x * y` puts the result exactly halfway between two floats, which random
+/// inputs rarely hit.
+fn check_fma_ties<F: Float + Hex>(libm_fn: fn(F, F, F, Round) -> FpResult<F>)
+where
+    MpFloat: Assign<F>,
+    for<'a> &'a MpFloat: az::Cast<F>,
+{
+    let three = F::ONE + F::ONE + F::ONE;
+    let sigs = [F::Int::ZERO, F::Int::ONE, F::SIG_MASK >> 1, F::SIG_MASK];
+
+    for e in (F::SIG_BITS + 2..F::EXP_SAT).step_by(5) {
+        // Half of the spacing between floats with exponent `e`.
+        let y = F::from_parts(false, e - F::SIG_BITS - 1, F::Int::ZERO);
+        for (neg, sig) in sigs.into_iter().flat_map(|sig| [(false, sig), (true, sig)]) {
+            let z = F::from_parts(neg, e, sig);
+            for x in [F::ONE, three, F::NEG_ONE, -three] {
+                for round in ROUNDS {
+                    let actual = libm_fn(x, y, z, round);
+                    let expected = directed::fma(x, y, z, round);
+                    check(actual, expected, round, (x, y, z));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn fma_ties() {
+    #[cfg(f16_enabled)]
+    check_fma_ties::<f16>(libm::fmaf16_round);
+    check_fma_ties::<f32>(libm::fmaf_round);
+    check_fma_ties::<f64>(libm::fma_round);
+    #[cfg(f128_enabled)]
+    check_fma_ties::<f128>(libm::fmaf128_round);
+}
@@ -68,12 +68,14 @@ libm_macros::for_each_function! {
     attributes: [
         // Also an assertion failure on i686: at `MPFR_ASSERTN (! mpfr_erangeflag_p ())`
         #[ignore = "large values are infeasible in MPFR"]
-        [jn, jnf, yn, ynf],
+        [jn, jnf, jnf128, yn, ynf, ynf128],
     ],
     skip: [
         // FIXME: test needed, see
         // https://github.com/rust-lang/libm/pull/311#discussion_r1818273392
         nextafter,
         nextafterf,
+        nextafterf128,
+        nextafterf16,
     ],
 }
@@ -0,0 +1,264 @@
+//! Check the exceptions reported by `libm::status` against the flags raised by MPFR.
+
+#![cfg(feature = "build-mpfr")]
+#![cfg_attr(f16_enabled, feature(f16))]
+#![cfg_attr(f128_enabled, feature(f128))]
+
+use gmp_mpfr_sys::mpfr;
+use libm::{FpResult, Round, Status};
+use libm_test::generate::edge_cases::{self, EdgeCaseInput};
+use libm_test::generate::random::{self, RandomInput};
+use libm_test::mpfloat::MpOp;
+use libm_test::{CheckBasis, CheckCtx, Float, GeneratorKind, Hex, MathOp, TupleCall};
+
+/// Arguments and results, to find NaNs and infinities.
+trait Special: Copy {
+    /// Any NaN, and any signaling NaN.
+    fn nans(self) -> (bool, bool);
+    fn infinite(self) -> bool;
+}
+
+macro_rules! impl_special_float {
+    ($($(#[$attr:meta])* $ty:ty),*) => {
+        $(
+            $(#[$attr])*
+            impl Special for $ty {
+                fn nans(self) -> (bool, bool) {
+                    let quiet = self.to_bits() & (1 << (<$ty as Float>::SIG_BITS - 1)) != 0;
+                    (self.is_nan(), self.is_nan() && !quiet)
+                }
+
+                fn infinite(self) -> bool {
+                    self.is_infinite()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_special_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Special for $ty {
+                fn nans(self) -> (bool, bool) {
+                    (false, false)
+                }
+
+                fn infinite(self) -> bool {
+                    false
+                }
+            }
+        )*
+    };
+}
+
+impl_special_float!(
+    #[cfg(f16_enabled)]
+    f16,
+    f32,
+    f64,
+    #[cfg(f128_enabled)]
+    f128
+);
+impl_special_int!(i32, i64, u32, Round);
+
+macro_rules! impl_special_tuple {
+    ($t0:ident $(, $t:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$t0: Special $(, $t: Special)*> Special for ($t0, $($t,)*) {
+            fn nans(self) -> (bool, bool) {
+                let ($t0, $($t,)*) = self;
+                let res = $t0.nans();
+                $(
+                    let (nan, snan) = $t.nans();
+                    let res = (res.0 || nan, res.1 || snan);
+                )*
+                res
+            }
+
+            fn infinite(self) -> bool {
+                let ($t0, $($t,)*) = self;
+                $t0.infinite() $(|| $t.infinite())*
+            }
+        }
+    };
+}
+
+impl_special_tuple!(A);
+impl_special_tuple!(A, B);
+impl_special_tuple!(A, B, C);
+
+/// Reconstruct the IEEE 754 flags for MPFR's result `expected`, which has already been
+/// converted to the output type. MPFR's intermediate results have an unbounded exponent range, so
+/// overflow is recovered from the result. Underflow is read from MPFR, which raises it when
+/// rounding to the exponent range of the output type.
+fn mpfr_status(input: impl Special, expected: impl Special) -> Status {
+    // SAFETY: reading the global MPFR flags has no preconditions.
+    let (nan, divby0, inexact, underflow) = unsafe {
+        (
+            mpfr::nanflag_p() != 0,
+            mpfr::divby0_p() != 0,
+            mpfr::inexflag_p() != 0,
+            mpfr::underflow_p() != 0,
+        )
+    };
+
+    let mut status = Status::OK;
+    if nan && !input.nans().0 {
+        status = Status::INVALID;
+    } else if divby0 {
+        status = Status::DIVIDE_BY_ZERO;
+    } else if expected.infinite() && !input.infinite() {
+        status = Status::OVERFLOW;
+        status.set_inexact(true);
+    } else if inexact {
+        // MPFR also raises underflow for exact subnormal results, which IEEE 754 does not.
+        status = Status::INEXACT;
+        status.set_underflow(underflow);
+    }
+    status
+}
+
+/// Check one input, skipping results that do not match MPFR: the flags of a wrong result are
+/// meaningless, and the value is checked elsewhere.
+fn check<I, R>(input: I, actual: FpResult<R>, expected: R, mp_status: Status)
+where
+    I: Special + Hex,
+    R: Special + Hex,
+{
+    if actual.val.hexf() != expected.hexf() {
+        return;
+    }
+
+    // Signaling NaNs are quieted before MPFR sees them.
+    if input.nans().1 {
+        return;
+    }
+
+    assert_eq!(
+        actual.status,
+        mp_status,
+        "input: {} result: {} (libm: {:?}, MPFR: {:?})",
+        input.hexf(),
+        expected.hexf(),
+        actual.status,
+        mp_status,
+    );
+}
+
+/// Call `f` with random inputs for `Op`, plus edge cases for functions of one or two arguments.
+/// The edge cases of functions with more arguments are a cartesian product that is too slow.
+fn for_each_input<Op>(mut f: impl FnMut(Op::RustArgs))
+where
+    Op: MathOp,
+    Op::RustArgs: RandomInput + EdgeCaseInput<Op>,
+{
+    let edge = CheckCtx::new(Op::IDENTIFIER, CheckBasis::Mpfr, GeneratorKind::EdgeCases);
+    if edge.input_count() <= 2 {
+        edge_cases::get_test_cases::<Op>(&edge).0.for_each(&mut f);
+    }
+
+    let rand = CheckCtx::new(Op::IDENTIFIER, CheckBasis::Mpfr, GeneratorKind::Random);
+    random::get_test_cases::<Op::RustArgs>(&rand).0.for_each(f);
+}
+
+macro_rules! status_tests {
+    (
+        fn_name: $fn_name:ident,
+        RustFn: fn($($arg:ty),* ,) -> $ret:ty,
+        attrs: [$($attr:meta),*],
+    ) => {
+        #[test]
+        $(#[$attr])*
+        fn $fn_name() {
+            type Op = libm_test::op::$fn_name::Routine;
+            let mut mp_vals = Op::new_mp();
+            let f = libm::status::$fn_name as fn($($arg),*) -> FpResult<$ret>;
+
+            for_each_input::<Op>(|input| {
+                // SAFETY: clearing the global MPFR flags has no preconditions.
+                unsafe { mpfr::clear_flags() };
+                let expected = Op::run(&mut mp_vals, input);
+                let mp_status = mpfr_status(input, expected);
+                let actual = input.call(f);
+                check(input, actual, expected, mp_status);
+            });
+        }
+    };
+}
+
+libm_macros::for_each_function! {
+    callback: status_tests,
+    emit_types: [RustFn],
+    attributes: [
+        #[ignore = "large values are infeasible in MPFR"]
+        [jn, jnf, jnf128, yn, ynf, ynf128],
+    ],
+    skip: [
+        // MPFR does not have these operations, or they are computed without MPFR's flags.
+        fromfp,
+        fromfpf,
+        fromfpf128,
+        fromfpf16,
+        fromfpx,
+        fromfpxf,
+        fromfpxf128,
+        fromfpxf16,
+        ilogb,
+        ilogbf,
+        ilogbf128,
+        ilogbf16,
+        llogb,
+        llogbf,
+        llogbf128,
+        llogbf16,
+        llrint,
+        llrintf,
+        llrintf128,
+        llrintf16,
+        llround,
+        llroundf,
+        llroundf128,
+        llroundf16,
+        logb,
+        logbf,
+        logbf128,
+        logbf16,
+        lrint,
+        lrintf,
+        lrintf128,
+        lrintf16,
+        lround,
+        lroundf,
+        lroundf128,
+        lroundf16,
+        nextafter,
+        nextafterf,
+        nextafterf128,
+        nextafterf16,
+        nextdown,
+        nextdownf,
+        nextdownf128,
+        nextdownf16,
+        nexttoward,
+        nexttowardf,
+        nextup,
+        nextupf,
+        nextupf128,
+        nextupf16,
+        // MPFR rounds to an integer without signaling inexact, like IEEE 754 `roundToIntegral`
+        // rather than C `rint`.
+        rint,
+        rintf,
+        rintf128,
+        rintf16,
+        ufromfp,
+        ufromfpf,
+        ufromfpf128,
+        ufromfpf16,
+        ufromfpx,
+        ufromfpxf,
+        ufromfpxf128,
+        ufromfpxf16,
+    ],
+}
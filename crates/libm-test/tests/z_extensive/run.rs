@@ -57,6 +57,8 @@ fn register_all_tests() -> Vec<Trial> {
             // https://github.com/rust-lang/libm/pull/311#discussion_r1818273392
             nextafter,
             nextafterf,
+            nextafterf128,
+            nextafterf16,
         ],
     }
 
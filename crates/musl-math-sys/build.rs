@@ -8,6 +8,8 @@ const LIB_NAME: &str = "musl_math_prefixed";
 
 /// Files that have more than one symbol. Map of file names to the symbols defined in that file.
 const MULTIPLE_SYMBOLS: &[(&str, &[&str])] = &[
+    ("__cexp", &["__ldexp_cexp"]),
+    ("__cexpf", &["__ldexp_cexpf"]),
     ("__invtrigl", &["__invtrigl", "__invtrigl_R", "__pio2_hi", "__pio2_lo"]),
     ("__polevll", &["__polevll", "__p1evll"]),
     ("erf", &["erf", "erfc"]),
@@ -110,7 +112,8 @@ fn build_musl_math(cfg: &Config) {
     let arch_dir = musl_dir.join("arch").join(&cfg.musl_arch);
     assert!(math.exists(), "musl source not found. Is the submodule up to date?");
 
-    let source_map = find_math_source(&math, cfg);
+    let mut source_map = find_math_source(&math, cfg);
+    source_map.extend(find_complex_source(&musl_dir.join("src/complex")));
     let out_path = cfg.out_dir.join(format!("lib{LIB_NAME}.a"));
 
     // Run configuration steps. Usually done as part of the musl `Makefile`.
@@ -155,7 +158,8 @@ fn build_musl_math(cfg: &Config) {
         .include(musl_dir.join("src/internal"))
         .include(obj_include)
         .include(musl_dir.join("include"))
-        .file(cfg.manifest_dir.join("c_patches/alias.c"));
+        .file(cfg.manifest_dir.join("c_patches/alias.c"))
+        .file(cfg.manifest_dir.join("c_patches/complex.c"));
 
     for (sym_name, src_file) in source_map {
         // Build the source file
@@ -250,6 +254,27 @@ fn find_math_source(math_root: &Path, cfg: &Config) -> BTreeMap<String, PathBuf>
     map
 }
 
+/// Build a map of `name -> path` for the complex functions, which have no arch-specific versions.
+///
+/// `creal` and `cimag` are skipped since `complex.h` also provides them as macros, which would
+/// prevent their symbols from being renamed.
+fn find_complex_source(complex_root: &Path) -> BTreeMap<String, PathBuf> {
+    let mut map = BTreeMap::new();
+
+    for item in fs::read_dir(complex_root).unwrap() {
+        let path = item.unwrap().path();
+        let sym_name = path.file_stem().unwrap().to_str().unwrap();
+
+        if sym_name.starts_with("creal") || sym_name.starts_with("cimag") {
+            continue;
+        }
+
+        map.insert(sym_name.to_owned(), path.to_owned());
+    }
+
+    map
+}
+
 /// Make sure we don't have something like a loose unprefixed `_cos` called somewhere, which could
 /// wind up linking to system libraries rather than the built musl library.
 fn validate_archive_symbols(out_path: &Path) {
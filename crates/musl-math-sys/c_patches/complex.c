@@ -0,0 +1,57 @@
+/* Rust has no portable way to pass `_Complex` values by value (e.g. i386
+ * returns `float _Complex` in registers but a two-float struct in memory), so
+ * expose the complex functions through wrappers that take pointers.
+ *
+ * The function names are redefined to their `musl_` versions by the build
+ * script, so e.g. `csin` here refers to `musl_csin`.
+ */
+
+#include <complex.h>
+
+#define UNARY(name, t) \
+	void musl_cwrap_##name(t complex *z) { *z = name(*z); }
+#define REAL(name, t) \
+	t musl_cwrap_##name(const t complex *z) { return name(*z); }
+#define BINARY(name, t) \
+	void musl_cwrap_##name(t complex *z, const t complex *w) { *z = name(*z, *w); }
+
+REAL(cabs, double)
+REAL(cabsf, float)
+UNARY(cacos, double)
+UNARY(cacosf, float)
+UNARY(cacosh, double)
+UNARY(cacoshf, float)
+REAL(carg, double)
+REAL(cargf, float)
+UNARY(casin, double)
+UNARY(casinf, float)
+UNARY(casinh, double)
+UNARY(casinhf, float)
+UNARY(catan, double)
+UNARY(catanf, float)
+UNARY(catanh, double)
+UNARY(catanhf, float)
+UNARY(ccos, double)
+UNARY(ccosf, float)
+UNARY(ccosh, double)
+UNARY(ccoshf, float)
+UNARY(cexp, double)
+UNARY(cexpf, float)
+UNARY(clog, double)
+UNARY(clogf, float)
+UNARY(conj, double)
+UNARY(conjf, float)
+BINARY(cpow, double)
+BINARY(cpowf, float)
+UNARY(cproj, double)
+UNARY(cprojf, float)
+UNARY(csin, double)
+UNARY(csinf, float)
+UNARY(csinh, double)
+UNARY(csinhf, float)
+UNARY(csqrt, double)
+UNARY(csqrtf, float)
+UNARY(ctan, double)
+UNARY(ctanf, float)
+UNARY(ctanh, double)
+UNARY(ctanhf, float)
@@ -48,6 +48,84 @@ macro_rules! functions {
     };
 }
 
+/// A complex number, laid out like C's `_Complex` types.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex<F> {
+    pub re: F,
+    pub im: F,
+}
+
+/// Macro for creating bindings to the complex functions. These are called through the wrappers in
+/// `c_patches/complex.c`, which take pointers since `_Complex` values can't portably be passed by
+/// value. `unary` functions map a complex number to a complex number, `real` functions map a
+/// complex number to a real number, and `binary` functions take two complex numbers.
+macro_rules! complex_functions {
+    ( $(
+        $wrap_name:ident: $name:ident($kind:ident, $fty:ty);
+    )* ) => {
+        unsafe extern "C" {
+            $( complex_functions!(@extern $kind $wrap_name, $fty); )*
+        }
+
+        $( complex_functions!(@safe $kind $wrap_name, $name, $fty); )*
+
+        #[cfg(test)]
+        mod complex_tests {
+            use super::Complex;
+
+            $(
+                // Run a simple check to ensure we can link and call the function without crashing.
+                #[test]
+                // FIXME(#309): LE PPC crashes calling some musl functions
+                #[cfg_attr(all(target_arch = "powerpc64", target_endian = "little"), ignore)]
+                fn $name() {
+                    let z = Complex::<$fty> { re: 1.0, im: 1.0 };
+                    complex_functions!(@test $kind super::$name, z);
+                }
+            )*
+        }
+    };
+
+    (@extern unary $wrap_name:ident, $fty:ty) => {
+        fn $wrap_name(z: &mut Complex<$fty>);
+    };
+    (@extern real $wrap_name:ident, $fty:ty) => {
+        fn $wrap_name(z: &Complex<$fty>) -> $fty;
+    };
+    (@extern binary $wrap_name:ident, $fty:ty) => {
+        fn $wrap_name(z: &mut Complex<$fty>, w: &Complex<$fty>);
+    };
+
+    (@safe unary $wrap_name:ident, $name:ident, $fty:ty) => {
+        pub fn $name(mut z: Complex<$fty>) -> Complex<$fty> {
+            // SAFETY: FFI call with no preconditions beyond valid pointers
+            unsafe { $wrap_name(&mut z) };
+            z
+        }
+    };
+    (@safe real $wrap_name:ident, $name:ident, $fty:ty) => {
+        pub fn $name(z: Complex<$fty>) -> $fty {
+            // SAFETY: FFI call with no preconditions beyond valid pointers
+            unsafe { $wrap_name(&z) }
+        }
+    };
+    (@safe binary $wrap_name:ident, $name:ident, $fty:ty) => {
+        pub fn $name(mut z: Complex<$fty>, w: Complex<$fty>) -> Complex<$fty> {
+            // SAFETY: FFI call with no preconditions beyond valid pointers
+            unsafe { $wrap_name(&mut z, &w) };
+            z
+        }
+    };
+
+    (@test binary $f:path, $z:ident) => {
+        $f($z, $z);
+    };
+    (@test $kind:ident $f:path, $z:ident) => {
+        $f($z);
+    };
+}
+
 #[cfg(test)]
 mod test_support {
     use core::ffi::c_char;
@@ -285,3 +363,46 @@ functions! {
     musl_yn: yn(a: c_int, b: f64) -> f64;
     musl_ynf: ynf(a: c_int, b: f32) -> f32;
 }
+
+complex_functions! {
+    musl_cwrap_cabs: cabs(real, f64);
+    musl_cwrap_cabsf: cabsf(real, f32);
+    musl_cwrap_cacos: cacos(unary, f64);
+    musl_cwrap_cacosf: cacosf(unary, f32);
+    musl_cwrap_cacosh: cacosh(unary, f64);
+    musl_cwrap_cacoshf: cacoshf(unary, f32);
+    musl_cwrap_carg: carg(real, f64);
+    musl_cwrap_cargf: cargf(real, f32);
+    musl_cwrap_casin: casin(unary, f64);
+    musl_cwrap_casinf: casinf(unary, f32);
+    musl_cwrap_casinh: casinh(unary, f64);
+    musl_cwrap_casinhf: casinhf(unary, f32);
+    musl_cwrap_catan: catan(unary, f64);
+    musl_cwrap_catanf: catanf(unary, f32);
+    musl_cwrap_catanh: catanh(unary, f64);
+    musl_cwrap_catanhf: catanhf(unary, f32);
+    musl_cwrap_ccos: ccos(unary, f64);
+    musl_cwrap_ccosf: ccosf(unary, f32);
+    musl_cwrap_ccosh: ccosh(unary, f64);
+    musl_cwrap_ccoshf: ccoshf(unary, f32);
+    musl_cwrap_cexp: cexp(unary, f64);
+    musl_cwrap_cexpf: cexpf(unary, f32);
+    musl_cwrap_clog: clog(unary, f64);
+    musl_cwrap_clogf: clogf(unary, f32);
+    musl_cwrap_conj: conj(unary, f64);
+    musl_cwrap_conjf: conjf(unary, f32);
+    musl_cwrap_cpow: cpow(binary, f64);
+    musl_cwrap_cpowf: cpowf(binary, f32);
+    musl_cwrap_cproj: cproj(unary, f64);
+    musl_cwrap_cprojf: cprojf(unary, f32);
+    musl_cwrap_csin: csin(unary, f64);
+    musl_cwrap_csinf: csinf(unary, f32);
+    musl_cwrap_csinh: csinh(unary, f64);
+    musl_cwrap_csinhf: csinhf(unary, f32);
+    musl_cwrap_csqrt: csqrt(unary, f64);
+    musl_cwrap_csqrtf: csqrtf(unary, f32);
+    musl_cwrap_ctan: ctan(unary, f64);
+    musl_cwrap_ctanf: ctanf(unary, f32);
+    musl_cwrap_ctanh: ctanh(unary, f64);
+    musl_cwrap_ctanhf: ctanhf(unary, f32);
+}
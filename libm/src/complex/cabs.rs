@@ -0,0 +1,20 @@
+use super::Complex;
+use crate::math::{hypot, hypotf};
+
+/// Absolute value (magnitude) of a complex number (f32)
+///
+/// Computed as `hypot(re, im)`, so the result is +inf if either part is infinite, even if the
+/// other part is NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cabsf(z: Complex<f32>) -> f32 {
+    hypotf(z.re, z.im)
+}
+
+/// Absolute value (magnitude) of a complex number (f64)
+///
+/// Computed as `hypot(re, im)`, so the result is +inf if either part is infinite, even if the
+/// other part is NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cabs(z: Complex<f64>) -> f64 {
+    hypot(z.re, z.im)
+}
@@ -0,0 +1,96 @@
+use core::f64::consts::FRAC_PI_2;
+
+use super::{Complex, cacosh};
+
+/// Complex inverse cosine (f32)
+///
+/// The result has a real part in `[0, pi]`, with the branch cuts along the real axis outside
+/// `[-1, 1]`. Special values are as specified by C99 Annex G. The result is computed in double
+/// precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacosf(z: Complex<f32>) -> Complex<f32> {
+    let r = cacos(Complex::new(z.re as f64, z.im as f64));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex inverse cosine (f64)
+///
+/// The result has a real part in `[0, pi]`, with the branch cuts along the real axis outside
+/// `[-1, 1]`. Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacos(z: Complex<f64>) -> Complex<f64> {
+    /* cacos(+-0 + i NaN) = pi/2 + i NaN, where cacosh only gives NaN + i NaN */
+    if z.re == 0.0 && z.im.is_nan() {
+        return Complex::new(FRAC_PI_2, z.im);
+    }
+
+    /* cacosh(z) = i*cacos(z) in the upper half plane, and -i*cacos(z) in the lower */
+    let r = cacosh(z);
+    if z.im.is_sign_negative() { Complex::new(-r.im, r.re) } else { Complex::new(r.im, -r.re) }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_3, FRAC_PI_4, PI};
+
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (FRAC_PI_2, -0.0)),
+            ((-0.0, -0.0), (FRAC_PI_2, 0.0)),
+            ((0.0, NAN), (FRAC_PI_2, NAN)),
+            ((-0.0, NAN), (FRAC_PI_2, NAN)),
+            ((1.0, INF), (FRAC_PI_2, -INF)),
+            ((1.0, -INF), (FRAC_PI_2, INF)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (PI, -INF)),
+            ((INF, 1.0), (0.0, -INF)),
+            ((INF, -1.0), (0.0, INF)),
+            ((-INF, INF), (2.356194490192345, -INF)),
+            ((INF, INF), (FRAC_PI_4, -INF)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (NAN, -INF)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("cacos", z, cacos(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("cacosf", z, cacosf(z), (ere as f32, eim as f32));
+        }
+
+        /* cacos(+-inf + i NaN) = NaN +- i inf */
+        let r = cacos(Complex::new(-INF, NAN));
+        assert!(r.re.is_nan() && r.im.is_infinite());
+    }
+
+    #[test]
+    fn branch_cut() {
+        /* The sign of the imaginary part selects the side of the cut on the real axis */
+        let r = cacos(Complex::new(2.0, 0.0));
+        assert_eq!(r.re, 0.0);
+        assert_approx_eq(r.im, -1.3169578969248166);
+        let r = cacos(Complex::new(-2.0, -0.0));
+        assert_eq!(r.re, PI);
+        assert_approx_eq(r.im, 1.3169578969248166);
+    }
+
+    #[test]
+    fn identity() {
+        let r = cacos(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 0.9045568943023813);
+        assert_approx_eq(r.im, -1.0612750619050357);
+
+        /* Real inputs in [-1, 1] reduce to acos */
+        let r = cacos(Complex::new(0.5, 0.0));
+        assert_approx_eq(r.re, FRAC_PI_3);
+        assert_eq!(r.im, -0.0);
+    }
+}
@@ -0,0 +1,138 @@
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LN_2, PI};
+
+use super::{Complex, clog, csqrt};
+use crate::math::{asinh, atan2, copysign, fabs};
+use crate::support::hf64;
+
+/* Above this magnitude, cacosh(z) = log(2z) to within rounding. */
+const LARGE: f64 = hf64("0x1p27");
+const FRAC_3PI_4: f64 = 2.356194490192345; /* 3*pi/4 */
+
+/// Complex inverse hyperbolic cosine (f32)
+///
+/// The result has a nonnegative real part and an imaginary part in `[-pi, pi]`, with the branch
+/// cut along the real axis below 1. Special values are as specified by C99 Annex G. The result is
+/// computed in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacoshf(z: Complex<f32>) -> Complex<f32> {
+    let r = cacosh(Complex::new(z.re as f64, z.im as f64));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex inverse hyperbolic cosine (f64)
+///
+/// The result has a nonnegative real part and an imaginary part in `[-pi, pi]`, with the branch
+/// cut along the real axis below 1. Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacosh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let ay = fabs(y);
+
+    /* cacosh commutes with conj, so work in the upper half plane. */
+    let (re, im) = if x.is_nan() {
+        /* cacosh(NaN + i inf) = inf + i NaN; cacosh(NaN + iy) = NaN + i NaN */
+        (if ay.is_infinite() { ay } else { x }, x)
+    } else if ay.is_nan() {
+        /* cacosh(+-inf + i NaN) = inf + i NaN; cacosh(x + i NaN) = NaN + i NaN */
+        (if x.is_infinite() { fabs(x) } else { ay }, ay)
+    } else if ay.is_infinite() {
+        /* cacosh(+-inf + i inf) = inf + i pi/4 or 3pi/4; cacosh(x + i inf) = inf + i pi/2 */
+        let im = if !x.is_infinite() {
+            FRAC_PI_2
+        } else if x > 0.0 {
+            FRAC_PI_4
+        } else {
+            FRAC_3PI_4
+        };
+        (ay, im)
+    } else if x.is_infinite() {
+        /* cacosh(+inf + iy) = inf + i0; cacosh(-inf + iy) = inf + i pi */
+        (fabs(x), if x > 0.0 { 0.0 } else { PI })
+    } else if fabs(x) > LARGE || ay > LARGE {
+        let w = clog(Complex::new(x, ay));
+        (w.re + LN_2, w.im)
+    } else {
+        /*
+         * Kahan's formula, from "Branch Cuts for Complex Elementary Functions":
+         *
+         *   cacosh(z) = asinh(Re(conj(sqrt(z - 1)) * sqrt(z + 1)))
+         *             + i 2 atan(Im(sqrt(z - 1)) / Re(sqrt(z + 1)))
+         *
+         * In the upper half plane both terms of the product are nonnegative, so there is no
+         * cancellation.
+         */
+        let a = csqrt(Complex::new(x - 1.0, ay));
+        let b = csqrt(Complex::new(x + 1.0, ay));
+        (asinh(a.re * b.re + a.im * b.im), 2.0 * atan2(a.im, b.re))
+    };
+
+    Complex::new(re, copysign(im, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, FRAC_PI_2)),
+            ((-0.0, -0.0), (0.0, -FRAC_PI_2)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((-1.0, -INF), (INF, -FRAC_PI_2)),
+            ((0.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (INF, PI)),
+            ((-INF, -1.0), (INF, -PI)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, -1.0), (INF, -0.0)),
+            ((-INF, INF), (INF, FRAC_3PI_4)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((-INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("cacosh", z, cacosh(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("cacoshf", z, cacoshf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn branch_cut() {
+        /* The sign of the imaginary part selects the side of the cut on the real axis */
+        let r = cacosh(Complex::new(-2.0, 0.0));
+        assert_approx_eq(r.re, 1.3169578969248166);
+        assert_eq!(r.im, PI);
+        let r = cacosh(Complex::new(-2.0, -0.0));
+        assert_approx_eq(r.re, 1.3169578969248166);
+        assert_eq!(r.im, -PI);
+        let r = cacosh(Complex::new(0.5, 0.0));
+        assert_eq!(r.re, 0.0);
+        assert_approx_eq(r.im, core::f64::consts::FRAC_PI_3);
+    }
+
+    #[test]
+    fn identity() {
+        let r = cacosh(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 1.0612750619050357);
+        assert_approx_eq(r.im, 0.9045568943023813);
+
+        let r = cacosh(Complex::new(1.0, 0.0));
+        assert_eq!((r.re, r.im), (0.0, 0.0));
+
+        /* Large inputs don't overflow */
+        let r = cacosh(Complex::new(-1e300, 0.0));
+        assert_approx_eq(r.re, 691.4686750787736);
+        assert_eq!(r.im, PI);
+    }
+}
@@ -0,0 +1,20 @@
+use super::Complex;
+use crate::math::{atan2, atan2f};
+
+/// Argument (phase angle) of a complex number (f32)
+///
+/// Computed as `atan2(im, re)`. The result is in the range `[-pi, pi]`, with the branch cut along
+/// the negative real axis; the sign of a zero imaginary part selects the side of the cut.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cargf(z: Complex<f32>) -> f32 {
+    atan2f(z.im, z.re)
+}
+
+/// Argument (phase angle) of a complex number (f64)
+///
+/// Computed as `atan2(im, re)`. The result is in the range `[-pi, pi]`, with the branch cut along
+/// the negative real axis; the sign of a zero imaginary part selects the side of the cut.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn carg(z: Complex<f64>) -> f64 {
+    atan2(z.im, z.re)
+}
@@ -0,0 +1,77 @@
+use super::{Complex, casinh};
+
+/// Complex inverse sine (f32)
+///
+/// The branch cuts are along the real axis outside `[-1, 1]`. Computed as `-i*casinh(i*z)`, so
+/// special values follow those of [`casinhf`](fn@super::casinhf) as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinf(z: Complex<f32>) -> Complex<f32> {
+    let r = casinh(Complex::new(-z.im as f64, z.re as f64));
+    Complex::new(r.im as f32, -r.re as f32)
+}
+
+/// Complex inverse sine (f64)
+///
+/// The branch cuts are along the real axis outside `[-1, 1]`. Computed as `-i*casinh(i*z)`, so
+/// special values follow those of [`casinh`](fn@super::casinh) as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casin(z: Complex<f64>) -> Complex<f64> {
+    let r = casinh(Complex::new(-z.im, z.re));
+    Complex::new(r.im, -r.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6};
+
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((INF, 1.0), (FRAC_PI_2, INF)),
+            ((-INF, -1.0), (-FRAC_PI_2, -INF)),
+            ((1.0, INF), (0.0, INF)),
+            ((INF, INF), (FRAC_PI_4, INF)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("casin", z, casin(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("casinf", z, casinf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn branch_cut() {
+        /* The sign of the imaginary part selects the side of the cut on the real axis */
+        let r = casin(Complex::new(2.0, 0.0));
+        assert_approx_eq(r.re, FRAC_PI_2);
+        assert_approx_eq(r.im, 1.3169578969248166);
+        let r = casin(Complex::new(2.0, -0.0));
+        assert_approx_eq(r.re, FRAC_PI_2);
+        assert_approx_eq(r.im, -1.3169578969248166);
+    }
+
+    #[test]
+    fn identity() {
+        let r = casin(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 0.6662394324925153);
+        assert_approx_eq(r.im, 1.0612750619050357);
+
+        /* Real inputs in [-1, 1] reduce to asin */
+        let r = casin(Complex::new(0.5, 0.0));
+        assert_approx_eq(r.re, FRAC_PI_6);
+        assert_eq!(r.im, 0.0);
+    }
+}
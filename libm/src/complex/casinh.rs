@@ -0,0 +1,137 @@
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LN_2};
+
+use super::{Complex, clog, csqrt};
+use crate::math::{asinh, atan2, copysign, fabs};
+use crate::support::hf64;
+
+/* Above this magnitude, casinh(z) = log(2z) to within rounding. */
+const LARGE: f64 = hf64("0x1p27");
+
+/// Complex inverse hyperbolic sine (f32)
+///
+/// The branch cuts are along the imaginary axis outside `[-i, i]`. Special values are as
+/// specified by C99 Annex G. The result is computed in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinhf(z: Complex<f32>) -> Complex<f32> {
+    let r = casinh(Complex::new(z.re as f64, z.im as f64));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex inverse hyperbolic sine (f64)
+///
+/// The branch cuts are along the imaginary axis outside `[-i, i]`. Special values are as
+/// specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let ax = fabs(x);
+    let ay = fabs(y);
+
+    /* casinh is odd and commutes with conj, so work in the first quadrant. */
+    let (re, im) = if ax.is_nan() {
+        if ay.is_infinite() {
+            /* casinh(NaN + i inf) = inf + i NaN (sign of the real part unspecified) */
+            (ay, ax)
+        } else if ay == 0.0 {
+            /* casinh(NaN + i0) = NaN + i0 */
+            (ax, ay)
+        } else {
+            (ax, ax)
+        }
+    } else if ay.is_nan() {
+        /* casinh(inf + i NaN) = inf + i NaN; casinh(x + i NaN) = NaN + i NaN */
+        if ax.is_infinite() { (ax, ay) } else { (ay, ay) }
+    } else if ax.is_infinite() {
+        /* casinh(inf + i inf) = inf + i pi/4; casinh(inf + iy) = inf + i0 */
+        (ax, if ay.is_infinite() { FRAC_PI_4 } else { 0.0 })
+    } else if ay.is_infinite() {
+        /* casinh(x + i inf) = inf + i pi/2 */
+        (ay, FRAC_PI_2)
+    } else if ax > LARGE || ay > LARGE {
+        let w = clog(Complex::new(ax, ay));
+        (w.re + LN_2, w.im)
+    } else {
+        /*
+         * Kahan's formula, from "Branch Cuts for Complex Elementary Functions":
+         *
+         *   casinh(z) = asinh(Im(conj(sqrt(1 - iz)) * sqrt(1 + iz)))
+         *             + i atan(Im(z) / Re(sqrt(1 - iz) * sqrt(1 + iz)))
+         *
+         * In the first quadrant the terms of each product have the same sign, so there is no
+         * cancellation.
+         */
+        let a = csqrt(Complex::new(1.0 + ay, -ax));
+        let b = csqrt(Complex::new(1.0 - ay, ax));
+        (asinh(a.re * b.im - a.im * b.re), atan2(ay, a.re * b.re - a.im * b.im))
+    };
+
+    Complex::new(copysign(re, x), copysign(im, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((-1.0, -INF), (-INF, -FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, -1.0), (INF, -0.0)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((-INF, INF), (-INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, -0.0), (NAN, -0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("casinh", z, casinh(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("casinhf", z, casinhf(z), (ere as f32, eim as f32));
+        }
+
+        /* casinh(NaN + i inf) = +-inf + i NaN */
+        let r = casinh(Complex::new(NAN, INF));
+        assert!(r.re.is_infinite() && r.im.is_nan());
+    }
+
+    #[test]
+    fn branch_cut() {
+        /* The sign of the real part selects the side of the cut on the imaginary axis */
+        let r = casinh(Complex::new(0.0, 2.0));
+        assert_approx_eq(r.re, 1.3169578969248166);
+        assert_approx_eq(r.im, FRAC_PI_2);
+        let r = casinh(Complex::new(-0.0, 2.0));
+        assert_approx_eq(r.re, -1.3169578969248166);
+        assert_approx_eq(r.im, FRAC_PI_2);
+    }
+
+    #[test]
+    fn identity() {
+        let r = casinh(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 1.0612750619050357);
+        assert_approx_eq(r.im, 0.6662394324925153);
+
+        /* Real inputs reduce to asinh */
+        let r = casinh(Complex::new(-3.0, 0.0));
+        assert_eq!((r.re, r.im), (asinh(-3.0), 0.0));
+
+        /* Large inputs don't overflow */
+        let r = casinh(Complex::new(1e300, 1e300));
+        assert_approx_eq(r.re, 691.8152486690535);
+        assert_approx_eq(r.im, FRAC_PI_4);
+    }
+}
@@ -0,0 +1,70 @@
+use super::{Complex, catanh};
+
+/// Complex inverse tangent (f32)
+///
+/// The branch cuts are along the imaginary axis outside `[-i, i]`. Computed as
+/// `-i*catanh(i*z)`, so special values follow those of [`catanhf`](fn@super::catanhf) as specified
+/// by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanf(z: Complex<f32>) -> Complex<f32> {
+    let r = catanh(Complex::new(-z.im as f64, z.re as f64));
+    Complex::new(r.im as f32, -r.re as f32)
+}
+
+/// Complex inverse tangent (f64)
+///
+/// The branch cuts are along the imaginary axis outside `[-i, i]`. Computed as
+/// `-i*catanh(i*z)`, so special values follow those of [`catanh`](fn@super::catanh) as specified by
+/// C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catan(z: Complex<f64>) -> Complex<f64> {
+    let r = catanh(Complex::new(-z.im, z.re));
+    Complex::new(r.im, -r.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((0.0, 1.0), (0.0, INF)),
+            ((-0.0, -1.0), (-0.0, -INF)),
+            ((INF, 1.0), (FRAC_PI_2, 0.0)),
+            ((-INF, -1.0), (-FRAC_PI_2, -0.0)),
+            ((1.0, INF), (FRAC_PI_2, 0.0)),
+            ((INF, INF), (FRAC_PI_2, 0.0)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("catan", z, catan(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("catanf", z, catanf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn identity() {
+        let r = catan(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 1.0172219678978514);
+        assert_approx_eq(r.im, 0.40235947810852507);
+
+        /* Real inputs reduce to atan */
+        let r = catan(Complex::new(-1.0, 0.0));
+        assert_approx_eq(r.re, -FRAC_PI_4);
+        assert_eq!(r.im, 0.0);
+    }
+}
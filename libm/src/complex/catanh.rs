@@ -0,0 +1,142 @@
+use core::f64::consts::FRAC_PI_2;
+
+use super::Complex;
+use crate::math::{atan2, copysign, fabs, hypot, log, log1p};
+use crate::support::hf64;
+
+/* Above this magnitude, the imaginary part is pi/2 to within rounding. */
+const LARGE: f64 = hf64("0x1p500");
+/* Below this, |1 - z|^2 may underflow. */
+const TINY: f64 = hf64("0x1p-500");
+
+/// Complex inverse hyperbolic tangent (f32)
+///
+/// The branch cuts are along the real axis outside `[-1, 1]`. Special values are as specified by
+/// C99 Annex G. The result is computed in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanhf(z: Complex<f32>) -> Complex<f32> {
+    let r = catanh(Complex::new(z.re as f64, z.im as f64));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex inverse hyperbolic tangent (f64)
+///
+/// The branch cuts are along the real axis outside `[-1, 1]`. Special values are as specified by
+/// C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let ax = fabs(x);
+    let ay = fabs(y);
+
+    /* catanh is odd and commutes with conj, so work in the first quadrant. */
+    let (re, im) = if ax.is_nan() {
+        /* catanh(NaN + i inf) = 0 + i pi/2 (sign of the real part unspecified) */
+        if ay.is_infinite() { (0.0, FRAC_PI_2) } else { (ax, ax) }
+    } else if ay.is_nan() {
+        /* catanh(+0 + i NaN) = +0 + i NaN; catanh(inf + i NaN) = 0 + i NaN */
+        if ax == 0.0 || ax.is_infinite() { (0.0, ay) } else { (ay, ay) }
+    } else if ax.is_infinite() || ay.is_infinite() {
+        /* catanh(x + i inf) = catanh(inf + iy) = 0 + i pi/2 */
+        (0.0, FRAC_PI_2)
+    } else if ax > LARGE || ay > LARGE {
+        /* Re = x / |z|^2, halving |z| to avoid overflow in hypot */
+        let h = hypot(ax * 0.5, ay * 0.5);
+        (((ax * 0.25) / h) / h, FRAC_PI_2)
+    } else {
+        /*
+         * From catanh(z) = (log(1 + z) - log(1 - z)) / 2:
+         *
+         *   Re = log(|1 + z|^2 / |1 - z|^2) / 4 = log1p(4x / |1 - z|^2) / 4
+         *   Im = arg((1 + z) * conj(1 - z)) / 2 = atan2(2y, (1 - x)(1 + x) - y^2) / 2
+         */
+        let h = hypot(1.0 - ax, ay);
+        let re = if h < TINY {
+            /* Near z = 1 the 1 in log1p is negligible, but h^2 would underflow. */
+            (0.5 * log(4.0 * ax) - log(h)) * 0.5
+        } else {
+            log1p(4.0 * ax / (h * h)) * 0.25
+        };
+        (re, 0.5 * atan2(2.0 * ay, (1.0 - ax) * (1.0 + ax) - ay * ay))
+    };
+
+    Complex::new(copysign(re, x), copysign(im, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::FRAC_PI_4;
+
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((-0.0, NAN), (-0.0, NAN)),
+            ((1.0, 0.0), (INF, 0.0)),
+            ((-1.0, -0.0), (-INF, -0.0)),
+            ((1.0, INF), (0.0, FRAC_PI_2)),
+            ((-1.0, -INF), (-0.0, -FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (0.0, FRAC_PI_2)),
+            ((-INF, -1.0), (-0.0, -FRAC_PI_2)),
+            ((INF, INF), (0.0, FRAC_PI_2)),
+            ((INF, NAN), (0.0, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("catanh", z, catanh(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("catanhf", z, catanhf(z), (ere as f32, eim as f32));
+        }
+
+        /* catanh(NaN + i inf) = +-0 + i pi/2 */
+        let r = catanh(Complex::new(NAN, -INF));
+        assert!(r.re == 0.0 && r.im == -FRAC_PI_2);
+    }
+
+    #[test]
+    fn branch_cut() {
+        /* The sign of the imaginary part selects the side of the cut on the real axis */
+        let r = catanh(Complex::new(2.0, 0.0));
+        assert_approx_eq(r.re, 0.5493061443340549);
+        assert_approx_eq(r.im, FRAC_PI_2);
+        let r = catanh(Complex::new(2.0, -0.0));
+        assert_approx_eq(r.re, 0.5493061443340549);
+        assert_approx_eq(r.im, -FRAC_PI_2);
+    }
+
+    #[test]
+    fn identity() {
+        let r = catanh(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 0.40235947810852507);
+        assert_approx_eq(r.im, 1.0172219678978514);
+
+        /* Real inputs reduce to atanh */
+        let r = catanh(Complex::new(0.5, 0.0));
+        assert_approx_eq(r.re, 0.5493061443340549);
+        assert_eq!(r.im, 0.0);
+
+        /* Near 1 with a tiny imaginary part, |1 - z|^2 underflows */
+        let r = catanh(Complex::new(1.0, 1e-300));
+        assert_approx_eq(r.re, 345.7343375393868);
+        assert_approx_eq(r.im, FRAC_PI_4);
+
+        /* Large inputs don't overflow */
+        let r = catanh(Complex::new(1e300, 0.0));
+        assert_eq!((r.re, r.im), (1e-300, FRAC_PI_2));
+        let r = catanh(Complex::new(f64::MAX, f64::MAX));
+        assert!(r.re > 0.0 && r.re.is_subnormal());
+    }
+}
@@ -0,0 +1,59 @@
+use super::{Complex, ccosh};
+
+/// Complex cosine (f32)
+///
+/// Computed as `ccosh(i*z)`, so special values follow those of [`ccoshf`](fn@super::ccoshf) as
+/// specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccosf(z: Complex<f32>) -> Complex<f32> {
+    let r = ccosh(Complex::new(-z.im as f64, z.re as f64));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex cosine (f64)
+///
+/// Computed as `ccosh(i*z)`, so special values follow those of [`ccosh`](fn@super::ccosh) as
+/// specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccos(z: Complex<f64>) -> Complex<f64> {
+    ccosh(Complex::new(-z.im, z.re))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (1.0, -0.0)),
+            ((-0.0, 0.0), (1.0, 0.0)),
+            ((INF, 0.0), (NAN, -0.0)),
+            ((NAN, 0.0), (NAN, -0.0)),
+            ((0.0, INF), (INF, -0.0)),
+            ((1.0, INF), (INF, -INF)),
+            ((INF, INF), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("ccos", z, ccos(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("ccosf", z, ccosf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn identity() {
+        /* cos(1 + i) = cos(1)cosh(1) - i sin(1)sinh(1) */
+        let r = ccos(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 0.8337300251311491);
+        assert_approx_eq(r.im, -0.9888977057628651);
+    }
+}
@@ -0,0 +1,197 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_cexp.c and s_cexpf.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::Complex;
+use super::k_cexp::{ldexp_cexp, ldexp_cexpf};
+use crate::math::{exp, expf, sincos, sincosf};
+
+const EXP_OVFL: u32 = 0x40862e42; /* high bits of MAX_EXP * ln2 ~= 710 */
+const CEXP_OVFL: u32 = 0x4096b8e4; /* (MAX_EXP - MIN_DENORM_EXP) * ln2 */
+
+const EXP_OVFLF: u32 = 0x42b17218; /* MAX_EXP * ln2 ~= 88.722839355 */
+const CEXP_OVFLF: u32 = 0x43400074; /* (MAX_EXP - MIN_DENORM_EXP) * ln2 */
+
+/// Complex exponential, base *e* (f32)
+///
+/// Computes `exp(re) * (cos(im) + i*sin(im))`, with special values as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cexpf(z: Complex<f32>) -> Complex<f32> {
+    let x = z.re;
+    let y = z.im;
+    let hy = y.to_bits() & 0x7fffffff;
+
+    /* cexp(x + I 0) = exp(x) + I 0 */
+    if hy == 0 {
+        return Complex::new(expf(x), y);
+    }
+    let hx = x.to_bits();
+    /* cexp(0 + I y) = cos(y) + I sin(y) */
+    if hx & 0x7fffffff == 0 {
+        let (s, c) = sincosf(y);
+        return Complex::new(c, s);
+    }
+
+    if hy >= 0x7f800000 {
+        if hx & 0x7fffffff != 0x7f800000 {
+            /* cexp(finite|NaN +- I Inf|NaN) = NaN + I NaN */
+            return Complex::new(y - y, y - y);
+        } else if hx & 0x80000000 != 0 {
+            /* cexp(-Inf +- I Inf|NaN) = 0 + I 0 */
+            return Complex::new(0.0, 0.0);
+        } else {
+            /* cexp(+Inf +- I Inf|NaN) = Inf + I NaN */
+            return Complex::new(x, y - y);
+        }
+    }
+
+    if (EXP_OVFLF..=CEXP_OVFLF).contains(&hx) {
+        /*
+         * x is between 88.7 and 192, so we must scale to avoid
+         * overflow in expf(x).
+         */
+        ldexp_cexpf(z, 0)
+    } else {
+        /*
+         * Cases covered here:
+         *  -  x < exp_ovfl and exp(x) won't overflow (common case)
+         *  -  x > cexp_ovfl, so exp(x) * s overflows for all s > 0
+         *  -  x = +-Inf (generated by exp())
+         *  -  x = NaN (spurious inexact exception from y)
+         */
+        let exp_x = expf(x);
+        let (s, c) = sincosf(y);
+        Complex::new(exp_x * c, exp_x * s)
+    }
+}
+
+/// Complex exponential, base *e* (f64)
+///
+/// Computes `exp(re) * (cos(im) + i*sin(im))`, with special values as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cexp(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let hy = (y.to_bits() >> 32) as u32 & 0x7fffffff;
+    let ly = y.to_bits() as u32;
+
+    /* cexp(x + I 0) = exp(x) + I 0 */
+    if hy | ly == 0 {
+        return Complex::new(exp(x), y);
+    }
+    let hx = (x.to_bits() >> 32) as u32;
+    let lx = x.to_bits() as u32;
+    /* cexp(0 + I y) = cos(y) + I sin(y) */
+    if (hx & 0x7fffffff) | lx == 0 {
+        let (s, c) = sincos(y);
+        return Complex::new(c, s);
+    }
+
+    if hy >= 0x7ff00000 {
+        if lx != 0 || hx & 0x7fffffff != 0x7ff00000 {
+            /* cexp(finite|NaN +- I Inf|NaN) = NaN + I NaN */
+            return Complex::new(y - y, y - y);
+        } else if hx & 0x80000000 != 0 {
+            /* cexp(-Inf +- I Inf|NaN) = 0 + I 0 */
+            return Complex::new(0.0, 0.0);
+        } else {
+            /* cexp(+Inf +- I Inf|NaN) = Inf + I NaN */
+            return Complex::new(x, y - y);
+        }
+    }
+
+    if (EXP_OVFL..=CEXP_OVFL).contains(&hx) {
+        /*
+         * x is between 709.7 and 1454.3, so we must scale to avoid
+         * overflow in exp(x).
+         */
+        ldexp_cexp(z, 0)
+    } else {
+        /*
+         * Cases covered here:
+         *  -  x < exp_ovfl and exp(x) won't overflow (common case)
+         *  -  x > cexp_ovfl, so exp(x) * s overflows for all s > 0
+         *  -  x = +-Inf (generated by exp())
+         *  -  x = NaN (spurious inexact exception from y)
+         */
+        let exp_x = exp(x);
+        let (s, c) = sincos(y);
+        Complex::new(exp_x * c, exp_x * s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        // Special values from C99 Annex G.6.3.1.
+        let cases = [
+            ((0.0, 0.0), (1.0, 0.0)),
+            ((-0.0, 0.0), (1.0, 0.0)),
+            ((0.0, -0.0), (1.0, -0.0)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((INF, -0.0), (INF, -0.0)),
+            ((-INF, 1.0), (0.0, 0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, -1.0), (INF, -INF)),
+            ((-INF, INF), (0.0, 0.0)),
+            ((INF, INF), (INF, NAN)),
+            ((-INF, NAN), (0.0, 0.0)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, -0.0), (NAN, -0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("cexp", z, cexp(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("cexpf", z, cexpf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn scaled_overflow() {
+        /* exp(710) overflows, but exp(710) * sin(1e-300) does not */
+        let r = cexp(Complex::new(710.0, 1e-300));
+        assert_eq!(r.re, INF);
+        assert_approx_eq(r.im, 2.2339947661617112e8);
+
+        let r = cexpf(Complex::new(100.0, 1e-30));
+        assert_eq!(r.re, f32::INFINITY);
+        assert_approx_eq(r.im, 2.688117e13);
+    }
+}
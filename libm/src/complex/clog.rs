@@ -0,0 +1,148 @@
+use super::Complex;
+use crate::math::{atan2, atan2f, fabs, fma, hypot, log, log1p};
+
+/// Complex natural logarithm (f32)
+///
+/// Computes `log(|z|) + i*arg(z)`, with the branch cut along the negative real axis. The imaginary
+/// part is in the range `[-pi, pi]`. Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clogf(z: Complex<f32>) -> Complex<f32> {
+    let x = z.re as f64;
+    let y = z.im as f64;
+
+    let re = if x.is_infinite() || y.is_infinite() {
+        /* Also covers an infinite part paired with NaN */
+        f64::INFINITY
+    } else {
+        /* Squares of `f32`s are exact and cannot overflow or underflow in `f64` */
+        let (xx, yy) = (x * x, y * y);
+        let (big, small) = if xx >= yy { (xx, yy) } else { (yy, xx) };
+        let s = big + small;
+
+        if (0.5..=2.0).contains(&s) {
+            /* Avoid cancellation in `log(s)` when `|z|` is close to 1 */
+            let t = if big >= 0.5 { (big - 1.0) + small } else { (big - 0.5) + (small - 0.5) };
+            0.5 * log1p(t)
+        } else {
+            0.5 * log(s)
+        }
+    };
+
+    Complex::new(re as f32, atan2f(z.im, z.re))
+}
+
+/// Complex natural logarithm (f64)
+///
+/// Computes `log(|z|) + i*arg(z)`, with the branch cut along the negative real axis. The imaginary
+/// part is in the range `[-pi, pi]`. Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clog(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(log_abs(z.re, z.im), atan2(z.im, z.re))
+}
+
+/// `log(hypot(x, y))`, without intermediate overflow or cancellation when the result is close to
+/// zero.
+fn log_abs(x: f64, y: f64) -> f64 {
+    let (mut ax, mut ay) = (fabs(x), fabs(y));
+    if ax < ay {
+        core::mem::swap(&mut ax, &mut ay);
+    }
+
+    if !ax.is_finite() || ay.is_nan() {
+        /* `hypot` returns inf if either part is infinite, even if the other is NaN */
+        return log(hypot(x, y));
+    }
+
+    if ax >= f64::from_bits(0x7fe0000000000000) {
+        /* `hypot` might overflow; scale down by 2. This is exact for `ax`. */
+        return log(hypot(ax * 0.5, ay * 0.5)) + core::f64::consts::LN_2;
+    }
+
+    if (0.5..2.0).contains(&ax) && ay > f64::from_bits(0x1e00000000000000) {
+        /*
+         * Near |z| = 1, compute |z|^2 - 1 in extra precision using exact squares:
+         * ax^2 = xh + xl, ay^2 = yh + yl.
+         */
+        let xh = ax * ax;
+        let xl = fma(ax, ax, -xh);
+        let yh = ay * ay;
+        let yl = fma(ay, ay, -yh);
+        let s = xh + yh;
+
+        if (0.5..=2.0).contains(&s) {
+            /* Both subtractions are exact, as is the sum when it cancels */
+            let t = if xh >= 0.5 { (xh - 1.0) + yh } else { (xh - 0.5) + (yh - 0.5) };
+            return 0.5 * log1p(t + (xl + yl));
+        }
+    }
+
+    log(hypot(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+    const PI: f64 = core::f64::consts::PI;
+    const FRAC_PI_2: f64 = core::f64::consts::FRAC_PI_2;
+    const FRAC_PI_4: f64 = core::f64::consts::FRAC_PI_4;
+    const FRAC_3PI_4: f64 = 3.0 * core::f64::consts::FRAC_PI_4;
+
+    #[test]
+    fn special_values() {
+        // Special values from C99 Annex G.6.3.2.
+        let cases = [
+            ((-0.0, 0.0), (-INF, PI)),
+            ((-0.0, -0.0), (-INF, -PI)),
+            ((0.0, 0.0), (-INF, 0.0)),
+            ((0.0, -0.0), (-INF, -0.0)),
+            ((2.0, INF), (INF, FRAC_PI_2)),
+            ((2.0, -INF), (INF, -FRAC_PI_2)),
+            ((2.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (INF, PI)),
+            ((-INF, -1.0), (INF, -PI)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, -1.0), (INF, -0.0)),
+            ((-INF, INF), (INF, FRAC_3PI_4)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((-INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, -INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((1.0, 0.0), (0.0, 0.0)),
+            ((-1.0, 0.0), (0.0, PI)),
+            ((-1.0, -0.0), (0.0, -PI)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("clog", z, clog(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("clogf", z, clogf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn extreme_magnitudes() {
+        /* |z| overflows, but log(|z|) does not */
+        let r = clog(Complex::new(f64::MAX, f64::MAX));
+        assert_approx_eq(r.re, 710.1292864836639);
+        assert_eq!(r.im, FRAC_PI_4);
+
+        let r = clogf(Complex::new(f32::MAX, f32::MAX));
+        assert_approx_eq(r.re, 89.06941);
+
+        /* Cancellation near the unit circle: |0.6 + 0.8i|^2 - 1 ~ 4.44e-17 */
+        let r = clog(Complex::new(0.6, 0.8));
+        assert_approx_eq(r.re, 2.2204460492503132e-17);
+        let r = clog(Complex::new(1.0, 1e-10));
+        assert_approx_eq(r.re, 5.0000000000000005e-21);
+        let r = clogf(Complex::new(0.6, 0.8));
+        assert_approx_eq(r.re, 2.3841858e-8);
+    }
+}
@@ -0,0 +1,17 @@
+use super::Complex;
+
+/// Complex conjugate (f32)
+///
+/// Returns `z` with the sign of the imaginary part flipped, including for zeros and NaNs.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn conjf(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(z.re, -z.im)
+}
+
+/// Complex conjugate (f64)
+///
+/// Returns `z` with the sign of the imaginary part flipped, including for zeros and NaNs.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn conj(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(z.re, -z.im)
+}
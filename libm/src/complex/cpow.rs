@@ -0,0 +1,50 @@
+use super::{Complex, cexp, clog, mul};
+
+/// Complex power function (f32)
+///
+/// Computes `z` raised to the power `w`, with the branch cut for `z` along the negative real
+/// axis. The result is computed as `cexp(w * clog(z))` in double precision, so it has no special
+/// cases beyond those of `cexp` and `clog`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cpowf(z: Complex<f32>, w: Complex<f32>) -> Complex<f32> {
+    let z = Complex::new(z.re as f64, z.im as f64);
+    let w = Complex::new(w.re as f64, w.im as f64);
+    let r = cexp(mul(w, clog(z)));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex power function (f64)
+///
+/// Computes `z` raised to the power `w`, with the branch cut for `z` along the negative real
+/// axis. The result is computed as `cexp(w * clog(z))`, so it has no special cases beyond those
+/// of `cexp` and `clog`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cpow(z: Complex<f64>, w: Complex<f64>) -> Complex<f64> {
+    cexp(mul(w, clog(z)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::assert_approx_eq;
+
+    #[test]
+    fn basic() {
+        let r = cpow(Complex::new(0.0, 1.0), Complex::new(2.0, 0.0));
+        assert_eq!(r.re, -1.0);
+        assert!(crate::math::fabs(r.im) < 1e-15);
+
+        /* i^i = exp(-pi/2) */
+        let r = cpow(Complex::new(0.0, 1.0), Complex::new(0.0, 1.0));
+        assert_approx_eq(r.re, 0.20787957635076193);
+        assert_eq!(r.im, 0.0);
+
+        let r = cpowf(Complex::new(0.0, 1.0), Complex::new(0.0, 1.0));
+        assert_approx_eq(r.re, 0.20787957);
+        assert_eq!(r.im, 0.0);
+
+        /* Zero to a power with a positive real part */
+        let r = cpow(Complex::new(0.0, 0.0), Complex::new(2.0, 1.0));
+        assert_eq!((r.re, r.im), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,58 @@
+use super::Complex;
+
+/// Projection onto the Riemann sphere (f32)
+///
+/// Returns `z`, except that all complex infinities (even those with one NaN part) are mapped to
+/// `inf + i*copysign(0, im)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cprojf(z: Complex<f32>) -> Complex<f32> {
+    if z.re.is_infinite() || z.im.is_infinite() {
+        let im = if z.im.is_sign_negative() { -0.0 } else { 0.0 };
+        return Complex::new(f32::INFINITY, im);
+    }
+    z
+}
+
+/// Projection onto the Riemann sphere (f64)
+///
+/// Returns `z`, except that all complex infinities (even those with one NaN part) are mapped to
+/// `inf + i*copysign(0, im)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cproj(z: Complex<f64>) -> Complex<f64> {
+    if z.re.is_infinite() || z.im.is_infinite() {
+        let im = if z.im.is_sign_negative() { -0.0 } else { 0.0 };
+        return Complex::new(f64::INFINITY, im);
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::assert_complex_eq;
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((INF, 1.0), (INF, 0.0)),
+            ((-INF, -1.0), (INF, -0.0)),
+            ((1.0, -INF), (INF, -0.0)),
+            ((NAN, INF), (INF, 0.0)),
+            ((-INF, NAN), (INF, 0.0)),
+            /* Everything else is returned unchanged */
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((-1.0, NAN), (-1.0, NAN)),
+            ((1e30, -1e30), (1e30, -1e30)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("cproj", z, cproj(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("cprojf", z, cprojf(z), (ere as f32, eim as f32));
+        }
+    }
+}
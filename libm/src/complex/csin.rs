@@ -0,0 +1,60 @@
+use super::{Complex, csinh};
+
+/// Complex sine (f32)
+///
+/// Computed as `-i*csinh(i*z)`, so special values follow those of [`csinhf`](fn@super::csinhf) as
+/// specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinf(z: Complex<f32>) -> Complex<f32> {
+    let r = csinh(Complex::new(-z.im as f64, z.re as f64));
+    Complex::new(r.im as f32, -r.re as f32)
+}
+
+/// Complex sine (f64)
+///
+/// Computed as `-i*csinh(i*z)`, so special values follow those of [`csinh`](fn@super::csinh) as
+/// specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csin(z: Complex<f64>) -> Complex<f64> {
+    let r = csinh(Complex::new(-z.im, z.re));
+    Complex::new(r.im, -r.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((INF, 0.0), (NAN, 0.0)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((0.0, INF), (0.0, INF)),
+            ((1.0, INF), (INF, INF)),
+            ((INF, INF), (NAN, INF)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("csin", z, csin(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("csinf", z, csinf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn identity() {
+        /* sin(1 + i) = sin(1)cosh(1) + i cos(1)sinh(1) */
+        let r = csin(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 1.2984575814159773);
+        assert_approx_eq(r.im, 0.6349639147847361);
+    }
+}
@@ -0,0 +1,201 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_csinh.c */
+/*-
+ * Copyright (c) 2005 Bruce D. Evans and Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::Complex;
+use super::k_cexp::ldexp_cexp;
+use crate::math::{copysign, cos, cosh, exp, fabs, sin, sinh};
+
+const HUGE: f64 = 8.98846567431157953865e+307; /* 0x1p1023 */
+
+/// Complex hyperbolic sine (f32)
+///
+/// Special values are as specified by C99 Annex G. The result is computed in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinhf(z: Complex<f32>) -> Complex<f32> {
+    let r = csinh(Complex::new(z.re as f64, z.im as f64));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex hyperbolic sine (f64)
+///
+/// Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let hx = (x.to_bits() >> 32) as u32;
+    let lx = x.to_bits() as u32;
+    let hy = (y.to_bits() >> 32) as u32;
+    let ly = y.to_bits() as u32;
+    let ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    /* Handle the nearly-non-exceptional cases where x and y are finite. */
+    if ix < 0x7ff00000 && iy < 0x7ff00000 {
+        if iy | ly == 0 {
+            return Complex::new(sinh(x), y);
+        }
+        if ix < 0x40360000 {
+            /* |x| < 22: normal case */
+            return Complex::new(sinh(x) * cos(y), cosh(x) * sin(y));
+        }
+
+        /* |x| >= 22, so cosh(x) ~= exp(|x|) */
+        if ix < 0x40862e42 {
+            /* x < 710: exp(|x|) won't overflow */
+            let h = exp(fabs(x)) * 0.5;
+            return Complex::new(copysign(h, x) * cos(y), h * sin(y));
+        } else if ix < 0x4096bbaa {
+            /* x < 1455: scale to avoid overflow */
+            let z = ldexp_cexp(Complex::new(fabs(x), y), -1);
+            return Complex::new(z.re * copysign(1.0, x), z.im);
+        } else {
+            /* x >= 1455: the result always overflows */
+            let h = HUGE * x;
+            return Complex::new(h * cos(y), h * h * sin(y));
+        }
+    }
+
+    /*
+     * sinh(+-0 +- I Inf) = +-0 + I dNaN.
+     * The sign of 0 in the result is unspecified.  Choice = same sign
+     * as the argument.  Raise the invalid floating-point exception.
+     *
+     * sinh(+-0 +- I NaN) = +-0 + I d(NaN).
+     * The sign of 0 in the result is unspecified.  Choice = same sign
+     * as the argument.
+     */
+    if ix | lx == 0 {
+        return Complex::new(x, y - y);
+    }
+
+    /*
+     * sinh(+-Inf +- I 0) = +-Inf + I +-0.
+     *
+     * sinh(NaN +- I 0)   = d(NaN) + I +-0.
+     */
+    if iy | ly == 0 {
+        return Complex::new(x, y);
+    }
+
+    /*
+     * sinh(x +- I Inf) = dNaN + I dNaN.
+     * Raise the invalid floating-point exception for finite nonzero x.
+     *
+     * sinh(x + I NaN) = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero x.  Choice = don't raise (except for signaling NaNs).
+     */
+    if ix < 0x7ff00000 {
+        return Complex::new(y - y, y - y);
+    }
+
+    /*
+     * sinh(+-Inf + I NaN)  = +-Inf + I d(NaN).
+     * The sign of Inf in the result is unspecified.  Choice = same sign
+     * as the argument.
+     *
+     * sinh(+-Inf +- I Inf) = +-Inf + I dNaN.
+     * The sign of Inf in the result is unspecified.  Choice = same sign
+     * as the argument.  Raise the invalid floating-point exception.
+     *
+     * sinh(+-Inf + I y)   = +-Inf cos(y) + I Inf sin(y)
+     */
+    if ix == 0x7ff00000 && lx == 0 {
+        if iy >= 0x7ff00000 {
+            return Complex::new(x, y - y);
+        }
+        return Complex::new(x * cos(y), f64::INFINITY * sin(y));
+    }
+
+    /*
+     * sinh(NaN1 + I NaN2) = d(NaN1, NaN2) + I d(NaN1, NaN2).
+     *
+     * sinh(NaN +- I Inf) = d(NaN, dNaN) + I d(NaN, dNaN).
+     * Optionally raises the invalid floating-point exception.
+     * Choice = raise.
+     *
+     * sinh(NaN + I y)    = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero y.  Choice = don't raise (except for signaling NaNs).
+     */
+    Complex::new((x + x) * (y - y), (x * x) * (y - y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((0.0, INF), (0.0, NAN)),
+            ((-0.0, NAN), (-0.0, NAN)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((-INF, -0.0), (-INF, -0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, 2.0), (-INF, INF)),
+            ((INF, INF), (INF, NAN)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("csinh", z, csinh(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("csinhf", z, csinhf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn large_real_part() {
+        let r = csinh(Complex::new(-710.5, 2.5));
+        assert!(r.re.is_finite() && r.re > 0.0);
+        assert!(r.im.is_finite() && r.im > 0.0);
+        let r = csinh(Complex::new(-2000.0, 1.0));
+        assert_eq!((r.re, r.im), (-INF, INF));
+    }
+
+    #[test]
+    fn identity() {
+        /* sinh(1 + i) = sinh(1)cos(1) + i cosh(1)sin(1) */
+        let r = csinh(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 0.6349639147847361);
+        assert_approx_eq(r.im, 1.2984575814159773);
+    }
+}
@@ -0,0 +1,222 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_csqrt.c and s_csqrtf.c */
+/*-
+ * Copyright (c) 2007 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::Complex;
+use crate::math::{copysign, copysignf, fabs, fabsf, hypot, sqrt};
+use crate::support::hf64;
+
+/* We risk spurious overflow for components >= DBL_MAX / (1 + sqrt(2)). */
+const THRESH: f64 = hf64("0x1.a827999fcef32p+1022");
+
+/// Complex square root (f32)
+///
+/// Returns the principal square root, with a nonnegative real part and the branch cut along the
+/// negative real axis. Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csqrtf(z: Complex<f32>) -> Complex<f32> {
+    let a = z.re;
+    let b = z.im;
+
+    /* Handle special cases. */
+    if a == 0.0 && b == 0.0 {
+        return Complex::new(0.0, b);
+    }
+    if b.is_infinite() {
+        return Complex::new(f32::INFINITY, b);
+    }
+    if a.is_nan() {
+        let t = (b - b) / (b - b); /* raise invalid if b is not a NaN */
+        return Complex::new(a, t); /* return NaN + NaN i */
+    }
+    if a.is_infinite() {
+        /*
+         * csqrtf(inf + NaN i)  = inf +  NaN i
+         * csqrtf(inf + y i)    = inf +  0 i
+         * csqrtf(-inf + NaN i) = NaN +- inf i
+         * csqrtf(-inf + y i)   = 0   +  inf i
+         */
+        if a.is_sign_negative() {
+            return Complex::new(fabsf(b - b), copysignf(a, b));
+        } else {
+            return Complex::new(a, copysignf(b - b, b));
+        }
+    }
+    /*
+     * The remaining special case (b is NaN) is handled just fine by
+     * the normal code path below.
+     */
+
+    /*
+     * We compute t in double precision to avoid overflow and to
+     * provide correct rounding in nearly all cases.
+     * This is Algorithm 312, CACM vol 10, Oct 1967.
+     */
+    let a = a as f64;
+    let b = b as f64;
+    if a >= 0.0 {
+        let t = sqrt((a + hypot(a, b)) * 0.5);
+        Complex::new(t as f32, (b / (2.0 * t)) as f32)
+    } else {
+        let t = sqrt((-a + hypot(a, b)) * 0.5);
+        Complex::new((fabs(b) / (2.0 * t)) as f32, copysign(t, b) as f32)
+    }
+}
+
+/// Complex square root (f64)
+///
+/// Returns the principal square root, with a nonnegative real part and the branch cut along the
+/// negative real axis. Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csqrt(z: Complex<f64>) -> Complex<f64> {
+    let mut a = z.re;
+    let mut b = z.im;
+
+    /* Handle special cases. */
+    if a == 0.0 && b == 0.0 {
+        return Complex::new(0.0, b);
+    }
+    if b.is_infinite() {
+        return Complex::new(f64::INFINITY, b);
+    }
+    if a.is_nan() {
+        let t = (b - b) / (b - b); /* raise invalid if b is not a NaN */
+        return Complex::new(a, t); /* return NaN + NaN i */
+    }
+    if a.is_infinite() {
+        /*
+         * csqrt(inf + NaN i)  = inf +  NaN i
+         * csqrt(inf + y i)    = inf +  0 i
+         * csqrt(-inf + NaN i) = NaN +- inf i
+         * csqrt(-inf + y i)   = 0   +  inf i
+         */
+        if a.is_sign_negative() {
+            return Complex::new(fabs(b - b), copysign(a, b));
+        } else {
+            return Complex::new(a, copysign(b - b, b));
+        }
+    }
+    /*
+     * The remaining special case (b is NaN) is handled just fine by
+     * the normal code path below.
+     */
+
+    /* Scale to avoid overflow. */
+    let scale = if fabs(a) >= THRESH || fabs(b) >= THRESH {
+        /*
+         * Don't scale a or b if this might give (spurious)
+         * underflow.  Then the unscaled value is an equivalent
+         * infinitesmal (or 0).
+         */
+        if fabs(a) >= f64::from_bits(0x0030000000000000) {
+            a *= 0.25;
+        }
+        if fabs(b) >= f64::from_bits(0x0030000000000000) {
+            b *= 0.25;
+        }
+        2.0
+    } else if fabs(a) < f64::MIN_POSITIVE && fabs(b) < f64::MIN_POSITIVE {
+        /* Scale to reduce inaccuracies when both components are denormal. */
+        a *= f64::from_bits(0x4350000000000000); /* 0x1p54 */
+        b *= f64::from_bits(0x4350000000000000);
+        f64::from_bits(0x3e40000000000000) /* 0x1p-27 */
+    } else {
+        1.0
+    };
+
+    /* Algorithm 312, CACM vol 10, Oct 1967. */
+    let (re, im) = if a >= 0.0 {
+        let t = sqrt((a + hypot(a, b)) * 0.5);
+        (t, b / (2.0 * t))
+    } else {
+        let t = sqrt((-a + hypot(a, b)) * 0.5);
+        (fabs(b) / (2.0 * t), copysign(t, b))
+    };
+
+    Complex::new(re * scale, im * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        // Special values from C99 Annex G.6.4.2.
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, 0.0), (0.0, 0.0)),
+            ((0.0, -0.0), (0.0, -0.0)),
+            ((-0.0, -0.0), (0.0, -0.0)),
+            ((1.0, INF), (INF, INF)),
+            ((-1.0, -INF), (INF, -INF)),
+            ((NAN, INF), (INF, INF)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (0.0, INF)),
+            ((-INF, -1.0), (0.0, -INF)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, -1.0), (INF, -0.0)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            /* Exact results, including both sides of the branch cut */
+            ((4.0, 0.0), (2.0, 0.0)),
+            ((-4.0, 0.0), (0.0, 2.0)),
+            ((-4.0, -0.0), (0.0, -2.0)),
+            ((0.0, 2.0), (1.0, 1.0)),
+            ((3.0, -4.0), (2.0, -1.0)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("csqrt", z, csqrt(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("csqrtf", z, csqrtf(z), (ere as f32, eim as f32));
+        }
+
+        /* csqrt(-inf + NaN i) = NaN +- inf i; the sign of the imaginary part is unspecified */
+        let r = csqrt(Complex::new(-INF, NAN));
+        assert!(r.re.is_nan() && r.im.is_infinite());
+        let r = csqrtf(Complex::new(f32::NEG_INFINITY, f32::NAN));
+        assert!(r.re.is_nan() && r.im.is_infinite());
+    }
+
+    #[test]
+    fn extreme_magnitudes() {
+        let r = csqrt(Complex::new(f64::MAX, f64::MAX));
+        assert_approx_eq(r.re, 1.4730945569055652e154);
+        assert_approx_eq(r.im, 6.1017574412827024e153);
+
+        let tiny = f64::from_bits(1);
+        let r = csqrt(Complex::new(tiny, tiny));
+        assert_approx_eq(r.re, 2.4421097261308304e-162);
+        assert_approx_eq(r.im, 1.0115549693666347e-162);
+    }
+}
@@ -0,0 +1,59 @@
+use super::{Complex, ctanh};
+
+/// Complex tangent (f32)
+///
+/// Computed as `-i*ctanh(i*z)`, so special values follow those of [`ctanhf`](fn@super::ctanhf) as
+/// specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanf(z: Complex<f32>) -> Complex<f32> {
+    let r = ctanh(Complex::new(-z.im as f64, z.re as f64));
+    Complex::new(r.im as f32, -r.re as f32)
+}
+
+/// Complex tangent (f64)
+///
+/// Computed as `-i*ctanh(i*z)`, so special values follow those of [`ctanh`](fn@super::ctanh) as
+/// specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctan(z: Complex<f64>) -> Complex<f64> {
+    let r = ctanh(Complex::new(-z.im, z.re));
+    Complex::new(r.im, -r.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((INF, 1.0), (NAN, NAN)),
+            ((1.0, INF), (0.0, 1.0)),
+            ((2.0, -INF), (-0.0, -1.0)),
+            ((INF, INF), (0.0, 1.0)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("ctan", z, ctan(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("ctanf", z, ctanf(z), (ere as f32, eim as f32));
+        }
+    }
+
+    #[test]
+    fn identity() {
+        let r = ctan(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 0.2717525853195118);
+        assert_approx_eq(r.im, 1.0839233273386946);
+    }
+}
@@ -0,0 +1,154 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_ctanh.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::Complex;
+use crate::math::{copysign, cos, exp, fabs, sin, sinh, sqrt, tan};
+
+/// Complex hyperbolic tangent (f32)
+///
+/// Special values are as specified by C99 Annex G. The result is computed in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanhf(z: Complex<f32>) -> Complex<f32> {
+    let r = ctanh(Complex::new(z.re as f64, z.im as f64));
+    Complex::new(r.re as f32, r.im as f32)
+}
+
+/// Complex hyperbolic tangent (f64)
+///
+/// Special values are as specified by C99 Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let ix = (x.to_bits() >> 32) as u32 & 0x7fffffff;
+
+    /*
+     * ctanh(NaN +- 0i) = NaN +- 0i
+     *
+     * ctanh(NaN + yi) = NaN + NaN i, for y != 0
+     *
+     * The imaginary part has the sign of x*sin(2*y), but there's no
+     * special effort to get this right.
+     *
+     * ctanh(+-Inf +- Inf i) = +-1 +- 0i
+     *
+     * ctanh(+-Inf + yi) = +-1 + 0 sin(2y)i, for y finite
+     *
+     * The imaginary part of the sign is unspecified.  This special
+     * case is only needed to avoid a spurious invalid exception when
+     * y is infinite.
+     */
+    if ix >= 0x7ff00000 {
+        if x.is_nan() {
+            return Complex::new(x, if y == 0.0 { y } else { x * y });
+        }
+        let s = if y.is_infinite() { y } else { sin(y) * cos(y) };
+        return Complex::new(copysign(1.0, x), copysign(0.0, s));
+    }
+
+    /*
+     * ctanh(x + i NAN) = NaN + NaN i
+     * ctanh(x + i inf) = NaN + NaN i
+     */
+    if !y.is_finite() {
+        return Complex::new(y - y, y - y);
+    }
+
+    /*
+     * ctanh(+-huge + i +-y) ~= +-1 +- i 2sin(2y)/exp(2x), using the
+     * approximation sinh^2(huge) ~= exp(2*huge) / 4.
+     * We use a modified formula to avoid spurious overflow.
+     */
+    if ix >= 0x40360000 {
+        /* x >= 22 */
+        let exp_mx = exp(-fabs(x));
+        return Complex::new(copysign(1.0, x), 4.0 * sin(y) * cos(y) * exp_mx * exp_mx);
+    }
+
+    /* Kahan's algorithm */
+    let t = tan(y);
+    let beta = 1.0 + t * t; /* = 1 / cos^2(y) */
+    let s = sinh(x);
+    let rho = sqrt(1.0 + s * s); /* = cosh(x) */
+    let denom = 1.0 + beta * s * s;
+    Complex::new((beta * rho * s) / denom, t / denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::{assert_approx_eq, assert_complex_eq};
+
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn special_values() {
+        let cases = [
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, -0.0), (-0.0, -0.0)),
+            ((0.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (1.0, 0.0)),
+            ((INF, 2.0), (1.0, -0.0)),
+            ((-INF, 1.0), (-1.0, 0.0)),
+            ((INF, INF), (1.0, 0.0)),
+            ((INF, -INF), (1.0, -0.0)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, -0.0), (NAN, -0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ];
+
+        for ((re, im), (ere, eim)) in cases {
+            let z = Complex::new(re, im);
+            assert_complex_eq("ctanh", z, ctanh(z), (ere, eim));
+            let z = Complex::new(re as f32, im as f32);
+            assert_complex_eq("ctanhf", z, ctanhf(z), (ere as f32, eim as f32));
+        }
+
+        /* The sign of the zero is unspecified when the imaginary part is NaN */
+        let r = ctanh(Complex::new(-INF, NAN));
+        assert!(r.re == -1.0 && r.im == 0.0);
+    }
+
+    #[test]
+    fn large_real_part() {
+        let r = ctanh(Complex::new(1000.0, 1.0));
+        assert_eq!((r.re, r.im), (1.0, 0.0));
+        let r = ctanh(Complex::new(-30.0, 1.0));
+        assert_eq!(r.re, -1.0);
+        assert!(r.im > 0.0 && r.im < 1e-25);
+    }
+
+    #[test]
+    fn identity() {
+        let r = ctanh(Complex::new(1.0, 1.0));
+        assert_approx_eq(r.re, 1.0839233273386946);
+        assert_approx_eq(r.im, 0.2717525853195118);
+    }
+}
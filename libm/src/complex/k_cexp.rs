@@ -0,0 +1,91 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/k_exp.c and k_expf.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::Complex;
+use crate::math::{exp, expf, sincos, sincosf};
+
+/* k is chosen to minimize |exp(k*ln2) - 2**k| */
+const K: i32 = 1799;
+const KLN2: f64 = 1246.97177782734161156; /* k * ln2 */
+
+const KF: i32 = 235;
+const KLN2F: f32 = 162.88958740234375; /* k * ln2 */
+
+/// Compute `exp(x)` scaled into the range `[2**1023, 2**1024)`, returning the scaled value and
+/// the exponent of the scale factor. This allows `exp(x) * s` to be computed without spurious
+/// overflow or loss of accuracy to denormalization when `exp(x)` overflows but the product does
+/// not.
+fn frexp_exp(x: f64) -> (f64, i32) {
+    let exp_x = exp(x - KLN2);
+    let bits = exp_x.to_bits();
+    let hx = (bits >> 32) as u32;
+    let expt = (hx >> 20) as i32 - (0x3ff + 1023) + K;
+    let hx = (hx & 0xfffff) | ((0x3ff + 1023) << 20);
+    let exp_x = f64::from_bits(((hx as u64) << 32) | (bits & 0xffffffff));
+    (exp_x, expt)
+}
+
+/// Single-precision version of [`frexp_exp`], scaling into `[2**127, 2**128)`.
+fn frexp_expf(x: f32) -> (f32, i32) {
+    let exp_x = expf(x - KLN2F);
+    let hx = exp_x.to_bits();
+    let expt = (hx >> 23) as i32 - (0x7f + 127) + KF;
+    let exp_x = f32::from_bits((hx & 0x7fffff) | ((0x7f + 127) << 23));
+    (exp_x, expt)
+}
+
+/// `cexp(z) * 2**expt` for large `re(z)`, where `exp(re(z))` would overflow even though the final
+/// result might not.
+pub(crate) fn ldexp_cexp(z: Complex<f64>, expt: i32) -> Complex<f64> {
+    let (exp_x, ex_expt) = frexp_exp(z.re);
+    let expt = expt + ex_expt;
+
+    /* Arrange so that scale1 * scale2 == 2**expt. */
+    let half_expt = expt / 2;
+    let scale1 = f64::from_bits(((0x3ff + half_expt) as u64) << 52);
+    let half_expt = expt - half_expt;
+    let scale2 = f64::from_bits(((0x3ff + half_expt) as u64) << 52);
+
+    let (s, c) = sincos(z.im);
+    Complex::new(c * exp_x * scale1 * scale2, s * exp_x * scale1 * scale2)
+}
+
+/// `cexpf(z) * 2**expt` for large `re(z)`, where `expf(re(z))` would overflow even though the final
+/// result might not.
+pub(crate) fn ldexp_cexpf(z: Complex<f32>, expt: i32) -> Complex<f32> {
+    let (exp_x, ex_expt) = frexp_expf(z.re);
+    let expt = expt + ex_expt;
+
+    /* Arrange so that scale1 * scale2 == 2**expt. */
+    let half_expt = expt / 2;
+    let scale1 = f32::from_bits(((0x7f + half_expt) as u32) << 23);
+    let half_expt = expt - half_expt;
+    let scale2 = f32::from_bits(((0x7f + half_expt) as u32) << 23);
+
+    let (s, c) = sincosf(z.im);
+    Complex::new(c * exp_x * scale1 * scale2, s * exp_x * scale1 * scale2)
+}
@@ -0,0 +1,114 @@
+//! Complex functions.
+//!
+//! These follow C99 Annex G (IEC 60559-compatible complex arithmetic) for the handling of special
+//! values, branch cuts, signed zeros and infinities. Functions are provided for `f32` (suffixed
+//! with `f`) and `f64`, and operate on [`Complex`], which is layout-compatible with C's
+//! `float _Complex` and `double _Complex`.
+
+mod cabs;
+mod cacos;
+mod cacosh;
+mod carg;
+mod casin;
+mod casinh;
+mod catan;
+mod catanh;
+mod ccos;
+mod ccosh;
+mod cexp;
+mod clog;
+mod conj;
+mod cpow;
+mod cproj;
+mod csin;
+mod csinh;
+mod csqrt;
+mod ctan;
+mod ctanh;
+mod k_cexp;
+
+pub use self::cabs::{cabs, cabsf};
+pub use self::cacos::{cacos, cacosf};
+pub use self::cacosh::{cacosh, cacoshf};
+pub use self::carg::{carg, cargf};
+pub use self::casin::{casin, casinf};
+pub use self::casinh::{casinh, casinhf};
+pub use self::catan::{catan, catanf};
+pub use self::catanh::{catanh, catanhf};
+pub use self::ccos::{ccos, ccosf};
+pub use self::ccosh::{ccosh, ccoshf};
+pub use self::cexp::{cexp, cexpf};
+pub use self::clog::{clog, clogf};
+pub use self::conj::{conj, conjf};
+pub use self::cpow::{cpow, cpowf};
+pub use self::cproj::{cproj, cprojf};
+pub use self::csin::{csin, csinf};
+pub use self::csinh::{csinh, csinhf};
+pub use self::csqrt::{csqrt, csqrtf};
+pub use self::ctan::{ctan, ctanf};
+pub use self::ctanh::{ctanh, ctanhf};
+
+/// A complex number with real part `re` and imaginary part `im`.
+///
+/// This has the same layout as C's `_Complex` types for the same float type, so it can be passed
+/// across an FFI boundary.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex<F> {
+    /// The real part.
+    pub re: F,
+    /// The imaginary part.
+    pub im: F,
+}
+
+impl<F> Complex<F> {
+    /// Create a complex number from its real and imaginary parts.
+    pub const fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+}
+
+/// Complex multiplication without the infinity recovery of Annex G.5.1, as used by `cpow`.
+fn mul(a: Complex<f64>, b: Complex<f64>) -> Complex<f64> {
+    Complex::new(a.re * b.re - a.im * b.im, a.re * b.im + a.im * b.re)
+}
+
+/// Assert that each part of `actual` matches `expected`. NaNs match any NaN, but the signs of
+/// zeros and infinities must agree.
+#[cfg(test)]
+#[track_caller]
+fn assert_complex_eq<F: crate::support::Float>(
+    name: &str,
+    z: Complex<F>,
+    actual: Complex<F>,
+    expected: (F, F),
+) {
+    use crate::support::Hexf;
+
+    let part_eq = |a: F, b: F| (a.is_nan() && b.is_nan()) || a.biteq(b);
+    assert!(
+        part_eq(actual.re, expected.0) && part_eq(actual.im, expected.1),
+        "{name}({}, {}) = ({}, {}), expected ({}, {})",
+        Hexf(z.re),
+        Hexf(z.im),
+        Hexf(actual.re),
+        Hexf(actual.im),
+        Hexf(expected.0),
+        Hexf(expected.1),
+    );
+}
+
+/// Assert that `actual` is within two machine epsilons of `expected`, relative to `expected`.
+#[cfg(test)]
+#[track_caller]
+fn assert_approx_eq<F: crate::support::Float>(actual: F, expected: F) {
+    use crate::support::Hexf;
+
+    let tol = expected.abs() * F::EPSILON * (F::ONE + F::ONE);
+    assert!(
+        (actual - expected).abs() <= tol,
+        "{} is not close to {}",
+        Hexf(actual),
+        Hexf(expected)
+    );
+}
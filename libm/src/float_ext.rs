@@ -0,0 +1,536 @@
+use crate::LibmFloat;
+use crate::libm_helper::sealed;
+use crate::math::support::Float;
+
+/// Methods matching those that `std` provides on `f32` and `f64`, for use without `std`.
+///
+/// Names, argument order and results for special inputs follow the inherent methods, so code
+/// written against `std` only needs this trait in scope. Everything is implemented with the
+/// functions in this crate, including `powi`, `div_euclid` and `rem_euclid`.
+///
+/// This is implemented for `f32` and `f64`, and for `f16` and `f128` when they are enabled.
+/// Where `std` is available its inherent methods take precedence for concrete types.
+///
+/// # Examples
+/// ```rust
+/// use libm::FloatExt;
+///
+/// fn polar<F: FloatExt>(x: F, y: F) -> (F, F) {
+///     (x.hypot(y), y.atan2(x))
+/// }
+///
+/// assert_eq!(polar(3.0f64, 4.0).0, 5.0);
+/// assert_eq!(FloatExt::powi(3.0f32, 4), 81.0);
+/// assert_eq!(FloatExt::rem_euclid(-7.0f64, 4.0), 1.0);
+/// ```
+pub trait FloatExt: Copy + sealed::Sealed {
+    /// Returns the largest integer less than or equal to `self`.
+    fn floor(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to `self`.
+    fn ceil(self) -> Self;
+
+    /// Returns the nearest integer to `self`. If a value is half-way between two integers, round
+    /// away from `0.0`.
+    fn round(self) -> Self;
+
+    /// Returns the nearest integer to `self`. Rounds half-way cases to the number with an even
+    /// least significant digit.
+    fn round_ties_even(self) -> Self;
+
+    /// Returns the integer part of `self`. This means that non-integer numbers are always
+    /// truncated towards zero.
+    fn trunc(self) -> Self;
+
+    /// Returns the fractional part of `self`.
+    fn fract(self) -> Self;
+
+    /// Computes the absolute value of `self`.
+    fn abs(self) -> Self;
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - NaN if the number is NaN
+    fn signum(self) -> Self;
+
+    /// Returns a number composed of the magnitude of `self` and the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+
+    /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Calculates Euclidean division, the matching method for `rem_euclid`.
+    ///
+    /// This computes the integer `n` such that `self = n * rhs + self.rem_euclid(rhs)`. In other
+    /// words, the result is `self / rhs` rounded to the integer `n` such that `self >= n * rhs`.
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+    ///
+    /// The return value `r` usually satisfies `0.0 <= r < rhs.abs()`, but it can be equal to
+    /// `rhs.abs()` because of rounding if `self` is negative and much smaller than `rhs.abs()` in
+    /// magnitude.
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Raises a number to an integer power.
+    ///
+    /// This uses repeated multiplication like `std`, so the result may differ from `powf`.
+    fn powi(self, n: i32) -> Self;
+
+    /// Raises a number to a floating point power.
+    fn powf(self, n: Self) -> Self;
+
+    /// Returns the square root of a number.
+    ///
+    /// Returns NaN if `self` is a negative number other than `-0.0`.
+    fn sqrt(self) -> Self;
+
+    /// Returns `e^(self)`, (the exponential function).
+    fn exp(self) -> Self;
+
+    /// Returns `2^(self)`.
+    fn exp2(self) -> Self;
+
+    /// Returns the natural logarithm of the number.
+    ///
+    /// This returns NaN when the number is negative, and negative infinity when it is zero.
+    fn ln(self) -> Self;
+
+    /// Returns the logarithm of the number with respect to an arbitrary base.
+    ///
+    /// This is `self.ln() / base.ln()`, so it may not be correctly rounded. `log2` and `log10`
+    /// are more accurate for those bases.
+    fn log(self, base: Self) -> Self;
+
+    /// Returns the base 2 logarithm of the number.
+    ///
+    /// This returns NaN when the number is negative, and negative infinity when it is zero.
+    fn log2(self) -> Self;
+
+    /// Returns the base 10 logarithm of the number.
+    ///
+    /// This returns NaN when the number is negative, and negative infinity when it is zero.
+    fn log10(self) -> Self;
+
+    /// Returns the cube root of a number.
+    fn cbrt(self) -> Self;
+
+    /// Computes the length of the hypotenuse of a right-angle triangle given legs of length
+    /// `self` and `other`.
+    fn hypot(self, other: Self) -> Self;
+
+    /// Computes the sine of a number (in radians).
+    fn sin(self) -> Self;
+
+    /// Computes the cosine of a number (in radians).
+    fn cos(self) -> Self;
+
+    /// Computes the tangent of a number (in radians).
+    fn tan(self) -> Self;
+
+    /// Computes the arcsine of a number. The result is in radians in the range `[-pi/2, pi/2]`,
+    /// or NaN if the number is outside the range `[-1, 1]`.
+    fn asin(self) -> Self;
+
+    /// Computes the arccosine of a number. The result is in radians in the range `[0, pi]`, or
+    /// NaN if the number is outside the range `[-1, 1]`.
+    fn acos(self) -> Self;
+
+    /// Computes the arctangent of a number. The result is in radians in the range
+    /// `[-pi/2, pi/2]`.
+    fn atan(self) -> Self;
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
+    ///
+    /// - `x = 0`, `y = 0`: `0`
+    /// - `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+    /// - `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+    /// - `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    fn atan2(self, other: Self) -> Self;
+
+    /// Simultaneously computes the sine and cosine of the number, returning
+    /// `(sin(self), cos(self))`.
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Returns `e^(self) - 1` in a way that is accurate even if the number is close to zero.
+    fn exp_m1(self) -> Self;
+
+    /// Returns `ln(1+n)` (natural logarithm) more accurately than if the operations were
+    /// performed separately.
+    ///
+    /// This returns NaN when `n < -1.0`, and negative infinity when `n == -1.0`.
+    fn ln_1p(self) -> Self;
+
+    /// Hyperbolic sine function.
+    fn sinh(self) -> Self;
+
+    /// Hyperbolic cosine function.
+    fn cosh(self) -> Self;
+
+    /// Hyperbolic tangent function.
+    fn tanh(self) -> Self;
+
+    /// Inverse hyperbolic sine function.
+    fn asinh(self) -> Self;
+
+    /// Inverse hyperbolic cosine function.
+    fn acosh(self) -> Self;
+
+    /// Inverse hyperbolic tangent function.
+    fn atanh(self) -> Self;
+
+    /// Converts radians to degrees.
+    fn to_degrees(self) -> Self;
+
+    /// Converts degrees to radians.
+    fn to_radians(self) -> Self;
+}
+
+macro_rules! float_ext_impl {
+    ($ty:ident) => {
+        impl FloatExt for $ty {
+            #[inline]
+            fn floor(self) -> Self {
+                LibmFloat::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                LibmFloat::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                LibmFloat::round(self)
+            }
+
+            #[inline]
+            fn round_ties_even(self) -> Self {
+                LibmFloat::roundeven(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                LibmFloat::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> Self {
+                self - LibmFloat::trunc(self)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                LibmFloat::fabs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                if self.is_nan() { Self::NAN } else { LibmFloat::copysign(1.0, self) }
+            }
+
+            #[inline]
+            fn copysign(self, sign: Self) -> Self {
+                LibmFloat::copysign(self, sign)
+            }
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                LibmFloat::fma(self, a, b)
+            }
+
+            #[inline]
+            fn div_euclid(self, rhs: Self) -> Self {
+                div_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                rem_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                powi(self, n)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                LibmFloat::pow(self, n)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                LibmFloat::sqrt(self)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                LibmFloat::exp(self)
+            }
+
+            #[inline]
+            fn exp2(self) -> Self {
+                LibmFloat::exp2(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                LibmFloat::log(self)
+            }
+
+            #[inline]
+            fn log(self, base: Self) -> Self {
+                LibmFloat::log(self) / LibmFloat::log(base)
+            }
+
+            #[inline]
+            fn log2(self) -> Self {
+                LibmFloat::log2(self)
+            }
+
+            #[inline]
+            fn log10(self) -> Self {
+                LibmFloat::log10(self)
+            }
+
+            #[inline]
+            fn cbrt(self) -> Self {
+                LibmFloat::cbrt(self)
+            }
+
+            #[inline]
+            fn hypot(self, other: Self) -> Self {
+                LibmFloat::hypot(self, other)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                LibmFloat::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                LibmFloat::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                LibmFloat::tan(self)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                LibmFloat::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                LibmFloat::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                LibmFloat::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                LibmFloat::atan2(self, other)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                LibmFloat::sincos(self)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> Self {
+                LibmFloat::expm1(self)
+            }
+
+            #[inline]
+            fn ln_1p(self) -> Self {
+                LibmFloat::log1p(self)
+            }
+
+            #[inline]
+            fn sinh(self) -> Self {
+                LibmFloat::sinh(self)
+            }
+
+            #[inline]
+            fn cosh(self) -> Self {
+                LibmFloat::cosh(self)
+            }
+
+            #[inline]
+            fn tanh(self) -> Self {
+                LibmFloat::tanh(self)
+            }
+
+            #[inline]
+            fn asinh(self) -> Self {
+                LibmFloat::asinh(self)
+            }
+
+            #[inline]
+            fn acosh(self) -> Self {
+                LibmFloat::acosh(self)
+            }
+
+            #[inline]
+            fn atanh(self) -> Self {
+                LibmFloat::atanh(self)
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                <$ty>::to_degrees(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                <$ty>::to_radians(self)
+            }
+        }
+    };
+}
+
+#[cfg(f16_enabled)]
+float_ext_impl!(f16);
+float_ext_impl!(f32);
+float_ext_impl!(f64);
+#[cfg(f128_enabled)]
+float_ext_impl!(f128);
+
+fn div_euclid<F: Float + LibmFloat>(x: F, rhs: F) -> F {
+    let q = LibmFloat::trunc(x / rhs);
+    if LibmFloat::fmod(x, rhs) < F::ZERO {
+        return if rhs > F::ZERO { q - F::ONE } else { q + F::ONE };
+    }
+    q
+}
+
+fn rem_euclid<F: Float + LibmFloat>(x: F, rhs: F) -> F {
+    let r = LibmFloat::fmod(x, rhs);
+    if r < F::ZERO { r + LibmFloat::fabs(rhs) } else { r }
+}
+
+/// Exponentiation by squaring, in the same order as compiler-rt's `__powidf2` which `std` uses.
+fn powi<F: Float>(x: F, n: i32) -> F {
+    let recip = n < 0;
+    let mut base = x;
+    let mut n = n;
+    let mut r = F::ONE;
+
+    loop {
+        if n & 1 != 0 {
+            r *= base;
+        }
+        n /= 2;
+        if n == 0 {
+            break;
+        }
+        base *= base;
+    }
+
+    if recip { F::ONE / r } else { r }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::hint::black_box;
+
+    use super::*;
+
+    const F32_CASES: &[f32] = &[
+        0.0,
+        -0.0,
+        0.5,
+        -0.5,
+        1.0,
+        -1.0,
+        1.5,
+        2.5,
+        -2.5,
+        7.0,
+        -7.0,
+        1e-40,
+        3.4e38,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+
+    const F64_CASES: &[f64] = &[
+        0.0,
+        -0.0,
+        0.5,
+        -0.5,
+        1.0,
+        -1.0,
+        1.5,
+        2.5,
+        -2.5,
+        7.0,
+        -7.0,
+        1e-310,
+        1.7e308,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    fn same<F: Float>(a: F, b: F) -> bool {
+        a.biteq(b) || (a.is_nan() && b.is_nan())
+    }
+
+    macro_rules! check_against_std {
+        ($ty:ty, $cases:ident) => {
+            for &x in $cases {
+                let x = black_box(x);
+                assert!(same(FloatExt::fract(x), <$ty>::fract(x)), "fract({x:?})");
+                assert!(same(FloatExt::signum(x), <$ty>::signum(x)), "signum({x:?})");
+                assert!(same(FloatExt::round(x), <$ty>::round(x)), "round({x:?})");
+                assert!(
+                    same(FloatExt::round_ties_even(x), <$ty>::round_ties_even(x)),
+                    "round_ties_even({x:?})"
+                );
+
+                for n in [-65, -3, -2, -1, 0, 1, 2, 3, 31, i32::MIN, i32::MAX] {
+                    assert!(same(FloatExt::powi(x, n), <$ty>::powi(x, n)), "powi({x:?}, {n})");
+                }
+
+                for &y in $cases {
+                    assert!(
+                        same(FloatExt::div_euclid(x, y), <$ty>::div_euclid(x, y)),
+                        "div_euclid({x:?}, {y:?})"
+                    );
+                    assert!(
+                        same(FloatExt::rem_euclid(x, y), <$ty>::rem_euclid(x, y)),
+                        "rem_euclid({x:?}, {y:?})"
+                    );
+                    assert!(
+                        same(FloatExt::copysign(x, y), <$ty>::copysign(x, y)),
+                        "copysign({x:?}, {y:?})"
+                    );
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn matches_std_f32() {
+        check_against_std!(f32, F32_CASES);
+    }
+
+    #[test]
+    fn matches_std_f64() {
+        check_against_std!(f64, F64_CASES);
+    }
+}
@@ -19,11 +19,15 @@
 #![allow(clippy::zero_divided_by_zero)]
 #![forbid(unsafe_op_in_unsafe_fn)]
 
+mod complex;
+mod float_ext;
 mod libm_helper;
 mod math;
 
 use core::{f32, f64};
 
+pub use complex::*;
+pub use float_ext::FloatExt;
 pub use libm_helper::*;
 
 pub use self::math::*;
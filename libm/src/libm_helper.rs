@@ -2,13 +2,17 @@ use core::marker::PhantomData;
 
 use crate::*;
 
-/// Generic helper for libm functions, abstracting over f32 and f64. <br/>
+/// Generic helper for libm functions, abstracting over f16, f32, f64 and f128. <br/>
 /// # Type Parameter:
-/// - `T`: Either `f32` or `f64`
+/// - `T`: Any type implementing [`LibmFloat`]
+///
+/// Functions that exist for every float type forward to [`LibmFloat`], so they can also be used
+/// with a generic `T`. The rest, such as the Bessel functions, are only available for the types
+/// that implement them.
 ///
 /// # Examples
 /// ```rust
-/// use libm::{self, Libm};
+/// use libm::{self, Libm, LibmFloat};
 ///
 /// const PI_F32: f32 = 3.1415927410e+00;
 /// const PI_F64: f64 = 3.1415926535897931160e+00;
@@ -18,25 +22,223 @@ use crate::*;
 ///
 /// assert!(Libm::<f64>::cos(0.0f64) == libm::cos(0.0));
 /// assert!(Libm::<f64>::sin(PI_F64) == libm::sin(PI_F64));
+///
+/// fn sin_plus_cos<F: LibmFloat>(x: F) -> F {
+///     Libm::<F>::hypot(Libm::<F>::sin(x), Libm::<F>::cos(x))
+/// }
+///
+/// assert!(sin_plus_cos(0.0f32) == 1.0);
+/// assert!(sin_plus_cos(0.0f64) == 1.0);
 /// ```
 pub struct Libm<T>(PhantomData<T>);
 
-macro_rules! libm_helper {
-    ($t:ident, funcs: $funcs:tt) => {
-        impl Libm<$t> {
+/// Define `LibmFloat` from a list of signatures and forward the same functions of `Libm<T>` to
+/// it. The first argument of each function becomes `self`.
+macro_rules! libm_float {
+    ($(#[$attr:meta])* pub trait $tr:ident; funcs: {$($func:tt;)*}) => {
+        $(#[$attr])*
+        pub trait $tr: Copy + sealed::Sealed {
+            $(
+                libm_float! { @decl $func }
+            )*
+        }
+
+        impl<T: $tr> Libm<T> {
             #![allow(unused_parens)]
 
-            libm_helper! { $funcs }
+            $(
+                libm_float! { @forward $tr, $func }
+            )*
+        }
+    };
+
+    (@decl (
+        fn $func:ident($a0:ident: Self $(, $arg:ident: $arg_typ:tt)*) -> ($($ret_typ:tt),*)
+    )) => {
+        #[allow(unused_parens)]
+        fn $func(self $(, $arg: $arg_typ)*) -> ($($ret_typ),*);
+    };
+
+    (@forward $tr:ident, (
+        fn $func:ident($a0:ident: Self $(, $arg:ident: $arg_typ:tt)*) -> ($($ret_typ:tt),*)
+    )) => {
+        #[inline(always)]
+        pub fn $func(
+            $a0: T $(, $arg: libm_float!(@ty $arg_typ))*
+        ) -> ($(libm_float!(@ty $ret_typ)),*) {
+            <T as $tr>::$func($a0 $(, $arg)*)
         }
     };
 
-    ({$($func:tt;)*}) => {
+    (@ty Self) => { T };
+    (@ty $ty:ty) => { $ty };
+}
+
+libm_float! {
+    /// Float types that provide the libm functions as methods.
+    ///
+    /// This is implemented for `f32` and `f64`, and for `f16` and `f128` when they are enabled.
+    /// It covers the functions that are available for all of these types; see [`Libm`] for the
+    /// others.
+    ///
+    /// Inherent methods of the primitive types take precedence, so use the trait path (for
+    /// example `LibmFloat::log(x)`) to call these on a concrete type.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use libm::LibmFloat;
+    ///
+    /// fn hypot_exp<F: LibmFloat>(x: F, y: F) -> F {
+    ///     x.hypot(y).exp()
+    /// }
+    ///
+    /// assert!(hypot_exp(3.0f32, 4.0) == libm::expf(5.0));
+    /// assert!(hypot_exp(3.0f64, 4.0) == libm::exp(5.0));
+    /// ```
+    pub trait LibmFloat;
+    funcs: {
+        // verify-sorted-start
+        (fn acos(x: Self) -> (Self));
+        (fn acosh(x: Self) -> (Self));
+        (fn acospi(x: Self) -> (Self));
+        (fn asin(x: Self) -> (Self));
+        (fn asinh(x: Self) -> (Self));
+        (fn asinpi(x: Self) -> (Self));
+        (fn atan(x: Self) -> (Self));
+        (fn atan2(y: Self, x: Self) -> (Self));
+        (fn atan2pi(y: Self, x: Self) -> (Self));
+        (fn atanh(x: Self) -> (Self));
+        (fn atanpi(x: Self) -> (Self));
+        (fn canonicalize(x: Self) -> (Self));
+        (fn cbrt(x: Self) -> (Self));
+        (fn ceil(x: Self) -> (Self));
+        (fn copysign(x: Self, y: Self) -> (Self));
+        (fn cos(x: Self) -> (Self));
+        (fn cosh(x: Self) -> (Self));
+        (fn cospi(x: Self) -> (Self));
+        (fn erf(x: Self) -> (Self));
+        (fn erfc(x: Self) -> (Self));
+        (fn exp(x: Self) -> (Self));
+        (fn exp2(x: Self) -> (Self));
+        (fn expm1(x: Self) -> (Self));
+        (fn fabs(x: Self) -> (Self));
+        (fn fdim(x: Self, y: Self) -> (Self));
+        (fn floor(x: Self) -> (Self));
+        (fn fma(x: Self, y: Self, z: Self) -> (Self));
+        (fn fmax(x: Self, y: Self) -> (Self));
+        (fn fmaximum(x: Self, y: Self) -> (Self));
+        (fn fmaximum_mag(x: Self, y: Self) -> (Self));
+        (fn fmaximum_mag_num(x: Self, y: Self) -> (Self));
+        (fn fmaximum_num(x: Self, y: Self) -> (Self));
+        (fn fmin(x: Self, y: Self) -> (Self));
+        (fn fminimum(x: Self, y: Self) -> (Self));
+        (fn fminimum_mag(x: Self, y: Self) -> (Self));
+        (fn fminimum_mag_num(x: Self, y: Self) -> (Self));
+        (fn fminimum_num(x: Self, y: Self) -> (Self));
+        (fn fmod(x: Self, y: Self) -> (Self));
+        (fn frexp(x: Self) -> (Self, i32));
+        (fn fromfp(x: Self, round: Round, width: u32) -> (Self));
+        (fn fromfpx(x: Self, round: Round, width: u32) -> (Self));
+        (fn getpayload(x: Self) -> (Self));
+        (fn hypot(x: Self, y: Self) -> (Self));
+        (fn ilogb(x: Self) -> (i32));
+        (fn issignaling(x: Self) -> (i32));
+        (fn ldexp(x: Self, n: i32) -> (Self));
+        (fn lgamma(x: Self) -> (Self));
+        (fn llogb(x: Self) -> (i64));
+        (fn llrint(x: Self) -> (i64));
+        (fn llround(x: Self) -> (i64));
+        (fn log(x: Self) -> (Self));
+        (fn log10(x: Self) -> (Self));
+        (fn log1p(x: Self) -> (Self));
+        (fn log2(x: Self) -> (Self));
+        (fn logb(x: Self) -> (Self));
+        (fn lrint(x: Self) -> (i64));
+        (fn lround(x: Self) -> (i64));
+        (fn modf(x: Self) -> (Self, Self));
+        (fn nearbyint(x: Self) -> (Self));
+        (fn nextafter(x: Self, y: Self) -> (Self));
+        (fn nextdown(x: Self) -> (Self));
+        (fn nextup(x: Self) -> (Self));
+        (fn pow(x: Self, y: Self) -> (Self));
+        (fn remainder(x: Self, y: Self) -> (Self));
+        (fn remquo(x: Self, y: Self) -> (Self, i32));
+        (fn rint(x: Self) -> (Self));
+        (fn round(x: Self) -> (Self));
+        (fn roundeven(x: Self) -> (Self));
+        (fn scalbln(x: Self, n: i64) -> (Self));
+        (fn scalbn(x: Self, n: i32) -> (Self));
+        (fn setpayload(pl: Self) -> (Self));
+        (fn setpayloadsig(pl: Self) -> (Self));
+        (fn sin(x: Self) -> (Self));
+        (fn sincos(x: Self) -> (Self, Self));
+        (fn sincospi(x: Self) -> (Self, Self));
+        (fn sinh(x: Self) -> (Self));
+        (fn sinpi(x: Self) -> (Self));
+        (fn sqrt(x: Self) -> (Self));
+        (fn tan(x: Self) -> (Self));
+        (fn tanh(x: Self) -> (Self));
+        (fn tanpi(x: Self) -> (Self));
+        (fn tgamma(x: Self) -> (Self));
+        (fn totalorder(x: Self, y: Self) -> (i32));
+        (fn totalordermag(x: Self, y: Self) -> (i32));
+        (fn trunc(x: Self) -> (Self));
+        (fn ufromfp(x: Self, round: Round, width: u32) -> (Self));
+        (fn ufromfpx(x: Self, round: Round, width: u32) -> (Self));
+        // verify-sorted-end
+    }
+}
+
+/// Implemented for every float type supported by `LibmFloat` and `FloatExt`.
+pub(crate) mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+    #[cfg(f16_enabled)]
+    impl Sealed for f16 {}
+    #[cfg(f128_enabled)]
+    impl Sealed for f128 {}
+}
+
+/// Implement `LibmFloat` for a type. Functions listed under `extra` are only added to `Libm<T>`
+/// for that type.
+macro_rules! libm_helper {
+    ($t:ident, funcs: {$($func:tt;)*} $(, extra: {$($extra:tt;)*})?) => {
+        impl LibmFloat for $t {
+            #![allow(unused_parens)]
+
+            $(
+                libm_helper! { @method $func }
+            )*
+        }
+
         $(
-            libm_helper! { $func }
-        )*
+            impl Libm<$t> {
+                #![allow(unused_parens)]
+
+                $(
+                    libm_helper! { @assoc $extra }
+                )*
+            }
+        )?
+    };
+
+    (@method (
+        fn $func:ident($a0:ident: $t0:ty $(, $arg:ident: $arg_typ:ty)*) -> ($($ret_typ:ty),*);
+            => $libm_fn:ident
+    )) => {
+        #[inline(always)]
+        fn $func(self $(, $arg: $arg_typ)*) -> ($($ret_typ),*) {
+            $libm_fn(self $(, $arg)*)
+        }
     };
 
-    ((fn $func:ident($($arg:ident: $arg_typ:ty),*) -> ($($ret_typ:ty),*); => $libm_fn:ident)) => {
+    (@assoc (
+        $(#[$meta:meta])*
+        fn $func:ident($($arg:ident: $arg_typ:ty),*) -> ($($ret_typ:ty),*); => $libm_fn:ident
+    )) => {
+        $(#[$meta])*
         #[inline(always)]
         pub fn $func($($arg: $arg_typ),*) -> ($($ret_typ),*) {
             $libm_fn($($arg),*)
@@ -51,20 +253,25 @@ libm_helper! {
         // verify-sorted-start
         (fn acos(x: f32) -> (f32);                  => acosf);
         (fn acosh(x: f32) -> (f32);                 => acoshf);
+        (fn acospi(x: f32) -> (f32);                => acospif);
         (fn asin(x: f32) -> (f32);                  => asinf);
         (fn asinh(x: f32) -> (f32);                 => asinhf);
+        (fn asinpi(x: f32) -> (f32);                => asinpif);
         (fn atan(x: f32) -> (f32);                  => atanf);
         (fn atan2(y: f32, x: f32) -> (f32);         => atan2f);
+        (fn atan2pi(y: f32, x: f32) -> (f32);       => atan2pif);
         (fn atanh(x: f32) -> (f32);                 => atanhf);
+        (fn atanpi(x: f32) -> (f32);                => atanpif);
+        (fn canonicalize(x: f32) -> (f32);          => canonicalizef);
         (fn cbrt(x: f32) -> (f32);                  => cbrtf);
         (fn ceil(x: f32) -> (f32);                  => ceilf);
         (fn copysign(x: f32, y: f32) -> (f32);      => copysignf);
         (fn cos(x: f32) -> (f32);                   => cosf);
         (fn cosh(x: f32) -> (f32);                  => coshf);
+        (fn cospi(x: f32) -> (f32);                 => cospif);
         (fn erf(x: f32) -> (f32);                   => erff);
         (fn erfc(x: f32) -> (f32);                  => erfcf);
         (fn exp(x: f32) -> (f32);                   => expf);
-        (fn exp10(x: f32) -> (f32);                 => exp10f);
         (fn exp2(x: f32) -> (f32);                  => exp2f);
         (fn expm1(x: f32) -> (f32);                 => expm1f);
         (fn fabs(x: f32) -> (f32);                  => fabsf);
@@ -72,38 +279,92 @@ libm_helper! {
         (fn floor(x: f32) -> (f32);                 => floorf);
         (fn fma(x: f32, y: f32, z: f32) -> (f32);   => fmaf);
         (fn fmax(x: f32, y: f32) -> (f32);          => fmaxf);
+        (fn fmaximum(x: f32, y: f32) -> (f32);      => fmaximumf);
+        (fn fmaximum_mag(x: f32, y: f32) -> (f32);  => fmaximum_magf);
+        (fn fmaximum_mag_num(x: f32, y: f32) -> (f32); => fmaximum_mag_numf);
+        (fn fmaximum_num(x: f32, y: f32) -> (f32);  => fmaximum_numf);
         (fn fmin(x: f32, y: f32) -> (f32);          => fminf);
+        (fn fminimum(x: f32, y: f32) -> (f32);      => fminimumf);
+        (fn fminimum_mag(x: f32, y: f32) -> (f32);  => fminimum_magf);
+        (fn fminimum_mag_num(x: f32, y: f32) -> (f32); => fminimum_mag_numf);
+        (fn fminimum_num(x: f32, y: f32) -> (f32);  => fminimum_numf);
         (fn fmod(x: f32, y: f32) -> (f32);          => fmodf);
         (fn frexp(x: f32) -> (f32, i32);            => frexpf);
+        (fn fromfp(x: f32, round: Round, width: u32) -> (f32); => fromfpf);
+        (fn fromfpx(x: f32, round: Round, width: u32) -> (f32); => fromfpxf);
+        (fn getpayload(x: f32) -> (f32);            => getpayloadf);
         (fn hypot(x: f32, y: f32) -> (f32);         => hypotf);
         (fn ilogb(x: f32) -> (i32);                 => ilogbf);
-        (fn j0(x: f32) -> (f32);                    => j0f);
-        (fn j1(x: f32) -> (f32);                    => j1f);
-        (fn jn(n: i32, x: f32) -> (f32);            => jnf);
+        (fn issignaling(x: f32) -> (i32);           => issignalingf);
         (fn ldexp(x: f32, n: i32) -> (f32);         => ldexpf);
         (fn lgamma(x: f32) -> (f32);                => lgammaf);
-        (fn lgamma_r(x: f32) -> (f32, i32);         => lgammaf_r);
+        (fn llogb(x: f32) -> (i64);                 => llogbf);
+        (fn llrint(x: f32) -> (i64);                => llrintf);
+        (fn llround(x: f32) -> (i64);               => llroundf);
         (fn log(x: f32) -> (f32);                   => logf);
         (fn log10(x: f32) -> (f32);                 => log10f);
         (fn log1p(x: f32) -> (f32);                 => log1pf);
         (fn log2(x: f32) -> (f32);                  => log2f);
+        (fn logb(x: f32) -> (f32);                  => logbf);
+        (fn lrint(x: f32) -> (i64);                 => lrintf);
+        (fn lround(x: f32) -> (i64);                => lroundf);
         (fn modf(x: f32) -> (f32, f32);             => modff);
+        (fn nearbyint(x: f32) -> (f32);             => nearbyintf);
         (fn nextafter(x: f32, y: f32) -> (f32);     => nextafterf);
+        (fn nextdown(x: f32) -> (f32);              => nextdownf);
+        (fn nextup(x: f32) -> (f32);                => nextupf);
         (fn pow(x: f32, y: f32) -> (f32);           => powf);
         (fn remainder(x: f32, y: f32) -> (f32);     => remainderf);
         (fn remquo(x: f32, y: f32) -> (f32, i32);   => remquof);
         (fn rint(x: f32) -> (f32);                  => rintf);
         (fn round(x: f32) -> (f32);                 => roundf);
         (fn roundeven(x: f32) -> (f32);             => roundevenf);
+        (fn scalbln(x: f32, n: i64) -> (f32);       => scalblnf);
         (fn scalbn(x: f32, n: i32) -> (f32);        => scalbnf);
+        (fn setpayload(pl: f32) -> (f32);           => setpayloadf);
+        (fn setpayloadsig(pl: f32) -> (f32);        => setpayloadsigf);
         (fn sin(x: f32) -> (f32);                   => sinf);
         (fn sincos(x: f32) -> (f32, f32);           => sincosf);
+        (fn sincospi(x: f32) -> (f32, f32);         => sincospif);
         (fn sinh(x: f32) -> (f32);                  => sinhf);
+        (fn sinpi(x: f32) -> (f32);                 => sinpif);
         (fn sqrt(x: f32) -> (f32);                  => sqrtf);
         (fn tan(x: f32) -> (f32);                   => tanf);
         (fn tanh(x: f32) -> (f32);                  => tanhf);
+        (fn tanpi(x: f32) -> (f32);                 => tanpif);
         (fn tgamma(x: f32) -> (f32);                => tgammaf);
+        (fn totalorder(x: f32, y: f32) -> (i32);    => totalorderf);
+        (fn totalordermag(x: f32, y: f32) -> (i32); => totalordermagf);
         (fn trunc(x: f32) -> (f32);                 => truncf);
+        (fn ufromfp(x: f32, round: Round, width: u32) -> (f32); => ufromfpf);
+        (fn ufromfpx(x: f32, round: Round, width: u32) -> (f32); => ufromfpxf);
+        // verify-sorted-end
+    },
+    extra: {
+        // verify-sorted-start
+        (#[cfg(f16_enabled)] fn fadd(x: f32, y: f32) -> (f16); => f16addf32);
+        (#[cfg(f16_enabled)] fn fdiv(x: f32, y: f32) -> (f16); => f16divf32);
+        (#[cfg(f16_enabled)] fn ffma(x: f32, y: f32, z: f32) -> (f16); => f16fmaf32);
+        (#[cfg(f16_enabled)] fn fmul(x: f32, y: f32) -> (f16); => f16mulf32);
+        (#[cfg(f16_enabled)] fn fsqrt(x: f32) -> (f16); => f16sqrtf32);
+        (#[cfg(f16_enabled)] fn fsub(x: f32, y: f32) -> (f16); => f16subf32);
+        (fn compoundn(x: f32, n: i64) -> (f32);     => compoundnf);
+        (fn exp10(x: f32) -> (f32);                 => exp10f);
+        (fn exp10m1(x: f32) -> (f32);               => exp10m1f);
+        (fn exp2m1(x: f32) -> (f32);                => exp2m1f);
+        (fn j0(x: f32) -> (f32);                    => j0f);
+        (fn j1(x: f32) -> (f32);                    => j1f);
+        (fn jn(n: i32, x: f32) -> (f32);            => jnf);
+        (fn lgamma_r(x: f32) -> (f32, i32);         => lgammaf_r);
+        (fn log10p1(x: f32) -> (f32);               => log10p1f);
+        (fn log2p1(x: f32) -> (f32);                => log2p1f);
+        (fn logp1(x: f32) -> (f32);                 => logp1f);
+        (fn nan(tagp: &str) -> (f32);               => nanf);
+        (fn nexttoward(x: f32, y: f64) -> (f32);    => nexttowardf);
+        (fn pown(x: f32, n: i64) -> (f32);          => pownf);
+        (fn powr(x: f32, y: f32) -> (f32);          => powrf);
+        (fn rootn(x: f32, n: i64) -> (f32);         => rootnf);
+        (fn rsqrt(x: f32) -> (f32);                 => rsqrtf);
         (fn y0(x: f32) -> (f32);                    => y0f);
         (fn y1(x: f32) -> (f32);                    => y1f);
         (fn yn(n: i32, x: f32) -> (f32);            => ynf);
@@ -117,20 +378,25 @@ libm_helper! {
         // verify-sorted-start
         (fn acos(x: f64) -> (f64);                  => acos);
         (fn acosh(x: f64) -> (f64);                 => acosh);
+        (fn acospi(x: f64) -> (f64);                => acospi);
         (fn asin(x: f64) -> (f64);                  => asin);
         (fn asinh(x: f64) -> (f64);                 => asinh);
+        (fn asinpi(x: f64) -> (f64);                => asinpi);
         (fn atan(x: f64) -> (f64);                  => atan);
         (fn atan2(y: f64, x: f64) -> (f64);         => atan2);
+        (fn atan2pi(y: f64, x: f64) -> (f64);       => atan2pi);
         (fn atanh(x: f64) -> (f64);                 => atanh);
+        (fn atanpi(x: f64) -> (f64);                => atanpi);
+        (fn canonicalize(x: f64) -> (f64);          => canonicalize);
         (fn cbrt(x: f64) -> (f64);                  => cbrt);
         (fn ceil(x: f64) -> (f64);                  => ceil);
         (fn copysign(x: f64, y: f64) -> (f64);      => copysign);
         (fn cos(x: f64) -> (f64);                   => cos);
         (fn cosh(x: f64) -> (f64);                  => cosh);
+        (fn cospi(x: f64) -> (f64);                 => cospi);
         (fn erf(x: f64) -> (f64);                   => erf);
         (fn erfc(x: f64) -> (f64);                  => erfc);
         (fn exp(x: f64) -> (f64);                   => exp);
-        (fn exp10(x: f64) -> (f64);                 => exp10);
         (fn exp2(x: f64) -> (f64);                  => exp2);
         (fn expm1(x: f64) -> (f64);                 => expm1);
         (fn fabs(x: f64) -> (f64);                  => fabs);
@@ -139,45 +405,91 @@ libm_helper! {
         (fn fma(x: f64, y: f64, z: f64) -> (f64);   => fma);
         (fn fmax(x: f64, y: f64) -> (f64);          => fmax);
         (fn fmaximum(x: f64, y: f64) -> (f64);      => fmaximum);
+        (fn fmaximum_mag(x: f64, y: f64) -> (f64);  => fmaximum_mag);
+        (fn fmaximum_mag_num(x: f64, y: f64) -> (f64); => fmaximum_mag_num);
         (fn fmaximum_num(x: f64, y: f64) -> (f64);  => fmaximum_num);
-        (fn fmaximum_numf(x: f32, y: f32) -> (f32); => fmaximum_numf);
-        (fn fmaximumf(x: f32, y: f32) -> (f32);     => fmaximumf);
         (fn fmin(x: f64, y: f64) -> (f64);          => fmin);
         (fn fminimum(x: f64, y: f64) -> (f64);      => fminimum);
+        (fn fminimum_mag(x: f64, y: f64) -> (f64);  => fminimum_mag);
+        (fn fminimum_mag_num(x: f64, y: f64) -> (f64); => fminimum_mag_num);
         (fn fminimum_num(x: f64, y: f64) -> (f64);  => fminimum_num);
-        (fn fminimum_numf(x: f32, y: f32) -> (f32); => fminimum_numf);
-        (fn fminimumf(x: f32, y: f32) -> (f32);     => fminimumf);
         (fn fmod(x: f64, y: f64) -> (f64);          => fmod);
         (fn frexp(x: f64) -> (f64, i32);            => frexp);
+        (fn fromfp(x: f64, round: Round, width: u32) -> (f64); => fromfp);
+        (fn fromfpx(x: f64, round: Round, width: u32) -> (f64); => fromfpx);
+        (fn getpayload(x: f64) -> (f64);            => getpayload);
         (fn hypot(x: f64, y: f64) -> (f64);         => hypot);
         (fn ilogb(x: f64) -> (i32);                 => ilogb);
-        (fn j0(x: f64) -> (f64);                    => j0);
-        (fn j1(x: f64) -> (f64);                    => j1);
-        (fn jn(n: i32, x: f64) -> (f64);            => jn);
+        (fn issignaling(x: f64) -> (i32);           => issignaling);
         (fn ldexp(x: f64, n: i32) -> (f64);         => ldexp);
         (fn lgamma(x: f64) -> (f64);                => lgamma);
-        (fn lgamma_r(x: f64) -> (f64, i32);         => lgamma_r);
+        (fn llogb(x: f64) -> (i64);                 => llogb);
+        (fn llrint(x: f64) -> (i64);                => llrint);
+        (fn llround(x: f64) -> (i64);               => llround);
         (fn log(x: f64) -> (f64);                   => log);
         (fn log10(x: f64) -> (f64);                 => log10);
         (fn log1p(x: f64) -> (f64);                 => log1p);
         (fn log2(x: f64) -> (f64);                  => log2);
+        (fn logb(x: f64) -> (f64);                  => logb);
+        (fn lrint(x: f64) -> (i64);                 => lrint);
+        (fn lround(x: f64) -> (i64);                => lround);
         (fn modf(x: f64) -> (f64, f64);             => modf);
+        (fn nearbyint(x: f64) -> (f64);             => nearbyint);
         (fn nextafter(x: f64, y: f64) -> (f64);     => nextafter);
+        (fn nextdown(x: f64) -> (f64);              => nextdown);
+        (fn nextup(x: f64) -> (f64);                => nextup);
         (fn pow(x: f64, y: f64) -> (f64);           => pow);
         (fn remainder(x: f64, y: f64) -> (f64);     => remainder);
         (fn remquo(x: f64, y: f64) -> (f64, i32);   => remquo);
         (fn rint(x: f64) -> (f64);                  => rint);
         (fn round(x: f64) -> (f64);                 => round);
-        (fn roundevem(x: f64) -> (f64);             => roundeven);
+        (fn roundeven(x: f64) -> (f64);             => roundeven);
+        (fn scalbln(x: f64, n: i64) -> (f64);       => scalbln);
         (fn scalbn(x: f64, n: i32) -> (f64);        => scalbn);
+        (fn setpayload(pl: f64) -> (f64);           => setpayload);
+        (fn setpayloadsig(pl: f64) -> (f64);        => setpayloadsig);
         (fn sin(x: f64) -> (f64);                   => sin);
         (fn sincos(x: f64) -> (f64, f64);           => sincos);
+        (fn sincospi(x: f64) -> (f64, f64);         => sincospi);
         (fn sinh(x: f64) -> (f64);                  => sinh);
+        (fn sinpi(x: f64) -> (f64);                 => sinpi);
         (fn sqrt(x: f64) -> (f64);                  => sqrt);
         (fn tan(x: f64) -> (f64);                   => tan);
         (fn tanh(x: f64) -> (f64);                  => tanh);
+        (fn tanpi(x: f64) -> (f64);                 => tanpi);
         (fn tgamma(x: f64) -> (f64);                => tgamma);
+        (fn totalorder(x: f64, y: f64) -> (i32);    => totalorder);
+        (fn totalordermag(x: f64, y: f64) -> (i32); => totalordermag);
         (fn trunc(x: f64) -> (f64);                 => trunc);
+        (fn ufromfp(x: f64, round: Round, width: u32) -> (f64); => ufromfp);
+        (fn ufromfpx(x: f64, round: Round, width: u32) -> (f64); => ufromfpx);
+        // verify-sorted-end
+    },
+    extra: {
+        // verify-sorted-start
+        (fn compoundn(x: f64, n: i64) -> (f64);     => compoundn);
+        (fn exp10(x: f64) -> (f64);                 => exp10);
+        (fn exp10m1(x: f64) -> (f64);               => exp10m1);
+        (fn exp2m1(x: f64) -> (f64);                => exp2m1);
+        (fn fadd(x: f64, y: f64) -> (f32);          => fadd);
+        (fn fdiv(x: f64, y: f64) -> (f32);          => fdiv);
+        (fn ffma(x: f64, y: f64, z: f64) -> (f32);  => ffma);
+        (fn fmul(x: f64, y: f64) -> (f32);          => fmul);
+        (fn fsqrt(x: f64) -> (f32);                 => fsqrt);
+        (fn fsub(x: f64, y: f64) -> (f32);          => fsub);
+        (fn j0(x: f64) -> (f64);                    => j0);
+        (fn j1(x: f64) -> (f64);                    => j1);
+        (fn jn(n: i32, x: f64) -> (f64);            => jn);
+        (fn lgamma_r(x: f64) -> (f64, i32);         => lgamma_r);
+        (fn log10p1(x: f64) -> (f64);               => log10p1);
+        (fn log2p1(x: f64) -> (f64);                => log2p1);
+        (fn logp1(x: f64) -> (f64);                 => logp1);
+        (fn nan(tagp: &str) -> (f64);               => nan);
+        (fn nexttoward(x: f64, y: f64) -> (f64);    => nexttoward);
+        (fn pown(x: f64, n: i64) -> (f64);          => pown);
+        (fn powr(x: f64, y: f64) -> (f64);          => powr);
+        (fn rootn(x: f64, n: i64) -> (f64);         => rootn);
+        (fn rsqrt(x: f64) -> (f64);                 => rsqrt);
         (fn y0(x: f64) -> (f64);                    => y0);
         (fn y1(x: f64) -> (f64);                    => y1);
         (fn yn(n: i32, x: f64) -> (f64);            => yn);
@@ -190,25 +502,99 @@ libm_helper! {
     f16,
     funcs: {
         // verify-sorted-start
+        (fn acos(x: f16) -> (f16);                  => acosf16);
+        (fn acosh(x: f16) -> (f16);                 => acoshf16);
+        (fn acospi(x: f16) -> (f16);                => acospif16);
+        (fn asin(x: f16) -> (f16);                  => asinf16);
+        (fn asinh(x: f16) -> (f16);                 => asinhf16);
+        (fn asinpi(x: f16) -> (f16);                => asinpif16);
+        (fn atan(x: f16) -> (f16);                  => atanf16);
+        (fn atan2(y: f16, x: f16) -> (f16);         => atan2f16);
+        (fn atan2pi(y: f16, x: f16) -> (f16);       => atan2pif16);
+        (fn atanh(x: f16) -> (f16);                 => atanhf16);
+        (fn atanpi(x: f16) -> (f16);                => atanpif16);
+        (fn canonicalize(x: f16) -> (f16);          => canonicalizef16);
+        (fn cbrt(x: f16) -> (f16);                  => cbrtf16);
         (fn ceil(x: f16) -> (f16);                  => ceilf16);
         (fn copysign(x: f16, y: f16) -> (f16);      => copysignf16);
+        (fn cos(x: f16) -> (f16);                   => cosf16);
+        (fn cosh(x: f16) -> (f16);                  => coshf16);
+        (fn cospi(x: f16) -> (f16);                 => cospif16);
+        (fn erf(x: f16) -> (f16);                   => erff16);
+        (fn erfc(x: f16) -> (f16);                  => erfcf16);
+        (fn exp(x: f16) -> (f16);                   => expf16);
+        (fn exp2(x: f16) -> (f16);                  => exp2f16);
+        (fn expm1(x: f16) -> (f16);                 => expm1f16);
         (fn fabs(x: f16) -> (f16);                  => fabsf16);
         (fn fdim(x: f16, y: f16) -> (f16);          => fdimf16);
         (fn floor(x: f16) -> (f16);                 => floorf16);
+        (fn fma(x: f16, y: f16, z: f16) -> (f16);   => fmaf16);
         (fn fmax(x: f16, y: f16) -> (f16);          => fmaxf16);
+        (fn fmaximum(x: f16, y: f16) -> (f16);      => fmaximumf16);
+        (fn fmaximum_mag(x: f16, y: f16) -> (f16);  => fmaximum_magf16);
+        (fn fmaximum_mag_num(x: f16, y: f16) -> (f16); => fmaximum_mag_numf16);
         (fn fmaximum_num(x: f16, y: f16) -> (f16);  => fmaximum_numf16);
-        (fn fmaximumf16(x: f16, y: f16) -> (f16);   => fmaximumf16);
         (fn fmin(x: f16, y: f16) -> (f16);          => fminf16);
         (fn fminimum(x: f16, y: f16) -> (f16);      => fminimumf16);
+        (fn fminimum_mag(x: f16, y: f16) -> (f16);  => fminimum_magf16);
+        (fn fminimum_mag_num(x: f16, y: f16) -> (f16); => fminimum_mag_numf16);
         (fn fminimum_num(x: f16, y: f16) -> (f16);  => fminimum_numf16);
         (fn fmod(x: f16, y: f16) -> (f16);          => fmodf16);
+        (fn frexp(x: f16) -> (f16, i32);            => frexpf16);
+        (fn fromfp(x: f16, round: Round, width: u32) -> (f16); => fromfpf16);
+        (fn fromfpx(x: f16, round: Round, width: u32) -> (f16); => fromfpxf16);
+        (fn getpayload(x: f16) -> (f16);            => getpayloadf16);
+        (fn hypot(x: f16, y: f16) -> (f16);         => hypotf16);
+        (fn ilogb(x: f16) -> (i32);                 => ilogbf16);
+        (fn issignaling(x: f16) -> (i32);           => issignalingf16);
         (fn ldexp(x: f16, n: i32) -> (f16);         => ldexpf16);
+        (fn lgamma(x: f16) -> (f16);                => lgammaf16);
+        (fn llogb(x: f16) -> (i64);                 => llogbf16);
+        (fn llrint(x: f16) -> (i64);                => llrintf16);
+        (fn llround(x: f16) -> (i64);               => llroundf16);
+        (fn log(x: f16) -> (f16);                   => logf16);
+        (fn log10(x: f16) -> (f16);                 => log10f16);
+        (fn log1p(x: f16) -> (f16);                 => log1pf16);
+        (fn log2(x: f16) -> (f16);                  => log2f16);
+        (fn logb(x: f16) -> (f16);                  => logbf16);
+        (fn lrint(x: f16) -> (i64);                 => lrintf16);
+        (fn lround(x: f16) -> (i64);                => lroundf16);
+        (fn modf(x: f16) -> (f16, f16);             => modff16);
+        (fn nearbyint(x: f16) -> (f16);             => nearbyintf16);
+        (fn nextafter(x: f16, y: f16) -> (f16);     => nextafterf16);
+        (fn nextdown(x: f16) -> (f16);              => nextdownf16);
+        (fn nextup(x: f16) -> (f16);                => nextupf16);
+        (fn pow(x: f16, y: f16) -> (f16);           => powf16);
+        (fn remainder(x: f16, y: f16) -> (f16);     => remainderf16);
+        (fn remquo(x: f16, y: f16) -> (f16, i32);   => remquof16);
         (fn rint(x: f16) -> (f16);                  => rintf16);
         (fn round(x: f16) -> (f16);                 => roundf16);
         (fn roundeven(x: f16) -> (f16);             => roundevenf16);
+        (fn scalbln(x: f16, n: i64) -> (f16);       => scalblnf16);
         (fn scalbn(x: f16, n: i32) -> (f16);        => scalbnf16);
-        (fn sqrtf(x: f16) -> (f16);                 => sqrtf16);
-        (fn truncf(x: f16) -> (f16);                => truncf16);
+        (fn setpayload(pl: f16) -> (f16);           => setpayloadf16);
+        (fn setpayloadsig(pl: f16) -> (f16);        => setpayloadsigf16);
+        (fn sin(x: f16) -> (f16);                   => sinf16);
+        (fn sincos(x: f16) -> (f16, f16);           => sincosf16);
+        (fn sincospi(x: f16) -> (f16, f16);         => sincospif16);
+        (fn sinh(x: f16) -> (f16);                  => sinhf16);
+        (fn sinpi(x: f16) -> (f16);                 => sinpif16);
+        (fn sqrt(x: f16) -> (f16);                  => sqrtf16);
+        (fn tan(x: f16) -> (f16);                   => tanf16);
+        (fn tanh(x: f16) -> (f16);                  => tanhf16);
+        (fn tanpi(x: f16) -> (f16);                 => tanpif16);
+        (fn tgamma(x: f16) -> (f16);                => tgammaf16);
+        (fn totalorder(x: f16, y: f16) -> (i32);    => totalorderf16);
+        (fn totalordermag(x: f16, y: f16) -> (i32); => totalordermagf16);
+        (fn trunc(x: f16) -> (f16);                 => truncf16);
+        (fn ufromfp(x: f16, round: Round, width: u32) -> (f16); => ufromfpf16);
+        (fn ufromfpx(x: f16, round: Round, width: u32) -> (f16); => ufromfpxf16);
+        // verify-sorted-end
+    },
+    extra: {
+        // verify-sorted-start
+        (fn exp10(x: f16) -> (f16);                 => exp10f16);
+        (fn nan(tagp: &str) -> (f16);               => nanf16);
         // verify-sorted-end
     }
 }
@@ -218,27 +604,166 @@ libm_helper! {
     f128,
     funcs: {
         // verify-sorted-start
+        (fn acos(x: f128) -> (f128);                => acosf128);
+        (fn acosh(x: f128) -> (f128);               => acoshf128);
+        (fn acospi(x: f128) -> (f128);              => acospif128);
+        (fn asin(x: f128) -> (f128);                => asinf128);
+        (fn asinh(x: f128) -> (f128);               => asinhf128);
+        (fn asinpi(x: f128) -> (f128);              => asinpif128);
+        (fn atan(x: f128) -> (f128);                => atanf128);
+        (fn atan2(y: f128, x: f128) -> (f128);      => atan2f128);
+        (fn atan2pi(y: f128, x: f128) -> (f128);    => atan2pif128);
+        (fn atanh(x: f128) -> (f128);               => atanhf128);
+        (fn atanpi(x: f128) -> (f128);              => atanpif128);
+        (fn canonicalize(x: f128) -> (f128);        => canonicalizef128);
+        (fn cbrt(x: f128) -> (f128);                => cbrtf128);
         (fn ceil(x: f128) -> (f128);                => ceilf128);
         (fn copysign(x: f128, y: f128) -> (f128);   => copysignf128);
+        (fn cos(x: f128) -> (f128);                 => cosf128);
+        (fn cosh(x: f128) -> (f128);                => coshf128);
+        (fn cospi(x: f128) -> (f128);               => cospif128);
+        (fn erf(x: f128) -> (f128);                 => erff128);
+        (fn erfc(x: f128) -> (f128);                => erfcf128);
+        (fn exp(x: f128) -> (f128);                 => expf128);
+        (fn exp2(x: f128) -> (f128);                => exp2f128);
+        (fn expm1(x: f128) -> (f128);               => expm1f128);
         (fn fabs(x: f128) -> (f128);                => fabsf128);
         (fn fdim(x: f128, y: f128) -> (f128);       => fdimf128);
         (fn floor(x: f128) -> (f128);               => floorf128);
         (fn fma(x: f128, y: f128, z: f128) -> (f128); => fmaf128);
         (fn fmax(x: f128, y: f128) -> (f128);       => fmaxf128);
-        (fn fmaximum(x: f128, y: f128) -> (f128);      => fmaximumf128);
-        (fn fmaximum_num(x: f128, y: f128) -> (f128);  => fmaximum_numf128);
+        (fn fmaximum(x: f128, y: f128) -> (f128);   => fmaximumf128);
+        (fn fmaximum_mag(x: f128, y: f128) -> (f128); => fmaximum_magf128);
+        (fn fmaximum_mag_num(x: f128, y: f128) -> (f128); => fmaximum_mag_numf128);
+        (fn fmaximum_num(x: f128, y: f128) -> (f128); => fmaximum_numf128);
         (fn fmin(x: f128, y: f128) -> (f128);       => fminf128);
-        (fn fminimum(x: f128, y: f128) -> (f128);      => fminimumf128);
-        (fn fminimum_num(x: f128, y: f128) -> (f128);  => fminimum_numf128);
+        (fn fminimum(x: f128, y: f128) -> (f128);   => fminimumf128);
+        (fn fminimum_mag(x: f128, y: f128) -> (f128); => fminimum_magf128);
+        (fn fminimum_mag_num(x: f128, y: f128) -> (f128); => fminimum_mag_numf128);
+        (fn fminimum_num(x: f128, y: f128) -> (f128); => fminimum_numf128);
         (fn fmod(x: f128, y: f128) -> (f128);       => fmodf128);
+        (fn frexp(x: f128) -> (f128, i32);          => frexpf128);
+        (fn fromfp(x: f128, round: Round, width: u32) -> (f128); => fromfpf128);
+        (fn fromfpx(x: f128, round: Round, width: u32) -> (f128); => fromfpxf128);
+        (fn getpayload(x: f128) -> (f128);          => getpayloadf128);
+        (fn hypot(x: f128, y: f128) -> (f128);      => hypotf128);
+        (fn ilogb(x: f128) -> (i32);                => ilogbf128);
+        (fn issignaling(x: f128) -> (i32);          => issignalingf128);
         (fn ldexp(x: f128, n: i32) -> (f128);       => ldexpf128);
+        (fn lgamma(x: f128) -> (f128);              => lgammaf128);
+        (fn llogb(x: f128) -> (i64);                => llogbf128);
+        (fn llrint(x: f128) -> (i64);               => llrintf128);
+        (fn llround(x: f128) -> (i64);              => llroundf128);
+        (fn log(x: f128) -> (f128);                 => logf128);
+        (fn log10(x: f128) -> (f128);               => log10f128);
+        (fn log1p(x: f128) -> (f128);               => log1pf128);
+        (fn log2(x: f128) -> (f128);                => log2f128);
+        (fn logb(x: f128) -> (f128);                => logbf128);
+        (fn lrint(x: f128) -> (i64);                => lrintf128);
+        (fn lround(x: f128) -> (i64);               => lroundf128);
+        (fn modf(x: f128) -> (f128, f128);          => modff128);
+        (fn nearbyint(x: f128) -> (f128);           => nearbyintf128);
+        (fn nextafter(x: f128, y: f128) -> (f128);  => nextafterf128);
+        (fn nextdown(x: f128) -> (f128);            => nextdownf128);
+        (fn nextup(x: f128) -> (f128);              => nextupf128);
+        (fn pow(x: f128, y: f128) -> (f128);        => powf128);
+        (fn remainder(x: f128, y: f128) -> (f128);  => remainderf128);
+        (fn remquo(x: f128, y: f128) -> (f128, i32); => remquof128);
         (fn rint(x: f128) -> (f128);                => rintf128);
         (fn round(x: f128) -> (f128);               => roundf128);
         (fn roundeven(x: f128) -> (f128);           => roundevenf128);
+        (fn scalbln(x: f128, n: i64) -> (f128);     => scalblnf128);
         (fn scalbn(x: f128, n: i32) -> (f128);      => scalbnf128);
+        (fn setpayload(pl: f128) -> (f128);         => setpayloadf128);
+        (fn setpayloadsig(pl: f128) -> (f128);      => setpayloadsigf128);
+        (fn sin(x: f128) -> (f128);                 => sinf128);
+        (fn sincos(x: f128) -> (f128, f128);        => sincosf128);
+        (fn sincospi(x: f128) -> (f128, f128);      => sincospif128);
+        (fn sinh(x: f128) -> (f128);                => sinhf128);
+        (fn sinpi(x: f128) -> (f128);               => sinpif128);
         (fn sqrt(x: f128) -> (f128);                => sqrtf128);
+        (fn tan(x: f128) -> (f128);                 => tanf128);
+        (fn tanh(x: f128) -> (f128);                => tanhf128);
+        (fn tanpi(x: f128) -> (f128);               => tanpif128);
+        (fn tgamma(x: f128) -> (f128);              => tgammaf128);
+        (fn totalorder(x: f128, y: f128) -> (i32);  => totalorderf128);
+        (fn totalordermag(x: f128, y: f128) -> (i32); => totalordermagf128);
         (fn trunc(x: f128) -> (f128);               => truncf128);
+        (fn ufromfp(x: f128, round: Round, width: u32) -> (f128); => ufromfpf128);
+        (fn ufromfpx(x: f128, round: Round, width: u32) -> (f128); => ufromfpxf128);
+        // verify-sorted-end
+    },
+    extra: {
+        // verify-sorted-start
+        (fn fadd(x: f128, y: f128) -> (f64);        => f64addf128);
+        (fn fdiv(x: f128, y: f128) -> (f64);        => f64divf128);
+        (fn ffma(x: f128, y: f128, z: f128) -> (f64); => f64fmaf128);
+        (fn fmul(x: f128, y: f128) -> (f64);        => f64mulf128);
+        (fn fsqrt(x: f128) -> (f64);                => f64sqrtf128);
+        (fn fsub(x: f128, y: f128) -> (f64);        => f64subf128);
+        (fn j0(x: f128) -> (f128);                  => j0f128);
+        (fn j1(x: f128) -> (f128);                  => j1f128);
+        (fn jn(n: i32, x: f128) -> (f128);          => jnf128);
+        (fn lgamma_r(x: f128) -> (f128, i32);       => lgammaf128_r);
+        (fn nan(tagp: &str) -> (f128);              => nanf128);
+        (fn y0(x: f128) -> (f128);                  => y0f128);
+        (fn y1(x: f128) -> (f128);                  => y1f128);
+        (fn yn(n: i32, x: f128) -> (f128);          => ynf128);
         // verify-sorted-end
     }
 }
 // verify-apilist-end
+
+/// Methods with misspelled names or for the wrong type, kept so existing code still compiles.
+impl Libm<f64> {
+    #[deprecated(note = "use `Libm::<f32>::fmaximum_num` instead")]
+    #[inline(always)]
+    pub fn fmaximum_numf(x: f32, y: f32) -> f32 {
+        fmaximum_numf(x, y)
+    }
+
+    #[deprecated(note = "use `Libm::<f32>::fmaximum` instead")]
+    #[inline(always)]
+    pub fn fmaximumf(x: f32, y: f32) -> f32 {
+        fmaximumf(x, y)
+    }
+
+    #[deprecated(note = "use `Libm::<f32>::fminimum_num` instead")]
+    #[inline(always)]
+    pub fn fminimum_numf(x: f32, y: f32) -> f32 {
+        fminimum_numf(x, y)
+    }
+
+    #[deprecated(note = "use `Libm::<f32>::fminimum` instead")]
+    #[inline(always)]
+    pub fn fminimumf(x: f32, y: f32) -> f32 {
+        fminimumf(x, y)
+    }
+
+    #[deprecated(note = "use `Libm::<f64>::roundeven` instead")]
+    #[inline(always)]
+    pub fn roundevem(x: f64) -> f64 {
+        roundeven(x)
+    }
+}
+
+#[cfg(f16_enabled)]
+impl Libm<f16> {
+    #[deprecated(note = "use `Libm::<f16>::fmaximum` instead")]
+    #[inline(always)]
+    pub fn fmaximumf16(x: f16, y: f16) -> f16 {
+        fmaximumf16(x, y)
+    }
+
+    #[deprecated(note = "use `Libm::<f16>::sqrt` instead")]
+    #[inline(always)]
+    pub fn sqrtf(x: f16) -> f16 {
+        sqrtf16(x)
+    }
+
+    #[deprecated(note = "use `Libm::<f16>::trunc` instead")]
+    #[inline(always)]
+    pub fn truncf(x: f16) -> f16 {
+        truncf16(x)
+    }
+}
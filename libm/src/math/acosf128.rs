@@ -0,0 +1,22 @@
+use super::k_atan2f128;
+use super::support::WideFloat;
+
+/// Arccosine (f128)
+///
+/// Computes the inverse cosine (arc cosine) of the input value. Arguments must be in the range
+/// -1 to 1. Returns values in radians, in the range of 0 to pi.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosf128(x: f128) -> f128 {
+    if x.is_nan() {
+        return x + x;
+    }
+    /* acos(|x|>1) is NaN */
+    if x.abs() > 1.0 {
+        return (x - x) / (x - x);
+    }
+
+    // acos(x) = atan2(sqrt(1 - x^2), x), where `1 - x^2` is computed without cancellation
+    let w = WideFloat::from_f128(x);
+    let s = ((WideFloat::ONE - w) * (WideFloat::ONE + w)).sqrt();
+    k_atan2f128(s, w).to_f128()
+}
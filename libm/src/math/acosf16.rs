@@ -0,0 +1,8 @@
+/// Arccosine (f16)
+///
+/// Computes the inverse cosine (arc cosine) of the input value. Arguments must be in the range
+/// -1 to 1. Returns values in radians, in the range of 0 to pi.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosf16(x: f16) -> f16 {
+    super::acos(x as f64) as f16
+}
@@ -0,0 +1,21 @@
+use super::k_logf128;
+use super::support::WideFloat;
+
+/// Inverse hyperbolic cosine (f128)
+///
+/// Calculates the inverse hyperbolic cosine of `x`. Is defined as `log(x + sqrt(x*x-1))`. `x`
+/// must be a number greater than or equal to 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acoshf128(x: f128) -> f128 {
+    if x.is_nan() || x == f128::INFINITY {
+        return x + x;
+    }
+    if x < 1.0 {
+        return (x - x) / (x - x); /* acosh(x<1) = NaN */
+    }
+
+    // `x - 1` is exact, so the argument to `log` keeps full relative accuracy near 1.
+    let w = WideFloat::from_f128(x);
+    let (e, t) = k_logf128(w + ((w - WideFloat::ONE) * (w + WideFloat::ONE)).sqrt());
+    (WideFloat::from_i32(e) * WideFloat::LN2 + t).to_f128()
+}
@@ -0,0 +1,13 @@
+/// Inverse hyperbolic cosine (f16)
+///
+/// Calculates the inverse hyperbolic cosine of `x`. Is defined as `log(x + sqrt(x*x-1))`. `x`
+/// must be a number greater than or equal to 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acoshf16(x: f16) -> f16 {
+    // The `f64` routine does not produce NaN for all `x < -1`, so check the domain here.
+    if x < 1.0 {
+        return (x - x) / (x - x);
+    }
+
+    super::acosh(x as f64) as f16
+}
@@ -0,0 +1,72 @@
+use super::asinpi::{div_dd, sqrt_one_minus_sq};
+use super::atanpi::{k_atanpi, one_minus};
+use super::fabs;
+
+/// Arccosine divided by π (f16)
+///
+/// Computes `acos(x) / π`, the inverse cosine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of 0 to 1.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospif16(x: f16) -> f16 {
+    acospi(x as f64) as f16
+}
+
+/// Arccosine divided by π (f32)
+///
+/// Computes `acos(x) / π`, the inverse cosine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of 0 to 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospif(x: f32) -> f32 {
+    acospi(x as f64) as f32
+}
+
+/// Arccosine divided by π (f64)
+///
+/// Computes `acos(x) / π`, the inverse cosine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of 0 to 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospi(x: f64) -> f64 {
+    if x.is_nan() {
+        return x + x;
+    }
+    let ax = fabs(x);
+    /* acospi(|x|>1) is NaN */
+    if ax > 1.0 {
+        return (x - x) / (x - x);
+    }
+    /* |x| < 2^-60, acospi(x) = 1/2 - x/π. This also keeps the quotient below from overflowing. */
+    if ax < f64::from_bits(0x3c30000000000000) {
+        return 0.5 - x * core::f64::consts::FRAC_1_PI;
+    }
+
+    // acos(|x|) = atan(sqrt(1 - x^2) / |x|), and acos(-x) = π - acos(x)
+    let (t, t_lo) = sqrt_one_minus_sq(ax);
+    let (q, q_lo) = div_dd(t, t_lo, ax, 0.0);
+    let (p, e) = k_atanpi(q, q_lo);
+    if x.is_sign_negative() { one_minus(p, e) } else { p + e }
+}
+
+/// Arccosine divided by π (f128)
+///
+/// Computes `acos(x) / π`, the inverse cosine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of 0 to 1.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospif128(x: f128) -> f128 {
+    use super::k_atan2f128;
+    use super::support::WideFloat;
+
+    if x.is_nan() {
+        return x + x;
+    }
+    /* acospi(|x|>1) is NaN */
+    if x.abs() > 1.0 {
+        return (x - x) / (x - x);
+    }
+
+    // acos(x) = atan2(sqrt(1 - x^2), x), where `1 - x^2` is computed without cancellation
+    let w = WideFloat::from_f128(x);
+    let s = ((WideFloat::ONE - w) * (WideFloat::ONE + w)).sqrt();
+    (k_atan2f128(s, w) / WideFloat::PI).to_f128()
+}
@@ -0,0 +1,22 @@
+use super::k_atan2f128;
+use super::support::WideFloat;
+
+/// Arcsine (f128)
+///
+/// Computes the inverse sine (arc sine) of the argument `x`. Arguments to asin must be in the
+/// range -1 to 1. Returns values in radians, in the range of -pi/2 to pi/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinf128(x: f128) -> f128 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+    /* asin(|x|>1) is NaN */
+    if x.abs() > 1.0 {
+        return (x - x) / (x - x);
+    }
+
+    // asin(x) = atan2(x, sqrt(1 - x^2)), where `1 - x^2` is computed without cancellation
+    let w = WideFloat::from_f128(x);
+    let c = ((WideFloat::ONE - w) * (WideFloat::ONE + w)).sqrt();
+    k_atan2f128(w, c).to_f128()
+}
@@ -0,0 +1,8 @@
+/// Arcsine (f16)
+///
+/// Computes the inverse sine (arc sine) of the argument `x`. Arguments to asin must be in the
+/// range -1 to 1. Returns values in radians, in the range of -pi/2 to pi/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinf16(x: f16) -> f16 {
+    super::asin(x as f64) as f16
+}
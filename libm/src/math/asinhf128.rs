@@ -0,0 +1,25 @@
+use super::support::WideFloat;
+use super::{k_log1pf128, k_logf128};
+
+/// Inverse hyperbolic sine (f128)
+///
+/// Calculates the inverse hyperbolic sine of `x`. Is defined as `sgn(x)*log(|x|+sqrt(x*x+1))`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinhf128(x: f128) -> f128 {
+    if x.is_nan() || x.is_infinite() || x == 0.0 {
+        return x + x;
+    }
+
+    let w = WideFloat::from_f128(x).abs();
+    let w2 = w * w;
+    let r = if w.exp() < -2 {
+        // |x| < 0.25: asinh(|x|) = log1p(|x| + x^2/(1+sqrt(1+x^2)))
+        k_log1pf128(w + w2 / (WideFloat::ONE + (WideFloat::ONE + w2).sqrt()))
+    } else {
+        let (e, t) = k_logf128(w + (w2 + WideFloat::ONE).sqrt());
+        WideFloat::from_i32(e) * WideFloat::LN2 + t
+    };
+
+    let r = r.to_f128();
+    if x < 0.0 { -r } else { r }
+}
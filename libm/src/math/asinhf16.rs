@@ -0,0 +1,7 @@
+/// Inverse hyperbolic sine (f16)
+///
+/// Calculates the inverse hyperbolic sine of `x`. Is defined as `sgn(x)*log(|x|+sqrt(x*x+1))`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinhf16(x: f16) -> f16 {
+    super::asinh(x as f64) as f16
+}
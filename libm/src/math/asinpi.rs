@@ -0,0 +1,97 @@
+use super::atanpi::k_atanpi;
+use super::{fabs, fma, sqrt};
+
+/// `sqrt(1 - x^2)` as an unevaluated sum `(hi, lo)`, for `|x| <= 1`.
+pub(crate) fn sqrt_one_minus_sq(x: f64) -> (f64, f64) {
+    /* x^2 = xx + xx_lo exactly, and `1 - xx` is computed with its exact rounding error */
+    let xx = x * x;
+    let xx_lo = fma(x, x, -xx);
+    let s = 1.0 - xx;
+    let s_lo = ((1.0 - s) - xx) - xx_lo;
+
+    if s == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let t = sqrt(s);
+    let t_lo = (fma(-t, t, s) + s_lo) / (2.0 * t);
+    (t, t_lo)
+}
+
+/// `(ah + al) / (bh + bl)` as an unevaluated sum, for `bh > 0` and small `al`, `bl`.
+pub(crate) fn div_dd(ah: f64, al: f64, bh: f64, bl: f64) -> (f64, f64) {
+    let q = ah / bh;
+    let q_lo = (fma(-q, bh, ah) + al - q * bl) / bh;
+    (q, q_lo)
+}
+
+/// Arcsine divided by π (f16)
+///
+/// Computes `asin(x) / π`, the inverse sine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of -1/2 to 1/2.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpif16(x: f16) -> f16 {
+    asinpi(x as f64) as f16
+}
+
+/// Arcsine divided by π (f32)
+///
+/// Computes `asin(x) / π`, the inverse sine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of -1/2 to 1/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpif(x: f32) -> f32 {
+    asinpi(x as f64) as f32
+}
+
+/// Arcsine divided by π (f64)
+///
+/// Computes `asin(x) / π`, the inverse sine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of -1/2 to 1/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpi(x: f64) -> f64 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+    let ax = fabs(x);
+    /* asinpi(|x|>1) is NaN */
+    if ax > 1.0 {
+        return (x - x) / (x - x);
+    }
+
+    // asin(x) = atan(x / sqrt(1 - x^2)), with the quotient kept to double-double precision
+    let (t, t_lo) = sqrt_one_minus_sq(ax);
+    let z = if t == 0.0 {
+        0.5
+    } else {
+        let (q, q_lo) = div_dd(ax, 0.0, t, t_lo);
+        let (p, e) = k_atanpi(q, q_lo);
+        p + e
+    };
+
+    if x.is_sign_negative() { -z } else { z }
+}
+
+/// Arcsine divided by π (f128)
+///
+/// Computes `asin(x) / π`, the inverse sine measured in half-turns. Arguments must be in the
+/// range -1 to 1. Returns a value in the range of -1/2 to 1/2.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpif128(x: f128) -> f128 {
+    use super::k_atan2f128;
+    use super::support::WideFloat;
+
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+    /* asinpi(|x|>1) is NaN */
+    if x.abs() > 1.0 {
+        return (x - x) / (x - x);
+    }
+
+    // asin(x) = atan2(x, sqrt(1 - x^2)), where `1 - x^2` is computed without cancellation
+    let w = WideFloat::from_f128(x);
+    let c = ((WideFloat::ONE - w) * (WideFloat::ONE + w)).sqrt();
+    (k_atan2f128(w, c) / WideFloat::PI).to_f128()
+}
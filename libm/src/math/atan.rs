@@ -67,7 +67,6 @@ const AT: [f64; 11] = [
 /// Returns a value in radians, in the range of -pi/2 to pi/2.
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn atan(x: f64) -> f64 {
-    let mut x = x;
     let mut ix = (x.to_bits() >> 32) as u32;
     let sign = ix >> 31;
     ix &= 0x7fff_ffff;
@@ -80,41 +79,50 @@ pub fn atan(x: f64) -> f64 {
         return if sign != 0 { -z } else { z };
     }
 
-    let id = if ix < 0x3fdc_0000 {
-        /* |x| < 0.4375 */
-        if ix < 0x3e40_0000 {
-            /* |x| < 2^-27 */
-            if ix < 0x0010_0000 {
-                /* raise underflow for subnormal x */
-                force_eval!(x as f32);
-            }
-
-            return x;
+    if ix < 0x3e40_0000 {
+        /* |x| < 2^-27 */
+        if ix < 0x0010_0000 {
+            /* raise underflow for subnormal x */
+            force_eval!(x as f32);
         }
 
+        return x;
+    }
+
+    let (hi, lo) = k_atan(x, ix);
+    let z = hi + lo;
+
+    if sign != 0 { -z } else { z }
+}
+
+/// `atan(|x|)` as the unevaluated sum `hi + lo`, for `2^-27 <= |x| < 2^66`.
+///
+/// `ix` is the high word of `|x|`. `lo` is not normalized against `hi`, but the sum is accurate
+/// to well below an ulp of the result.
+pub(crate) fn k_atan(x: f64, ix: u32) -> (f64, f64) {
+    let mut x = fabs(x);
+    let id = if ix < 0x3fdc_0000 {
+        /* |x| < 0.4375 */
         -1
-    } else {
-        x = fabs(x);
-        if ix < 0x3ff30000 {
-            /* |x| < 1.1875 */
-            if ix < 0x3fe60000 {
-                /* 7/16 <= |x| < 11/16 */
-                x = (2. * x - 1.) / (2. + x);
-                0
-            } else {
-                /* 11/16 <= |x| < 19/16 */
-                x = (x - 1.) / (x + 1.);
-                1
-            }
-        } else if ix < 0x40038000 {
-            /* |x| < 2.4375 */
-            x = (x - 1.5) / (1. + 1.5 * x);
-            2
+    } else if ix < 0x3ff30000 {
+        /* |x| < 1.1875 */
+        if ix < 0x3fe60000 {
+            /* 7/16 <= |x| < 11/16 */
+            x = (2. * x - 1.) / (2. + x);
+            0
         } else {
-            /* 2.4375 <= |x| < 2^66 */
-            x = -1. / x;
-            3
+            /* 11/16 <= |x| < 19/16 */
+            x = (x - 1.) / (x + 1.);
+            1
         }
+    } else if ix < 0x40038000 {
+        /* |x| < 2.4375 */
+        x = (x - 1.5) / (1. + 1.5 * x);
+        2
+    } else {
+        /* 2.4375 <= |x| < 2^66 */
+        x = -1. / x;
+        3
     };
 
     let z = x * x;
@@ -124,12 +132,10 @@ pub fn atan(x: f64) -> f64 {
     let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
 
     if id < 0 {
-        return x - x * (s1 + s2);
+        return (x, -(x * (s1 + s2)));
     }
 
-    let z = i!(ATANHI, id as usize) - (x * (s1 + s2) - i!(ATANLO, id as usize) - x);
-
-    if sign != 0 { -z } else { z }
+    (i!(ATANHI, id as usize), -(x * (s1 + s2) - i!(ATANLO, id as usize) - x))
 }
 
 #[cfg(test)]
@@ -0,0 +1,36 @@
+use super::k_atan2f128;
+use super::support::WideFloat;
+
+/// Arctangent of y/x (f128)
+///
+/// Computes the inverse tangent (arc tangent) of `y/x`. Produces the correct result even for
+/// angles near pi/2 or -pi/2 (that is, when `x` is near 0). Returns a value in radians, in the
+/// range of -pi to pi.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2f128(y: f128, x: f128) -> f128 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    /* when y = 0 */
+    if y == 0.0 {
+        return if x.is_sign_negative() {
+            /* atan(+-0,-anything) = +-pi */
+            WideFloat::PI.to_f128().copysign(y)
+        } else {
+            /* atan(+-0,+anything) = +-0 */
+            y
+        };
+    }
+
+    // Infinities behave like a unit vector along their axis, e.g. `atan2(inf, -inf) = 3pi/4` is
+    // the same as `atan2(1, -1)`, and finite values beside an infinity act like zero.
+    let (y, x) = if x.is_infinite() || y.is_infinite() {
+        let to_unit = |v: f128| if v.is_infinite() { v.signum() } else { 0.0f128.copysign(v) };
+        (to_unit(y), to_unit(x))
+    } else {
+        (y, x)
+    };
+
+    k_atan2f128(WideFloat::from_f128(y), WideFloat::from_f128(x)).to_f128()
+}
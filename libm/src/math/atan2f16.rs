@@ -0,0 +1,9 @@
+/// Arctangent of y/x (f16)
+///
+/// Computes the inverse tangent (arc tangent) of `y/x`. Produces the correct result even for
+/// angles near pi/2 or -pi/2 (that is, when `x` is near 0). Returns a value in radians, in the
+/// range of -pi to pi.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2f16(y: f16, x: f16) -> f16 {
+    super::atan2(y as f64, x as f64) as f16
+}
@@ -0,0 +1,127 @@
+use super::atanpi::{k_atanpi, one_minus};
+use super::{fabs, fma};
+
+/// Arctangent of y/x divided by π (f16)
+///
+/// Computes `atan2(y, x) / π`, the angle of the point `(x, y)` measured in half-turns. Returns
+/// a value in the range of -1 to 1.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pif16(y: f16, x: f16) -> f16 {
+    atan2pi(y as f64, x as f64) as f16
+}
+
+/// Arctangent of y/x divided by π (f32)
+///
+/// Computes `atan2(y, x) / π`, the angle of the point `(x, y)` measured in half-turns. Returns
+/// a value in the range of -1 to 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pif(y: f32, x: f32) -> f32 {
+    atan2pi(y as f64, x as f64) as f32
+}
+
+/// Arctangent of y/x divided by π (f64)
+///
+/// Computes `atan2(y, x) / π`, the angle of the point `(x, y)` measured in half-turns. Returns
+/// a value in the range of -1 to 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pi(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    let mut ix = (x.to_bits() >> 32) as u32;
+    let lx = x.to_bits() as u32;
+    let mut iy = (y.to_bits() >> 32) as u32;
+    let ly = y.to_bits() as u32;
+    let m = ((iy >> 31) & 1) | ((ix >> 30) & 2); /* 2*sign(x)+sign(y) */
+    ix &= 0x7fffffff;
+    iy &= 0x7fffffff;
+
+    /* when y = 0 */
+    if (iy | ly) == 0 {
+        return match m {
+            0 | 1 => y, /* atan2pi(+-0,+anything)=+-0 */
+            2 => 1.0,   /* atan2pi(+0,-anything) = 1 */
+            _ => -1.0,  /* atan2pi(-0,-anything) =-1 */
+        };
+    }
+    /* when x = 0 */
+    if (ix | lx) == 0 {
+        return if m & 1 != 0 { -0.5 } else { 0.5 };
+    }
+    /* when x is INF */
+    if ix == 0x7ff00000 {
+        if iy == 0x7ff00000 {
+            return match m {
+                0 => 0.25,  /* atan2pi(+INF,+INF) */
+                1 => -0.25, /* atan2pi(-INF,+INF) */
+                2 => 0.75,  /* atan2pi(+INF,-INF) */
+                _ => -0.75, /* atan2pi(-INF,-INF) */
+            };
+        } else {
+            return match m {
+                0 => 0.0,  /* atan2pi(+...,+INF) */
+                1 => -0.0, /* atan2pi(-...,+INF) */
+                2 => 1.0,  /* atan2pi(+...,-INF) */
+                _ => -1.0, /* atan2pi(-...,-INF) */
+            };
+        }
+    }
+    /* |y/x| > 0x1p64 */
+    if ix.wrapping_add(64 << 20) < iy || iy == 0x7ff00000 {
+        return if m & 1 != 0 { -0.5 } else { 0.5 };
+    }
+    /* |y/x| < 0x1p-64, x<0 */
+    if (m & 2 != 0) && iy.wrapping_add(64 << 20) < ix {
+        return if m & 1 != 0 { -1.0 } else { 1.0 };
+    }
+
+    /* atan(|y/x|) / π, keeping the rounding error of the quotient */
+    let (ay, ax) = (fabs(y), fabs(x));
+    let qh = ay / ax;
+    let ql = fma(-qh, ax, ay) / ax;
+    let (p, e) = k_atanpi(qh, ql);
+    match m {
+        0 => p + e,            /* atan2pi(+,+) */
+        1 => -(p + e),         /* atan2pi(-,+) */
+        2 => one_minus(p, e),  /* atan2pi(+,-) */
+        _ => -one_minus(p, e), /* atan2pi(-,-) */
+    }
+}
+
+/// Arctangent of y/x divided by π (f128)
+///
+/// Computes `atan2(y, x) / π`, the angle of the point `(x, y)` measured in half-turns. Returns
+/// a value in the range of -1 to 1.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pif128(y: f128, x: f128) -> f128 {
+    use super::k_atan2f128;
+    use super::support::WideFloat;
+
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    /* when y = 0 */
+    if y == 0.0 {
+        return if x.is_sign_negative() {
+            /* atan2pi(+-0,-anything) = +-1 */
+            1.0f128.copysign(y)
+        } else {
+            /* atan2pi(+-0,+anything) = +-0 */
+            y
+        };
+    }
+
+    // Infinities behave like a unit vector along their axis, as in `atan2f128`.
+    let (y, x) = if x.is_infinite() || y.is_infinite() {
+        let to_unit = |v: f128| if v.is_infinite() { v.signum() } else { 0.0f128.copysign(v) };
+        (to_unit(y), to_unit(x))
+    } else {
+        (y, x)
+    };
+
+    let a = k_atan2f128(WideFloat::from_f128(y), WideFloat::from_f128(x));
+    (a / WideFloat::PI).to_f128()
+}
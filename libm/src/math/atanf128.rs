@@ -0,0 +1,18 @@
+use super::k_atan2f128;
+use super::support::WideFloat;
+
+/// Arctangent (f128)
+///
+/// Computes the inverse tangent (arc tangent) of the input value. Returns a value in radians, in
+/// the range of -pi/2 to pi/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanf128(x: f128) -> f128 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+
+    // atan(+-inf) = +-pi/2
+    let y = if x.is_infinite() { x.signum() } else { x };
+    let x = if x.is_infinite() { 0.0 } else { 1.0 };
+    k_atan2f128(WideFloat::from_f128(y), WideFloat::from_f128(x)).to_f128()
+}
@@ -0,0 +1,8 @@
+/// Arctangent (f16)
+///
+/// Computes the inverse tangent (arc tangent) of the input value. Returns a value in radians, in
+/// the range of -pi/2 to pi/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanf16(x: f16) -> f16 {
+    super::atan(x as f64) as f16
+}
@@ -0,0 +1,31 @@
+use super::support::WideFloat;
+use super::{k_log1pf128, k_logf128};
+
+/// Inverse hyperbolic tangent (f128)
+///
+/// Calculates the inverse hyperbolic tangent of `x`. Is defined as `log((1+x)/(1-x))/2`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanhf128(x: f128) -> f128 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+    if x.abs() > 1.0 {
+        return (x - x) / (x - x); /* atanh(|x|>1) = NaN */
+    }
+    if x.abs() == 1.0 {
+        return x / 0.0; /* atanh(+-1) = +-inf */
+    }
+
+    // atanh(|x|) = log1p(2|x|/(1-|x|)) / 2
+    let w = WideFloat::from_f128(x).abs();
+    let f = w.mul_pow2(1) / (WideFloat::ONE - w);
+    let r = if w.exp() < -3 {
+        k_log1pf128(f)
+    } else {
+        let (e, t) = k_logf128(WideFloat::ONE + f);
+        WideFloat::from_i32(e) * WideFloat::LN2 + t
+    };
+
+    let r = r.mul_pow2(-1).to_f128();
+    if x < 0.0 { -r } else { r }
+}
@@ -0,0 +1,7 @@
+/// Inverse hyperbolic tangent (f16)
+///
+/// Calculates the inverse hyperbolic tangent of `x`. Is defined as `log((1+x)/(1-x))/2`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanhf16(x: f16) -> f16 {
+    super::atanh(x as f64) as f16
+}
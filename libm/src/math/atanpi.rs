@@ -0,0 +1,97 @@
+use super::atan::k_atan;
+use super::{fabs, fma};
+
+/// 1/π split into a head and a tail.
+const INV_PI_HI: f64 = core::f64::consts::FRAC_1_PI; /* 0x3FD45F30, 0x6DC9C883 */
+const INV_PI_LO: f64 = -1.96786766751824862313e-17; /* 0xBC76B01E, 0xC5417056 */
+
+/// `(hi + lo) / π` as an unevaluated sum.
+fn mul_inv_pi(hi: f64, lo: f64) -> (f64, f64) {
+    let p = hi * INV_PI_HI;
+    let e = fma(hi, INV_PI_HI, -p) + (hi * INV_PI_LO + lo * INV_PI_HI);
+    (p, e)
+}
+
+/// `atan(qh + ql) / π` as an unevaluated sum, for finite `qh >= 0` and `|ql|` below an ulp of
+/// `qh`. Carrying `ql` lets callers pass in a quotient without rounding it first.
+pub(crate) fn k_atanpi(qh: f64, ql: f64) -> (f64, f64) {
+    let ix = (qh.to_bits() >> 32) as u32;
+    if ix < 0x3e40_0000 {
+        /* |q| < 2^-27, atan(q) = q to working precision */
+        return mul_inv_pi(qh, ql);
+    }
+
+    /* atan(qh + ql) = atan(qh) + ql / (1 + qh^2) to first order */
+    let (hi, lo) = k_atan(qh, ix);
+    mul_inv_pi(hi, lo + ql / (1.0 + qh * qh))
+}
+
+/// `1 - (p + e)` for `0 <= p <= 1/2`, where `p + e` is a result from `k_atanpi`.
+pub(crate) fn one_minus(p: f64, e: f64) -> f64 {
+    /* `1 - p` with its exact rounding error, valid since `|p| <= 1` */
+    let s = 1.0 - p;
+    let err = (1.0 - s) - p;
+    s + (err - e)
+}
+
+/// Arctangent divided by π (f16)
+///
+/// Computes `atan(x) / π`, the inverse tangent measured in half-turns. Returns a value in the
+/// range of -1/2 to 1/2.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpif16(x: f16) -> f16 {
+    atanpi(x as f64) as f16
+}
+
+/// Arctangent divided by π (f32)
+///
+/// Computes `atan(x) / π`, the inverse tangent measured in half-turns. Returns a value in the
+/// range of -1/2 to 1/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpif(x: f32) -> f32 {
+    atanpi(x as f64) as f32
+}
+
+/// Arctangent divided by π (f64)
+///
+/// Computes `atan(x) / π`, the inverse tangent measured in half-turns. Returns a value in the
+/// range of -1/2 to 1/2.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpi(x: f64) -> f64 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+
+    let ix = (x.to_bits() >> 32) as u32 & 0x7fff_ffff;
+    let z = if ix >= 0x4410_0000 {
+        /* |x| >= 2^66, atan(x) / π rounds to 1/2 */
+        0.5
+    } else {
+        let (p, e) = k_atanpi(fabs(x), 0.0);
+        p + e
+    };
+
+    if x.is_sign_negative() { -z } else { z }
+}
+
+/// Arctangent divided by π (f128)
+///
+/// Computes `atan(x) / π`, the inverse tangent measured in half-turns. Returns a value in the
+/// range of -1/2 to 1/2.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpif128(x: f128) -> f128 {
+    use super::k_atan2f128;
+    use super::support::WideFloat;
+
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+
+    // atanpi(+-inf) = +-1/2
+    let y = if x.is_infinite() { x.signum() } else { x };
+    let x = if x.is_infinite() { 0.0 } else { 1.0 };
+    let a = k_atan2f128(WideFloat::from_f128(y), WideFloat::from_f128(x));
+    (a / WideFloat::PI).to_f128()
+}
@@ -0,0 +1,63 @@
+/// Return the canonical encoding of `x`.
+///
+/// All encodings of these types are canonical, so this returns `x` unchanged unless it is a
+/// signaling NaN, which is converted to a quiet NaN with the same sign and payload.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn canonicalizef16(x: f16) -> f16 {
+    super::generic::canonicalize(x).val
+}
+
+/// Return the canonical encoding of `x`.
+///
+/// All encodings of these types are canonical, so this returns `x` unchanged unless it is a
+/// signaling NaN, which is converted to a quiet NaN with the same sign and payload.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn canonicalizef(x: f32) -> f32 {
+    super::generic::canonicalize(x).val
+}
+
+/// Return the canonical encoding of `x`.
+///
+/// All encodings of these types are canonical, so this returns `x` unchanged unless it is a
+/// signaling NaN, which is converted to a quiet NaN with the same sign and payload.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn canonicalize(x: f64) -> f64 {
+    super::generic::canonicalize(x).val
+}
+
+/// Return the canonical encoding of `x`.
+///
+/// All encodings of these types are canonical, so this returns `x` unchanged unless it is a
+/// signaling NaN, which is converted to a quiet NaN with the same sign and payload.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn canonicalizef128(x: f128) -> f128 {
+    super::generic::canonicalize(x).val
+}
+
+/// Return 1 if `x` is a signaling NaN, 0 otherwise.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn issignalingf16(x: f16) -> i32 {
+    super::generic::issignaling(x).into()
+}
+
+/// Return 1 if `x` is a signaling NaN, 0 otherwise.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn issignalingf(x: f32) -> i32 {
+    super::generic::issignaling(x).into()
+}
+
+/// Return 1 if `x` is a signaling NaN, 0 otherwise.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn issignaling(x: f64) -> i32 {
+    super::generic::issignaling(x).into()
+}
+
+/// Return 1 if `x` is a signaling NaN, 0 otherwise.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn issignalingf128(x: f128) -> i32 {
+    super::generic::issignaling(x).into()
+}
@@ -14,6 +14,10 @@ pub fn cbrt(x: f64) -> f64 {
 }
 
 pub fn cbrt_round(x: f64, round: Round) -> FpResult<f64> {
+    // The cube root of a float is never halfway between two floats, so ties away from zero
+    // produces the same results as ties to even.
+    let round = if round == Round::NearestAway { Round::Nearest } else { round };
+
     const ESCALE: [f64; 3] = [
         1.0,
         hf64!("0x1.428a2f98d728bp+0"), /* 2^(1/3) */
@@ -212,4 +216,14 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn nearest_away() {
+        let inputs = [0.0, -0.0, 1.0, -8.0, 2.0, 1e-310, -3.5e300, f64::INFINITY];
+        for x in
+            inputs.into_iter().chain([0x3ff0000000000001, 0xf7f792b28f600000].map(f64::from_bits))
+        {
+            assert_biteq!(cbrt_round(x, Round::NearestAway).val, cbrt(x));
+        }
+    }
 }
@@ -0,0 +1,12 @@
+use super::support::WideFloat;
+
+/// Compute the cube root of the argument (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cbrtf128(x: f128) -> f128 {
+    if x.is_nan() || x.is_infinite() || x == 0.0 {
+        return x + x;
+    }
+
+    let r = WideFloat::from_f128(x).abs().cbrt().to_f128();
+    if x < 0.0 { -r } else { r }
+}
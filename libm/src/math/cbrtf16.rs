@@ -0,0 +1,5 @@
+/// Compute the cube root of the argument (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cbrtf16(x: f16) -> f16 {
+    super::cbrt(x as f64) as f16
+}
@@ -0,0 +1,58 @@
+use super::pown::{pow_dd, pow1p_large};
+use super::{log1p, scalbn};
+
+const HUGE: f64 = 1.0e300;
+const TINY: f64 = 1.0e-300;
+
+/// Compound interest: `1+x` raised to the integer power `n` (f32).
+///
+/// This is NaN for `x < -1`. `compoundnf(x, 0)` is 1 for any other `x`, including NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundnf(x: f32, n: i64) -> f32 {
+    compoundn(x as f64, n) as f32
+}
+
+/// Compound interest: `1+x` raised to the integer power `n` (f64).
+///
+/// This is NaN for `x < -1`. `compoundn(x, 0)` is 1 for any other `x`, including NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundn(x: f64, n: i64) -> f64 {
+    if x < -1.0 {
+        return (x - x) / 0.0;
+    }
+    if n == 0 {
+        return 1.0;
+    }
+    if x.is_nan() {
+        return x + x;
+    }
+    if x == -1.0 {
+        /* +0 for n > 0, +inf for n < 0 */
+        let z = x + 1.0;
+        return if n > 0 { z } else { 1.0 / z };
+    }
+    if x.is_infinite() {
+        return if n > 0 { x } else { 0.0 };
+    }
+
+    /* estimate log2 of the result to catch overflow and underflow early */
+    let lg = log1p(x) * core::f64::consts::LOG2_E * n as f64;
+    if lg > 1100.0 {
+        return HUGE * HUGE;
+    }
+    if lg < -1200.0 {
+        return TINY * TINY;
+    }
+
+    if n.unsigned_abs() > 1 << 40 {
+        return pow1p_large(x, n);
+    }
+
+    /* 1 + x exactly as hi + lo */
+    let hi = 1.0 + x;
+    let b = hi - 1.0;
+    let lo = (1.0 - (hi - b)) + (x - b);
+
+    let (h, l, e) = pow_dd(hi, lo, n);
+    scalbn(h + l, e as i32)
+}
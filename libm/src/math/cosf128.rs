@@ -0,0 +1,21 @@
+use super::{k_cosf128, k_sinf128, rem_pio2f128};
+
+/// The cosine of `x` (f128).
+///
+/// `x` is specified in radians.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosf128(x: f128) -> f128 {
+    /* cos(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_pio2f128(x);
+    let res = match n & 3 {
+        0 => k_cosf128(r),
+        1 => -k_sinf128(r),
+        2 => -k_cosf128(r),
+        _ => k_sinf128(r),
+    };
+    res.to_f128()
+}
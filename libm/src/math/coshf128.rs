@@ -0,0 +1,27 @@
+use super::support::WideFloat;
+use super::{k_expf128, k_expm1f128};
+
+/// The hyperbolic cosine of `x` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn coshf128(x: f128) -> f128 {
+    if x.is_nan() {
+        return x + x;
+    }
+
+    /* cosh(x) overflows for |x| > log(2 * MAX) ~= 11357.2 */
+    if x.abs() > 11358.0 {
+        return f128::INFINITY;
+    }
+
+    let w = WideFloat::from_f128(x).abs();
+    let r = if w.is_zero() || w.exp() < 0 {
+        // cosh(x) = 1 + t^2 / (2(t+1)) with t = expm1(|x|)
+        let t = k_expm1f128(w);
+        WideFloat::ONE + (t * t) / (t + WideFloat::ONE).mul_pow2(1)
+    } else {
+        let e = k_expf128(w);
+        (e + WideFloat::ONE / e).mul_pow2(-1)
+    };
+
+    r.to_f128()
+}
@@ -0,0 +1,5 @@
+/// The hyperbolic cosine of `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn coshf16(x: f16) -> f16 {
+    super::cosh(x as f64) as f16
+}
@@ -0,0 +1,101 @@
+use super::generic::rem_half;
+use super::sinpi::mul_pi;
+use super::{k_cos, k_cosf, k_sin, k_sinf};
+
+/// The cosine of `πx` (f16).
+///
+/// This is exact at multiples of `1/2`, with `+0` returned at the odd multiples.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospif16(x: f16) -> f16 {
+    cospi(x as f64) as f16
+}
+
+/// The cosine of `πx` (f32).
+///
+/// This is exact at multiples of `1/2`, with `+0` returned at the odd multiples.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospif(x: f32) -> f32 {
+    /* cospi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 => 1.0,
+            2 => -1.0,
+            _ => 0.0,
+        };
+    }
+
+    let y = core::f64::consts::PI * r as f64;
+    match n {
+        0 => k_cosf(y),
+        1 => -k_sinf(y),
+        2 => -k_cosf(y),
+        _ => k_sinf(y),
+    }
+}
+
+/// The cosine of `πx` (f64).
+///
+/// This is exact at multiples of `1/2`, with `+0` returned at the odd multiples.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospi(x: f64) -> f64 {
+    /* cospi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 => 1.0,
+            2 => -1.0,
+            _ => 0.0,
+        };
+    }
+
+    let (y0, y1) = mul_pi(r);
+    match n {
+        0 => k_cos(y0, y1),
+        1 => -k_sin(y0, y1, 1),
+        2 => -k_cos(y0, y1),
+        _ => k_sin(y0, y1, 1),
+    }
+}
+
+/// The cosine of `πx` (f128).
+///
+/// This is exact at multiples of `1/2`, with `+0` returned at the odd multiples.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospif128(x: f128) -> f128 {
+    use super::support::WideFloat;
+    use super::{k_cosf128, k_sinf128};
+
+    /* cospi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 => 1.0,
+            2 => -1.0,
+            _ => 0.0,
+        };
+    }
+
+    let y = WideFloat::PI * WideFloat::from_f128(r);
+    let res = match n {
+        0 => k_cosf128(y),
+        1 => -k_sinf128(y),
+        2 => -k_cosf128(y),
+        _ => k_sinf128(y),
+    };
+    res.to_f128()
+}
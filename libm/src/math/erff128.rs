@@ -0,0 +1,98 @@
+use super::k_expf128;
+use super::support::WideFloat;
+
+/// Below this, `erf` is summed directly and `erfc` is computed as `1 - erf`, losing at most about
+/// 55 of the 256 working bits.
+const SERIES_MAX: f128 = 6.0;
+
+/// `erf(x)` for `0 <= x < SERIES_MAX`.
+fn erf_series(x: WideFloat) -> WideFloat {
+    // erf(x) = 2/sqrt(pi) * e^(-x^2) * sum(x * (2x^2)^n / (2n+1)!!), which has only positive
+    // terms so there is no cancellation.
+    let x2 = (x * x).mul_pow2(1);
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1;
+    loop {
+        n += 2;
+        term = (term * x2).div_small(n);
+        if term.is_zero() || term.exp() < sum.exp() - 260 {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    WideFloat::FRAC_2_SQRT_PI * k_expf128(-(x * x)) * sum
+}
+
+/// `erfc(x)` for `SERIES_MAX <= x < 2^10`.
+fn erfc_cf(x: WideFloat) -> WideFloat {
+    // erfc(x) = e^(-x^2)/sqrt(pi) / (x + (1/2)/(x + 1/(x + (3/2)/(x + ...)))), evaluated from the
+    // bottom. 150 levels are enough for full precision when `x >= SERIES_MAX`.
+    let mut t = x;
+    for k in (1..=150).rev() {
+        t = x + WideFloat::from_i32(k).mul_pow2(-1) / t;
+    }
+
+    (WideFloat::FRAC_2_SQRT_PI * k_expf128(-(x * x))).mul_pow2(-1) / t
+}
+
+/// Error function (f128)
+///
+/// Calculates an approximation to the “error function”, which estimates
+/// the probability that an observation will fall within x standard
+/// deviations of the mean (assuming a normal distribution).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn erff128(x: f128) -> f128 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+
+    /* erf(x) rounds to +-1 once erfc(|x|) < 2^-114, i.e. for |x| > 8.9 */
+    let r = if x.abs() >= 10.0 {
+        1.0
+    } else {
+        let w = WideFloat::from_f128(x).abs();
+        if x.abs() < SERIES_MAX {
+            erf_series(w).to_f128()
+        } else {
+            (WideFloat::ONE - erfc_cf(w)).to_f128()
+        }
+    };
+
+    if x < 0.0 { -r } else { r }
+}
+
+/// Complementary error function (f128)
+///
+/// Calculates the complementary probability.
+/// Is `1 - erf(x)`. Is computed directly, so that you can use it to avoid
+/// the loss of precision that would result from subtracting
+/// large probabilities (on large `x`) from 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn erfcf128(x: f128) -> f128 {
+    if x.is_nan() {
+        return x + x;
+    }
+
+    /* erfc(x) underflows for x > 106.6 and rounds to 2 for x < -10 */
+    if x > 110.0 {
+        return 0.0;
+    }
+    if x <= -10.0 {
+        return 2.0;
+    }
+
+    let w = WideFloat::from_f128(x);
+    let r = if x <= -SERIES_MAX {
+        WideFloat::TWO - erfc_cf(w.abs())
+    } else if x < 0.0 {
+        WideFloat::ONE + erf_series(w.abs())
+    } else if x < SERIES_MAX {
+        WideFloat::ONE - erf_series(w)
+    } else {
+        erfc_cf(w)
+    };
+
+    r.to_f128()
+}
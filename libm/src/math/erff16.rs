@@ -0,0 +1,20 @@
+/// Error function (f16)
+///
+/// Calculates an approximation to the “error function”, which estimates
+/// the probability that an observation will fall within x standard
+/// deviations of the mean (assuming a normal distribution).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn erff16(x: f16) -> f16 {
+    super::erf(x as f64) as f16
+}
+
+/// Complementary error function (f16)
+///
+/// Calculates the complementary probability.
+/// Is `1 - erf(x)`. Is computed directly, so that you can use it to avoid
+/// the loss of precision that would result from subtracting
+/// large probabilities (on large `x`) from 1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn erfcf16(x: f16) -> f16 {
+    super::erfc(x as f64) as f16
+}
@@ -0,0 +1,5 @@
+/// Calculates 10 raised to the power of `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10f16(x: f16) -> f16 {
+    super::exp10(x as f64) as f16
+}
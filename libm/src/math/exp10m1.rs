@@ -0,0 +1,35 @@
+use super::exp2m1::expm1_dd;
+use super::fma;
+
+const LN10: f64 = core::f64::consts::LN_10; /* 0x40026bb1, 0xbbb55516 */
+const LN10_LO: f64 = -2.17075622338224935076e-16; /* 0xbcaf48ad, 0x494ea3e9 */
+
+/// 10 raised to the power of `x`, minus 1 (f32).
+///
+/// Unlike `exp10(x) - 1`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10m1f(x: f32) -> f32 {
+    exp10m1(x as f64) as f32
+}
+
+/// 10 raised to the power of `x`, minus 1 (f64).
+///
+/// Unlike `exp10(x) - 1`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10m1(x: f64) -> f64 {
+    if x == 0.0 || x.is_nan() {
+        return x + x;
+    }
+    /* 10^x is below half an ulp of 1 for x < -18, and overflows for x > 310 */
+    if x < -18.0 {
+        return -1.0;
+    }
+    if x > 310.0 {
+        return x * f64::MAX;
+    }
+
+    /* x*ln(10) as hi + lo */
+    let hi = x * LN10;
+    let lo = fma(x, LN10, -hi) + x * LN10_LO;
+    expm1_dd(hi, lo)
+}
@@ -0,0 +1,25 @@
+use super::k_expm1f128;
+use super::support::WideFloat;
+
+/// Exponential, base 2 (f128).
+///
+/// Calculate `2^x`, that is, 2 raised to the power `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2f128(x: f128) -> f128 {
+    if x.is_nan() {
+        return x + x;
+    }
+
+    if x >= 16384.0 {
+        return f128::INFINITY;
+    }
+    if x < -16496.0 {
+        return 0.0;
+    }
+
+    // 2^x = 2^k * e^(r*ln2) where k is an integer, and the reduction is exact.
+    let x = WideFloat::from_f128(x);
+    let k = x.round_int();
+    let r = x - WideFloat::from_i32(k);
+    (WideFloat::ONE + k_expm1f128(r * WideFloat::LN2)).mul_pow2(k).to_f128()
+}
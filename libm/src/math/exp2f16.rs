@@ -0,0 +1,5 @@
+/// Exponential, base 2 (f16). Calculate `2^x`, that is, 2 raised to the power `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2f16(x: f16) -> f16 {
+    super::exp2(x as f64) as f16
+}
@@ -0,0 +1,41 @@
+use super::{expm1, fma};
+
+const LN2: f64 = core::f64::consts::LN_2; /* 0x3fe62e42, 0xfefa39ef */
+const LN2_LO: f64 = 2.31904681384629955842e-17; /* 0x3c7abc9e, 0x3b39803f */
+
+/// `expm1(hi + lo)` for `|lo|` below an ulp of `hi`.
+pub(crate) fn expm1_dd(hi: f64, lo: f64) -> f64 {
+    let e = expm1(hi);
+    if !e.is_finite() {
+        return e;
+    }
+
+    /* expm1(hi + lo) = expm1(hi) + lo * exp(hi) to first order */
+    e + lo * (1.0 + e)
+}
+
+/// 2 raised to the power of `x`, minus 1 (f32).
+///
+/// Unlike `exp2(x) - 1`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1f(x: f32) -> f32 {
+    exp2m1(x as f64) as f32
+}
+
+/// 2 raised to the power of `x`, minus 1 (f64).
+///
+/// Unlike `exp2(x) - 1`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1(x: f64) -> f64 {
+    if x == 0.0 || x.is_nan() {
+        return x + x;
+    }
+    if x.is_infinite() {
+        return if x > 0.0 { x } else { -1.0 };
+    }
+
+    /* x*ln(2) as hi + lo */
+    let hi = x * LN2;
+    let lo = fma(x, LN2, -hi) + x * LN2_LO;
+    expm1_dd(hi, lo)
+}
@@ -0,0 +1,20 @@
+use super::k_expf128;
+use super::support::WideFloat;
+
+/// The exponential of `x`, that is, *e* raised to the power `x` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf128(x: f128) -> f128 {
+    if x.is_nan() {
+        return x + x;
+    }
+
+    // Beyond these bounds the result overflows or underflows to zero
+    if x > 11357.0 {
+        return f128::INFINITY;
+    }
+    if x < -11434.0 {
+        return 0.0;
+    }
+
+    k_expf128(WideFloat::from_f128(x)).to_f128()
+}
@@ -0,0 +1,7 @@
+/// The exponential of `x`, that is, *e* raised to the power `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf16(x: f16) -> f16 {
+    // The `f64` result is within an ulp of the exact value, far closer than the spacing between
+    // `f16` rounding boundaries, so narrowing rounds correctly. This is checked exhaustively.
+    super::exp(x as f64) as f16
+}
@@ -0,0 +1,29 @@
+use super::support::WideFloat;
+use super::{k_expf128, k_expm1f128};
+
+/// Exponential, base *e*, of `x`-1 (f128).
+///
+/// The result is accurate even for small values of `x`, where using `exp(x)-1` would lose many
+/// significant digits.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expm1f128(x: f128) -> f128 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+
+    if x > 11357.0 {
+        return f128::INFINITY;
+    }
+    /* e^x is below half an ulp of 1 */
+    if x < -80.0 {
+        return -1.0;
+    }
+
+    let w = WideFloat::from_f128(x);
+    if w.exp() < 0 {
+        k_expm1f128(w).to_f128()
+    } else {
+        // |x| >= 1 so |e^x - 1| >= 0.63 and the subtraction loses at most two bits
+        (k_expf128(w) - WideFloat::ONE).to_f128()
+    }
+}
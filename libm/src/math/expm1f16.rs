@@ -0,0 +1,8 @@
+/// Exponential, base *e*, of `x`-1 (f16).
+///
+/// The result is accurate even for small values of `x`, where using `exp(x)-1` would lose many
+/// significant digits.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expm1f16(x: f16) -> f16 {
+    super::expm1(x as f64) as f16
+}
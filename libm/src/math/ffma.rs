@@ -0,0 +1,47 @@
+use super::support::{FpResult, Round};
+
+/// Compute `(x * y) + z`, rounding the result once to `f16`.
+///
+/// This is not the same as computing in `f32` and then converting, which rounds twice.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f16fmaf32(x: f32, y: f32, z: f32) -> f16 {
+    super::generic::ffma_round(x, y, z, Round::Nearest).val
+}
+
+/// Compute `(x * y) + z`, rounding the result once to `f16` in the direction given by `round`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f16fmaf32_round(x: f32, y: f32, z: f32, round: Round) -> FpResult<f16> {
+    super::generic::ffma_round(x, y, z, round)
+}
+
+/// Compute `(x * y) + z`, rounding the result once to `f32`.
+///
+/// This is not the same as computing in `f64` and then converting, which rounds twice.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ffma(x: f64, y: f64, z: f64) -> f32 {
+    super::generic::ffma_round(x, y, z, Round::Nearest).val
+}
+
+/// Compute `(x * y) + z`, rounding the result once to `f32` in the direction given by `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ffma_round(x: f64, y: f64, z: f64, round: Round) -> FpResult<f32> {
+    super::generic::ffma_round(x, y, z, round)
+}
+
+/// Compute `(x * y) + z`, rounding the result once to `f64`.
+///
+/// This is not the same as computing in `f128` and then converting, which rounds twice.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f64fmaf128(x: f128, y: f128, z: f128) -> f64 {
+    super::generic::ffma_round(x, y, z, Round::Nearest).val
+}
+
+/// Compute `(x * y) + z`, rounding the result once to `f64` in the direction given by `round`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f64fmaf128_round(x: f128, y: f128, z: f128, round: Round) -> FpResult<f64> {
+    super::generic::ffma_round(x, y, z, round)
+}
@@ -1,15 +1,26 @@
 /* SPDX-License-Identifier: MIT */
 /* origin: musl src/math/fmaf.c. Ported to generic Rust algorithm in 2025, TG. */
 
+use super::generic::NarrowHelper;
 use super::support::{FpResult, IntTy, Round, Status};
 use super::{CastFrom, CastInto, DFloat, Float, HFloat, MinInt};
 
-// Placeholder so we can have `fmaf16` in the `Float` trait.
-#[allow(unused)]
+/// Floating multiply add (f16)
+///
+/// Computes `(x*y)+z`, rounded as one ternary operation (i.e. calculated with infinite precision).
 #[cfg(f16_enabled)]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
-pub(crate) fn fmaf16(_x: f16, _y: f16, _z: f16) -> f16 {
-    unimplemented!()
+pub fn fmaf16(x: f16, y: f16, z: f16) -> f16 {
+    fma_wide_round(x, y, z, Round::Nearest).val
+}
+
+/// Floating multiply add (f16) with rounding mode `round`.
+///
+/// Computes `(x*y)+z`, rounded once in the direction given by `round`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf16_round(x: f16, y: f16, z: f16, round: Round) -> FpResult<f16> {
+    fma_wide_round(x, y, z, round)
 }
 
 /// Floating multiply add (f32)
@@ -26,20 +37,33 @@ pub fn fmaf(x: f32, y: f32, z: f32) -> f32 {
     fma_wide_round(x, y, z, Round::Nearest).val
 }
 
+/// Floating multiply add (f32) with rounding mode `round`.
+///
+/// Computes `(x*y)+z`, rounded once in the direction given by `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf_round(x: f32, y: f32, z: f32, round: Round) -> FpResult<f32> {
+    fma_wide_round(x, y, z, round)
+}
+
 /// Fma implementation when a hardware-backed larger float type is available. For `f32` and `f64`,
 /// `f64` has enough precision to represent the `f32` in its entirety, except for double rounding.
 #[inline]
 pub fn fma_wide_round<F, B>(x: F, y: F, z: F, round: Round) -> FpResult<F>
 where
     F: Float + HFloat<D = B>,
-    B: Float + DFloat<H = F>,
+    B: Float + DFloat<H = F> + NarrowHelper,
     B::Int: CastInto<i32>,
     i32: CastFrom<i32>,
 {
+    if round != Round::Nearest {
+        // The wide result is rounded to nearest, so narrowing it would round twice.
+        return super::generic::ffma_round(x.widen(), y.widen(), z.widen(), round);
+    }
+
     let one = IntTy::<B>::ONE;
 
     let xy: B = x.widen() * y.widen();
-    let mut result: B = xy + z.widen();
+    let result: B = xy + z.widen();
     let mut ui: B::Int = result.to_bits();
     let re = result.ex();
     let zb: B = z.widen();
@@ -48,34 +72,22 @@ where
     let excess_prec = ui & ((one << prec_diff) - one);
     let halfway = one << (prec_diff - 1);
 
+    if re == B::EXP_SAT {
+        // The result is NaN or infinite, which is only invalid for `0 * inf` or `inf - inf`.
+        let nan_input = x.is_nan() || y.is_nan() || z.is_nan();
+        let status = if result.is_nan() && !nan_input { Status::INVALID } else { Status::OK };
+        return FpResult::new(result.narrow(), status);
+    }
+
+    let exact = result - xy == zb && result - zb == xy;
+
     // Common case: the larger precision is fine if...
     // This is not a halfway case
     if excess_prec != halfway
-        // Or the result is NaN
-        || re == B::EXP_SAT
         // Or the result is exact
-        || (result - xy == zb && result - zb == xy)
-        // Or the mode is something other than round to nearest
-        || round != Round::Nearest
+        || exact
     {
-        let min_inexact_exp = (B::EXP_BIAS as i32 + F::EXP_MIN_SUBNORM) as u32;
-        let max_inexact_exp = (B::EXP_BIAS as i32 + F::EXP_MIN) as u32;
-
-        let mut status = Status::OK;
-
-        if (min_inexact_exp..max_inexact_exp).contains(&re) && status.inexact() {
-            // This branch is never hit; requires previous operations to set a status
-            status.set_inexact(false);
-
-            result = xy + z.widen();
-            if status.inexact() {
-                status.set_underflow(true);
-            } else {
-                status.set_inexact(true);
-            }
-        }
-
-        return FpResult { val: result.narrow(), status };
+        return nearest_status(result, exact);
     }
 
     let neg = ui >> (B::BITS - 1) != IntTy::<B>::ZERO;
@@ -86,7 +98,30 @@ where
         ui -= one;
     }
 
-    FpResult::ok(B::from_bits(ui).narrow())
+    nearest_status(B::from_bits(ui), false)
+}
+
+/// Narrow the finite `wide`, which is exactly `x * y + z` if `exact` is set, and determine the
+/// status.
+fn nearest_status<F, B>(wide: B, exact: bool) -> FpResult<F>
+where
+    F: Float + HFloat<D = B>,
+    B: Float + DFloat<H = F>,
+{
+    let res = wide.narrow();
+
+    if res.is_infinite() {
+        return FpResult::new(res, Status::OVERFLOW.with(Status::INEXACT));
+    }
+
+    if exact && res.widen() == wide {
+        return FpResult::ok(res);
+    }
+
+    // Tininess is detected before rounding, like `fma_round`.
+    let mut status = Status::INEXACT;
+    status.set_underflow(wide.abs() < F::MIN_POSITIVE_NORMAL.widen());
+    FpResult::new(res, status)
 }
 
 #[cfg(test)]
@@ -101,4 +136,54 @@ mod tests {
         let expected = f32::from_bits(1501560833);
         assert_eq!(fmaf(a, b, c), expected);
     }
+
+    #[test]
+    fn directed_f32() {
+        let rounds = [Round::Negative, Round::Positive, Round::Zero];
+        let x = 1.0f32 + f32::EPSILON;
+        let lo = 1.0f32 + 2.0 * f32::EPSILON;
+        let hi = 1.0f32 + 3.0 * f32::EPSILON;
+
+        // `x * x` is exactly `1 + 2eps + eps^2`.
+        for round in rounds {
+            let exp = if round == Round::Positive { hi } else { lo };
+            let FpResult { val, status } = fma_wide_round(x, x, 0.0, round);
+            assert_biteq!(val, exp, "{round:?}");
+            assert_eq!(status, Status::INEXACT, "{round:?}");
+
+            let exp = if round == Round::Negative { -hi } else { -lo };
+            assert_biteq!(fma_wide_round(-x, x, 0.0, round).val, exp, "{round:?}");
+        }
+
+        // Exact cancellation
+        assert_biteq!(fma_wide_round(2.0f32, 3.0, -6.0, Round::Negative).val, -0.0);
+        assert_biteq!(fma_wide_round(2.0f32, 3.0, -6.0, Round::Positive).val, 0.0);
+
+        // Overflow
+        assert_biteq!(fma_wide_round(f32::MAX, 2.0, 0.0, Round::Zero).val, f32::MAX);
+        assert_biteq!(fma_wide_round(f32::MAX, 2.0, 0.0, Round::Positive).val, f32::INFINITY);
+        assert_biteq!(fma_wide_round(f32::MAX, -2.0, 0.0, Round::Positive).val, f32::MIN);
+
+        // Ties away from zero
+        let half_ulp = f32::EPSILON / 2.0;
+        assert_biteq!(fma_wide_round(1.0, half_ulp, 1.0, Round::Nearest).val, 1.0);
+        assert_biteq!(fma_wide_round(1.0, half_ulp, 1.0, Round::NearestAway).val, x);
+        assert_biteq!(fma_wide_round(-1.0, half_ulp, -1.0, Round::NearestAway).val, -x);
+    }
+
+    #[test]
+    fn nearest_status_f32() {
+        let status = |x, y, z| fma_wide_round::<f32, f64>(x, y, z, Round::Nearest).status;
+        let x = 1.0f32 + f32::EPSILON;
+        let min_sub = f32::from_bits(1);
+
+        assert_eq!(status(2.0, 3.0, -6.0), Status::OK);
+        assert_eq!(status(x, x, 0.0), Status::INEXACT);
+        assert_eq!(status(1.0, f32::EPSILON / 2.0, 1.0), Status::INEXACT);
+        assert_eq!(status(min_sub, 0.5, 0.0), Status::UNDERFLOW.with(Status::INEXACT));
+        assert_eq!(status(f32::MAX, 2.0, 0.0), Status::OVERFLOW.with(Status::INEXACT));
+        assert_eq!(status(f32::INFINITY, 0.0, 1.0), Status::INVALID);
+        assert_eq!(status(f32::INFINITY, 2.0, 1.0), Status::OK);
+        assert_eq!(status(f32::NAN, 0.0, 1.0), Status::OK);
+    }
 }
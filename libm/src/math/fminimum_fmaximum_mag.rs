@@ -0,0 +1,199 @@
+/// Return the argument with the lesser magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `minimumMagnitude`. Ties in magnitude are broken as in
+/// `minimum`, so -0.0 < 0.0.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_magf16(x: f16, y: f16) -> f16 {
+    super::generic::fminimum_mag(x, y)
+}
+
+/// Return the argument with the lesser magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `minimumMagnitude`. Ties in magnitude are broken as in
+/// `minimum`, so -0.0 < 0.0.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_magf(x: f32, y: f32) -> f32 {
+    super::generic::fminimum_mag(x, y)
+}
+
+/// Return the argument with the lesser magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `minimumMagnitude`. Ties in magnitude are broken as in
+/// `minimum`, so -0.0 < 0.0.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_mag(x: f64, y: f64) -> f64 {
+    super::generic::fminimum_mag(x, y)
+}
+
+/// Return the argument with the lesser magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `minimumMagnitude`. Ties in magnitude are broken as in
+/// `minimum`, so -0.0 < 0.0.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_magf128(x: f128, y: f128) -> f128 {
+    super::generic::fminimum_mag(x, y)
+}
+
+/// Return the argument with the greater magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `maximumMagnitude`. Ties in magnitude are broken as in
+/// `maximum`, so -0.0 < 0.0.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_magf16(x: f16, y: f16) -> f16 {
+    super::generic::fmaximum_mag(x, y)
+}
+
+/// Return the argument with the greater magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `maximumMagnitude`. Ties in magnitude are broken as in
+/// `maximum`, so -0.0 < 0.0.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_magf(x: f32, y: f32) -> f32 {
+    super::generic::fmaximum_mag(x, y)
+}
+
+/// Return the argument with the greater magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `maximumMagnitude`. Ties in magnitude are broken as in
+/// `maximum`, so -0.0 < 0.0.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_mag(x: f64, y: f64) -> f64 {
+    super::generic::fmaximum_mag(x, y)
+}
+
+/// Return the argument with the greater magnitude or, if either argument is NaN, NaN.
+///
+/// This coincides with IEEE 754-2019 `maximumMagnitude`. Ties in magnitude are broken as in
+/// `maximum`, so -0.0 < 0.0.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_magf128(x: f128, y: f128) -> f128 {
+    super::generic::fmaximum_mag(x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::{Float, Hexf, MinInt};
+
+    fn fminimum_mag_spec_test<F: Float>(f: impl Fn(F, F) -> F) {
+        let cases = [
+            (F::ZERO, F::ZERO, F::ZERO),
+            (F::ONE, F::ONE, F::ONE),
+            (F::ZERO, F::ONE, F::ZERO),
+            (F::ONE, F::ZERO, F::ZERO),
+            (F::ZERO, F::NEG_ONE, F::ZERO),
+            (F::NEG_ONE, F::ZERO, F::ZERO),
+            (F::ONE, F::NEG_ONE, F::NEG_ONE),
+            (F::NEG_ONE, F::ONE, F::NEG_ONE),
+            (F::NEG_ONE, F::NEG_ONE, F::NEG_ONE),
+            (F::INFINITY, F::ZERO, F::ZERO),
+            (F::NEG_INFINITY, F::ZERO, F::ZERO),
+            (F::NEG_INFINITY, F::INFINITY, F::NEG_INFINITY),
+            (F::NEG_INFINITY, F::ONE, F::ONE),
+            (F::NAN, F::ZERO, F::NAN),
+            (F::ZERO, F::NAN, F::NAN),
+            (F::NAN, F::NEG_INFINITY, F::NAN),
+            (F::NAN, F::NAN, F::NAN),
+            (F::ZERO, F::NEG_ZERO, F::NEG_ZERO),
+            (F::NEG_ZERO, F::ZERO, F::NEG_ZERO),
+            (F::NEG_ZERO, F::NEG_ZERO, F::NEG_ZERO),
+        ];
+
+        for (x, y, res) in cases {
+            let val = f(x, y);
+            assert_biteq!(val, res, "fminimum_mag({}, {})", Hexf(x), Hexf(y));
+        }
+
+        // Signaling NaNs are not handled specially, but must not produce a number.
+        let snan = F::from_bits(F::EXP_MASK | F::Int::ONE);
+        for (x, y) in [(snan, F::ONE), (F::ONE, snan), (snan, F::NAN), (snan, snan)] {
+            let val = f(x, y);
+            assert!(val.is_nan(), "fminimum_mag({}, {}) = {}", Hexf(x), Hexf(y), Hexf(val));
+        }
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn fminimum_mag_spec_tests_f16() {
+        fminimum_mag_spec_test::<f16>(fminimum_magf16);
+    }
+
+    #[test]
+    fn fminimum_mag_spec_tests_f32() {
+        fminimum_mag_spec_test::<f32>(fminimum_magf);
+    }
+
+    #[test]
+    fn fminimum_mag_spec_tests_f64() {
+        fminimum_mag_spec_test::<f64>(fminimum_mag);
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn fminimum_mag_spec_tests_f128() {
+        fminimum_mag_spec_test::<f128>(fminimum_magf128);
+    }
+
+    fn fmaximum_mag_spec_test<F: Float>(f: impl Fn(F, F) -> F) {
+        let cases = [
+            (F::ZERO, F::ZERO, F::ZERO),
+            (F::ONE, F::ONE, F::ONE),
+            (F::ZERO, F::ONE, F::ONE),
+            (F::ONE, F::ZERO, F::ONE),
+            (F::ZERO, F::NEG_ONE, F::NEG_ONE),
+            (F::NEG_ONE, F::ZERO, F::NEG_ONE),
+            (F::ONE, F::NEG_ONE, F::ONE),
+            (F::NEG_ONE, F::ONE, F::ONE),
+            (F::NEG_ONE, F::NEG_ONE, F::NEG_ONE),
+            (F::INFINITY, F::ZERO, F::INFINITY),
+            (F::NEG_INFINITY, F::ZERO, F::NEG_INFINITY),
+            (F::NEG_INFINITY, F::INFINITY, F::INFINITY),
+            (F::NEG_INFINITY, F::ONE, F::NEG_INFINITY),
+            (F::NAN, F::ZERO, F::NAN),
+            (F::ZERO, F::NAN, F::NAN),
+            (F::NAN, F::NEG_INFINITY, F::NAN),
+            (F::NAN, F::NAN, F::NAN),
+            (F::ZERO, F::NEG_ZERO, F::ZERO),
+            (F::NEG_ZERO, F::ZERO, F::ZERO),
+            (F::NEG_ZERO, F::NEG_ZERO, F::NEG_ZERO),
+        ];
+
+        for (x, y, res) in cases {
+            let val = f(x, y);
+            assert_biteq!(val, res, "fmaximum_mag({}, {})", Hexf(x), Hexf(y));
+        }
+
+        // Signaling NaNs are not handled specially, but must not produce a number.
+        let snan = F::from_bits(F::EXP_MASK | F::Int::ONE);
+        for (x, y) in [(snan, F::ONE), (F::ONE, snan), (snan, F::NAN), (snan, snan)] {
+            let val = f(x, y);
+            assert!(val.is_nan(), "fmaximum_mag({}, {}) = {}", Hexf(x), Hexf(y), Hexf(val));
+        }
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn fmaximum_mag_spec_tests_f16() {
+        fmaximum_mag_spec_test::<f16>(fmaximum_magf16);
+    }
+
+    #[test]
+    fn fmaximum_mag_spec_tests_f32() {
+        fmaximum_mag_spec_test::<f32>(fmaximum_magf);
+    }
+
+    #[test]
+    fn fmaximum_mag_spec_tests_f64() {
+        fmaximum_mag_spec_test::<f64>(fmaximum_mag);
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn fmaximum_mag_spec_tests_f128() {
+        fmaximum_mag_spec_test::<f128>(fmaximum_magf128);
+    }
+}
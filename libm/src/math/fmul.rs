@@ -0,0 +1,47 @@
+use super::support::{FpResult, Round};
+
+/// Multiply `x` by `y`, rounding the result once to `f16`.
+///
+/// This is not the same as computing in `f32` and then converting, which rounds twice.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f16mulf32(x: f32, y: f32) -> f16 {
+    super::generic::fmul_round(x, y, Round::Nearest).val
+}
+
+/// Multiply `x` by `y`, rounding the result once to `f16` in the direction given by `round`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f16mulf32_round(x: f32, y: f32, round: Round) -> FpResult<f16> {
+    super::generic::fmul_round(x, y, round)
+}
+
+/// Multiply `x` by `y`, rounding the result once to `f32`.
+///
+/// This is not the same as computing in `f64` and then converting, which rounds twice.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmul(x: f64, y: f64) -> f32 {
+    super::generic::fmul_round(x, y, Round::Nearest).val
+}
+
+/// Multiply `x` by `y`, rounding the result once to `f32` in the direction given by `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmul_round(x: f64, y: f64, round: Round) -> FpResult<f32> {
+    super::generic::fmul_round(x, y, round)
+}
+
+/// Multiply `x` by `y`, rounding the result once to `f64`.
+///
+/// This is not the same as computing in `f128` and then converting, which rounds twice.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f64mulf128(x: f128, y: f128) -> f64 {
+    super::generic::fmul_round(x, y, Round::Nearest).val
+}
+
+/// Multiply `x` by `y`, rounding the result once to `f64` in the direction given by `round`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f64mulf128_round(x: f128, y: f128, round: Round) -> FpResult<f64> {
+    super::generic::fmul_round(x, y, round)
+}
@@ -0,0 +1,193 @@
+use super::support::Round;
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `fromfp`.
+///
+/// [`status::fromfpf16`](crate::status::fromfpf16) also reports the exceptions raised.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpf16(x: f16, round: Round, width: u32) -> f16 {
+    super::generic::fromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `fromfp`.
+///
+/// [`status::fromfpf`](crate::status::fromfpf) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpf(x: f32, round: Round, width: u32) -> f32 {
+    super::generic::fromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `fromfp`.
+///
+/// [`status::fromfp`](crate::status::fromfp) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfp(x: f64, round: Round, width: u32) -> f64 {
+    super::generic::fromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `fromfp`.
+///
+/// [`status::fromfpf128`](crate::status::fromfpf128) also reports the exceptions raised.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpf128(x: f128, round: Round, width: u32) -> f128 {
+    super::generic::fromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `ufromfp`.
+///
+/// [`status::ufromfpf16`](crate::status::ufromfpf16) also reports the exceptions raised.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpf16(x: f16, round: Round, width: u32) -> f16 {
+    super::generic::ufromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `ufromfp`.
+///
+/// [`status::ufromfpf`](crate::status::ufromfpf) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpf(x: f32, round: Round, width: u32) -> f32 {
+    super::generic::ufromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `ufromfp`.
+///
+/// [`status::ufromfp`](crate::status::ufromfp) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfp(x: f64, round: Round, width: u32) -> f64 {
+    super::generic::ufromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// NaN is also returned if `x` is infinite or NaN, or if `width` is zero. This is C23 `ufromfp`.
+///
+/// [`status::ufromfpf128`](crate::status::ufromfpf128) also reports the exceptions raised.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpf128(x: f128, round: Round, width: u32) -> f128 {
+    super::generic::ufromfp(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// This is C23 `fromfpx`, which only differs from `fromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::fromfpxf16`](crate::status::fromfpxf16) also reports the exceptions raised.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpxf16(x: f16, round: Round, width: u32) -> f16 {
+    super::generic::fromfpx(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// This is C23 `fromfpx`, which only differs from `fromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::fromfpxf`](crate::status::fromfpxf) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpxf(x: f32, round: Round, width: u32) -> f32 {
+    super::generic::fromfpx(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// This is C23 `fromfpx`, which only differs from `fromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::fromfpx`](crate::status::fromfpx) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpx(x: f64, round: Round, width: u32) -> f64 {
+    super::generic::fromfpx(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in a signed integer of `width` bits.
+///
+/// This is C23 `fromfpx`, which only differs from `fromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::fromfpxf128`](crate::status::fromfpxf128) also reports the exceptions raised.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpxf128(x: f128, round: Round, width: u32) -> f128 {
+    super::generic::fromfpx(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// This is C23 `ufromfpx`, which only differs from `ufromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::ufromfpxf16`](crate::status::ufromfpxf16) also reports the exceptions raised.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpxf16(x: f16, round: Round, width: u32) -> f16 {
+    super::generic::ufromfpx(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// This is C23 `ufromfpx`, which only differs from `ufromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::ufromfpxf`](crate::status::ufromfpxf) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpxf(x: f32, round: Round, width: u32) -> f32 {
+    super::generic::ufromfpx(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// This is C23 `ufromfpx`, which only differs from `ufromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::ufromfpx`](crate::status::ufromfpx) also reports the exceptions raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpx(x: f64, round: Round, width: u32) -> f64 {
+    super::generic::ufromfpx(x, round, width).val
+}
+
+/// Round `x` to an integer in the direction `round`, returning NaN if the result does not fit
+/// in an unsigned integer of `width` bits.
+///
+/// This is C23 `ufromfpx`, which only differs from `ufromfp` in raising inexact if the result
+/// is not `x`.
+///
+/// [`status::ufromfpxf128`](crate::status::ufromfpxf128) also reports the exceptions raised.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpxf128(x: f128, round: Round, width: u32) -> f128 {
+    super::generic::ufromfpx(x, round, width).val
+}
@@ -0,0 +1,93 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! IEEE 754-2019 `canonicalize` and `isSignaling`.
+//!
+//! Every encoding of the binary interchange formats is canonical, so the only thing
+//! `canonicalize` changes is to quiet a signaling NaN, as any other arithmetic operation would.
+//! A NaN is quiet if the most significant bit of the significand field is set.
+
+use super::super::support::{FpResult, Status};
+use super::super::{Float, MinInt};
+
+/// Return `x`, with signaling NaNs converted to quiet NaNs with the same sign and payload.
+#[inline]
+pub fn canonicalize<F: Float>(x: F) -> FpResult<F> {
+    if issignaling(x) {
+        let quiet_bit = F::IMPLICIT_BIT >> 1;
+        return FpResult::new(F::from_bits(x.to_bits() | quiet_bit), Status::INVALID);
+    }
+
+    FpResult::ok(x)
+}
+
+/// Return true if `x` is a signaling NaN.
+#[inline]
+pub fn issignaling<F: Float>(x: F) -> bool {
+    let quiet_bit = F::IMPLICIT_BIT >> 1;
+    x.is_nan() && (x.to_bits() & quiet_bit) == F::Int::ZERO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::Hexf;
+
+    fn spec_test<F: Float>() {
+        let quiet_bit = F::IMPLICIT_BIT >> 1;
+        let snan = F::from_bits(F::EXP_MASK | F::Int::ONE);
+        let snan_max = F::from_bits(F::EXP_MASK | (F::SIG_MASK >> 1));
+        let qnan = F::from_bits(F::EXP_MASK | quiet_bit);
+        let qnan_pl = F::from_bits(F::EXP_MASK | quiet_bit | F::Int::ONE);
+
+        let not_nan = [
+            F::ZERO,
+            F::NEG_ZERO,
+            F::ONE,
+            F::NEG_ONE,
+            F::from_bits(F::Int::ONE),
+            F::MAX,
+            F::INFINITY,
+            F::NEG_INFINITY,
+        ];
+        for x in not_nan.into_iter().chain([qnan, -qnan, qnan_pl, -qnan_pl]) {
+            assert!(!issignaling(x), "{}", Hexf(x));
+            let FpResult { val, status } = canonicalize(x);
+            assert_biteq!(val, x);
+            assert_eq!(status, Status::OK, "{}", Hexf(x));
+        }
+
+        let signaling = [
+            (snan, F::from_bits(snan.to_bits() | quiet_bit)),
+            (-snan, F::from_bits((-snan).to_bits() | quiet_bit)),
+            (snan_max, F::from_bits(F::EXP_MASK | F::SIG_MASK)),
+        ];
+        for (x, exp) in signaling {
+            assert!(issignaling(x), "{}", Hexf(x));
+            let FpResult { val, status } = canonicalize(x);
+            assert_biteq!(val, exp);
+            assert_eq!(status, Status::INVALID, "{}", Hexf(x));
+            assert!(!issignaling(val), "{}", Hexf(val));
+        }
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn spec_tests_f16() {
+        spec_test::<f16>();
+    }
+
+    #[test]
+    fn spec_tests_f32() {
+        spec_test::<f32>();
+    }
+
+    #[test]
+    fn spec_tests_f64() {
+        spec_test::<f64>();
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn spec_tests_f128() {
+        spec_test::<f128>();
+    }
+}
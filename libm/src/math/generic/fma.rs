@@ -0,0 +1,541 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/fma.c. Ported to generic Rust algorithm in 2025, TG. */
+
+use super::super::support::{DInt, FpResult, HInt, IntTy, Round, Status};
+use super::super::{CastFrom, CastInto, Float, Int, MinInt};
+
+/// Fused multiply-add that works when there is not a larger float size available. Computes
+/// `(x * y) + z`.
+#[inline]
+pub fn fma_round<F>(x: F, y: F, z: F, round: Round) -> FpResult<F>
+where
+    F: Float,
+    F: CastFrom<F::SignedInt>,
+    F: CastFrom<i8>,
+    F::Int: HInt,
+    u32: CastInto<F::Int>,
+{
+    let one = IntTy::<F>::ONE;
+    let zero = IntTy::<F>::ZERO;
+
+    // Normalize such that the top of the mantissa is zero and we have a guard bit.
+    let nx = Norm::from_float(x);
+    let ny = Norm::from_float(y);
+    let mut nz = Norm::from_float(z);
+
+    if nx.is_zero_nan_inf() || ny.is_zero_nan_inf() {
+        // Value will overflow, defer to non-fused operations. The product is exact here, so only
+        // the sign of an exact zero sum depends on the rounding mode.
+        let xy = x * y;
+        let r = xy + z;
+        if r.is_nan() && !x.is_nan() && !y.is_nan() && !z.is_nan() {
+            // `0 * inf` or `inf - inf`
+            return FpResult::new(r, Status::INVALID);
+        }
+        if r == F::ZERO
+            && round == Round::Negative
+            && (xy.is_sign_negative() || z.is_sign_negative())
+        {
+            return FpResult::ok(F::NEG_ZERO);
+        }
+        return FpResult::ok(r);
+    }
+
+    if nz.is_zero_nan_inf() {
+        if !nz.is_zero() {
+            // `z` is NaN or infinity, which sets the result.
+            return FpResult::ok(z);
+        }
+
+        // The product still needs to be rounded, and its status flags tracked, so continue with
+        // a zero significand aligned to the product.
+        nz = Norm { m: zero, e: nx.e + ny.e, neg: nz.neg };
+    }
+
+    // multiply: r = x * y
+    let zhi: F::Int;
+    let zlo: F::Int;
+    let (mut rlo, mut rhi) = nx.m.widen_mul(ny.m).lo_hi();
+
+    // Exponent result of multiplication
+    let mut e: i32 = nx.e + ny.e;
+    // Needed shift to align `z` to the multiplication result
+    let mut d: i32 = nz.e - e;
+    let sbits = F::BITS as i32;
+
+    // Scale `z`. Shift `z <<= kz`, `r >>= kr`, so `kz+kr == d`, set `e = e+kr` (== ez-kz)
+    if d > 0 {
+        // The magnitude of `z` is larger than `x * y`
+        if d < sbits {
+            // Maximum shift of one `F::BITS` means shifted `z` will fit into `2 * F::BITS`. Shift
+            // it into `(zhi, zlo)`. No exponent adjustment necessary.
+            zlo = nz.m << d;
+            zhi = nz.m >> (sbits - d);
+        } else {
+            // Shift larger than `sbits`, `z` only needs the top half `zhi`. Place it there (acts
+            // as a shift by `sbits`).
+            zlo = zero;
+            zhi = nz.m;
+            d -= sbits;
+
+            // `z`'s exponent is large enough that it now needs to be taken into account.
+            e = nz.e - sbits;
+
+            if d == 0 {
+                // Exactly `sbits`, nothing to do
+            } else if d < sbits {
+                // Remaining shift fits within `sbits`. Leave `z` in place, shift `x * y`
+                rlo = (rhi << (sbits - d)) | (rlo >> d);
+                // Set the sticky bit
+                rlo |= IntTy::<F>::from((rlo << (sbits - d)) != zero);
+                rhi = rhi >> d;
+            } else {
+                // `z`'s magnitude is enough that `x * y` is irrelevant. It was nonzero, so set
+                // the sticky bit.
+                rlo = one;
+                rhi = zero;
+            }
+        }
+    } else {
+        // `z`'s magnitude once shifted fits entirely within `zlo`
+        zhi = zero;
+        d = -d;
+        if d == 0 {
+            // No shift needed
+            zlo = nz.m;
+        } else if d < sbits {
+            // Shift s.t. `nz.m` fits into `zlo`
+            let sticky = IntTy::<F>::from((nz.m << (sbits - d)) != zero);
+            zlo = (nz.m >> d) | sticky;
+        } else {
+            // Would be entirely shifted out, only set the sticky bit
+            zlo = one;
+        }
+    }
+
+    /* addition */
+
+    let mut neg = nx.neg ^ ny.neg;
+    let samesign: bool = !neg ^ nz.neg;
+    let mut rhi_nonzero = true;
+
+    if samesign {
+        // r += z
+        rlo = rlo.wrapping_add(zlo);
+        rhi += zhi + IntTy::<F>::from(rlo < zlo);
+    } else {
+        // r -= z
+        let (res, borrow) = rlo.overflowing_sub(zlo);
+        rlo = res;
+        rhi = rhi.wrapping_sub(zhi.wrapping_add(IntTy::<F>::from(borrow)));
+        if (rhi >> (F::BITS - 1)) != zero {
+            rlo = rlo.signed().wrapping_neg().unsigned();
+            rhi = rhi.signed().wrapping_neg().unsigned() - IntTy::<F>::from(rlo != zero);
+            neg = !neg;
+        }
+        rhi_nonzero = rhi != zero;
+    }
+
+    /* Construct result */
+
+    // Shift result into `rhi`, left-aligned. Last bit is sticky
+    if rhi_nonzero {
+        // `d` > 0, need to shift both `rhi` and `rlo` into result
+        e += sbits;
+        d = rhi.leading_zeros() as i32 - 1;
+        rhi = (rhi << d) | (rlo >> (sbits - d));
+        // Update sticky
+        rhi |= IntTy::<F>::from((rlo << d) != zero);
+    } else if rlo != zero {
+        // `rhi` is zero, `rlo` is the entire result and needs to be shifted
+        d = rlo.leading_zeros() as i32 - 1;
+        if d < 0 {
+            // Shift and set sticky
+            rhi = (rlo >> 1) | (rlo & one);
+        } else {
+            rhi = rlo << d;
+        }
+    } else {
+        // exact +/- 0.0
+        if round == Round::Negative {
+            return FpResult::ok(F::NEG_ZERO);
+        }
+        return FpResult::ok(x * y + z);
+    }
+
+    e -= d;
+
+    if round != Round::Nearest {
+        return round_with(rhi, e, neg, round);
+    }
+
+    // Use int->float conversion to populate the significand.
+    // i is in [1 << (BITS - 2), (1 << (BITS - 1)) - 1]
+    let mut i: F::SignedInt = rhi.signed();
+
+    if neg {
+        i = -i;
+    }
+
+    // `|r|` is in `[0x1p62,0x1p63]` for `f64`
+    let mut r: F = F::cast_from_lossy(i);
+
+    /* Account for subnormal and rounding */
+
+    // Unbiased exponent for the maximum value of `r`
+    let max_pow = F::BITS - 1 + F::EXP_BIAS;
+
+    // Truncation is exact exactly when rounding to nearest is, and detects tininess before
+    // rounding the same way. If it overflows then so does the nearest result.
+    let mut status = round_with::<F>(rhi, e, neg, Round::Zero).status;
+
+    if e < -(max_pow as i32 - 2) {
+        // Result is subnormal before rounding
+        if e == -(max_pow as i32 - 1) {
+            let mut c = F::from_parts(false, max_pow, zero);
+            if neg {
+                c = -c;
+            }
+
+            if r == c {
+                // Min normal after rounding,
+                status.set_underflow(true);
+                r = F::MIN_POSITIVE_NORMAL.copysign(r);
+                return FpResult::new(r, status);
+            }
+
+            if (rhi << (F::SIG_BITS + 1)) != zero {
+                // Account for truncated bits. One bit will be lost in the `scalbn` call, add
+                // another top bit to avoid double rounding if inexact.
+                let iu: F::Int = (rhi >> 1) | (rhi & one) | (one << (F::BITS - 2));
+                i = iu.signed();
+
+                if neg {
+                    i = -i;
+                }
+
+                r = F::cast_from_lossy(i);
+
+                // Remove the top bit
+                r = F::cast_from(2i8) * r - c;
+                status.set_underflow(true);
+            }
+        } else {
+            // Only round once when scaled
+            d = F::EXP_BITS as i32 - 1;
+            let sticky = IntTy::<F>::from(rhi << (F::BITS as i32 - d) != zero);
+            i = (((rhi >> d) | sticky) << d).signed();
+
+            if neg {
+                i = -i;
+            }
+
+            r = F::cast_from_lossy(i);
+        }
+    }
+
+    // Use our exponent to scale the final value.
+    let r = super::scalbn(r, e);
+    if r.is_infinite() {
+        status = Status::OVERFLOW.with(Status::INEXACT);
+    }
+
+    FpResult::new(r, status)
+}
+
+/// Round `(-1)^neg * m * 2^e` using `round`, which must not be `Round::Nearest`. `m` is
+/// left-aligned as in `fma_round` (the top bit is clear, the next bit is set) and its last bit
+/// is sticky.
+fn round_with<F: Float>(m: F::Int, e: i32, neg: bool, round: Round) -> FpResult<F> {
+    let zero = IntTy::<F>::ZERO;
+    let one = IntTy::<F>::ONE;
+
+    // Whether inexact results round away from zero, rather than being truncated. Nearest results
+    // are only rounded away if the dropped bits are at least half an ulp, but can always
+    // overflow to infinity.
+    let away = match round {
+        Round::Nearest => unreachable!(),
+        Round::Negative => neg,
+        Round::Positive => !neg,
+        Round::Zero => false,
+        Round::NearestAway => true,
+    };
+
+    // Biased exponent of the leading bit, and the number of bits that need to be dropped.
+    let mut be = e + (F::BITS - 2 + F::EXP_BIAS) as i32;
+    let mut shift = F::BITS - 2 - F::SIG_BITS;
+    let tiny = be < 1;
+
+    if tiny {
+        // Subnormal results drop additional bits. Use a biased exponent of 1 so the implicit bit
+        // does not get added twice below.
+        shift = shift.saturating_add((1 - be) as u32);
+        be = 1;
+    }
+
+    let (mut sig, rem) =
+        if shift < F::BITS { (m >> shift, m & ((one << shift) - one)) } else { (zero, m) };
+
+    let mut status = Status::OK;
+    if rem != zero {
+        status = Status::INEXACT;
+        status.set_underflow(tiny);
+
+        let up = if round == Round::NearestAway {
+            shift < F::BITS && rem >= one << (shift - 1)
+        } else {
+            away
+        };
+
+        if up {
+            sig += one;
+        }
+    }
+
+    let sign = if neg { F::SIGN_MASK } else { zero };
+    let overflow = if away { F::EXP_MASK } else { F::EXP_MASK - one };
+
+    // `sig` includes the implicit bit for normal results, and any carry out of the significand
+    // propagates into the exponent.
+    if be >= F::EXP_SAT as i32 {
+        let status = Status::OVERFLOW.with(Status::INEXACT);
+        return FpResult::new(F::from_bits(overflow | sign), status);
+    }
+
+    let bits = sig + (F::Int::cast_from(be as u32 - 1) << F::SIG_BITS);
+    if bits >= F::EXP_MASK {
+        let status = Status::OVERFLOW.with(Status::INEXACT);
+        return FpResult::new(F::from_bits(overflow | sign), status);
+    }
+
+    FpResult::new(F::from_bits(bits | sign), status)
+}
+
+/// Representation of `F` that has handled subnormals.
+#[derive(Clone, Copy, Debug)]
+struct Norm<F: Float> {
+    /// Normalized significand with one guard bit, unsigned.
+    m: F::Int,
+    /// Exponent of the mantissa such that `m * 2^e = x`. Accounts for the shift in the mantissa
+    /// and the guard bit; that is, 1.0 will normalize as `m = 1 << 53` and `e = -53`.
+    e: i32,
+    neg: bool,
+}
+
+impl<F: Float> Norm<F> {
+    /// Unbias the exponent and account for the mantissa's precision, including the guard bit.
+    const EXP_UNBIAS: u32 = F::EXP_BIAS + F::SIG_BITS + 1;
+
+    /// Values greater than this had a saturated exponent (infinity or NaN), OR were zero and we
+    /// adjusted the exponent such that it exceeds this threashold.
+    const ZERO_INF_NAN: u32 = F::EXP_SAT - Self::EXP_UNBIAS;
+
+    fn from_float(x: F) -> Self {
+        let mut ix = x.to_bits();
+        let mut e = x.ex() as i32;
+        let neg = x.is_sign_negative();
+        if e == 0 {
+            // Normalize subnormals by multiplication
+            let scale_i = F::BITS - 1;
+            let scale_f = F::from_parts(false, scale_i + F::EXP_BIAS, F::Int::ZERO);
+            let scaled = x * scale_f;
+            ix = scaled.to_bits();
+            e = scaled.ex() as i32;
+            e = if e == 0 {
+                // If the exponent is still zero, the input was zero. Artifically set this value
+                // such that the final `e` will exceed `ZERO_INF_NAN`.
+                1 << F::EXP_BITS
+            } else {
+                // Otherwise, account for the scaling we just did.
+                e - scale_i as i32
+            };
+        }
+
+        e -= Self::EXP_UNBIAS as i32;
+
+        // Absolute  value, set the implicit bit, and shift to create a guard bit
+        ix &= F::SIG_MASK;
+        ix |= F::IMPLICIT_BIT;
+        ix <<= 1;
+
+        Self { m: ix, e, neg }
+    }
+
+    /// True if the value was zero, infinity, or NaN.
+    fn is_zero_nan_inf(self) -> bool {
+        self.e >= Self::ZERO_INF_NAN as i32
+    }
+
+    /// The only value we have
+    fn is_zero(self) -> bool {
+        // The only exponent that strictly exceeds this value is our sentinel value for zero.
+        self.e > Self::ZERO_INF_NAN as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test the generic `fma_round` algorithm for a given float.
+    fn spec_test<F>()
+    where
+        F: Float,
+        F: CastFrom<F::SignedInt>,
+        F: CastFrom<i8>,
+        F::Int: HInt,
+        u32: CastInto<F::Int>,
+    {
+        let x = F::from_bits(F::Int::ONE);
+        let y = F::from_bits(F::Int::ONE);
+        let z = F::ZERO;
+
+        let fma = |x, y, z| fma_round(x, y, z, Round::Nearest).val;
+
+        // 754-2020 says "When the exact result of (a × b) + c is non-zero yet the result of
+        // fusedMultiplyAdd is zero because of rounding, the zero result takes the sign of the
+        // exact result"
+        assert_biteq!(fma(x, y, z), F::ZERO);
+        assert_biteq!(fma(x, -y, z), F::NEG_ZERO);
+        assert_biteq!(fma(-x, y, z), F::NEG_ZERO);
+        assert_biteq!(fma(-x, -y, z), F::ZERO);
+
+        // Directed rounding of a product that is too small to represent
+        let fma_r = |x, y, z, round| fma_round(x, y, z, round).val;
+        let min_sub = F::from_bits(F::Int::ONE);
+        assert_biteq!(fma_r(x, y, z, Round::Positive), min_sub);
+        assert_biteq!(fma_r(x, y, z, Round::Negative), F::ZERO);
+        assert_biteq!(fma_r(x, y, z, Round::Zero), F::ZERO);
+        assert_biteq!(fma_r(x, -y, z, Round::Positive), F::NEG_ZERO);
+        assert_biteq!(fma_r(x, -y, z, Round::Negative), -min_sub);
+        assert_biteq!(fma_r(x, -y, z, Round::Zero), F::NEG_ZERO);
+
+        // Exact zero sums are only negative when rounding down
+        let two = F::ONE + F::ONE;
+        assert_biteq!(fma_r(F::ONE, F::ONE, F::NEG_ONE, Round::Negative), F::NEG_ZERO);
+        assert_biteq!(fma_r(F::ONE, F::ONE, F::NEG_ONE, Round::Positive), F::ZERO);
+        assert_biteq!(fma_r(F::ZERO, two, F::NEG_ZERO, Round::Negative), F::NEG_ZERO);
+        assert_biteq!(fma_r(F::ZERO, two, F::NEG_ZERO, Round::Zero), F::ZERO);
+
+        // Overflow goes to infinity only when rounding away from zero
+        assert_biteq!(fma_r(F::MAX, two, F::ZERO, Round::Positive), F::INFINITY);
+        assert_biteq!(fma_r(F::MAX, two, F::ZERO, Round::Negative), F::MAX);
+        assert_biteq!(fma_r(F::MAX, two, F::ZERO, Round::Zero), F::MAX);
+        assert_biteq!(fma_r(F::MAX, -two, F::ZERO, Round::Negative), F::NEG_INFINITY);
+        assert_biteq!(fma_r(F::MAX, -two, F::ZERO, Round::Positive), F::MIN);
+
+        // `1 + tiny` rounds to one of the neighbors of 1
+        let up = F::ONE + F::EPSILON;
+        assert_biteq!(fma_r(min_sub, min_sub, F::ONE, Round::Positive), up);
+        assert_biteq!(fma_r(min_sub, min_sub, F::ONE, Round::Zero), F::ONE);
+        assert_biteq!(fma_r(min_sub, -min_sub, F::ONE, Round::Positive), F::ONE);
+        assert_biteq!(fma_r(min_sub, -min_sub, F::ONE, Round::Zero), F::ONE - F::EPSILON / two);
+
+        // Exact ties round away from zero rather than to even
+        let half_ulp = F::EPSILON / two;
+        let odd = F::ONE + F::EPSILON;
+        assert_biteq!(fma_r(F::ONE, half_ulp, F::ONE, Round::Nearest), F::ONE);
+        assert_biteq!(fma_r(F::ONE, half_ulp, F::ONE, Round::NearestAway), odd);
+        assert_biteq!(fma_r(F::ONE, -half_ulp, F::NEG_ONE, Round::NearestAway), -odd);
+        assert_biteq!(fma_r(F::ONE, half_ulp, odd, Round::NearestAway), odd + F::EPSILON);
+        assert_biteq!(fma_r(F::MAX, two, F::ZERO, Round::NearestAway), F::INFINITY);
+
+        // Rounding to nearest reports exceptions like the other modes
+        let status = |x, y, z| fma_round(x, y, z, Round::Nearest).status;
+        assert_eq!(status(two, two, F::ONE), Status::OK);
+        assert_eq!(status(F::ONE, half_ulp, F::ONE), Status::INEXACT);
+        assert_eq!(status(min_sub, min_sub, F::ONE), Status::INEXACT);
+        assert_eq!(status(x, y, z), Status::UNDERFLOW.with(Status::INEXACT));
+        assert_eq!(status(F::MAX, two, F::ZERO), Status::OVERFLOW.with(Status::INEXACT));
+        assert_eq!(status(F::INFINITY, two, F::ONE), Status::OK);
+    }
+
+    const ROUNDS: [Round; 5] =
+        [Round::Nearest, Round::Negative, Round::Positive, Round::Zero, Round::NearestAway];
+
+    /// Random-ish values with exponents close enough to interact, plus some that land in the
+    /// subnormal and overflow ranges.
+    fn directed_inputs(count: usize) -> impl Iterator<Item = (f64, f64, f64)> {
+        let mut state = 0x2545f4914f6cdd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        (0..count).map(move |i| {
+            let (a, b, c) = (next(), next(), next());
+            let ex = [1023, 1, 1980, 40][i % 4] + (a >> 58) as i32;
+            let ey = [1023, 100, 70, 1000][i % 4] - (b >> 58) as i32;
+            let ez = (ex + ey - 1023 + (c >> 58) as i32 - 32).clamp(0, 2046);
+            let f = |r: u64, e: i32| f64::from_bits((r & !(0x7ff << 52)) | ((e as u64) << 52));
+            (f(a, ex), f(b, ey), f(c, ez))
+        })
+    }
+
+    #[test]
+    fn directed_f32_matches_wide() {
+        for (x, y, z) in directed_inputs(20000) {
+            let (x, y, z) = (x as f32, y as f32, z as f32);
+            for round in ROUNDS {
+                let res = fma_round(x, y, z, round);
+                let exp = super::super::super::fma_wide::fma_wide_round(x, y, z, round);
+                assert_biteq!(res.val, exp.val, "{round:?} {x:?} {y:?} {z:?}");
+                assert_eq!(res.status, exp.status, "{round:?} {x:?} {y:?} {z:?}");
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn directed_f64_matches_narrow() {
+        for (x, y, z) in directed_inputs(20000) {
+            for round in ROUNDS {
+                let res = fma_round(x, y, z, round);
+                let exp = super::super::ffma_round(x as f128, y as f128, z as f128, round);
+                assert_biteq!(res.val, exp.val, "{round:?} {x:?} {y:?} {z:?}");
+                assert_eq!(res.status, exp.status, "{round:?} {x:?} {y:?} {z:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn spec_test_f32() {
+        spec_test::<f32>();
+    }
+
+    #[test]
+    fn spec_test_f64() {
+        spec_test::<f64>();
+
+        let expect_underflow = [
+            (
+                hf64!("0x1.0p-1070"),
+                hf64!("0x1.0p-1070"),
+                hf64!("0x1.ffffffffffffp-1023"),
+                hf64!("0x0.ffffffffffff8p-1022"),
+            ),
+            (
+                // FIXME: we raise underflow but this should only be inexact (based on C and
+                // `rustc_apfloat`).
+                hf64!("0x1.0p-1070"),
+                hf64!("0x1.0p-1070"),
+                hf64!("-0x1.0p-1022"),
+                hf64!("-0x1.0p-1022"),
+            ),
+        ];
+
+        for (x, y, z, res) in expect_underflow {
+            let FpResult { val, status } = fma_round(x, y, z, Round::Nearest);
+            assert_biteq!(val, res);
+            assert_eq!(status, Status::UNDERFLOW.with(Status::INEXACT));
+        }
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn spec_test_f128() {
+        spec_test::<f128>();
+    }
+}
@@ -0,0 +1,31 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! IEEE 754-2019 `maximumMagnitude`.
+//!
+//! Per the spec, returns the canonicalized result of:
+//! - `x` if `|x| > |y|`
+//! - `y` if `|y| > |x|`
+//! - `maximum(x, y)` if `|x| == |y|`
+//! - qNaN if either operation is NaN
+//!
+//! Excluded from our implementation is sNaN handling.
+
+use super::super::Float;
+
+#[inline]
+pub fn fmaximum_mag<F: Float>(x: F, y: F) -> F {
+    let ax = x.abs();
+    let ay = y.abs();
+
+    let res = if x.is_nan() {
+        x
+    } else if y.is_nan() {
+        y
+    } else if ax > ay || (ax == ay && x.is_sign_positive()) {
+        x
+    } else {
+        y
+    };
+
+    // Canonicalize
+    res * F::ONE
+}
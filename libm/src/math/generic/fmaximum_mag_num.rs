@@ -0,0 +1,24 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! IEEE 754-2019 `maximumMagnitudeNumber`.
+//!
+//! Per the spec, returns:
+//! - `x` if `|x| > |y|`
+//! - `y` if `|y| > |x|`
+//! - `maximumNumber(x, y)` if `|x| == |y|`
+//! - Non-NaN if one operand is NaN
+//! - qNaN if both operands are NaN
+//!
+//! Excluded from our implementation is sNaN handling.
+
+use super::super::Float;
+
+#[inline]
+pub fn fmaximum_mag_num<F: Float>(x: F, y: F) -> F {
+    let ax = x.abs();
+    let ay = y.abs();
+
+    let res = if x.is_nan() || ax < ay || (ax == ay && y.is_sign_positive()) { y } else { x };
+
+    // Canonicalize
+    res * F::ONE
+}
@@ -0,0 +1,31 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/frexp.c. Ported to generic Rust algorithm in 2025. */
+
+use super::super::{CastFrom, Float, IntTy, MinInt};
+
+/// Decompose a float into a normalized value within the range `[0.5, 1)`, and a power of 2.
+#[inline]
+pub fn frexp<F: Float>(x: F) -> (F, i32)
+where
+    F::Int: CastFrom<u32>,
+{
+    let mut ix = x.to_bits();
+    let ee = x.ex();
+
+    if ee == 0 {
+        if x != F::ZERO {
+            // Subnormal; scale into the normal range by 2^SIG_BITS and adjust the exponent.
+            let scale = F::from_parts(false, F::EXP_BIAS + F::SIG_BITS, IntTy::<F>::ZERO);
+            let (x, e) = frexp(x * scale);
+            return (x, e - F::SIG_BITS as i32);
+        }
+        return (x, 0);
+    } else if ee == F::EXP_SAT {
+        return (x, 0);
+    }
+
+    let e = ee as i32 - (F::EXP_BIAS as i32 - 1);
+    ix &= F::SIGN_MASK | F::SIG_MASK;
+    ix |= IntTy::<F>::cast_from(F::EXP_BIAS - 1) << F::SIG_BITS;
+    (F::from_bits(ix), e)
+}
@@ -0,0 +1,193 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! C23 `fromfp`, `ufromfp`, `fromfpx` and `ufromfpx`.
+//!
+//! All four round `x` to an integer and check that it fits in an integer of `width` bits. If
+//! it does not, or if `x` is infinite or NaN, or `width` is zero, the result is NaN and
+//! invalid is raised. The `x` variants additionally raise inexact if the result differs
+//! from `x`.
+
+use super::super::support::{FpResult, Round, Status};
+use super::super::{Float, MinInt};
+
+/// Round to an integer that fits in a signed `width`-bit integer.
+#[inline]
+pub fn fromfp<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    convert(x, round, width, true, false)
+}
+
+/// Round to an integer that fits in an unsigned `width`-bit integer.
+#[inline]
+pub fn ufromfp<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    convert(x, round, width, false, false)
+}
+
+/// Same as [`fromfp`] but raises inexact if the result is not `x`.
+#[inline]
+pub fn fromfpx<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    convert(x, round, width, true, true)
+}
+
+/// Same as [`ufromfp`] but raises inexact if the result is not `x`.
+#[inline]
+pub fn ufromfpx<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    convert(x, round, width, false, true)
+}
+
+fn convert<F: Float>(x: F, round: Round, width: u32, signed: bool, exact: bool) -> FpResult<F> {
+    if x.is_nan() || x.is_infinite() || width == 0 {
+        return FpResult::new(F::NAN, Status::INVALID);
+    }
+
+    let FpResult { val: r, status } = super::rint_round(x, round);
+
+    if !fits(r, width, signed) {
+        return FpResult::new(F::NAN, Status::INVALID);
+    }
+
+    if exact { FpResult::new(r, status) } else { FpResult::ok(r) }
+}
+
+/// Check whether the integer `r` is in range of an integer with `width` bits.
+fn fits<F: Float>(r: F, width: u32, signed: bool) -> bool {
+    if r == F::ZERO {
+        return true;
+    }
+    if !signed && r.is_sign_negative() {
+        return false;
+    }
+
+    // `|r| >= 1` so this is the exact exponent, and `|r| < 2^bits` is `e < bits`.
+    let e = r.exp_unbiased() as u32;
+    let bits = if signed { width - 1 } else { width };
+
+    // The signed range also includes `-2^(width - 1)`.
+    e < bits || (signed && r.is_sign_negative() && e == bits && r.frac() == F::Int::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::Hexf;
+
+    const ALL: [Round; 5] =
+        [Round::Positive, Round::Negative, Round::Zero, Round::NearestAway, Round::Nearest];
+
+    fn check<F: Float>(
+        f: fn(F, Round, u32) -> FpResult<F>,
+        x: F,
+        round: Round,
+        width: u32,
+        exp: F,
+        exp_stat: Status,
+    ) {
+        let FpResult { val, status } = f(x, round, width);
+        if exp.is_nan() {
+            assert!(val.is_nan(), "{} {round:?} {width}: expected NaN, got {}", Hexf(x), Hexf(val));
+        } else {
+            assert_biteq!(val, exp, "{} {round:?} {width}", Hexf(x));
+        }
+        assert_eq!(status, exp_stat, "{} {round:?} {width}", Hexf(x));
+    }
+
+    fn spec_test<F: Float>() {
+        let nan = F::NAN;
+        let half = F::from_parts(false, F::EXP_BIAS - 1, F::Int::ZERO);
+        let two = F::ONE + F::ONE;
+        let three = two + F::ONE;
+        let five_half = two + half;
+        let neg_five_half = -five_half;
+
+        for round in ALL {
+            for f in [fromfp, ufromfp, fromfpx, ufromfpx] {
+                check(f, nan, round, 8, nan, Status::INVALID);
+                check(f, F::INFINITY, round, 8, nan, Status::INVALID);
+                check(f, F::NEG_INFINITY, round, 8, nan, Status::INVALID);
+                check(f, F::ONE, round, 0, nan, Status::INVALID);
+                check(f, F::ZERO, round, 0, nan, Status::INVALID);
+                check(f, F::ZERO, round, 1, F::ZERO, Status::OK);
+                check(f, F::NEG_ZERO, round, 1, F::NEG_ZERO, Status::OK);
+                check(f, F::MAX, round, u32::MAX, F::MAX, Status::OK);
+            }
+
+            // Exact integers are never inexact
+            check(fromfpx, three, round, 3, three, Status::OK);
+            check(ufromfpx, three, round, 2, three, Status::OK);
+        }
+
+        let cases = [
+            (Round::Positive, three, -two),
+            (Round::Negative, two, -three),
+            (Round::Zero, two, -two),
+            (Round::NearestAway, three, -three),
+            (Round::Nearest, two, -two),
+        ];
+        for (round, pos, neg) in cases {
+            check(fromfp, five_half, round, 8, pos, Status::OK);
+            check(fromfp, neg_five_half, round, 8, neg, Status::OK);
+            check(fromfpx, five_half, round, 8, pos, Status::INEXACT);
+            check(fromfpx, neg_five_half, round, 8, neg, Status::INEXACT);
+            check(ufromfpx, five_half, round, 8, pos, Status::INEXACT);
+        }
+
+        // Signed `width = 2` is `-2..=1`, unsigned `width = 1` is `0..=1`
+        check(fromfp, -two, Round::Nearest, 2, -two, Status::OK);
+        check(fromfp, two, Round::Nearest, 2, nan, Status::INVALID);
+        check(fromfp, -three, Round::Nearest, 2, nan, Status::INVALID);
+        check(fromfp, -F::ONE, Round::Nearest, 1, -F::ONE, Status::OK);
+        check(fromfp, F::ONE, Round::Nearest, 1, nan, Status::INVALID);
+        check(ufromfp, F::ONE, Round::Nearest, 1, F::ONE, Status::OK);
+        check(ufromfp, two, Round::Nearest, 1, nan, Status::INVALID);
+        check(ufromfp, -F::ONE, Round::Nearest, 8, nan, Status::INVALID);
+
+        // Rounding happens before the range check
+        check(fromfp, -five_half, Round::Positive, 2, -two, Status::OK);
+        check(fromfp, -five_half, Round::Negative, 2, nan, Status::INVALID);
+        check(ufromfp, -half, Round::Positive, 1, F::NEG_ZERO, Status::OK);
+        check(ufromfp, -half, Round::Nearest, 1, F::NEG_ZERO, Status::OK);
+        check(ufromfp, -half, Round::NearestAway, 1, nan, Status::INVALID);
+        check(ufromfpx, -half, Round::Zero, 1, F::NEG_ZERO, Status::INEXACT);
+
+        // `MAX` is a little under `2^(EXP_MAX + 1)`
+        let w = F::EXP_MAX as u32 + 1;
+        check(ufromfp, F::MAX, Round::Nearest, w, F::MAX, Status::OK);
+        check(ufromfp, F::MAX, Round::Nearest, w - 1, nan, Status::INVALID);
+        check(fromfp, F::MAX, Round::Nearest, w + 1, F::MAX, Status::OK);
+        check(fromfp, F::MAX, Round::Nearest, w, nan, Status::INVALID);
+        check(fromfp, F::MIN, Round::Nearest, w, nan, Status::INVALID);
+        check(fromfp, F::MIN, Round::Nearest, w + 1, F::MIN, Status::OK);
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn spec_tests_f16() {
+        spec_test::<f16>();
+    }
+
+    #[test]
+    fn spec_tests_f32() {
+        spec_test::<f32>();
+    }
+
+    #[test]
+    fn spec_tests_f64() {
+        spec_test::<f64>();
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn spec_tests_f128() {
+        spec_test::<f128>();
+    }
+
+    #[test]
+    fn i32_u32_bounds() {
+        check(fromfp, -2147483648.0f64, Round::Nearest, 32, -2147483648.0, Status::OK);
+        check(fromfp, 2147483648.0f64, Round::Nearest, 32, f64::NAN, Status::INVALID);
+        check(fromfp, 2147483647.5f64, Round::Negative, 32, 2147483647.0, Status::OK);
+        check(fromfp, 2147483647.5f64, Round::Nearest, 32, f64::NAN, Status::INVALID);
+        check(fromfp, -2147483648.5f64, Round::Zero, 32, -2147483648.0, Status::OK);
+        check(fromfp, -2147483648.5f64, Round::Negative, 32, f64::NAN, Status::INVALID);
+        check(ufromfp, 4294967295.0f64, Round::Nearest, 32, 4294967295.0, Status::OK);
+        check(ufromfp, 4294967295.5f64, Round::Nearest, 32, f64::NAN, Status::INVALID);
+    }
+}
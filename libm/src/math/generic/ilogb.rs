@@ -0,0 +1,43 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/ilogb.c. Ported to generic Rust algorithm in 2025. */
+
+use super::super::{Float, Int, MinInt};
+
+const FP_ILOGBNAN: i32 = i32::MIN;
+const FP_ILOGB0: i32 = FP_ILOGBNAN;
+const FP_LLOGBNAN: i64 = i64::MIN;
+const FP_LLOGB0: i64 = FP_LLOGBNAN;
+
+/// Extract the unbiased exponent of `x` as an integer.
+#[inline]
+pub fn ilogb<F: Float>(x: F) -> i32 {
+    let zero = F::Int::ZERO;
+    let mut i = x.to_bits();
+    let e = x.ex() as i32;
+
+    if e == 0 {
+        i <<= F::EXP_BITS + 1;
+        if i == zero {
+            force_eval!(0.0 / 0.0);
+            return FP_ILOGB0;
+        }
+        /* subnormal x */
+        -(F::EXP_BIAS as i32) - i.leading_zeros() as i32
+    } else if e == F::EXP_SAT as i32 {
+        force_eval!(0.0 / 0.0);
+        if i << (F::EXP_BITS + 1) != zero { FP_ILOGBNAN } else { i32::MAX }
+    } else {
+        e - F::EXP_BIAS as i32
+    }
+}
+
+/// Extract the unbiased exponent of `x` as an `i64`.
+#[inline]
+pub fn llogb<F: Float>(x: F) -> i64 {
+    // Every exponent fits in an `i32`, so only the special values need to change.
+    match ilogb(x) {
+        FP_ILOGB0 => FP_LLOGB0,
+        i32::MAX => i64::MAX,
+        e => e.into(),
+    }
+}
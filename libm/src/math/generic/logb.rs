@@ -0,0 +1,20 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/logb.c. Ported to generic Rust algorithm in 2025. */
+
+use super::super::{CastFrom, Float};
+use super::ilogb;
+
+/// Extract the unbiased exponent of `x` as a float.
+#[inline]
+pub fn logb<F: Float + CastFrom<i32>>(x: F) -> F {
+    if x.ex() == F::EXP_SAT {
+        /* logb(+-inf) = inf, logb(nan) = nan */
+        return x * x;
+    }
+    if x == F::ZERO {
+        /* logb(+-0) = -inf, raising divide-by-zero */
+        return F::NEG_ONE / (x * x);
+    }
+
+    F::cast_from(ilogb(x))
+}
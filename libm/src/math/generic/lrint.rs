@@ -0,0 +1,168 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! Rounding to `i64`, used for `lrint`, `llrint`, `lround` and `llround`.
+//!
+//! C leaves the result unspecified if the rounded value does not fit in the return type. We
+//! return `i64::MIN` and raise invalid in this case, for NaN and for infinities, which matches
+//! the "integer indefinite" value produced by x86's conversion instructions.
+
+use super::super::support::{FpResult, Round, Status};
+use super::super::{CastInto, Float, MinInt};
+
+/// Round to an integer in the direction `round`, raising inexact if the result is not `x`.
+#[inline]
+pub fn lrint_round<F: Float>(x: F, round: Round) -> FpResult<i64>
+where
+    F::Int: CastInto<u64>,
+{
+    let FpResult { val: r, status } = super::rint_round(x, round);
+    let res = to_i64(r);
+
+    if res.status == Status::OK { FpResult::new(res.val, status) } else { res }
+}
+
+/// Round to the nearest integer, breaking ties away from zero.
+#[inline]
+pub fn lround<F: Float>(x: F) -> FpResult<i64>
+where
+    F::Int: CastInto<u64>,
+{
+    to_i64(super::round(x))
+}
+
+/// Convert an integral float to `i64`.
+fn to_i64<F: Float>(r: F) -> FpResult<i64>
+where
+    F::Int: CastInto<u64>,
+{
+    const INVALID: FpResult<i64> = FpResult { val: i64::MIN, status: Status::INVALID };
+
+    if r.is_nan() || r.is_infinite() {
+        return INVALID;
+    }
+    if r == F::ZERO {
+        return FpResult::ok(0);
+    }
+
+    // `|r| >= 1` so this is the exact exponent. `-2^63` is the only value that fits with `e = 63`.
+    let e = r.exp_unbiased();
+    if e >= 63 {
+        if e == 63 && r.is_sign_negative() && r.frac() == F::Int::ZERO {
+            return FpResult::ok(i64::MIN);
+        }
+        return INVALID;
+    }
+
+    // Shift the significand so that the value is in the lowest bits. `e < 63` so the result
+    // always fits in a `u64`.
+    let sig = r.frac() | F::IMPLICIT_BIT;
+    let e = e as u32;
+    let abs: u64 = if e >= F::SIG_BITS {
+        sig.cast() << (e - F::SIG_BITS)
+    } else {
+        (sig >> (F::SIG_BITS - e)).cast()
+    };
+
+    let res = if r.is_sign_negative() { -(abs as i64) } else { abs as i64 };
+    FpResult::ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::Hexf;
+
+    fn lrint<F: Float>(x: F) -> FpResult<i64>
+    where
+        F::Int: CastInto<u64>,
+    {
+        lrint_round(x, Round::Nearest)
+    }
+
+    fn check<F: Float>(f: fn(F) -> FpResult<i64>, x: F, exp: i64, exp_stat: Status) {
+        let FpResult { val, status } = f(x);
+        assert_eq!(val, exp, "{}", Hexf(x));
+        assert_eq!(status, exp_stat, "{}", Hexf(x));
+    }
+
+    fn spec_test<F: Float>()
+    where
+        F::Int: CastInto<u64>,
+    {
+        let half = F::from_parts(false, F::EXP_BIAS - 1, F::Int::ZERO);
+        let two = F::ONE + F::ONE;
+        let five_half = two + two + half;
+
+        for f in [lrint::<F>, lround::<F>] {
+            check(f, F::NAN, i64::MIN, Status::INVALID);
+            check(f, F::NEG_NAN, i64::MIN, Status::INVALID);
+            check(f, F::INFINITY, i64::MIN, Status::INVALID);
+            check(f, F::NEG_INFINITY, i64::MIN, Status::INVALID);
+            check(f, F::ZERO, 0, Status::OK);
+            check(f, F::NEG_ZERO, 0, Status::OK);
+            check(f, F::ONE, 1, Status::OK);
+            check(f, F::NEG_ONE, -1, Status::OK);
+        }
+
+        check(lrint, F::MIN_POSITIVE_NORMAL, 0, Status::INEXACT);
+        check(lround, F::MIN_POSITIVE_NORMAL, 0, Status::OK);
+
+        // Ties go to even for `lrint` and away from zero for `lround`
+        check(lrint, half, 0, Status::INEXACT);
+        check(lrint, -half, 0, Status::INEXACT);
+        check(lrint, five_half, 4, Status::INEXACT);
+        check(lrint, -five_half, -4, Status::INEXACT);
+        check(lround, half, 1, Status::OK);
+        check(lround, -half, -1, Status::OK);
+        check(lround, five_half, 5, Status::OK);
+        check(lround, -five_half, -5, Status::OK);
+
+        if F::EXP_MAX >= 64 {
+            let pow63 = F::from_parts(false, F::EXP_BIAS + 63, F::Int::ZERO);
+            for f in [lrint::<F>, lround::<F>] {
+                check(f, -pow63, i64::MIN, Status::OK);
+                check(f, pow63, i64::MIN, Status::INVALID);
+                check(f, F::MAX, i64::MIN, Status::INVALID);
+                check(f, F::MIN, i64::MIN, Status::INVALID);
+            }
+        } else {
+            // Every finite value is in range
+            for f in [lrint::<F>, lround::<F>] {
+                check(f, F::MAX, -f(F::MIN).val, Status::OK);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn spec_tests_f16() {
+        spec_test::<f16>();
+    }
+
+    #[test]
+    fn spec_tests_f32() {
+        spec_test::<f32>();
+    }
+
+    #[test]
+    fn spec_tests_f64() {
+        spec_test::<f64>();
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn spec_tests_f128() {
+        spec_test::<f128>();
+    }
+
+    #[test]
+    fn i64_bounds() {
+        check(lrint, 9223372036854774784.0f64, 9223372036854774784, Status::OK);
+        check(lround, -9223372036854775808.0f64, i64::MIN, Status::OK);
+        check(lround, 4503599627370495.5f64, 4503599627370496, Status::OK);
+        check(lrint, 4503599627370495.5f64, 4503599627370496, Status::INEXACT);
+        check(lrint, 4503599627370494.5f64, 4503599627370494, Status::INEXACT);
+        check(lround, 16777215.0f32, 16777215, Status::OK);
+        check(lrint, 0.49999997f32, 0, Status::INEXACT);
+        check(lround, 0.49999997f32, 0, Status::OK);
+    }
+}
@@ -1,38 +1,76 @@
 // Note: generic functions are marked `#[inline]` because, even though generic functions are
 // typically inlined, this does not seem to always be the case.
 
+mod canonicalize;
 mod ceil;
 mod copysign;
 mod fabs;
 mod fdim;
 mod floor;
+mod fma;
 mod fmax;
 mod fmaximum;
+mod fmaximum_mag;
+mod fmaximum_mag_num;
 mod fmaximum_num;
 mod fmin;
 mod fminimum;
+mod fminimum_mag;
+mod fminimum_mag_num;
 mod fminimum_num;
 mod fmod;
+mod frexp;
+mod fromfp;
+mod ilogb;
+mod logb;
+mod lrint;
+mod modf;
+mod narrow;
+mod nextafter;
+mod payload;
+mod rem_half;
+mod remquo;
 mod rint;
 mod round;
 mod scalbn;
 mod sqrt;
+mod totalorder;
 mod trunc;
 
+pub use canonicalize::{canonicalize, issignaling};
 pub use ceil::ceil;
 pub use copysign::copysign;
 pub use fabs::fabs;
 pub use fdim::fdim;
 pub use floor::floor;
+pub use fma::fma_round;
 pub use fmax::fmax;
 pub use fmaximum::fmaximum;
+pub use fmaximum_mag::fmaximum_mag;
+pub use fmaximum_mag_num::fmaximum_mag_num;
 pub use fmaximum_num::fmaximum_num;
 pub use fmin::fmin;
 pub use fminimum::fminimum;
+pub use fminimum_mag::fminimum_mag;
+pub use fminimum_mag_num::fminimum_mag_num;
 pub use fminimum_num::fminimum_num;
 pub use fmod::fmod;
+pub use frexp::frexp;
+pub use fromfp::{fromfp, fromfpx, ufromfp, ufromfpx};
+pub use ilogb::{ilogb, llogb};
+pub use logb::logb;
+pub use lrint::{lrint_round, lround};
+pub use modf::modf;
+pub use narrow::{
+    NarrowHelper, fadd_round, fdiv_round, ffma_round, fmul_round, fsqrt_round, fsub_round,
+};
+pub use nextafter::{nextafter, nextdown, nexttoward, nextup};
+pub use payload::{getpayload, nan, setpayload, setpayloadsig};
+pub use rem_half::rem_half;
+pub use remquo::remquo;
 pub use rint::rint_round;
 pub use round::round;
 pub use scalbn::scalbn;
-pub use sqrt::sqrt;
+pub use sqrt::{sqrt, sqrt_round};
+pub use totalorder::{totalorder, totalordermag};
 pub use trunc::trunc;
@@ -0,0 +1,37 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/modf.c. Ported to generic Rust algorithm in 2025. */
+
+use super::super::{Float, MinInt};
+
+/// Split `x` into fractional and integral parts, both with the sign of `x`.
+#[inline]
+pub fn modf<F: Float>(x: F) -> (F, F) {
+    let zero = F::Int::ZERO;
+    let mut u = x.to_bits();
+    let e = x.exp_unbiased();
+
+    /* no fractional part */
+    if e >= F::SIG_BITS as i32 {
+        if x.is_nan() {
+            return (x, x);
+        }
+        u &= F::SIGN_MASK;
+        return (F::from_bits(u), x);
+    }
+
+    /* no integral part */
+    if e < 0 {
+        u &= F::SIGN_MASK;
+        return (x, F::from_bits(u));
+    }
+
+    let mask = F::SIG_MASK >> e as u32;
+    if u & mask == zero {
+        u &= F::SIGN_MASK;
+        return (F::from_bits(u), x);
+    }
+
+    u &= !mask;
+    let int = F::from_bits(u);
+    (x - int, int)
+}
@@ -0,0 +1,552 @@
+//! Arithmetic that rounds to a narrower type than its operands, e.g. `f64 + f64 -> f32`.
+//!
+//! Computing the result in the wide type and then narrowing it would round twice, which is
+//! not always correct. Instead, each operation computes its result in the wide type using
+//! round-to-odd: if the result is inexact, the last bit of the significand is forced to one.
+//! As long as the wide type has at least two more than twice the bits of precision of the
+//! narrow type, which is true for all of `f32 -> f16`, `f64 -> f32` and `f128 -> f64`, rounding
+//! that value once more gives the same result as rounding the exact result directly. Since the
+//! inexact case can never be a tie in the narrow type, the final rounding can also be done in
+//! any direction.
+//!
+//! Round-to-odd results are computed from the round-to-nearest result plus the sign of the
+//! rounding error, which is available exactly from the usual error-free transformations.
+
+use super::super::support::{FpResult, IntTy, Round, Status};
+use super::super::{DFloat, Float, HFloat, MinInt};
+
+/// Operations needed on the wide type that are not part of `Float`.
+pub trait NarrowHelper: DFloat {
+    /// Decompose into a significand in `[0.5, 1)` and a power of two.
+    fn frexp(self) -> (Self, i32);
+
+    /// `self * 2^n`.
+    fn scalbn(self, n: i32) -> Self;
+
+    /// Correctly rounded square root.
+    fn sqrt(self) -> Self;
+}
+
+#[cfg(f16_enabled)]
+impl NarrowHelper for f32 {
+    fn frexp(self) -> (Self, i32) {
+        super::super::frexpf(self)
+    }
+
+    fn scalbn(self, n: i32) -> Self {
+        super::super::scalbnf(self, n)
+    }
+
+    fn sqrt(self) -> Self {
+        super::super::sqrtf(self)
+    }
+}
+
+impl NarrowHelper for f64 {
+    fn frexp(self) -> (Self, i32) {
+        super::super::frexp(self)
+    }
+
+    fn scalbn(self, n: i32) -> Self {
+        super::super::scalbn(self, n)
+    }
+
+    fn sqrt(self) -> Self {
+        super::super::sqrt(self)
+    }
+}
+
+#[cfg(f128_enabled)]
+impl NarrowHelper for f128 {
+    fn frexp(self) -> (Self, i32) {
+        super::super::frexpf128(self)
+    }
+
+    fn scalbn(self, n: i32) -> Self {
+        super::super::scalbnf128(self, n)
+    }
+
+    fn sqrt(self) -> Self {
+        super::super::sqrtf128(self)
+    }
+}
+
+/// `x + y` rounded to the narrow type.
+#[inline]
+pub fn fadd_round<B: NarrowHelper>(x: B, y: B, round: Round) -> FpResult<B::H> {
+    if x.is_nan() || y.is_nan() || x.is_infinite() || y.is_infinite() {
+        return special(x + y, !x.is_nan() && !y.is_nan());
+    }
+
+    let s = x + y;
+    if s == B::ZERO {
+        // Addition that rounds to zero is exact.
+        return FpResult::ok(zero_sum(x, y, round).narrow());
+    }
+
+    let val = if s.is_infinite() {
+        // Overflow in the wide type; the largest finite value overflows the narrow type the same
+        // way.
+        B::MAX.copysign(s)
+    } else {
+        let (s, err) = two_sum(x, y);
+        round_odd(s, err)
+    };
+
+    narrow_round(val, round)
+}
+
+/// `x - y` rounded to the narrow type.
+#[inline]
+pub fn fsub_round<B: NarrowHelper>(x: B, y: B, round: Round) -> FpResult<B::H> {
+    fadd_round(x, -y, round)
+}
+
+/// `x * y` rounded to the narrow type.
+#[inline]
+pub fn fmul_round<B: NarrowHelper>(x: B, y: B, round: Round) -> FpResult<B::H> {
+    if !is_finite_nonzero(x) || !is_finite_nonzero(y) {
+        // Zeros, infinities and NaNs all give exact results.
+        return special(x * y, !x.is_nan() && !y.is_nan());
+    }
+
+    let (mx, ex) = x.frexp();
+    let (my, ey) = y.frexp();
+
+    // The product of the significands is in `[0.25, 1)` so nothing can overflow or underflow.
+    let p = mx * my;
+    let err = mx.fma(my, -p);
+
+    scale_narrow(round_odd(p, err), ex + ey, round)
+}
+
+/// `x / y` rounded to the narrow type.
+#[inline]
+pub fn fdiv_round<B: NarrowHelper>(x: B, y: B, round: Round) -> FpResult<B::H> {
+    if !is_finite_nonzero(x) || !is_finite_nonzero(y) {
+        let q = x / y;
+        if y == B::ZERO && is_finite_nonzero(x) {
+            return FpResult::new(q.narrow(), Status::DIVIDE_BY_ZERO);
+        }
+        return special(q, !x.is_nan() && !y.is_nan());
+    }
+
+    let (mx, ex) = x.frexp();
+    let (my, ey) = y.frexp();
+
+    // The quotient is in `(0.5, 2)`, and the remainder of a correctly rounded quotient is exact.
+    let q = mx / my;
+    let rem = (-q).fma(my, mx);
+    let err = if my.is_sign_negative() { -rem } else { rem };
+
+    scale_narrow(round_odd(q, err), ex - ey, round)
+}
+
+/// `sqrt(x)` rounded to the narrow type.
+#[inline]
+pub fn fsqrt_round<B: NarrowHelper>(x: B, round: Round) -> FpResult<B::H> {
+    if x.is_nan() || x == B::ZERO || x == B::INFINITY {
+        return FpResult::ok(x.narrow());
+    }
+    if x.is_sign_negative() {
+        return FpResult::new(B::NAN.narrow(), Status::INVALID);
+    }
+
+    // Make the exponent even so it can be halved, leaving the significand in `[0.5, 2)`.
+    let (mut m, mut e) = x.frexp();
+    if e & 1 != 0 {
+        m = m + m;
+        e -= 1;
+    }
+
+    // The remainder of a correctly rounded square root is exact.
+    let s = m.sqrt();
+    let rem = (-s).fma(s, m);
+
+    scale_narrow(round_odd(s, rem), e / 2, round)
+}
+
+/// `(x * y) + z` rounded once to the narrow type.
+#[inline]
+pub fn ffma_round<B: NarrowHelper>(x: B, y: B, z: B, round: Round) -> FpResult<B::H> {
+    if !is_finite_nonzero(x) || !is_finite_nonzero(y) {
+        if x.is_nan() || y.is_nan() || x.is_infinite() || y.is_infinite() {
+            let r = x.fma(y, z);
+            return special(r, !x.is_nan() && !y.is_nan() && !z.is_nan());
+        }
+
+        // One of `x` or `y` is zero so the product is an exact signed zero.
+        return fadd_round(x * y, z, round);
+    }
+    if z == B::ZERO {
+        // The product is nonzero, so adding zero never changes the result.
+        return fmul_round(x, y, round);
+    }
+    if z.is_nan() || z.is_infinite() {
+        return special(z, false);
+    }
+
+    let (mx, ex) = x.frexp();
+    let (my, ey) = y.frexp();
+    let (mz, ez) = z.frexp();
+
+    // Work relative to the product so `mx * my` is in `[0.25, 1)`.
+    let d = ez - (ex + ey);
+    let far = (2 * B::SIG_BITS + 8) as i32;
+
+    if d > far {
+        // The product is far below the last bit of `z` and only decides the direction of
+        // rounding.
+        return scale_narrow(round_odd(mz, mx * my), ez, round);
+    }
+
+    let zz = if d < -far {
+        // `z` is far below the last bit of the exact product. Any value that small with the
+        // same sign gives the same round-to-odd result, so use one that is in range.
+        B::ONE.scalbn(-3 * B::SIG_BITS as i32).copysign(mz)
+    } else {
+        mz.scalbn(d)
+    };
+
+    let r = mx.fma(my, zz);
+    if r == B::ZERO {
+        // `fma` that rounds to zero is exact.
+        return FpResult::ok(zero_sum(mx * my, zz, round).narrow());
+    }
+
+    // Exact error of the fma, `err = (mx * my + zz) - r`, from Boldo and Muller's `ErrFma`.
+    // Only its sign is needed.
+    let u1 = mx * my;
+    let u2 = mx.fma(my, -u1);
+    let (a1, a2) = two_sum(zz, u2);
+    let (b1, b2) = two_sum(u1, a1);
+    let g = (b1 - r) + b2;
+    let r2 = g + a2;
+    let r3 = a2 - (r2 - g);
+    let err = if r2 != B::ZERO { r2 } else { r3 };
+
+    scale_narrow(round_odd(r, err), ex + ey, round)
+}
+
+fn is_finite_nonzero<F: Float>(x: F) -> bool {
+    !x.is_nan() && !x.is_infinite() && x != F::ZERO
+}
+
+/// Narrow the result of an operation with a NaN, infinite or zero operand, which is exact.
+/// `INVALID` is raised if the result is NaN but no input was.
+fn special<B: DFloat>(r: B, inputs_not_nan: bool) -> FpResult<B::H> {
+    let status = if r.is_nan() && inputs_not_nan { Status::INVALID } else { Status::OK };
+    FpResult::new(r.narrow(), status)
+}
+
+/// The sign of an exact zero sum `x + y`, which depends on the rounding mode when the signs of
+/// `x` and `y` differ.
+fn zero_sum<F: Float>(x: F, y: F, round: Round) -> F {
+    if x.is_sign_negative() == y.is_sign_negative() {
+        F::ZERO.copysign(x)
+    } else if round == Round::Negative {
+        F::NEG_ZERO
+    } else {
+        F::ZERO
+    }
+}
+
+/// Exact sum `a + b = s + err`, assuming nothing overflows.
+fn two_sum<F: Float>(a: F, b: F) -> (F, F) {
+    let s = a + b;
+    let bb = s - a;
+    let err = (a - (s - bb)) + (b - bb);
+    (s, err)
+}
+
+/// Round `r + err` to odd, where `r` is the result rounded to nearest and `err` is (an
+/// approximation with the correct sign of) its rounding error.
+fn round_odd<F: Float>(r: F, err: F) -> F {
+    let one = IntTy::<F>::ONE;
+    let bits = r.to_bits();
+
+    if err == F::ZERO || bits & one != IntTy::<F>::ZERO {
+        return r;
+    }
+    if r == F::ZERO {
+        return F::from_bits(one).copysign(err);
+    }
+
+    // `r` is even, so the neighbor in the direction of the error is odd.
+    if r.is_sign_negative() == err.is_sign_negative() {
+        F::from_bits(bits + one)
+    } else {
+        F::from_bits(bits - one)
+    }
+}
+
+/// Narrow `r * 2^e`, where `r` is nonzero and already rounded to odd.
+fn scale_narrow<B: NarrowHelper>(r: B, e: i32, round: Round) -> FpResult<B::H> {
+    let (m, k) = r.frexp();
+    let e = e + k;
+
+    // Values far outside of the range of the narrow type may not fit in the wide type either.
+    // Replace them with values that round the same way.
+    let val = if e > B::H::EXP_MAX + 2 {
+        B::MAX.copysign(m)
+    } else if e < B::H::EXP_MIN_SUBNORM - 2 {
+        B::MIN_POSITIVE_NORMAL.copysign(m)
+    } else {
+        m.scalbn(e)
+    };
+
+    narrow_round(val, round)
+}
+
+/// Narrow `x` with the given rounding mode. If `x` is inexact, it must be rounded to odd.
+fn narrow_round<B: DFloat>(x: B, round: Round) -> FpResult<B::H> {
+    let mut n = x.narrow();
+    let nw = n.widen();
+    if nw == x || x.is_nan() {
+        return FpResult::ok(n);
+    }
+
+    // `as` rounds to nearest with ties to even, so adjust by one step for the other modes.
+    let up = match round {
+        Round::Nearest => None,
+        Round::Negative => (nw > x).then_some(false),
+        Round::Positive => (nw < x).then_some(true),
+        Round::Zero => (nw.abs() > x.abs()).then_some(x.is_sign_negative()),
+        Round::NearestAway => {
+            // Values rounded to odd are never halfway, so only exact ties need to move. Both
+            // differences are exact in the wide type.
+            let up = nw < x;
+            let tie = x - nw == next(n, up).widen() - x;
+            (tie && nw.abs() < x.abs()).then_some(up)
+        }
+    };
+    if let Some(up) = up {
+        n = next(n, up);
+    }
+
+    let mut status = Status::INEXACT;
+
+    // Overflow happens if the result, rounded with an unbounded exponent, is larger than the
+    // largest finite value.
+    let limit =
+        B::from_parts(false, (B::EXP_BIAS as i32 + B::H::EXP_MAX + 1) as u32, IntTy::<B>::ZERO);
+    if n.is_infinite() || x.abs() >= limit {
+        status = status.with(Status::OVERFLOW);
+    }
+    if x.abs() < B::H::MIN_POSITIVE_NORMAL.widen() {
+        status.set_underflow(true);
+    }
+
+    FpResult::new(n, status)
+}
+
+/// The next representable value after `x` toward positive (`up`) or negative infinity.
+fn next<F: Float>(x: F, up: bool) -> F {
+    let one = IntTy::<F>::ONE;
+
+    if x == F::ZERO {
+        let tiny = F::from_bits(one);
+        return if up { tiny } else { -tiny };
+    }
+
+    let bits = x.to_bits();
+    if x.is_sign_positive() == up { F::from_bits(bits + one) } else { F::from_bits(bits - one) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUNDS: [Round; 5] =
+        [Round::Nearest, Round::Negative, Round::Positive, Round::Zero, Round::NearestAway];
+
+    #[test]
+    fn round_modes() {
+        // 1 + 2^-30 lies between two f32 values; 1 - 2^-30 is below 1.
+        let x = 1.0 + hf64!("0x1p-30");
+        let lo = 1.0f32;
+        let hi = 1.0 + f32::EPSILON;
+
+        let expected = [lo, lo, hi, lo, lo];
+        for (round, exp) in ROUNDS.into_iter().zip(expected) {
+            let FpResult { val, status } = fadd_round(x, 0.0, round);
+            assert_biteq!(val, exp, "{round:?}");
+            assert_eq!(status, Status::INEXACT, "{round:?}");
+
+            let FpResult { val, .. } = fsub_round(-x, 0.0, round);
+            let exp = match round {
+                Round::Negative => -hi,
+                Round::Positive => -lo,
+                _ => -exp,
+            };
+            assert_biteq!(val, exp, "{round:?}");
+        }
+    }
+
+    #[test]
+    fn double_rounding() {
+        // 1 + 2^-24 + 2^-60 rounds to 1 + 2^-24 in f64, which is a tie in f32 that rounds down.
+        // The exact result is above the tie so it must round up.
+        let x = 1.0 + hf64!("0x1p-24");
+        let y = hf64!("0x1p-60");
+        let hi = 1.0 + f32::EPSILON;
+        assert_biteq!(fadd_round(x, y, Round::Nearest).val, hi);
+        assert_biteq!(x as f32, 1.0);
+
+        // (1 + 2^-30)^2 = 1 + 2^-29 + 2^-60, with the same problem.
+        let m = 1.0 + hf64!("0x1p-30");
+        assert_biteq!(fmul_round(m, m, Round::Nearest).val, 1.0);
+        let m = 1.0 + hf64!("0x1p-25");
+        assert_biteq!(fmul_round(m, m, Round::Nearest).val, hi);
+
+        // fma(2^-12, 2^-12, 1 + 2^-24) is exactly above a tie.
+        let t = hf64!("0x1p-36");
+        assert_biteq!(ffma_round(t, t, x, Round::Nearest).val, hi);
+        assert_biteq!(ffma_round(t, -t, x, Round::Nearest).val, 1.0);
+    }
+
+    #[test]
+    fn signed_zero() {
+        for round in ROUNDS {
+            let exp = if round == Round::Negative { -0.0f32 } else { 0.0 };
+            assert_biteq!(fadd_round(1.5f64, -1.5, round).val, exp, "{round:?}");
+            assert_biteq!(fsub_round(1.5f64, 1.5, round).val, exp, "{round:?}");
+            assert_biteq!(ffma_round(2.0f64, 3.0, -6.0, round).val, exp, "{round:?}");
+            assert_biteq!(fadd_round(-0.0f64, -0.0, round).val, -0.0f32, "{round:?}");
+            assert_biteq!(ffma_round(0.0f64, -1.0, -0.0, round).val, -0.0f32, "{round:?}");
+            assert_biteq!(fmul_round(-0.0f64, 3.0, round).val, -0.0f32, "{round:?}");
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let big = f64::MAX;
+        let inf = f32::INFINITY;
+        let max = f32::MAX;
+
+        let expected = [inf, max, inf, max];
+        for (round, exp) in ROUNDS.into_iter().zip(expected) {
+            for res in [
+                fadd_round(big, big, round),
+                fmul_round(big, 2.0, round),
+                fdiv_round(big, 0.5, round),
+                ffma_round(big, big, 1.0, round),
+                fadd_round(1e39, 0.0, round),
+            ] {
+                assert_biteq!(res.val, exp, "{round:?}");
+                assert_eq!(res.status, Status::INEXACT.with(Status::OVERFLOW), "{round:?}");
+            }
+        }
+
+        // Just above the largest value but not enough to overflow when rounding to nearest
+        let x = max as f64 + hf64!("0x1p100");
+        let FpResult { val, status } = fadd_round(x, 0.0, Round::Nearest);
+        assert_biteq!(val, max);
+        assert_eq!(status, Status::INEXACT);
+        let FpResult { val, status } = fadd_round(x, 0.0, Round::Positive);
+        assert_biteq!(val, inf);
+        assert_eq!(status, Status::INEXACT.with(Status::OVERFLOW));
+    }
+
+    #[test]
+    fn underflow() {
+        let tiny = f64::MIN_POSITIVE;
+        let sub = f32::from_bits(1);
+
+        let expected = [0.0, 0.0, sub, 0.0];
+        for (round, exp) in ROUNDS.into_iter().zip(expected) {
+            for res in [
+                fadd_round(tiny, tiny, round),
+                fmul_round(tiny, tiny, round),
+                fdiv_round(tiny, 3.0, round),
+                ffma_round(tiny, tiny, tiny, round),
+            ] {
+                assert_biteq!(res.val, exp, "{round:?}");
+                assert_eq!(res.status, Status::INEXACT.with(Status::UNDERFLOW), "{round:?}");
+            }
+        }
+
+        // Exact subnormal results do not underflow
+        let FpResult { val, status } = fmul_round(sub as f64, 3.0, Round::Nearest);
+        assert_biteq!(val, f32::from_bits(3));
+        assert_eq!(status, Status::OK);
+    }
+
+    #[test]
+    fn exceptions() {
+        let inf = f64::INFINITY;
+
+        let res = fadd_round(inf, -inf, Round::Nearest);
+        assert!(res.val.is_nan());
+        assert_eq!(res.status, Status::INVALID);
+
+        let res = fmul_round(0.0, inf, Round::Nearest);
+        assert!(res.val.is_nan());
+        assert_eq!(res.status, Status::INVALID);
+
+        let res = fdiv_round(0.0f64, 0.0, Round::Nearest);
+        assert!(res.val.is_nan());
+        assert_eq!(res.status, Status::INVALID);
+
+        let res = fsqrt_round(-1.0f64, Round::Nearest);
+        assert!(res.val.is_nan());
+        assert_eq!(res.status, Status::INVALID);
+
+        let res = ffma_round(inf, 1.0, -inf, Round::Nearest);
+        assert!(res.val.is_nan());
+        assert_eq!(res.status, Status::INVALID);
+
+        let res = fdiv_round(-1.0f64, 0.0, Round::Nearest);
+        assert_biteq!(res.val, f32::NEG_INFINITY);
+        assert_eq!(res.status, Status::DIVIDE_BY_ZERO);
+
+        let res = fdiv_round(inf, 0.0, Round::Nearest);
+        assert_biteq!(res.val, f32::INFINITY);
+        assert_eq!(res.status, Status::OK);
+
+        let res = fadd_round(f64::NAN, 1.0, Round::Nearest);
+        assert!(res.val.is_nan());
+        assert_eq!(res.status, Status::OK);
+    }
+
+    #[test]
+    fn directed_results() {
+        // 1/3 and sqrt(2) are never exact.
+        for (round, up) in [(Round::Negative, false), (Round::Positive, true), (Round::Zero, false)]
+        {
+            let FpResult { val, status } = fdiv_round(1.0f64, 3.0, round);
+            assert_eq!(status, Status::INEXACT);
+            assert_eq!((val as f64) > 1.0 / 3.0, up, "{round:?}");
+
+            let FpResult { val, status } = fsqrt_round(2.0f64, round);
+            assert_eq!(status, Status::INEXACT);
+            assert_eq!((val as f64) > core::f64::consts::SQRT_2, up, "{round:?}");
+        }
+    }
+
+    #[test]
+    fn ties_away() {
+        let half_ulp = f64::from(f32::EPSILON) / 2.0;
+        let odd = 1.0 + f32::EPSILON;
+
+        for (x, even, away) in
+            [(1.0, 1.0, odd), (f64::from(odd), odd + f32::EPSILON, odd + f32::EPSILON)]
+        {
+            let res = fadd_round(x, half_ulp, Round::Nearest);
+            assert_biteq!(res.val, even);
+            assert_eq!(res.status, Status::INEXACT);
+
+            let res = fadd_round(x, half_ulp, Round::NearestAway);
+            assert_biteq!(res.val, away);
+            assert_eq!(res.status, Status::INEXACT);
+
+            let res = fsub_round(-x, half_ulp, Round::NearestAway);
+            assert_biteq!(res.val, -away);
+        }
+
+        // Anything short of a tie still rounds to nearest.
+        let below = half_ulp - f64::EPSILON;
+        assert_biteq!(fadd_round(1.0, below, Round::NearestAway).val, 1.0f32);
+        assert_biteq!(fmul_round(f64::from(f32::MAX), 2.0, Round::NearestAway).val, f32::INFINITY);
+    }
+}
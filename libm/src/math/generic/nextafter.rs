@@ -0,0 +1,177 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/nextafter.c. Ported to generic Rust algorithm in 2025. */
+
+use super::super::{CastInto, Float, MinInt};
+
+/// Return the next representable value after `x` in the direction of `y`.
+#[inline]
+pub fn nextafter<F: Float>(x: F, y: F) -> F {
+    let zero = F::Int::ZERO;
+    let one = F::Int::ONE;
+
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    let mut ux = x.to_bits();
+    let uy = y.to_bits();
+    if ux == uy {
+        return y;
+    }
+
+    let ax = ux & !F::SIGN_MASK;
+    let ay = uy & !F::SIGN_MASK;
+    if ax == zero {
+        if ay == zero {
+            return y;
+        }
+        ux = (uy & F::SIGN_MASK) | one;
+    } else if ax > ay || (ux ^ uy) & F::SIGN_MASK != zero {
+        ux -= one;
+    } else {
+        ux += one;
+    }
+
+    let e = ux & F::EXP_MASK;
+    let r = F::from_bits(ux);
+    // raise overflow if r is infinite and x is finite
+    if e == F::EXP_MASK {
+        force_eval!(x + x);
+    }
+    // raise underflow if r is subnormal or zero
+    if e == zero {
+        force_eval!(x * x + r * r);
+    }
+    r
+}
+
+/// Return the next representable value after `x` in the direction of `y`, which may be of a
+/// wider type.
+#[inline]
+pub fn nexttoward<F: Float, G: Float + From<F> + CastInto<F>>(x: F, y: G) -> F {
+    if x.is_nan() || y.is_nan() {
+        return x + y.cast_lossy();
+    }
+
+    let wx = G::from(x);
+    if wx == y {
+        // The result is `y` converted to `F`, which is only different from `x` in the sign of zero
+        return if x == F::ZERO && y.is_sign_negative() != x.is_sign_negative() { -x } else { x };
+    }
+
+    nextafter(x, if y > wx { F::INFINITY } else { F::NEG_INFINITY })
+}
+
+/// Return the least value that compares greater than `x`.
+///
+/// Unlike `nextafter`, this does not raise overflow or underflow.
+#[inline]
+pub fn nextup<F: Float>(x: F) -> F {
+    if x.is_nan() {
+        return x + x;
+    }
+
+    let bits = x.to_bits();
+    if bits == F::INFINITY.to_bits() {
+        return x;
+    }
+
+    let abs = bits & !F::SIGN_MASK;
+    let next = if abs == F::Int::ZERO {
+        // The smallest positive subnormal, for both `+0.0` and `-0.0`
+        F::Int::ONE
+    } else if bits == abs {
+        // Positive: counting up is more positive
+        bits + F::Int::ONE
+    } else {
+        // Negative: counting down is more positive
+        bits - F::Int::ONE
+    };
+    F::from_bits(next)
+}
+
+/// Return the greatest value that compares less than `x`.
+#[inline]
+pub fn nextdown<F: Float>(x: F) -> F {
+    -nextup(-x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::Hexf;
+
+    fn spec_test<F: Float>() {
+        let tiny = F::from_bits(F::Int::ONE);
+
+        // Pairs of adjacent values
+        let cases = [
+            (F::NEG_INFINITY, F::MIN),
+            (F::NEG_ONE, F::from_bits(F::NEG_ONE.to_bits() - F::Int::ONE)),
+            (-tiny, F::NEG_ZERO),
+            (F::ZERO, tiny),
+            (F::from_bits(F::ONE.to_bits() - F::Int::ONE), F::ONE),
+            (F::MAX, F::INFINITY),
+        ];
+        for (lo, hi) in cases {
+            assert_biteq!(nextup(lo), hi, "nextup({})", Hexf(lo));
+            assert_biteq!(nextdown(hi), lo, "nextdown({})", Hexf(hi));
+        }
+
+        assert_biteq!(nextup(F::NEG_ZERO), tiny);
+        assert_biteq!(nextdown(F::ZERO), -tiny);
+        assert_biteq!(nextup(F::INFINITY), F::INFINITY);
+        assert_biteq!(nextdown(F::NEG_INFINITY), F::NEG_INFINITY);
+        assert!(nextup(F::NAN).is_nan());
+        assert!(nextdown(F::NAN).is_nan());
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn spec_tests_f16() {
+        spec_test::<f16>();
+    }
+
+    #[test]
+    fn spec_tests_f32() {
+        spec_test::<f32>();
+    }
+
+    #[test]
+    fn spec_tests_f64() {
+        spec_test::<f64>();
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn spec_tests_f128() {
+        spec_test::<f128>();
+    }
+
+    #[test]
+    fn nexttoward_wide() {
+        let one_up = f32::from_bits(0x3f80_0001);
+        let one_down = f32::from_bits(0x3f7f_ffff);
+
+        // Directions between two `f32`s still move `x`
+        assert_biteq!(nexttoward(1.0f32, 1.0 + f64::EPSILON), one_up);
+        assert_biteq!(nexttoward(1.0f32, 1.0 - f64::EPSILON), one_down);
+        assert_biteq!(nexttoward(1.0f32, 1.0f64), 1.0);
+        assert_biteq!(nexttoward(0.0f32, -0.0f64), -0.0);
+        assert_biteq!(nexttoward(-0.0f32, 1e-300f64), f32::from_bits(1));
+        assert_biteq!(nexttoward(f32::MAX, f64::MAX), f32::INFINITY);
+        assert_biteq!(nexttoward(f32::INFINITY, f64::MAX), f32::MAX);
+        assert!(nexttoward(1.0f32, f64::NAN).is_nan());
+        assert!(nexttoward(f32::NAN, 1.0f64).is_nan());
+    }
+
+    /// Some targets return a default NaN from arithmetic, so only check where the payload is
+    /// propagated by the hardware.
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn nexttoward_nan_payload() {
+        let y = f64::from_bits(0x7ff8_0000_2000_0000);
+        assert_biteq!(nexttoward(1.0f32, y), f32::from_bits(0x7fc0_0001));
+        assert_biteq!(nextafter(1.0f64, y), y);
+    }
+}
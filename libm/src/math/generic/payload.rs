@@ -0,0 +1,226 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! C23 `getpayload`, `setpayload` and `setpayloadsig`, and C `nan`.
+//!
+//! The payload of a NaN is its significand field excluding the most significant bit, which
+//! distinguishes quiet and signaling NaNs. The payload is passed and returned as a float that
+//! holds the integer value, so it can be any integer in `0..2^(SIG_BITS - 1)`.
+
+use super::super::{Float, Int, MinInt};
+
+/// Return the payload of `x` if it is a NaN, otherwise -1.0.
+#[inline]
+pub fn getpayload<F: Float>(x: F) -> F {
+    if !x.is_nan() {
+        return F::NEG_ONE;
+    }
+
+    let pl = x.to_bits() & (F::SIG_MASK >> 1);
+    if pl == F::Int::ZERO {
+        return F::ZERO;
+    }
+
+    // The payload has fewer bits than the significand so the conversion is exact. Shift the
+    // highest set bit to the implicit bit position, then use its position as the exponent.
+    let e = F::Int::BITS - 1 - pl.leading_zeros();
+    F::from_parts(false, F::EXP_BIAS + e, pl << (F::SIG_BITS - e))
+}
+
+/// Return a quiet NaN with payload `pl`, or +0.0 if `pl` is not a valid payload.
+#[inline]
+pub fn setpayload<F: Float>(pl: F) -> F {
+    match payload_bits(pl) {
+        Some(bits) => F::from_bits(F::EXP_MASK | (F::IMPLICIT_BIT >> 1) | bits),
+        None => F::ZERO,
+    }
+}
+
+/// Return a signaling NaN with payload `pl`, or +0.0 if `pl` is not a valid payload. Zero is
+/// not a valid payload here since that would be the encoding of infinity.
+#[inline]
+pub fn setpayloadsig<F: Float>(pl: F) -> F {
+    match payload_bits(pl) {
+        Some(bits) if bits != F::Int::ZERO => F::from_bits(F::EXP_MASK | bits),
+        _ => F::ZERO,
+    }
+}
+
+/// Return a quiet NaN with a payload given by `tagp`.
+///
+/// As in glibc, `tagp` is read like `strtoull` with base 0 would: decimal, octal with a leading
+/// `0` or hexadecimal with a leading `0x`. The payload is the low bits of that integer, or zero
+/// if `tagp` is not entirely a valid integer.
+#[inline]
+pub fn nan<F: Float>(tagp: &str) -> F {
+    let pl = parse_tag::<F::Int>(tagp.as_bytes()).unwrap_or(F::Int::ZERO);
+    F::from_bits(F::EXP_MASK | (F::IMPLICIT_BIT >> 1) | (pl & (F::SIG_MASK >> 1)))
+}
+
+/// Parse a C integer constant without a sign or suffix, wrapping on overflow.
+fn parse_tag<I: Int>(s: &[u8]) -> Option<I> {
+    let (digits, radix) = match s {
+        [b'0', b'x' | b'X', rest @ ..] => (rest, 16),
+        [b'0', rest @ ..] => (rest, 8),
+        _ => (s, 10),
+    };
+
+    let mut acc = I::ZERO;
+    for &c in digits {
+        let d = (c as char).to_digit(radix)?;
+        acc = acc.wrapping_mul(I::cast_from(radix)).wrapping_add(I::cast_from(d));
+    }
+    Some(acc)
+}
+
+/// Convert `pl` to significand bits if it is a nonnegative integer that fits in the payload.
+/// Negative zero is rejected because of its sign.
+fn payload_bits<F: Float>(pl: F) -> Option<F::Int> {
+    if pl.to_bits() == F::Int::ZERO {
+        return Some(F::Int::ZERO);
+    }
+    if pl.is_sign_negative() {
+        return None;
+    }
+
+    // Values less than one can't be integers. This also excludes subnormals, for which
+    // `exp_unbiased` is not the real exponent. NaN and infinity are too large.
+    let e = pl.exp_unbiased();
+    if e < 0 || e >= F::SIG_BITS as i32 - 1 {
+        return None;
+    }
+
+    // Anything shifted out is a fractional part.
+    let shift = F::SIG_BITS - e as u32;
+    let sig = pl.frac() | F::IMPLICIT_BIT;
+    if sig & ((F::Int::ONE << shift) - F::Int::ONE) != F::Int::ZERO {
+        return None;
+    }
+
+    Some(sig >> shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::{CastFrom, Hexf};
+
+    fn spec_test<F: Float>() {
+        let half = F::from_parts(false, F::EXP_BIAS - 1, F::Int::ZERO);
+        let two = F::ONE + F::ONE;
+        let three = two + F::ONE;
+        // `2^(SIG_BITS - 1)` is the first value that is too large
+        let pl_limit = F::from_parts(false, F::EXP_BIAS + F::SIG_BITS - 1, F::Int::ZERO);
+        let pl_max = pl_limit - F::ONE;
+        let quiet = F::EXP_MASK | (F::IMPLICIT_BIT >> 1);
+
+        // Quiet NaNs
+        let cases = [
+            (F::ZERO, quiet),
+            (F::ONE, quiet | F::Int::ONE),
+            (three, quiet | F::Int::cast_from(3u32)),
+            (pl_max, F::EXP_MASK | F::SIG_MASK),
+        ];
+        for (pl, bits) in cases {
+            let x = setpayload(pl);
+            assert_biteq!(x, F::from_bits(bits), "setpayload({})", Hexf(pl));
+            assert_biteq!(getpayload(x), pl, "getpayload({})", Hexf(x));
+            assert_biteq!(getpayload(-x), pl, "getpayload({})", Hexf(-x));
+        }
+
+        // Signaling NaNs
+        let cases = [
+            (F::ONE, F::EXP_MASK | F::Int::ONE),
+            (three, F::EXP_MASK | F::Int::cast_from(3u32)),
+            (pl_max, F::EXP_MASK | (F::SIG_MASK >> 1)),
+        ];
+        for (pl, bits) in cases {
+            let x = setpayloadsig(pl);
+            assert_biteq!(x, F::from_bits(bits), "setpayloadsig({})", Hexf(pl));
+            assert_biteq!(getpayload(x), pl, "getpayload({})", Hexf(x));
+        }
+
+        // Invalid payloads
+        let invalid = [
+            F::NEG_ZERO,
+            F::NEG_ONE,
+            half,
+            three / two,
+            F::from_bits(F::Int::ONE),
+            pl_limit,
+            F::MAX,
+            F::INFINITY,
+            F::NAN,
+        ];
+        for pl in invalid {
+            assert_biteq!(setpayload(pl), F::ZERO, "setpayload({})", Hexf(pl));
+            assert_biteq!(setpayloadsig(pl), F::ZERO, "setpayloadsig({})", Hexf(pl));
+        }
+        assert_biteq!(setpayloadsig(F::ZERO), F::ZERO);
+
+        // Non-NaN inputs
+        for x in [F::ZERO, F::ONE, F::NEG_INFINITY, F::MAX] {
+            assert_biteq!(getpayload(x), F::NEG_ONE, "getpayload({})", Hexf(x));
+        }
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn spec_tests_f16() {
+        spec_test::<f16>();
+    }
+
+    #[test]
+    fn spec_tests_f32() {
+        spec_test::<f32>();
+    }
+
+    #[test]
+    fn spec_tests_f64() {
+        spec_test::<f64>();
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn spec_tests_f128() {
+        spec_test::<f128>();
+    }
+
+    #[test]
+    fn payload_values() {
+        assert_eq!(getpayload(f32::from_bits(0x7fc0_1234)), 4660.0);
+        assert_eq!(getpayload(f64::from_bits(0xfff8_0000_0000_0042)), 66.0);
+        assert_eq!(setpayload(4660.0f32).to_bits(), 0x7fc0_1234);
+        assert_eq!(setpayloadsig(4660.0f32).to_bits(), 0x7f80_1234);
+        assert_eq!(setpayload(4194303.0f32).to_bits(), 0x7fff_ffff);
+        assert_eq!(setpayload(4194304.0f32).to_bits(), 0);
+        assert_eq!(setpayload(1234.5f64).to_bits(), 0);
+    }
+
+    #[test]
+    fn nan_tags() {
+        let cases = [
+            ("", 0x7fc0_0000),
+            ("0", 0x7fc0_0000),
+            ("42", 0x7fc0_002a),
+            ("052", 0x7fc0_002a),
+            ("0x2a", 0x7fc0_002a),
+            ("0X2A", 0x7fc0_002a),
+            ("0x3fffff", 0x7fff_ffff),
+            // Only the low bits are kept
+            ("0x400001", 0x7fc0_0001),
+            ("0xffffffffffffffffff", 0x7fff_ffff),
+            // Invalid tags give the default NaN
+            ("0x", 0x7fc0_0000),
+            ("08", 0x7fc0_0000),
+            ("12a", 0x7fc0_0000),
+            ("-1", 0x7fc0_0000),
+            (" 1", 0x7fc0_0000),
+            ("nan", 0x7fc0_0000),
+        ];
+        for (tagp, bits) in cases {
+            assert_eq!(nan::<f32>(tagp).to_bits(), bits, "nan({tagp:?})");
+        }
+
+        assert_eq!(nan::<f64>("0x2a").to_bits(), 0x7ff8_0000_0000_002a);
+        assert_eq!(nan::<f64>("2251799813685247").to_bits(), 0x7fff_ffff_ffff_ffff);
+    }
+}
@@ -0,0 +1,54 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+
+use super::super::{CastFrom, CastInto, Float, MinInt};
+use super::round;
+
+/// Argument reduction for the `*pi` trigonometric functions.
+///
+/// Returns `(n, r)` such that `x = k / 2 + r` for an integer `k` with `n = k mod 4`, and
+/// `|r| <= 1/4`. The reduction is exact, and `r` is `+0` whenever `x` is a multiple of `1/2`.
+/// `x` must be finite.
+#[inline]
+pub fn rem_half<F: Float>(x: F) -> (u32, F) {
+    /* |x| >= 2^(SIG_BITS + 1) is an even integer, so `2x` is a multiple of 4 */
+    if x.exp_unbiased() > F::SIG_BITS as i32 {
+        return (0, F::ZERO);
+    }
+
+    let half = F::from_parts(false, F::EXP_BIAS - 1, F::Int::ZERO);
+
+    /* `2x` cannot overflow here and `k / 2` is a multiple of ulp(x), so both steps are exact */
+    let k = round(x + x);
+    let r = x - k * half;
+    if k == F::ZERO {
+        return (0, r);
+    }
+
+    /* `k` is an integer below 2^(SIG_BITS + 2); read its low bits from the significand */
+    let e = k.exp_unbiased() as u32;
+    let sig = k.frac() | F::IMPLICIT_BIT;
+    let k_abs = if e <= F::SIG_BITS { sig >> (F::SIG_BITS - e) } else { sig << (e - F::SIG_BITS) };
+    let n: u32 = (k_abs & F::Int::cast_from(3u32)).cast();
+    let n = if k.is_sign_negative() { n.wrapping_neg() & 3 } else { n };
+
+    (n, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduction() {
+        assert_biteq!(rem_half(0.0f64).1, 0.0);
+        assert_biteq!(rem_half(-0.0f64).1, 0.0);
+        assert_eq!(rem_half(0.25f64), (1, -0.25));
+        assert_eq!(rem_half(0.75f64), (2, -0.25));
+        assert_eq!(rem_half(1.5f64), (3, 0.0));
+        assert_eq!(rem_half(-0.5f64), (3, 0.0));
+        assert_eq!(rem_half(-1.75f64), (0, 0.25));
+        assert_eq!(rem_half(4503599627370495.5f64), (3, 0.0));
+        assert_eq!(rem_half(4503599627370497.0f64), (2, 0.0));
+        assert_eq!(rem_half(f64::MAX), (0, 0.0));
+    }
+}
@@ -0,0 +1,107 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/remquo.c. Ported to generic Rust algorithm in 2025. */
+
+use super::super::{CastFrom, Float, Int, MinInt};
+
+/// Compute the IEEE remainder of `x / y` along with at least the low three bits of the quotient,
+/// with the sign of `x / y`.
+#[inline]
+pub fn remquo<F: Float>(mut x: F, mut y: F) -> (F, i32)
+where
+    F::Int: CastFrom<u32>,
+{
+    let zero = F::Int::ZERO;
+    let one = F::Int::ONE;
+    let mut uxi = x.to_bits();
+    let mut uy = y.to_bits();
+    let mut ex = x.ex().signed();
+    let mut ey = y.ex().signed();
+    let sx = x.is_sign_negative();
+    let sy = y.is_sign_negative();
+
+    if uy << 1 == zero || y.is_nan() || ex == F::EXP_SAT as i32 {
+        return ((x * y) / (x * y), 0);
+    }
+    if uxi << 1 == zero {
+        return (x, 0);
+    }
+
+    /* normalize x and y */
+    if ex == 0 {
+        let i = uxi << (F::EXP_BITS + 1);
+        ex -= i.leading_zeros() as i32;
+        uxi <<= -ex + 1;
+    } else {
+        uxi &= F::Int::MAX >> F::EXP_BITS;
+        uxi |= one << F::SIG_BITS;
+    }
+
+    if ey == 0 {
+        let i = uy << (F::EXP_BITS + 1);
+        ey -= i.leading_zeros() as i32;
+        uy <<= -ey + 1;
+    } else {
+        uy &= F::Int::MAX >> F::EXP_BITS;
+        uy |= one << F::SIG_BITS;
+    }
+
+    let mut q: u32 = 0;
+
+    if ex + 1 != ey {
+        if ex < ey {
+            return (x, 0);
+        }
+
+        /* x mod y */
+        while ex > ey {
+            let i = uxi.wrapping_sub(uy);
+            if i >> (F::BITS - 1) == zero {
+                uxi = i;
+                q += 1;
+            }
+            uxi <<= 1;
+            q <<= 1;
+            ex -= 1;
+        }
+
+        let i = uxi.wrapping_sub(uy);
+        if i >> (F::BITS - 1) == zero {
+            uxi = i;
+            q += 1;
+        }
+
+        if uxi == zero {
+            // Any exponent below that of the smallest subnormal `y` works here, as long as the
+            // shift below stays in range.
+            ex = -(F::SIG_BITS as i32) - 2;
+        } else {
+            while uxi >> F::SIG_BITS == zero {
+                uxi <<= 1;
+                ex -= 1;
+            }
+        }
+    }
+
+    /* scale result and decide between |x| and |x|-|y| */
+    if ex > 0 {
+        uxi -= one << F::SIG_BITS;
+        uxi |= F::Int::cast_from(ex as u32) << F::SIG_BITS;
+    } else {
+        uxi >>= -ex + 1;
+    }
+
+    x = F::from_bits(uxi);
+    if sy {
+        y = -y;
+    }
+
+    if ex == ey || (ex + 1 == ey && (x + x > y || (x + x == y && q % 2 != 0))) {
+        x = x - y;
+        // TODO: this matches musl behavior, but it is incorrect
+        q = q.wrapping_add(1);
+    }
+
+    q &= 0x7fffffff;
+    let quo = if sx ^ sy { -(q as i32) } else { q as i32 };
+    if sx { (-x, quo) } else { (x, quo) }
+}
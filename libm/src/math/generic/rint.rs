@@ -2,12 +2,27 @@
 /* origin: musl src/math/rint.c */
 
 use super::super::Float;
-use super::super::support::{FpResult, Round};
+use super::super::support::{FpResult, Round, Status};
 
 /// IEEE 754-2019 `roundToIntegralExact`, which respects rounding mode and raises inexact if
 /// applicable.
 #[inline]
-pub fn rint_round<F: Float>(x: F, _round: Round) -> FpResult<F> {
+pub fn rint_round<F: Float>(x: F, round: Round) -> FpResult<F> {
+    // Modes other than nearest are exactly the other integral rounding operations.
+    let res = match round {
+        Round::Nearest => rint_nearest(x),
+        Round::Negative => super::floor(x),
+        Round::Positive => super::ceil(x),
+        Round::Zero => super::trunc(x),
+        Round::NearestAway => super::round(x),
+    };
+
+    // NaN is returned unchanged, and the only rounding of an infinity or an integer is itself.
+    if res != x && !x.is_nan() { FpResult::new(res, Status::INEXACT) } else { FpResult::ok(res) }
+}
+
+/// Round to the nearest integer, breaking ties toward even.
+fn rint_nearest<F: Float>(x: F) -> F {
     let toint = F::ONE / F::EPSILON;
     let e = x.ex();
     let positive = x.is_sign_positive();
@@ -18,12 +33,12 @@ pub fn rint_round<F: Float>(x: F, _round: Round) -> FpResult<F> {
         if cfg!(x86_no_sse) && (F::BITS == 32 || F::BITS == 64) { force_eval!(x) } else { x }
     };
 
-    let res = if e >= F::EXP_BIAS + F::SIG_BITS {
+    if e >= F::EXP_BIAS + F::SIG_BITS {
         // No fractional part; exact result can be returned.
         x
     } else {
-        // Apply a net-zero adjustment that nudges `y` in the direction of the rounding mode. For
-        // Rust this is always nearest, but ideally it would take `round` into account.
+        // Apply a net-zero adjustment that nudges `y` in the direction of the rounding mode, which
+        // is always nearest for Rust.
         let y = if positive {
             force(force(x) + toint) - toint
         } else {
@@ -36,23 +51,26 @@ pub fn rint_round<F: Float>(x: F, _round: Round) -> FpResult<F> {
         } else {
             y
         }
-    };
-
-    FpResult::ok(res)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::support::{Hexf, Status};
+    use crate::support::Hexf;
+
+    const ROUNDS: [Round; 5] =
+        [Round::Nearest, Round::Negative, Round::Positive, Round::Zero, Round::NearestAway];
 
     fn spec_test<F: Float>(cases: &[(F, F, Status)]) {
         let roundtrip = [F::ZERO, F::ONE, F::NEG_ONE, F::NEG_ZERO, F::INFINITY, F::NEG_INFINITY];
 
         for x in roundtrip {
-            let FpResult { val, status } = rint_round(x, Round::Nearest);
-            assert_biteq!(val, x, "rint_round({})", Hexf(x));
-            assert_eq!(status, Status::OK, "{}", Hexf(x));
+            for round in ROUNDS {
+                let FpResult { val, status } = rint_round(x, round);
+                assert_biteq!(val, x, "rint_round({}, {round:?})", Hexf(x));
+                assert_eq!(status, Status::OK, "{}", Hexf(x));
+            }
         }
 
         for &(x, res, res_stat) in cases {
@@ -72,20 +90,20 @@ mod tests {
     #[test]
     fn spec_tests_f32() {
         let cases = [
-            (0.1, 0.0, Status::OK),
-            (-0.1, -0.0, Status::OK),
-            (0.5, 0.0, Status::OK),
-            (-0.5, -0.0, Status::OK),
-            (0.9, 1.0, Status::OK),
-            (-0.9, -1.0, Status::OK),
-            (1.1, 1.0, Status::OK),
-            (-1.1, -1.0, Status::OK),
-            (1.5, 2.0, Status::OK),
-            (-1.5, -2.0, Status::OK),
-            (1.9, 2.0, Status::OK),
-            (-1.9, -2.0, Status::OK),
-            (2.8, 3.0, Status::OK),
-            (-2.8, -3.0, Status::OK),
+            (0.1, 0.0, Status::INEXACT),
+            (-0.1, -0.0, Status::INEXACT),
+            (0.5, 0.0, Status::INEXACT),
+            (-0.5, -0.0, Status::INEXACT),
+            (0.9, 1.0, Status::INEXACT),
+            (-0.9, -1.0, Status::INEXACT),
+            (1.1, 1.0, Status::INEXACT),
+            (-1.1, -1.0, Status::INEXACT),
+            (1.5, 2.0, Status::INEXACT),
+            (-1.5, -2.0, Status::INEXACT),
+            (1.9, 2.0, Status::INEXACT),
+            (-1.9, -2.0, Status::INEXACT),
+            (2.8, 3.0, Status::INEXACT),
+            (-2.8, -3.0, Status::INEXACT),
         ];
         spec_test::<f32>(&cases);
     }
@@ -93,24 +111,82 @@ mod tests {
     #[test]
     fn spec_tests_f64() {
         let cases = [
-            (0.1, 0.0, Status::OK),
-            (-0.1, -0.0, Status::OK),
-            (0.5, 0.0, Status::OK),
-            (-0.5, -0.0, Status::OK),
-            (0.9, 1.0, Status::OK),
-            (-0.9, -1.0, Status::OK),
-            (1.1, 1.0, Status::OK),
-            (-1.1, -1.0, Status::OK),
-            (1.5, 2.0, Status::OK),
-            (-1.5, -2.0, Status::OK),
-            (1.9, 2.0, Status::OK),
-            (-1.9, -2.0, Status::OK),
-            (2.8, 3.0, Status::OK),
-            (-2.8, -3.0, Status::OK),
+            (0.1, 0.0, Status::INEXACT),
+            (-0.1, -0.0, Status::INEXACT),
+            (0.5, 0.0, Status::INEXACT),
+            (-0.5, -0.0, Status::INEXACT),
+            (0.9, 1.0, Status::INEXACT),
+            (-0.9, -1.0, Status::INEXACT),
+            (1.1, 1.0, Status::INEXACT),
+            (-1.1, -1.0, Status::INEXACT),
+            (1.5, 2.0, Status::INEXACT),
+            (-1.5, -2.0, Status::INEXACT),
+            (1.9, 2.0, Status::INEXACT),
+            (-1.9, -2.0, Status::INEXACT),
+            (2.8, 3.0, Status::INEXACT),
+            (-2.8, -3.0, Status::INEXACT),
         ];
         spec_test::<f64>(&cases);
     }
 
+    #[test]
+    fn directed_f64() {
+        // (input, negative, positive, zero, nearest away)
+        let cases = [
+            (0.1, 0.0, 1.0, 0.0, 0.0),
+            (-0.1, -1.0, -0.0, -0.0, -0.0),
+            (0.5, 0.0, 1.0, 0.0, 1.0),
+            (-0.5, -1.0, -0.0, -0.0, -1.0),
+            (1.5, 1.0, 2.0, 1.0, 2.0),
+            (-1.5, -2.0, -1.0, -1.0, -2.0),
+            (2.5, 2.0, 3.0, 2.0, 3.0),
+            (-2.5, -3.0, -2.0, -2.0, -3.0),
+            (2.8, 2.0, 3.0, 2.0, 3.0),
+            (-2.8, -3.0, -2.0, -2.0, -3.0),
+            (
+                4503599627370494.5,
+                4503599627370494.0,
+                4503599627370495.0,
+                4503599627370494.0,
+                4503599627370495.0,
+            ),
+            (
+                -4503599627370495.5,
+                -4503599627370496.0,
+                -4503599627370495.0,
+                -4503599627370495.0,
+                -4503599627370496.0,
+            ),
+            (f64::MIN_POSITIVE, 0.0, 1.0, 0.0, 0.0),
+            (-f64::MIN_POSITIVE, -1.0, -0.0, -0.0, -0.0),
+        ];
+
+        for (x, neg, pos, zero, away) in cases {
+            assert_biteq!(rint_round(x, Round::Negative).val, neg, "{}", Hexf(x));
+            assert_biteq!(rint_round(x, Round::Positive).val, pos, "{}", Hexf(x));
+            assert_biteq!(rint_round(x, Round::Zero).val, zero, "{}", Hexf(x));
+            assert_biteq!(rint_round(x, Round::NearestAway).val, away, "{}", Hexf(x));
+
+            // None of the inputs are integers, so every mode is inexact.
+            for round in ROUNDS {
+                assert_eq!(rint_round(x, round).status, Status::INEXACT, "{} {round:?}", Hexf(x));
+            }
+        }
+    }
+
+    #[test]
+    fn status_f64() {
+        for round in ROUNDS {
+            for x in [2.0, -3.0, 4503599627370496.0, f64::MAX] {
+                assert_eq!(rint_round(x, round).status, Status::OK, "{} {round:?}", Hexf(x));
+            }
+
+            let FpResult { val, status } = rint_round(f64::NAN, round);
+            assert!(val.is_nan(), "{round:?}");
+            assert_eq!(status, Status::OK, "{round:?}");
+        }
+    }
+
     #[test]
     #[cfg(f128_enabled)]
     fn spec_tests_f128() {
@@ -59,7 +59,7 @@ where
 }
 
 #[inline]
-pub fn sqrt_round<F>(x: F, _round: Round) -> FpResult<F>
+pub fn sqrt_round<F>(x: F, round: Round) -> FpResult<F>
 where
     F: Float + SqrtHelper,
     F::Int: HInt,
@@ -204,6 +204,11 @@ where
     // `m - 2^(2p)m + m^2`
     let d1 = m.wrapping_sub(d0);
     m += d1 >> (F::BITS - 1);
+
+    // `2^(2p)m - y^2` for the nearest result `y`. The true value is small so wrapping is fine;
+    // zero means the result is exact, otherwise the sign tells us whether the infinitely precise
+    // result lies above or below `y`.
+    let rem = (m_u2 << shift).wrapping_sub(m.wrapping_mul(m));
     m &= F::SIG_MASK;
 
     match exp {
@@ -211,26 +216,31 @@ where
         Exp::NoShift(e) => m |= e,
     };
 
-    let mut y = F::from_bits(m);
-
-    // FIXME(f16): the fenv math does not work for `f16`
-    if F::BITS > 16 {
-        // Handle rounding and inexact. `(m + 1)^2 == 2^shift m` is exact; for all other cases, add
-        // a tiny value to cause fenv effects.
-        let d2 = d1.wrapping_add(m).wrapping_add(one);
-        let mut tiny = if d2 == zero {
-            cold_path();
-            zero
-        } else {
-            F::IMPLICIT_BIT
-        };
-
-        tiny |= (d1 ^ d2) & F::SIGN_MASK;
-        let t = F::from_bits(tiny);
-        y = y + t;
+    if rem == zero {
+        cold_path();
+        return FpResult::ok(F::from_bits(m));
+    }
+
+    // The result is always positive and normal, so stepping toward the other neighbor can be
+    // done by adjusting the integer representation.
+    let above = rem & F::SIGN_MASK == zero;
+    match round {
+        // The square root of a float is never halfway between two floats, so both nearest modes
+        // produce the same result.
+        Round::Nearest | Round::NearestAway => (),
+        Round::Positive => {
+            if above {
+                m += one;
+            }
+        }
+        Round::Negative | Round::Zero => {
+            if !above {
+                m -= one;
+            }
+        }
     }
 
-    FpResult::ok(y)
+    FpResult::new(F::from_bits(m), Status::INEXACT)
 }
 
 /// Multiply at the wider integer size, returning the high half.
@@ -367,6 +377,8 @@ static RSQRT_TAB: [u16; 128] = [
 
 #[cfg(test)]
 mod tests {
+    use core::cmp::Ordering;
+
     use super::*;
 
     /// Test behavior specified in IEEE 754 `squareRoot`.
@@ -399,6 +411,45 @@ mod tests {
         }
     }
 
+    /// Check directed rounding against `cmp`, which compares `y * y` to `x` exactly.
+    fn directed_test<F>(inputs: impl Iterator<Item = F>, cmp: impl Fn(F, F) -> Ordering)
+    where
+        F: Float + SqrtHelper,
+        F::Int: HInt,
+        F::Int: From<u8>,
+        F::Int: From<F::ISet2>,
+        F::Int: CastInto<F::ISet1>,
+        F::Int: CastInto<F::ISet2>,
+        u32: CastInto<F::Int>,
+    {
+        let one = F::Int::ONE;
+
+        for x in inputs {
+            let up = sqrt_round(x, Round::Positive);
+            let down = sqrt_round(x, Round::Negative);
+            let zero = sqrt_round(x, Round::Zero);
+            let near = sqrt_round(x, Round::Nearest);
+            let (ux, ub, db) = (x.to_bits(), up.val.to_bits(), down.val.to_bits());
+
+            assert_biteq!(zero.val, down.val, "{ux:#x}");
+            assert!(near.val == up.val || near.val == down.val, "{ux:#x}");
+
+            if cmp(near.val, x) == Ordering::Equal {
+                assert_biteq!(up.val, near.val, "{ux:#x}");
+                assert_biteq!(down.val, near.val, "{ux:#x}");
+                assert!(up.status == Status::OK && down.status == Status::OK, "{ux:#x}");
+                continue;
+            }
+
+            assert_eq!(ub, db + one, "{ux:#x}");
+            assert_eq!(cmp(up.val, x), Ordering::Greater, "{ux:#x}");
+            assert_eq!(cmp(down.val, x), Ordering::Less, "{ux:#x}");
+            for r in [up, down, zero, near] {
+                assert!(r.status == Status::INEXACT, "{ux:#x}");
+            }
+        }
+    }
+
     #[test]
     #[cfg(f16_enabled)]
     fn sanity_check_f16() {
@@ -412,6 +463,13 @@ mod tests {
         spec_test::<f16>();
     }
 
+    #[test]
+    #[cfg(f16_enabled)]
+    fn directed_tests_f16() {
+        let inputs = (1..f16::INFINITY.to_bits()).map(f16::from_bits);
+        directed_test(inputs, |y, x| (y as f64 * y as f64).partial_cmp(&(x as f64)).unwrap());
+    }
+
     #[test]
     #[cfg(f16_enabled)]
     #[allow(clippy::approx_constant)]
@@ -446,6 +504,12 @@ mod tests {
         spec_test::<f32>();
     }
 
+    #[test]
+    fn directed_tests_f32() {
+        let inputs = (1..f32::INFINITY.to_bits()).step_by(0x1003).map(f32::from_bits);
+        directed_test(inputs, |y, x| (y as f64 * y as f64).partial_cmp(&(x as f64)).unwrap());
+    }
+
     #[test]
     #[allow(clippy::approx_constant)]
     fn conformance_tests_f32() {
@@ -477,6 +541,15 @@ mod tests {
         spec_test::<f64>();
     }
 
+    #[test]
+    fn directed_tests_f64() {
+        // Keep `y * y - x` well away from the subnormal range so its sign is exact.
+        let inputs = (0x2000_0000_0000_0000u64..0x6000_0000_0000_0000)
+            .step_by(0x0000_1234_5678_9abd)
+            .map(f64::from_bits);
+        directed_test(inputs, |y, x| super::super::super::fma(y, y, -x).partial_cmp(&0.0).unwrap());
+    }
+
     #[test]
     #[allow(clippy::approx_constant)]
     fn conformance_tests_f64() {
@@ -510,6 +583,18 @@ mod tests {
         spec_test::<f128>();
     }
 
+    #[test]
+    #[cfg(f128_enabled)]
+    fn directed_tests_f128() {
+        // Keep `y * y - x` well away from the subnormal range so its sign is exact.
+        let inputs = (0x2000u128..0x6000)
+            .map(|hi| (hi << 112) | (hi * 0x1234_5678_9abc_def1_2345_6789_abcd))
+            .map(f128::from_bits);
+        directed_test(inputs, |y, x| {
+            super::super::super::fmaf128(y, y, -x).partial_cmp(&0.0).unwrap()
+        });
+    }
+
     #[test]
     #[cfg(f128_enabled)]
     #[allow(clippy::approx_constant)]
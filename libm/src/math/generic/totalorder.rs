@@ -0,0 +1,112 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! IEEE 754-2019 `totalOrder` and `totalOrderMag`.
+//!
+//! The total order is:
+//!
+//! ```text
+//! -qNaN < -sNaN < -inf < -finite < -0.0 < +0.0 < +finite < +inf < +sNaN < +qNaN
+//! ```
+//!
+//! NaNs of the same kind and sign are ordered by payload, which is the same as ordering by their
+//! bit patterns. Note that this differs from `<=` for `-0.0 <= +0.0` and for NaNs.
+
+use super::super::{Float, Int};
+
+/// Return true if `x` is ordered before or equal to `y` in the total order.
+#[inline]
+pub fn totalorder<F: Float>(x: F, y: F) -> bool {
+    key(x) <= key(y)
+}
+
+/// Return true if `|x|` is ordered before or equal to `|y|` in the total order.
+#[inline]
+pub fn totalordermag<F: Float>(x: F, y: F) -> bool {
+    (x.to_bits() & !F::SIGN_MASK) <= (y.to_bits() & !F::SIGN_MASK)
+}
+
+/// Map `x` to a signed integer that sorts in the total order.
+///
+/// Positive values already sort by their bit patterns. For negative values, flipping everything
+/// but the sign bit reverses the order of the magnitude.
+fn key<F: Float>(x: F) -> F::SignedInt {
+    let bits = x.to_bits_signed();
+    let sign_fill: F::Int = (bits >> (F::BITS - 1)).unsigned();
+    bits ^ (sign_fill >> 1u32).signed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::{Hexf, MinInt};
+
+    fn spec_test<F: Float>() {
+        let qnan = F::NAN;
+        let snan = F::from_bits(F::EXP_MASK | F::Int::ONE);
+        let qnan_pl = F::from_bits(qnan.to_bits() | F::Int::ONE);
+        let snan_pl = F::from_bits(snan.to_bits() | (F::Int::ONE << 1));
+        let sub = F::from_bits(F::Int::ONE);
+
+        // All values in increasing total order
+        let ordered = [
+            -qnan_pl,
+            -qnan,
+            -snan_pl,
+            -snan,
+            F::NEG_INFINITY,
+            F::MIN,
+            F::NEG_ONE,
+            -sub,
+            F::NEG_ZERO,
+            F::ZERO,
+            sub,
+            F::ONE,
+            F::MAX,
+            F::INFINITY,
+            snan,
+            snan_pl,
+            qnan,
+            qnan_pl,
+        ];
+
+        for (i, x) in ordered.into_iter().enumerate() {
+            for (j, y) in ordered.into_iter().enumerate() {
+                assert_eq!(totalorder(x, y), i <= j, "totalorder({}, {})", Hexf(x), Hexf(y));
+                assert_eq!(
+                    totalordermag(x, y),
+                    totalorder(x.abs(), y.abs()),
+                    "totalordermag({}, {})",
+                    Hexf(x),
+                    Hexf(y)
+                );
+            }
+        }
+
+        assert!(totalordermag(F::ZERO, F::NEG_ZERO));
+        assert!(totalordermag(F::NEG_ZERO, F::ZERO));
+        assert!(totalordermag(F::NEG_ONE, F::ONE));
+        assert!(totalordermag(snan, -qnan));
+        assert!(!totalordermag(-qnan, F::NEG_INFINITY));
+    }
+
+    #[test]
+    #[cfg(f16_enabled)]
+    fn spec_tests_f16() {
+        spec_test::<f16>();
+    }
+
+    #[test]
+    fn spec_tests_f32() {
+        spec_test::<f32>();
+    }
+
+    #[test]
+    fn spec_tests_f64() {
+        spec_test::<f64>();
+    }
+
+    #[test]
+    #[cfg(f128_enabled)]
+    fn spec_tests_f128() {
+        spec_test::<f128>();
+    }
+}
@@ -0,0 +1,19 @@
+use super::support::WideFloat;
+
+/// The length of the hypotenuse of a right triangle with legs `x` and `y` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn hypotf128(x: f128, y: f128) -> f128 {
+    /* hypot(+-inf, y) = +inf, even if y is NaN */
+    if x.is_infinite() || y.is_infinite() {
+        return f128::INFINITY;
+    }
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    // The squares are exact and the exponent range of `WideFloat` rules out spurious overflow or
+    // underflow, so no scaling is needed.
+    let x = WideFloat::from_f128(x);
+    let y = WideFloat::from_f128(y);
+    (x * x + y * y).sqrt().to_f128()
+}
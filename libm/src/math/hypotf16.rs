@@ -0,0 +1,5 @@
+/// The length of the hypotenuse of a right triangle with legs `x` and `y` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn hypotf16(x: f16, y: f16) -> f16 {
+    super::hypot(x as f64, y as f64) as f16
+}
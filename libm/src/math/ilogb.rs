@@ -1,28 +1,33 @@
-const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
-const FP_ILOGB0: i32 = FP_ILOGBNAN;
+/// Extract the binary exponent of `x` (f16).
+///
+/// Zero returns `i32::MIN`, NaN returns `i32::MIN` and infinity returns `i32::MAX`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ilogbf16(x: f16) -> i32 {
+    super::generic::ilogb(x)
+}
 
+/// Extract the binary exponent of `x` (f32).
+///
+/// Zero returns `i32::MIN`, NaN returns `i32::MIN` and infinity returns `i32::MAX`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ilogbf(x: f32) -> i32 {
+    super::generic::ilogb(x)
+}
+
+/// Extract the binary exponent of `x` (f64).
+///
+/// Zero returns `i32::MIN`, NaN returns `i32::MIN` and infinity returns `i32::MAX`.
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn ilogb(x: f64) -> i32 {
-    let mut i: u64 = x.to_bits();
-    let e = ((i >> 52) & 0x7ff) as i32;
+    super::generic::ilogb(x)
+}
 
-    if e == 0 {
-        i <<= 12;
-        if i == 0 {
-            force_eval!(0.0 / 0.0);
-            return FP_ILOGB0;
-        }
-        /* subnormal x */
-        let mut e = -0x3ff;
-        while (i >> 63) == 0 {
-            e -= 1;
-            i <<= 1;
-        }
-        e
-    } else if e == 0x7ff {
-        force_eval!(0.0 / 0.0);
-        if (i << 12) != 0 { FP_ILOGBNAN } else { i32::MAX }
-    } else {
-        e - 0x3ff
-    }
+/// Extract the binary exponent of `x` (f128).
+///
+/// Zero returns `i32::MIN`, NaN returns `i32::MIN` and infinity returns `i32::MAX`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ilogbf128(x: f128) -> i32 {
+    super::generic::ilogb(x)
 }
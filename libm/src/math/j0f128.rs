@@ -0,0 +1,29 @@
+use super::{k_j01f128, k_y01f128};
+
+/// Zeroth order of the [Bessel function](https://en.wikipedia.org/wiki/Bessel_function) of the first kind (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn j0f128(x: f128) -> f128 {
+    /* j0(+-inf)=0, j0(nan)=nan */
+    if !x.is_finite() {
+        return 1.0 / (x * x);
+    }
+
+    k_j01f128(0, x.abs()).to_f128()
+}
+
+/// Zeroth order of the [Bessel function](https://en.wikipedia.org/wiki/Bessel_function) of the second kind (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn y0f128(x: f128) -> f128 {
+    /* y0(nan)=nan, y0(<0)=nan, y0(0)=-inf, y0(inf)=0 */
+    if x == 0.0 {
+        return -1.0 / 0.0;
+    }
+    if x.is_sign_negative() {
+        return 0.0 / 0.0;
+    }
+    if !x.is_finite() {
+        return 1.0 / x;
+    }
+
+    k_y01f128(0, x).to_f128()
+}
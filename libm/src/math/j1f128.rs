@@ -0,0 +1,33 @@
+use super::{k_j01f128, k_y01f128};
+
+/// First order of the [Bessel function](https://en.wikipedia.org/wiki/Bessel_function) of the first kind (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn j1f128(x: f128) -> f128 {
+    /* j1(+-inf)=0, j1(nan)=nan, j1(+-0)=+-0 */
+    if !x.is_finite() {
+        return 1.0 / (x * x);
+    }
+    if x == 0.0 {
+        return x;
+    }
+
+    let r = k_j01f128(1, x.abs()).to_f128();
+    if x < 0.0 { -r } else { r }
+}
+
+/// First order of the [Bessel function](https://en.wikipedia.org/wiki/Bessel_function) of the second kind (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn y1f128(x: f128) -> f128 {
+    /* y1(nan)=nan, y1(<0)=nan, y1(0)=-inf, y1(inf)=0 */
+    if x == 0.0 {
+        return -1.0 / 0.0;
+    }
+    if x.is_sign_negative() {
+        return 0.0 / 0.0;
+    }
+    if !x.is_finite() {
+        return 1.0 / x;
+    }
+
+    k_y01f128(1, x).to_f128()
+}
@@ -0,0 +1,118 @@
+use super::k_besself128::HANKEL_MIN;
+use super::support::{Float, WideFloat};
+use super::{k_hankelf128, k_j01f128, k_jn_millerf128, k_jn_seriesf128, k_y01f128, log2};
+
+/// Below this, `J_n` is summed from its power series, which then loses at most a few bits.
+const SERIES_MAX: f128 = 1.0;
+
+/// Whether the asymptotic expansion is accurate for order `n` at `x`.
+fn use_hankel(n: u32, x: f128) -> bool {
+    let n = n as f128;
+    x >= HANKEL_MIN && x >= n * n
+}
+
+/// Whether `J_n(x)` for `x >= 1` certainly rounds to zero.
+fn jn_underflows(n: u32, x: f128) -> bool {
+    // |J_n(x)| <= (x/2)^n / n! < (e*x / 2n)^n. Leave a bit of margin for the rounding error of
+    // the estimate, so that everything that passes is below half of the smallest subnormal.
+    let n = n as f64;
+    let e = n * log2(core::f64::consts::E * x as f64 / (2.0 * n));
+    e < (f128::EXP_MIN_SUBNORM - 2) as f64
+}
+
+/// Integer order of the [Bessel function](https://en.wikipedia.org/wiki/Bessel_function) of the first kind (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn jnf128(n: i32, x: f128) -> f128 {
+    if x.is_nan() {
+        return x;
+    }
+
+    /* J(-n,x) = (-1)^n * J(n, x), J(n, -x) = (-1)^n * J(n, x) */
+    let nm = n.unsigned_abs();
+    let neg = nm & 1 == 1 && ((n < 0) != x.is_sign_negative());
+    let x = x.abs();
+
+    let r = if x.is_infinite() {
+        0.0
+    } else if nm == 0 {
+        k_j01f128(0, x).to_f128()
+    } else if x == 0.0 {
+        0.0
+    } else if nm == 1 {
+        k_j01f128(1, x).to_f128()
+    } else if use_hankel(nm, x) {
+        k_hankelf128(nm, x).0.to_f128()
+    } else if x < SERIES_MAX {
+        k_jn_seriesf128(nm, WideFloat::from_f128(x)).to_f128()
+    } else if jn_underflows(nm, x) {
+        // J_n(x) > 0 for 0 < x < n
+        0.0
+    } else if x >= HANKEL_MIN && (nm as f128) < x {
+        jn_forward(nm, x).to_f128()
+    } else {
+        k_jn_millerf128(nm, x).to_f128()
+    };
+
+    if neg { -r } else { r }
+}
+
+/// `J_n(x)` by forward recurrence from `J_0` and `J_1`, which is stable for `n < x`.
+fn jn_forward(n: u32, x: f128) -> WideFloat {
+    let inv_x = WideFloat::ONE / WideFloat::from_f128(x);
+    let mut a = k_j01f128(0, x);
+    let mut b = k_j01f128(1, x);
+    for k in 1..n {
+        /* J(k+1,x) = 2k/x * J(k,x) - J(k-1,x) */
+        let next = WideFloat::from_i128(2 * i128::from(k)) * inv_x * b - a;
+        a = b;
+        b = next;
+    }
+    b
+}
+
+/// Integer order of the [Bessel function](https://en.wikipedia.org/wiki/Bessel_function) of the second kind (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ynf128(n: i32, x: f128) -> f128 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.is_sign_negative() && x != 0.0 {
+        /* x < 0 */
+        return 0.0 / 0.0;
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    if x == 0.0 {
+        return -1.0 / 0.0;
+    }
+
+    /* Y(-n,x) = (-1)^n * Y(n, x) */
+    let nm = n.unsigned_abs();
+    let neg = n < 0 && nm & 1 == 1;
+
+    let r = if nm <= 1 {
+        k_y01f128(nm, x)
+    } else if use_hankel(nm, x) {
+        k_hankelf128(nm, x).1
+    } else {
+        // Forward recurrence is stable for `Y`. Once the values exceed the range of `f128` they
+        // only grow further with the same sign, so stop there.
+        let inv_x = WideFloat::ONE / WideFloat::from_f128(x);
+        let mut a = k_y01f128(0, x);
+        let mut b = k_y01f128(1, x);
+        for k in 1..nm {
+            /* Y(k+1,x) = 2k/x * Y(k,x) - Y(k-1,x) */
+            let next = WideFloat::from_i128(2 * i128::from(k)) * inv_x * b - a;
+            a = b;
+            b = next;
+            if b.exp() > f128::EXP_MAX {
+                break;
+            }
+        }
+        b
+    };
+
+    let r = r.to_f128();
+    if neg { -r } else { r }
+}
@@ -0,0 +1,41 @@
+use super::support::WideFloat;
+
+/// `atan(t)` for `0 <= t <= 1`.
+fn k_atanf128(mut t: WideFloat) -> WideFloat {
+    // Halve the angle three times with `atan(t) = 2 * atan(t / (1 + sqrt(1 + t^2)))`, leaving
+    // `t <= tan(π/32)` so that the series converges quickly.
+    for _ in 0..3 {
+        t = t / (WideFloat::ONE + (WideFloat::ONE + t * t).sqrt());
+    }
+
+    let t2 = t * t;
+    let mut pow = t;
+    let mut sum = t;
+    let mut n = 1;
+    loop {
+        n += 2;
+        pow = -(pow * t2);
+        let term = pow.div_small(n);
+        if term.is_zero() || term.exp() < sum.exp() - 260 {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    sum.mul_pow2(3)
+}
+
+/// The angle of the point `(x, y)`, for finite inputs that are not both zero.
+pub(crate) fn k_atan2f128(y: WideFloat, x: WideFloat) -> WideFloat {
+    debug_assert!(!(y.is_zero() && x.is_zero()));
+
+    let (ay, ax) = (y.abs(), x.abs());
+    let mut a =
+        if ay <= ax { k_atanf128(ay / ax) } else { WideFloat::FRAC_PI_2 - k_atanf128(ax / ay) };
+
+    if x.is_sign_negative() {
+        a = WideFloat::PI - a;
+    }
+
+    if y.is_sign_negative() { -a } else { a }
+}
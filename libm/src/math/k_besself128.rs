@@ -0,0 +1,221 @@
+use super::support::{Float, WideFloat};
+use super::{cbrt, k_cosf128, k_logf128, k_sinf128, rem_pio2f128};
+
+/// At and above this, the asymptotic expansion converges to better than `2^-256`. Below it, the
+/// power series would cancel badly near the zeros, so Miller's recurrence is used instead.
+pub(crate) const HANKEL_MIN: f128 = 90.0;
+
+/// `J_n(x)` for `x >= 0` from its power series. This is only accurate for small `x`.
+pub(crate) fn k_jn_seriesf128(n: u32, x: WideFloat) -> WideFloat {
+    // J_n(x) = sum((-1)^k * (x/2)^(2k+n) / (k! * (n+k)!))
+    let h = x.mul_pow2(-1);
+    let h2 = h * h;
+
+    // |J_n(x)| is bounded by the first term, so stop once that underflows and is still shrinking.
+    let mut term = WideFloat::ONE;
+    for k in 1..=n {
+        term = (term * h).div_small(k);
+        if term.exp() < f128::EXP_MIN_SUBNORM - 1 && WideFloat::from_i128(k.into()) > h {
+            return WideFloat::ZERO;
+        }
+    }
+
+    let mut sum = term;
+    let mut k = 0;
+    loop {
+        k += 1;
+        term = -(term * h2).div_small(k).div_small(n + k);
+        if term.is_zero() || term.exp() < sum.exp() - 260 {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    sum
+}
+
+/// `(J_n(x), Y_n(x))` for finite `x >= HANKEL_MIN` from the asymptotic expansion. `x` must also
+/// be large compared to `n^2` for the result to be accurate.
+pub(crate) fn k_hankelf128(n: u32, x: f128) -> (WideFloat, WideFloat) {
+    // With χ = x - (2n+1)π/4,
+    //     J_n(x) = sqrt(2/(πx)) * (P * cos(χ) - Q * sin(χ))
+    //     Y_n(x) = sqrt(2/(πx)) * (P * sin(χ) + Q * cos(χ))
+    // where P and Q take the even and odd terms of `sum((-1)^floor(k/2) * a_k / x^k)` with
+    // a_k = (4n^2 - 1^2) * (4n^2 - 3^2) * ... * (4n^2 - (2k-1)^2) / (k! * 8^k).
+    let w = WideFloat::from_f128(x);
+    let mu = WideFloat::from_i128(4 * i128::from(n) * i128::from(n));
+    let inv_8x = WideFloat::ONE / w.mul_pow2(3);
+
+    let mut p = WideFloat::ONE;
+    let mut q = WideFloat::ZERO;
+    let mut term = WideFloat::ONE;
+    let mut k: u32 = 0;
+    loop {
+        k += 1;
+        let odd = i128::from(2 * k - 1);
+        let next = ((mu - WideFloat::from_i128(odd * odd)) * term * inv_8x).div_small(k);
+
+        // Stop at full precision, or at the smallest term once the series starts to diverge.
+        if next.is_zero() || next.exp() < -260 || (k > 1 && next.abs() > term.abs()) {
+            break;
+        }
+
+        term = next;
+        match k % 4 {
+            0 => p = p + term,
+            1 => q = q + term,
+            2 => p = p - term,
+            _ => q = q - term,
+        }
+    }
+
+    // cos(x - π/4) = (cos(x) + sin(x)) / sqrt(2) and sin(x - π/4) = (sin(x) - cos(x)) / sqrt(2).
+    // The factor of `1/sqrt(2)` is folded into the amplitude.
+    let (i, r) = rem_pio2f128(x);
+    let (s, c) = (k_sinf128(r), k_cosf128(r));
+    let (s, c) = match i & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let (cos_t, sin_t) = (c + s, s - c);
+
+    // Rotate by -nπ/2
+    let (cos_chi, sin_chi) = match n & 3 {
+        0 => (cos_t, sin_t),
+        1 => (sin_t, -cos_t),
+        2 => (-cos_t, -sin_t),
+        _ => (-sin_t, cos_t),
+    };
+
+    let amp = (WideFloat::FRAC_2_PI / w.mul_pow2(1)).sqrt();
+    (amp * (p * cos_chi - q * sin_chi), amp * (p * sin_chi + q * cos_chi))
+}
+
+/// Rescale the running values of Miller's recurrence once they grow past `2^MILLER_RESCALE_EXP`,
+/// so the exponent of `WideFloat` cannot overflow.
+const MILLER_RESCALE_EXP: i32 = 1000;
+
+/// Normalized results of Miller's backward recurrence at some `x`.
+struct Miller {
+    /// `J_n(x)` for the requested `n`.
+    jn: WideFloat,
+    j0: WideFloat,
+    j1: WideFloat,
+    /// `sum((-1)^k * J_2k(x) / k)` for `k >= 1`.
+    s0: WideFloat,
+    /// `sum((-1)^k * (J_(2k-1)(x) - J_(2k+1)(x)) / k)` for `k >= 1`.
+    s1: WideFloat,
+}
+
+/// Miller's backward recurrence for `J_k(x)` with finite `x > 0`, which is stable for all `k`.
+fn miller(n: u32, x: f128) -> Miller {
+    // Start far enough above `max(n, x)` that the neglected `J_k` are below the working
+    // precision. The margin comes from the Airy-like decay of `J_k(x)` once `k` passes `x`.
+    let m = if (n as f128) < x { x as f64 } else { n as f64 };
+    let top = m as u32 + 64 + (42.0 * cbrt(m / 2.0)) as u32;
+    let inv_x = WideFloat::ONE / WideFloat::from_f128(x);
+
+    // Run the recurrence down from arbitrary starting values, then normalize with the identity
+    // J_0(x) + 2 * sum(J_2k(x)) = 1.
+    let mut a = WideFloat::ZERO;
+    let mut b = WideFloat::ONE;
+    let mut jn = WideFloat::ZERO;
+    let mut norm = WideFloat::ZERO;
+    let mut s0 = WideFloat::ZERO;
+    let mut s1 = WideFloat::ZERO;
+    for k in (1..=top).rev() {
+        /* J(k-1,x) = 2k/x * J(k,x) - J(k+1,x) */
+        let prev = WideFloat::from_i128(2 * i128::from(k)) * inv_x * b - a;
+
+        if k & 1 == 0 {
+            let half = k / 2;
+            let (t0, t1) = (b.div_small(half), (prev - a).div_small(half));
+            if half & 1 == 0 {
+                s0 = s0 + t0;
+                s1 = s1 + t1;
+            } else {
+                s0 = s0 - t0;
+                s1 = s1 - t1;
+            }
+            norm = norm + b.mul_pow2(1);
+        }
+
+        a = b;
+        b = prev;
+        if k - 1 == n {
+            jn = b;
+        }
+
+        if b.exp() > MILLER_RESCALE_EXP {
+            let e = -b.exp();
+            a = a.mul_pow2(e);
+            b = b.mul_pow2(e);
+            // `jn` is fixed once reached and only shrinks relative to `norm`, so flush it once it
+            // is certain to underflow rather than letting its exponent run away.
+            jn = jn.mul_pow2(e);
+            if jn.exp() < f128::EXP_MIN_SUBNORM - MILLER_RESCALE_EXP {
+                jn = WideFloat::ZERO;
+            }
+            norm = norm.mul_pow2(e);
+            s0 = s0.mul_pow2(e);
+            s1 = s1.mul_pow2(e);
+        }
+    }
+    norm = norm + b;
+
+    let inv_norm = WideFloat::ONE / norm;
+    Miller {
+        jn: jn * inv_norm,
+        j0: b * inv_norm,
+        j1: a * inv_norm,
+        s0: s0 * inv_norm,
+        s1: s1 * inv_norm,
+    }
+}
+
+/// `J_n(x)` for finite `x > 0` by Miller's recurrence. This takes time proportional to
+/// `max(n, x)`.
+pub(crate) fn k_jn_millerf128(n: u32, x: f128) -> WideFloat {
+    miller(n, x).jn
+}
+
+/// `J_n(x)` for `n` of 0 or 1 and finite `x >= 0`.
+pub(crate) fn k_j01f128(n: u32, x: f128) -> WideFloat {
+    debug_assert!(n <= 1);
+
+    if x >= HANKEL_MIN {
+        k_hankelf128(n, x).0
+    } else if x == 0.0 {
+        if n == 0 { WideFloat::ONE } else { WideFloat::ZERO }
+    } else if n == 1 && x < hf128!("0x1p-64") {
+        // J_1(x) = x/2 - x^3/16 + ..., where the cubic term is lost to the working precision.
+        // It still matters when `x/2` is halfway between two subnormals, so keep the result just
+        // below `x/2` to round the tie toward zero.
+        let h = WideFloat::from_f128(x).mul_pow2(-1);
+        h - h.mul_pow2(-200)
+    } else {
+        miller(n, x).jn
+    }
+}
+
+/// `Y_n(x)` for `n` of 0 or 1 and finite `x > 0`.
+pub(crate) fn k_y01f128(n: u32, x: f128) -> WideFloat {
+    debug_assert!(n <= 1);
+
+    if x >= HANKEL_MIN {
+        return k_hankelf128(n, x).1;
+    }
+
+    // Neumann's expansions, with L = ln(x/2) + γ:
+    //     Y_0(x) = (2/π) * (L * J_0(x) - 2 * s0)
+    //     Y_1(x) = (2/π) * (L * J_1(x) + s1 - J_0(x) / x)
+    let m = miller(1, x);
+    let w = WideFloat::from_f128(x);
+    let (e, t) = k_logf128(w.mul_pow2(-1));
+    let l = WideFloat::from_i32(e) * WideFloat::LN2 + t + WideFloat::EULER_GAMMA;
+    let y = if n == 0 { l * m.j0 - m.s0.mul_pow2(1) } else { l * m.j1 + m.s1 - m.j0 / w };
+
+    WideFloat::FRAC_2_PI * y
+}
@@ -0,0 +1,19 @@
+use super::support::WideFloat;
+
+/// `cos(r)` for `|r| <= π/4`.
+pub(crate) fn k_cosf128(r: WideFloat) -> WideFloat {
+    let r2 = r * r;
+    let mut term = WideFloat::ONE;
+    let mut sum = WideFloat::ONE;
+    let mut n = 0;
+    loop {
+        term = -(term * r2).div_small((n + 1) * (n + 2));
+        n += 2;
+        if term.is_zero() || term.exp() < sum.exp() - 260 {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    sum
+}
@@ -0,0 +1,36 @@
+use super::support::WideFloat;
+
+/// `e^r - 1` for `|r| <= 1`, accurate relative to the result.
+pub(crate) fn k_expm1f128(r: WideFloat) -> WideFloat {
+    // Scale the argument down so the Taylor series converges quickly, then undo the scaling
+    // with `expm1(2y) = expm1(y) * (expm1(y) + 2)`. Unlike squaring `exp(y)`, this does not lose
+    // relative accuracy when the result is small.
+    let scale = if r.is_zero() { 0 } else { (r.exp() + 10).max(0) };
+    let y = r.mul_pow2(-scale);
+
+    let mut term = y;
+    let mut sum = y;
+    let mut n = 1;
+    loop {
+        n += 1;
+        term = (term * y).div_small(n);
+        if term.is_zero() || term.exp() < sum.exp() - 260 {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    for _ in 0..scale {
+        sum = sum * (sum + WideFloat::TWO);
+    }
+
+    sum
+}
+
+/// `e^x` for `|x| < 2^20`.
+pub(crate) fn k_expf128(x: WideFloat) -> WideFloat {
+    // x = k*ln2 + r with |r| <= ln2/2
+    let k = (x * WideFloat::LOG2_E).round_int();
+    let r = x - WideFloat::from_i32(k) * WideFloat::LN2;
+    (WideFloat::ONE + k_expm1f128(r)).mul_pow2(k)
+}
@@ -0,0 +1,98 @@
+use super::support::WideFloat;
+use super::{k_cosf128, k_logf128, k_sinf128, roundf128};
+
+/// Arguments are shifted up to at least this value before using Stirling's series, which makes
+/// the terms of `BERNOULLI` sufficient for full precision.
+const STIRLING_MIN: i32 = 64;
+
+/// The Bernoulli numbers `B_2` through `B_58`, as numerator and denominator.
+const BERNOULLI: [(i128, u32); 29] = [
+    (1, 6),
+    (-1, 30),
+    (1, 42),
+    (-1, 30),
+    (5, 66),
+    (-691, 2730),
+    (7, 6),
+    (-3617, 510),
+    (43867, 798),
+    (-174611, 330),
+    (854513, 138),
+    (-236364091, 2730),
+    (8553103, 6),
+    (-23749461029, 870),
+    (8615841276005, 14322),
+    (-7709321041217, 510),
+    (2577687858367, 6),
+    (-26315271553053477373, 1919190),
+    (2929993913841559, 6),
+    (-261082718496449122051, 13530),
+    (1520097643918070802691, 1806),
+    (-27833269579301024235023, 690),
+    (596451111593912163277961, 282),
+    (-5609403368997817686249127547, 46410),
+    (495057205241079648212477525, 66),
+    (-801165718135489957347924991853, 1590),
+    (29149963634884862421418123812691, 798),
+    (-2479392929313226753685415739663229, 870),
+    (84483613348880041862046775994036021, 354),
+];
+
+/// `ln(Γ(x))` for `x > 0`.
+pub(crate) fn k_lgammaf128(x: WideFloat) -> WideFloat {
+    debug_assert!(x > WideFloat::ZERO);
+
+    // Γ(x) = Γ(x + n) / (x * (x+1) * ... * (x+n-1))
+    let mut y = x;
+    let mut p = WideFloat::ONE;
+    while y < WideFloat::from_i32(STIRLING_MIN) {
+        p = p * y;
+        y = y + WideFloat::ONE;
+    }
+
+    // ln(Γ(y)) = (y - 1/2) * ln(y) - y + ln(2π)/2 + sum(B_2k / (2k * (2k-1) * y^(2k-1)))
+    let (e, t) = k_logf128(y);
+    let ln_y = WideFloat::from_i32(e) * WideFloat::LN2 + t;
+    let mut r = (y - WideFloat::ONE.mul_pow2(-1)) * ln_y - y
+        + (WideFloat::LN2 + WideFloat::LN_PI).mul_pow2(-1);
+
+    let z = WideFloat::ONE / y;
+    let z2 = z * z;
+    let mut pow = z;
+    for (i, &(num, den)) in BERNOULLI.iter().enumerate() {
+        let k = i as u32 + 1;
+        let term = (WideFloat::from_i128(num) * pow).div_small(den).div_small(2 * k * (2 * k - 1));
+        if term.is_zero() || term.exp() < r.exp() - 260 {
+            break;
+        }
+        r = r + term;
+        pow = pow * z2;
+    }
+
+    if p == WideFloat::ONE {
+        return r;
+    }
+
+    let (e, t) = k_logf128(p);
+    r - (WideFloat::from_i32(e) * WideFloat::LN2 + t)
+}
+
+/// `sin(πx)` for finite `x`, exact at integers and accurate relative to the result elsewhere.
+pub(crate) fn k_sinpif128(x: f128) -> WideFloat {
+    // Reduce to `r` in [-1, 1] with `x - r` an even integer. The subtraction is exact.
+    let r = x - 2.0 * roundf128(x * 0.5);
+
+    // sin(π|r|) = sin(π(1 - |r|)), which leaves an argument in [0, 1/2].
+    let mut s = r.abs();
+    if s > 0.5 {
+        s = 1.0 - s;
+    }
+
+    let v = if s <= 0.25 {
+        k_sinf128(WideFloat::PI * WideFloat::from_f128(s))
+    } else {
+        k_cosf128(WideFloat::PI * WideFloat::from_f128(0.5 - s))
+    };
+
+    if r < 0.0 { -v } else { v }
+}
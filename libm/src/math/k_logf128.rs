@@ -0,0 +1,40 @@
+use super::support::WideFloat;
+
+/// `ln(1 + f)` for `sqrt(2)/2 - 1 <= f <= sqrt(2) - 1`, accurate relative to the result.
+pub(crate) fn k_log1pf128(f: WideFloat) -> WideFloat {
+    // ln(1 + f) = 2 * atanh(z) with z = f / (2 + f), and |z| <= 0.1716 over the domain.
+    let z = f / (f + WideFloat::TWO);
+    let z2 = z * z;
+
+    let mut pow = z;
+    let mut sum = z;
+    let mut n = 1;
+    loop {
+        n += 2;
+        pow = pow * z2;
+        let term = pow.div_small(n);
+        if term.is_zero() || term.exp() < sum.exp() - 260 {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    sum.mul_pow2(1)
+}
+
+/// Split `ln(x)` for `x > 0` as `(e, t)` where `ln(x) = e * ln(2) + t` and `|t| <= ln(2)/2`.
+///
+/// Keeping the parts separate lets callers scale the integer part exactly, e.g. for `log2`.
+pub(crate) fn k_logf128(x: WideFloat) -> (i32, WideFloat) {
+    debug_assert!(x > WideFloat::ZERO);
+
+    // x = 2^e * m with sqrt(2)/2 <= m < sqrt(2). The subtraction for `f` is exact.
+    let mut e = x.exp();
+    let mut m = x.mul_pow2(-e);
+    if m >= WideFloat::SQRT_2 {
+        e += 1;
+        m = m.mul_pow2(-1);
+    }
+
+    (e, k_log1pf128(m - WideFloat::ONE))
+}
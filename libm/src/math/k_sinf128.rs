@@ -0,0 +1,19 @@
+use super::support::WideFloat;
+
+/// `sin(r)` for `|r| <= π/4`.
+pub(crate) fn k_sinf128(r: WideFloat) -> WideFloat {
+    let r2 = r * r;
+    let mut term = r;
+    let mut sum = r;
+    let mut n = 1;
+    loop {
+        term = -(term * r2).div_small((n + 1) * (n + 2));
+        n += 2;
+        if term.is_zero() || term.exp() < sum.exp() - 260 {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    sum
+}
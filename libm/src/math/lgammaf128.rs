@@ -0,0 +1,8 @@
+use super::lgammaf128_r;
+
+/// The natural logarithm of the
+/// [Gamma function](https://en.wikipedia.org/wiki/Gamma_function) (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lgammaf128(x: f128) -> f128 {
+    lgammaf128_r(x).0
+}
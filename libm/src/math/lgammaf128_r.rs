@@ -0,0 +1,35 @@
+use super::support::WideFloat;
+use super::{k_lgammaf128, k_logf128, k_sinpif128, truncf128};
+
+/// The natural logarithm of the absolute value of the
+/// [Gamma function](https://en.wikipedia.org/wiki/Gamma_function) (f128), and the sign of the
+/// Gamma function as `1` or `-1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lgammaf128_r(x: f128) -> (f128, i32) {
+    if !x.is_finite() {
+        return (x * x, 1);
+    }
+    if x == 0.0 {
+        /* lgamma(+-0) = +inf, with the sign of the zero */
+        return (f128::INFINITY, if x.is_sign_negative() { -1 } else { 1 });
+    }
+    if x == 1.0 || x == 2.0 {
+        return (0.0, 1); /* lgamma(1) = lgamma(2) = 0 exactly */
+    }
+
+    if x > 0.0 {
+        return (k_lgammaf128(WideFloat::from_f128(x)).to_f128(), 1);
+    }
+
+    if x == truncf128(x) {
+        return (1.0 / (x - x), 1); /* -integer */
+    }
+
+    // Reflection: ln|Γ(x)| = ln(π) - ln|sin(πx)| - ln(Γ(1 - x))
+    let s = k_sinpif128(x);
+    let (e, t) = k_logf128(s.abs());
+    let ln_s = WideFloat::from_i32(e) * WideFloat::LN2 + t;
+    let r = WideFloat::LN_PI - ln_s - k_lgammaf128(WideFloat::ONE - WideFloat::from_f128(x));
+
+    (r.to_f128(), if s.is_sign_negative() { -1 } else { 1 })
+}
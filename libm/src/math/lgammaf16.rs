@@ -0,0 +1,6 @@
+/// The natural logarithm of the
+/// [Gamma function](https://en.wikipedia.org/wiki/Gamma_function) (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lgammaf16(x: f16) -> f16 {
+    super::lgamma(x as f64) as f16
+}
@@ -0,0 +1,33 @@
+/// Extract the binary exponent of `x` as an `i64` (f16).
+///
+/// Zero returns `i64::MIN`, NaN returns `i64::MIN` and infinity returns `i64::MAX`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llogbf16(x: f16) -> i64 {
+    super::generic::llogb(x)
+}
+
+/// Extract the binary exponent of `x` as an `i64` (f32).
+///
+/// Zero returns `i64::MIN`, NaN returns `i64::MIN` and infinity returns `i64::MAX`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llogbf(x: f32) -> i64 {
+    super::generic::llogb(x)
+}
+
+/// Extract the binary exponent of `x` as an `i64` (f64).
+///
+/// Zero returns `i64::MIN`, NaN returns `i64::MIN` and infinity returns `i64::MAX`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llogb(x: f64) -> i64 {
+    super::generic::llogb(x)
+}
+
+/// Extract the binary exponent of `x` as an `i64` (f128).
+///
+/// Zero returns `i64::MIN`, NaN returns `i64::MIN` and infinity returns `i64::MAX`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llogbf128(x: f128) -> i64 {
+    super::generic::llogb(x)
+}
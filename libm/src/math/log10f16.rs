@@ -0,0 +1,5 @@
+/// The base 10 logarithm of `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10f16(x: f16) -> f16 {
+    super::log10(x as f64) as f16
+}
@@ -0,0 +1,53 @@
+use super::fma;
+use super::log1p::k_log1p;
+
+const IVLN10: f64 = core::f64::consts::LOG10_E; /* 0x3fdbcb7b, 0x1526e50e */
+const IVLN10_LO: f64 = 1.09831965021676507274e-17; /* 0x3c695355, 0xbaaafad3 */
+const IVLN10HI: f64 = 4.34294481878168880939e-01; /* 0x3fdbcb7b, 0x15200000 */
+const IVLN10LO: f64 = 2.50829467116452752298e-11; /* 0x3dbb9438, 0xca9aadd5 */
+const LOG10_2HI: f64 = 3.01029995663611771306e-01; /* 0x3FD34413, 0x509F6000 */
+const LOG10_2LO: f64 = 3.69423907715893078616e-13; /* 0x3D59FEF3, 0x11F12B36 */
+
+/// The base 10 logarithm of 1+`x` (f32).
+///
+/// Unlike `log10(1 + x)`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10p1f(x: f32) -> f32 {
+    log10p1(x as f64) as f32
+}
+
+/// The base 10 logarithm of 1+`x` (f64).
+///
+/// Unlike `log10(1 + x)`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10p1(x: f64) -> f64 {
+    let hx = (x.to_bits() >> 32) as u32;
+    if hx >= 0xbff00000 {
+        /* x <= -1.0 */
+        if x == -1. {
+            return x / 0.0; /* log10p1(-1) = -inf */
+        }
+        return (x - x) / 0.0; /* log10p1(x<-1) = NaN */
+    }
+    if (hx & 0x7fffffff) >= 0x7ff00000 {
+        return x + x;
+    }
+    if (hx & 0x7fffffff) < 0x3c300000 {
+        /* |x| < 2**-60, log10(1+x) = x/ln(10) to working precision */
+        return fma(x, IVLN10, x * IVLN10_LO);
+    }
+
+    let (dk, hi, lo) = k_log1p(x);
+
+    /* See log10.c for details. */
+    let mut val_hi = hi * IVLN10HI;
+    let y = dk * LOG10_2HI;
+    let mut val_lo = dk * LOG10_2LO + (lo + hi) * IVLN10LO + lo * IVLN10HI;
+
+    /* spadd(val_hi, val_lo, y) */
+    let w = y + val_hi;
+    val_lo += (y - w) + val_hi;
+    val_hi = w;
+
+    val_lo + val_hi
+}
@@ -138,3 +138,49 @@ pub fn log1p(x: f64) -> f64 {
     dk = k as f64;
     s * (hfsq + r) + (dk * LN2_LO + c) - hfsq + f + dk * LN2_HI
 }
+
+/// Reduce `1 + x = 2^k * (1 + f)` and evaluate `log(1 + f)` in extra precision.
+///
+/// Returns `(k, hi, lo)` with `log(1 + x) ~ k*ln(2) + hi + lo`, where `hi` has its low 32 bits
+/// cleared so it can be scaled exactly by a similarly truncated constant (see log2.c). `x` must be
+/// finite and greater than -1.
+pub(crate) fn k_log1p(x: f64) -> (f64, f64, f64) {
+    let mut ui: u64;
+    let mut f: f64 = x;
+    let mut c: f64 = 0.;
+    let mut k: i32 = 0;
+
+    let hx = (x.to_bits() >> 32) as u32;
+    if (hx >= 0x3fda827a && (hx >> 31) == 0) || hx > 0xbfd2bec4 {
+        /* 1+x outside [sqrt(2)/2, sqrt(2)), reduce it */
+        ui = (1. + x).to_bits();
+        let mut hu = (ui >> 32) as u32;
+        hu += 0x3ff00000 - 0x3fe6a09e;
+        k = (hu >> 20) as i32 - 0x3ff;
+        /* correction term ~ log(1+x)-log(u), avoid underflow in c/u */
+        if k < 54 {
+            c = if k >= 2 { 1. - (f64::from_bits(ui) - x) } else { x - (f64::from_bits(ui) - 1.) };
+            c /= f64::from_bits(ui);
+        }
+        /* reduce u into [sqrt(2)/2, sqrt(2)] */
+        hu = (hu & 0x000fffff) + 0x3fe6a09e;
+        ui = ((hu as u64) << 32) | (ui & 0xffffffff);
+        f = f64::from_bits(ui) - 1.;
+    }
+
+    let hfsq = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    let r = t2 + t1;
+
+    /* hi+lo = f - hfsq + s*(hfsq+R) + c ~ log(1+f) + c */
+    ui = (f - hfsq).to_bits();
+    ui &= (-1i64 as u64) << 32;
+    let hi = f64::from_bits(ui);
+    let lo = f - hi - hfsq + s * (hfsq + r) + c;
+
+    (k as f64, hi, lo)
+}
@@ -0,0 +1,25 @@
+use super::support::WideFloat;
+use super::{k_log1pf128, k_logf128};
+
+/// The natural logarithm of 1+`x` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log1pf128(x: f128) -> f128 {
+    if x.is_nan() || x == f128::INFINITY || x == 0.0 {
+        return x + x;
+    }
+    if x == -1.0 {
+        return x / 0.0; /* log1p(-1) = -inf */
+    }
+    if x < -1.0 {
+        return (x - x) / 0.0; /* log1p(x<-1) = NaN */
+    }
+
+    let w = WideFloat::from_f128(x);
+    if x > -0.25 && x < 0.375 {
+        // Use `x` directly rather than rounding `1 + x`
+        return k_log1pf128(w).to_f128();
+    }
+
+    let (e, t) = k_logf128(WideFloat::ONE + w);
+    (WideFloat::from_i32(e) * WideFloat::LN2 + t).to_f128()
+}
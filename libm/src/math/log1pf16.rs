@@ -0,0 +1,5 @@
+/// The natural logarithm of 1+`x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log1pf16(x: f16) -> f16 {
+    super::log1p(x as f64) as f16
+}
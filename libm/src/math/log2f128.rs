@@ -0,0 +1,20 @@
+use super::k_logf128;
+use super::support::WideFloat;
+
+/// The base 2 logarithm of `x` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2f128(x: f128) -> f128 {
+    if x.is_nan() || x == f128::INFINITY {
+        return x + x;
+    }
+    if x == 0.0 {
+        return -1. / (x * x); /* log(+-0)=-inf */
+    }
+    if x < 0.0 {
+        return (x - x) / 0.0; /* log(-#) = NaN */
+    }
+
+    // The integer part is exact, so powers of two give exact results
+    let (e, t) = k_logf128(WideFloat::from_f128(x));
+    (WideFloat::from_i32(e) + t * WideFloat::LOG2_E).to_f128()
+}
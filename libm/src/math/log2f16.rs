@@ -0,0 +1,5 @@
+/// The base 2 logarithm of `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2f16(x: f16) -> f16 {
+    super::log2(x as f64) as f16
+}
@@ -0,0 +1,50 @@
+use super::fma;
+use super::log1p::k_log1p;
+
+const IVLN2: f64 = core::f64::consts::LOG2_E; /* 0x3ff71547, 0x652b82fe */
+const IVLN2_LO: f64 = 2.03552737409310331110e-17; /* 0x3c7777d0, 0xffda0d24 */
+const IVLN2HI: f64 = 1.44269504072144627571e+00; /* 0x3ff71547, 0x65200000 */
+const IVLN2LO: f64 = 1.67517131648865118353e-10; /* 0x3de705fc, 0x2eefa200 */
+
+/// The base 2 logarithm of 1+`x` (f32).
+///
+/// Unlike `log2(1 + x)`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1f(x: f32) -> f32 {
+    log2p1(x as f64) as f32
+}
+
+/// The base 2 logarithm of 1+`x` (f64).
+///
+/// Unlike `log2(1 + x)`, this stays accurate for `x` close to zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1(x: f64) -> f64 {
+    let hx = (x.to_bits() >> 32) as u32;
+    if hx >= 0xbff00000 {
+        /* x <= -1.0 */
+        if x == -1. {
+            return x / 0.0; /* log2p1(-1) = -inf */
+        }
+        return (x - x) / 0.0; /* log2p1(x<-1) = NaN */
+    }
+    if (hx & 0x7fffffff) >= 0x7ff00000 {
+        return x + x;
+    }
+    if (hx & 0x7fffffff) < 0x3c300000 {
+        /* |x| < 2**-60, log2(1+x) = x/ln(2) to working precision */
+        return fma(x, IVLN2, x * IVLN2_LO);
+    }
+
+    let (y, hi, lo) = k_log1p(x);
+
+    /* See log2.c for details. */
+    let mut val_hi = hi * IVLN2HI;
+    let mut val_lo = (lo + hi) * IVLN2LO + lo * IVLN2HI;
+
+    /* spadd(val_hi, val_lo, y) */
+    let w = y + val_hi;
+    val_lo += (y - w) + val_hi;
+    val_hi = w;
+
+    val_lo + val_hi
+}
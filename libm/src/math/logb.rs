@@ -0,0 +1,37 @@
+/// Extract the binary exponent of `x` as a float (f16).
+///
+/// This is `ilogb` for finite nonzero `x`, but zero returns negative infinity and infinities
+/// return positive infinity.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logbf16(x: f16) -> f16 {
+    super::generic::logb(x)
+}
+
+/// Extract the binary exponent of `x` as a float (f32).
+///
+/// This is `ilogb` for finite nonzero `x`, but zero returns negative infinity and infinities
+/// return positive infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logbf(x: f32) -> f32 {
+    super::generic::logb(x)
+}
+
+/// Extract the binary exponent of `x` as a float (f64).
+///
+/// This is `ilogb` for finite nonzero `x`, but zero returns negative infinity and infinities
+/// return positive infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logb(x: f64) -> f64 {
+    super::generic::logb(x)
+}
+
+/// Extract the binary exponent of `x` as a float (f128).
+///
+/// This is `ilogb` for finite nonzero `x`, but zero returns negative infinity and infinities
+/// return positive infinity.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logbf128(x: f128) -> f128 {
+    super::generic::logb(x)
+}
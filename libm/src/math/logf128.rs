@@ -0,0 +1,19 @@
+use super::k_logf128;
+use super::support::WideFloat;
+
+/// The natural logarithm of `x` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf128(x: f128) -> f128 {
+    if x.is_nan() || x == f128::INFINITY {
+        return x + x;
+    }
+    if x == 0.0 {
+        return -1. / (x * x); /* log(+-0)=-inf */
+    }
+    if x < 0.0 {
+        return (x - x) / 0.0; /* log(-#) = NaN */
+    }
+
+    let (e, t) = k_logf128(WideFloat::from_f128(x));
+    (WideFloat::from_i32(e) * WideFloat::LN2 + t).to_f128()
+}
@@ -0,0 +1,5 @@
+/// The natural logarithm of `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf16(x: f16) -> f16 {
+    super::log(x as f64) as f16
+}
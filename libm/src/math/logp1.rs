@@ -0,0 +1,17 @@
+use super::{log1p, log1pf};
+
+/// The natural logarithm of 1+`x` (f32).
+///
+/// This is the C23 name for `log1pf`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logp1f(x: f32) -> f32 {
+    log1pf(x)
+}
+
+/// The natural logarithm of 1+`x` (f64).
+///
+/// This is the C23 name for `log1p`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logp1(x: f64) -> f64 {
+    log1p(x)
+}
@@ -0,0 +1,97 @@
+use super::support::Round;
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llrint`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrintf16(x: f16) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llrint`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrintf(x: f32) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llrint`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrint(x: f64) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llrint`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrintf128(x: f128) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lrint`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llrintf16(x: f16) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lrint`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llrintf(x: f32) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lrint`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llrint(x: f64) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lrint`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llrintf128(x: f128) -> i64 {
+    super::generic::lrint_round(x, Round::Nearest).val
+}
@@ -0,0 +1,95 @@
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llround`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lroundf16(x: f16) -> i64 {
+    super::generic::lround(x).val
+}
+
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llround`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lroundf(x: f32) -> i64 {
+    super::generic::lround(x).val
+}
+
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llround`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lround(x: f64) -> i64 {
+    super::generic::lround(x).val
+}
+
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// The return type is `i64` regardless of the size of C's `long`, so this is the same as
+/// `llround`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lroundf128(x: f128) -> i64 {
+    super::generic::lround(x).val
+}
+
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lround`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llroundf16(x: f16) -> i64 {
+    super::generic::lround(x).val
+}
+
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lround`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llroundf(x: f32) -> i64 {
+    super::generic::lround(x).val
+}
+
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lround`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llround(x: f64) -> i64 {
+    super::generic::lround(x).val
+}
+
+/// Round `x` to the nearest integer, breaking ties away from zero, and convert it to `i64`.
+///
+/// C leaves the result unspecified if `x` is NaN or infinite, or if the rounded value is out of
+/// range; `i64::MIN` is returned in these cases.
+///
+/// This is the same as `lround`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llroundf128(x: f128) -> i64 {
+    super::generic::lround(x).val
+}
@@ -91,6 +91,9 @@ cfg_if! {
     }
 }
 
+// Variants of the public functions that report exceptions
+pub mod status;
+
 // Private modules
 mod arch;
 mod expo2;
@@ -121,55 +124,73 @@ use self::rem_pio2_large::rem_pio2_large;
 use self::rem_pio2f::rem_pio2f;
 #[allow(unused_imports)]
 use self::support::{CastFrom, CastInto, DFloat, DInt, Float, HFloat, HInt, Int, IntTy, MinInt};
+// Support types that are part of the public API
+pub use self::support::{FpResult, Round, Status};
 
 // Public modules
 mod acos;
 mod acosf;
 mod acosh;
 mod acoshf;
+mod acospi;
 mod asin;
 mod asinf;
 mod asinh;
 mod asinhf;
+mod asinpi;
 mod atan;
 mod atan2;
 mod atan2f;
+mod atan2pi;
 mod atanf;
 mod atanh;
 mod atanhf;
+mod atanpi;
+mod canonicalize;
 mod cbrt;
 mod cbrtf;
 mod ceil;
+mod compoundn;
 mod copysign;
 mod cos;
 mod cosf;
 mod cosh;
 mod coshf;
+mod cospi;
 mod erf;
 mod erff;
 mod exp;
 mod exp10;
 mod exp10f;
+mod exp10m1;
 mod exp2;
 mod exp2f;
+mod exp2m1;
 mod expf;
 mod expm1;
 mod expm1f;
 mod fabs;
+mod fadd;
 mod fdim;
+mod fdiv;
+mod ffma;
 mod floor;
 mod fma;
 mod fma_wide;
 mod fmin_fmax;
 mod fminimum_fmaximum;
+mod fminimum_fmaximum_mag;
+mod fminimum_fmaximum_mag_num;
 mod fminimum_fmaximum_num;
 mod fmod;
+mod fmul;
 mod frexp;
-mod frexpf;
+mod fromfp;
+mod fsqrt;
+mod fsub;
 mod hypot;
 mod hypotf;
 mod ilogb;
-mod ilogbf;
 mod j0;
 mod j0f;
 mod j1;
@@ -181,41 +202,58 @@ mod lgamma;
 mod lgamma_r;
 mod lgammaf;
 mod lgammaf_r;
+mod llogb;
 mod log;
 mod log10;
 mod log10f;
+mod log10p1;
 mod log1p;
 mod log1pf;
 mod log2;
 mod log2f;
+mod log2p1;
+mod logb;
 mod logf;
+mod logp1;
+mod lrint;
+mod lround;
 mod modf;
-mod modff;
+mod nan;
+mod nearbyint;
 mod nextafter;
-mod nextafterf;
+mod nexttoward;
+mod nextup;
+mod payload;
 mod pow;
 mod powf;
+mod pown;
+mod powr;
 mod remainder;
-mod remainderf;
 mod remquo;
-mod remquof;
 mod rint;
+mod rootn;
 mod round;
 mod roundeven;
+mod rsqrt;
+mod scalbln;
 mod scalbn;
 mod sin;
 mod sincos;
 mod sincosf;
+mod sincospi;
 mod sinf;
 mod sinh;
 mod sinhf;
+mod sinpi;
 mod sqrt;
 mod tan;
 mod tanf;
 mod tanh;
 mod tanhf;
+mod tanpi;
 mod tgamma;
 mod tgammaf;
+mod totalorder;
 mod trunc;
 
 // Use separated imports instead of {}-grouped imports for easier merging.
@@ -223,49 +261,69 @@ pub use self::acos::acos;
 pub use self::acosf::acosf;
 pub use self::acosh::acosh;
 pub use self::acoshf::acoshf;
+pub use self::acospi::{acospi, acospif};
 pub use self::asin::asin;
 pub use self::asinf::asinf;
 pub use self::asinh::asinh;
 pub use self::asinhf::asinhf;
+pub use self::asinpi::{asinpi, asinpif};
 pub use self::atan::atan;
 pub use self::atan2::atan2;
 pub use self::atan2f::atan2f;
+pub use self::atan2pi::{atan2pi, atan2pif};
 pub use self::atanf::atanf;
 pub use self::atanh::atanh;
 pub use self::atanhf::atanhf;
+pub use self::atanpi::{atanpi, atanpif};
+pub use self::canonicalize::{canonicalize, canonicalizef, issignaling, issignalingf};
 pub use self::cbrt::cbrt;
 pub use self::cbrtf::cbrtf;
 pub use self::ceil::{ceil, ceilf};
+pub use self::compoundn::{compoundn, compoundnf};
 pub use self::copysign::{copysign, copysignf};
 pub use self::cos::cos;
 pub use self::cosf::cosf;
 pub use self::cosh::cosh;
 pub use self::coshf::coshf;
+pub use self::cospi::{cospi, cospif};
 pub use self::erf::{erf, erfc};
 pub use self::erff::{erfcf, erff};
 pub use self::exp::exp;
 pub use self::exp2::exp2;
 pub use self::exp2f::exp2f;
+pub use self::exp2m1::{exp2m1, exp2m1f};
 pub use self::exp10::exp10;
 pub use self::exp10f::exp10f;
+pub use self::exp10m1::{exp10m1, exp10m1f};
 pub use self::expf::expf;
 pub use self::expm1::expm1;
 pub use self::expm1f::expm1f;
 pub use self::fabs::{fabs, fabsf};
+pub use self::fadd::{fadd, fadd_round};
 pub use self::fdim::{fdim, fdimf};
+pub use self::fdiv::{fdiv, fdiv_round};
+pub use self::ffma::{ffma, ffma_round};
 pub use self::floor::{floor, floorf};
-pub use self::fma::fma;
-pub use self::fma_wide::fmaf;
+pub use self::fma::{fma, fma_round};
+pub use self::fma_wide::{fmaf, fmaf_round};
 pub use self::fmin_fmax::{fmax, fmaxf, fmin, fminf};
 pub use self::fminimum_fmaximum::{fmaximum, fmaximumf, fminimum, fminimumf};
+pub use self::fminimum_fmaximum_mag::{fmaximum_mag, fmaximum_magf, fminimum_mag, fminimum_magf};
+pub use self::fminimum_fmaximum_mag_num::{
+    fmaximum_mag_num, fmaximum_mag_numf, fminimum_mag_num, fminimum_mag_numf,
+};
 pub use self::fminimum_fmaximum_num::{fmaximum_num, fmaximum_numf, fminimum_num, fminimum_numf};
 pub use self::fmod::{fmod, fmodf};
-pub use self::frexp::frexp;
-pub use self::frexpf::frexpf;
+pub use self::fmul::{fmul, fmul_round};
+pub use self::frexp::{frexp, frexpf};
+pub use self::fromfp::{
+    fromfp, fromfpf, fromfpx, fromfpxf, ufromfp, ufromfpf, ufromfpx, ufromfpxf,
+};
+pub use self::fsqrt::{fsqrt, fsqrt_round};
+pub use self::fsub::{fsub, fsub_round};
 pub use self::hypot::hypot;
 pub use self::hypotf::hypotf;
-pub use self::ilogb::ilogb;
-pub use self::ilogbf::ilogbf;
+pub use self::ilogb::{ilogb, ilogbf};
 pub use self::j0::{j0, y0};
 pub use self::j0f::{j0f, y0f};
 pub use self::j1::{j1, y1};
@@ -277,90 +335,318 @@ pub use self::lgamma::lgamma;
 pub use self::lgamma_r::lgamma_r;
 pub use self::lgammaf::lgammaf;
 pub use self::lgammaf_r::lgammaf_r;
+pub use self::llogb::{llogb, llogbf};
 pub use self::log::log;
 pub use self::log1p::log1p;
 pub use self::log1pf::log1pf;
 pub use self::log2::log2;
 pub use self::log2f::log2f;
+pub use self::log2p1::{log2p1, log2p1f};
 pub use self::log10::log10;
 pub use self::log10f::log10f;
+pub use self::log10p1::{log10p1, log10p1f};
+pub use self::logb::{logb, logbf};
 pub use self::logf::logf;
-pub use self::modf::modf;
-pub use self::modff::modff;
-pub use self::nextafter::nextafter;
-pub use self::nextafterf::nextafterf;
+pub use self::logp1::{logp1, logp1f};
+pub use self::lrint::{llrint, llrintf, lrint, lrintf};
+pub use self::lround::{llround, llroundf, lround, lroundf};
+pub use self::modf::{modf, modff};
+pub use self::nan::{nan, nanf};
+pub use self::nearbyint::{nearbyint, nearbyintf};
+pub use self::nextafter::{nextafter, nextafterf};
+pub use self::nexttoward::{nexttoward, nexttowardf};
+pub use self::nextup::{nextdown, nextdownf, nextup, nextupf};
+pub use self::payload::{
+    getpayload, getpayloadf, setpayload, setpayloadf, setpayloadsig, setpayloadsigf,
+};
 pub use self::pow::pow;
 pub use self::powf::powf;
-pub use self::remainder::remainder;
-pub use self::remainderf::remainderf;
-pub use self::remquo::remquo;
-pub use self::remquof::remquof;
-pub use self::rint::{rint, rintf};
+pub use self::pown::{pown, pownf};
+pub use self::powr::{powr, powrf};
+pub use self::remainder::{remainder, remainderf};
+pub use self::remquo::{remquo, remquof};
+pub use self::rint::{rint, rint_round, rintf, rintf_round};
+pub use self::rootn::{rootn, rootnf};
 pub use self::round::{round, roundf};
 pub use self::roundeven::{roundeven, roundevenf};
+pub use self::rsqrt::{rsqrt, rsqrtf};
+pub use self::scalbln::{scalbln, scalblnf};
 pub use self::scalbn::{scalbn, scalbnf};
 pub use self::sin::sin;
 pub use self::sincos::sincos;
 pub use self::sincosf::sincosf;
+pub use self::sincospi::{sincospi, sincospif};
 pub use self::sinf::sinf;
 pub use self::sinh::sinh;
 pub use self::sinhf::sinhf;
-pub use self::sqrt::{sqrt, sqrtf};
+pub use self::sinpi::{sinpi, sinpif};
+pub use self::sqrt::{sqrt, sqrt_round, sqrtf, sqrtf_round};
 pub use self::tan::tan;
 pub use self::tanf::tanf;
 pub use self::tanh::tanh;
 pub use self::tanhf::tanhf;
+pub use self::tanpi::{tanpi, tanpif};
 pub use self::tgamma::tgamma;
 pub use self::tgammaf::tgammaf;
+pub use self::totalorder::{totalorder, totalorderf, totalordermag, totalordermagf};
 pub use self::trunc::{trunc, truncf};
 
 cfg_if! {
     if #[cfg(f16_enabled)] {
         // verify-sorted-start
+        mod acosf16;
+        mod acoshf16;
+        mod asinf16;
+        mod asinhf16;
+        mod atan2f16;
+        mod atanf16;
+        mod atanhf16;
+        mod cbrtf16;
+        mod cosf16;
+        mod coshf16;
+        mod erff16;
+        mod exp10f16;
+        mod exp2f16;
+        mod expf16;
+        mod expm1f16;
+        mod hypotf16;
+        mod lgammaf16;
+        mod log10f16;
+        mod log1pf16;
+        mod log2f16;
+        mod logf16;
+        mod powf16;
+        mod sincosf16;
+        mod sinf16;
+        mod sinhf16;
+        mod tanf16;
+        mod tanhf16;
+        mod tgammaf16;
+        // verify-sorted-end
+
+        // verify-sorted-start
+        pub use self::acosf16::acosf16;
+        pub use self::acoshf16::acoshf16;
+        pub use self::acospi::acospif16;
+        pub use self::asinf16::asinf16;
+        pub use self::asinhf16::asinhf16;
+        pub use self::asinpi::asinpif16;
+        pub use self::atan2f16::atan2f16;
+        pub use self::atan2pi::atan2pif16;
+        pub use self::atanf16::atanf16;
+        pub use self::atanhf16::atanhf16;
+        pub use self::atanpi::atanpif16;
+        pub use self::canonicalize::{canonicalizef16, issignalingf16};
+        pub use self::cbrtf16::cbrtf16;
         pub use self::ceil::ceilf16;
         pub use self::copysign::copysignf16;
+        pub use self::cosf16::cosf16;
+        pub use self::coshf16::coshf16;
+        pub use self::cospi::cospif16;
+        pub use self::erff16::{erfcf16, erff16};
+        pub use self::exp10f16::exp10f16;
+        pub use self::exp2f16::exp2f16;
+        pub use self::expf16::expf16;
+        pub use self::expm1f16::expm1f16;
         pub use self::fabs::fabsf16;
+        pub use self::fadd::{f16addf32, f16addf32_round};
         pub use self::fdim::fdimf16;
+        pub use self::fdiv::{f16divf32, f16divf32_round};
+        pub use self::ffma::{f16fmaf32, f16fmaf32_round};
         pub use self::floor::floorf16;
+        pub use self::fma_wide::{fmaf16, fmaf16_round};
         pub use self::fmin_fmax::{fmaxf16, fminf16};
         pub use self::fminimum_fmaximum::{fmaximumf16, fminimumf16};
+        pub use self::fminimum_fmaximum_mag::{fmaximum_magf16, fminimum_magf16};
+        pub use self::fminimum_fmaximum_mag_num::{fmaximum_mag_numf16, fminimum_mag_numf16};
         pub use self::fminimum_fmaximum_num::{fmaximum_numf16, fminimum_numf16};
         pub use self::fmod::fmodf16;
+        pub use self::fmul::{f16mulf32, f16mulf32_round};
+        pub use self::frexp::frexpf16;
+        pub use self::fromfp::{fromfpf16, fromfpxf16, ufromfpf16, ufromfpxf16};
+        pub use self::fsqrt::{f16sqrtf32, f16sqrtf32_round};
+        pub use self::fsub::{f16subf32, f16subf32_round};
+        pub use self::hypotf16::hypotf16;
+        pub use self::ilogb::ilogbf16;
         pub use self::ldexp::ldexpf16;
-        pub use self::rint::rintf16;
+        pub use self::lgammaf16::lgammaf16;
+        pub use self::llogb::llogbf16;
+        pub use self::log10f16::log10f16;
+        pub use self::log1pf16::log1pf16;
+        pub use self::log2f16::log2f16;
+        pub use self::logb::logbf16;
+        pub use self::logf16::logf16;
+        pub use self::lrint::{llrintf16, lrintf16};
+        pub use self::lround::{llroundf16, lroundf16};
+        pub use self::modf::modff16;
+        pub use self::nan::nanf16;
+        pub use self::nearbyint::nearbyintf16;
+        pub use self::nextafter::nextafterf16;
+        pub use self::nextup::{nextdownf16, nextupf16};
+        pub use self::payload::{getpayloadf16, setpayloadf16, setpayloadsigf16};
+        pub use self::powf16::powf16;
+        pub use self::remainder::remainderf16;
+        pub use self::remquo::remquof16;
+        pub use self::rint::{rintf16, rintf16_round};
         pub use self::round::roundf16;
         pub use self::roundeven::roundevenf16;
+        pub use self::scalbln::scalblnf16;
         pub use self::scalbn::scalbnf16;
-        pub use self::sqrt::sqrtf16;
+        pub use self::sincosf16::sincosf16;
+        pub use self::sincospi::sincospif16;
+        pub use self::sinf16::sinf16;
+        pub use self::sinhf16::sinhf16;
+        pub use self::sinpi::sinpif16;
+        pub use self::sqrt::{sqrtf16, sqrtf16_round};
+        pub use self::tanf16::tanf16;
+        pub use self::tanhf16::tanhf16;
+        pub use self::tanpi::tanpif16;
+        pub use self::tgammaf16::tgammaf16;
+        pub use self::totalorder::{totalorderf16, totalordermagf16};
         pub use self::trunc::truncf16;
         // verify-sorted-end
-
-        #[allow(unused_imports)]
-        pub(crate) use self::fma_wide::fmaf16;
     }
 }
 
 cfg_if! {
     if #[cfg(f128_enabled)] {
         // verify-sorted-start
+        mod acosf128;
+        mod acoshf128;
+        mod asinf128;
+        mod asinhf128;
+        mod atan2f128;
+        mod atanf128;
+        mod atanhf128;
+        mod cbrtf128;
+        mod cosf128;
+        mod coshf128;
+        mod erff128;
+        mod exp2f128;
+        mod expf128;
+        mod expm1f128;
+        mod hypotf128;
+        mod j0f128;
+        mod j1f128;
+        mod jnf128;
+        mod k_atanf128;
+        mod k_besself128;
+        mod k_cosf128;
+        mod k_expf128;
+        mod k_lgammaf128;
+        mod k_logf128;
+        mod k_sinf128;
+        mod lgammaf128;
+        mod lgammaf128_r;
+        mod log10f128;
+        mod log1pf128;
+        mod log2f128;
+        mod logf128;
+        mod powf128;
+        mod rem_pio2f128;
+        mod sincosf128;
+        mod sinf128;
+        mod sinhf128;
+        mod tanf128;
+        mod tanhf128;
+        mod tgammaf128;
+        // verify-sorted-end
+
+        // verify-sorted-start
+        pub use self::acosf128::acosf128;
+        pub use self::acoshf128::acoshf128;
+        pub use self::acospi::acospif128;
+        pub use self::asinf128::asinf128;
+        pub use self::asinhf128::asinhf128;
+        pub use self::asinpi::asinpif128;
+        pub use self::atan2f128::atan2f128;
+        pub use self::atan2pi::atan2pif128;
+        pub use self::atanf128::atanf128;
+        pub use self::atanhf128::atanhf128;
+        pub use self::atanpi::atanpif128;
+        pub use self::canonicalize::{canonicalizef128, issignalingf128};
+        pub use self::cbrtf128::cbrtf128;
         pub use self::ceil::ceilf128;
         pub use self::copysign::copysignf128;
+        pub use self::cosf128::cosf128;
+        pub use self::coshf128::coshf128;
+        pub use self::cospi::cospif128;
+        pub use self::erff128::{erfcf128, erff128};
+        pub use self::exp2f128::exp2f128;
+        pub use self::expf128::expf128;
+        pub use self::expm1f128::expm1f128;
         pub use self::fabs::fabsf128;
+        pub use self::fadd::{f64addf128, f64addf128_round};
         pub use self::fdim::fdimf128;
+        pub use self::fdiv::{f64divf128, f64divf128_round};
+        pub use self::ffma::{f64fmaf128, f64fmaf128_round};
         pub use self::floor::floorf128;
-        pub use self::fma::fmaf128;
+        pub use self::fma::{fmaf128, fmaf128_round};
         pub use self::fmin_fmax::{fmaxf128, fminf128};
         pub use self::fminimum_fmaximum::{fmaximumf128, fminimumf128};
+        pub use self::fminimum_fmaximum_mag::{fmaximum_magf128, fminimum_magf128};
+        pub use self::fminimum_fmaximum_mag_num::{fmaximum_mag_numf128, fminimum_mag_numf128};
         pub use self::fminimum_fmaximum_num::{fmaximum_numf128, fminimum_numf128};
         pub use self::fmod::fmodf128;
+        pub use self::fmul::{f64mulf128, f64mulf128_round};
+        pub use self::frexp::frexpf128;
+        pub use self::fromfp::{fromfpf128, fromfpxf128, ufromfpf128, ufromfpxf128};
+        pub use self::fsqrt::{f64sqrtf128, f64sqrtf128_round};
+        pub use self::fsub::{f64subf128, f64subf128_round};
+        pub use self::hypotf128::hypotf128;
+        pub use self::ilogb::ilogbf128;
+        pub use self::j0f128::{j0f128, y0f128};
+        pub use self::j1f128::{j1f128, y1f128};
+        pub use self::jnf128::{jnf128, ynf128};
         pub use self::ldexp::ldexpf128;
-        pub use self::rint::rintf128;
+        pub use self::lgammaf128::lgammaf128;
+        pub use self::lgammaf128_r::lgammaf128_r;
+        pub use self::llogb::llogbf128;
+        pub use self::log10f128::log10f128;
+        pub use self::log1pf128::log1pf128;
+        pub use self::log2f128::log2f128;
+        pub use self::logb::logbf128;
+        pub use self::logf128::logf128;
+        pub use self::lrint::{llrintf128, lrintf128};
+        pub use self::lround::{llroundf128, lroundf128};
+        pub use self::modf::modff128;
+        pub use self::nan::nanf128;
+        pub use self::nearbyint::nearbyintf128;
+        pub use self::nextafter::nextafterf128;
+        pub use self::nextup::{nextdownf128, nextupf128};
+        pub use self::payload::{getpayloadf128, setpayloadf128, setpayloadsigf128};
+        pub use self::powf128::powf128;
+        pub use self::remainder::remainderf128;
+        pub use self::remquo::remquof128;
+        pub use self::rint::{rintf128, rintf128_round};
         pub use self::round::roundf128;
         pub use self::roundeven::roundevenf128;
+        pub use self::scalbln::scalblnf128;
         pub use self::scalbn::scalbnf128;
-        pub use self::sqrt::sqrtf128;
+        pub use self::sincosf128::sincosf128;
+        pub use self::sincospi::sincospif128;
+        pub use self::sinf128::sinf128;
+        pub use self::sinhf128::sinhf128;
+        pub use self::sinpi::sinpif128;
+        pub use self::sqrt::{sqrtf128, sqrtf128_round};
+        pub use self::tanf128::tanf128;
+        pub use self::tanhf128::tanhf128;
+        pub use self::tanpi::tanpif128;
+        pub use self::tgammaf128::tgammaf128;
+        pub use self::totalorder::{totalorderf128, totalordermagf128};
         pub use self::trunc::truncf128;
         // verify-sorted-end
+
+        use self::k_atanf128::k_atan2f128;
+        use self::k_besself128::{
+            k_hankelf128, k_j01f128, k_jn_millerf128, k_jn_seriesf128, k_y01f128,
+        };
+        use self::k_cosf128::k_cosf128;
+        use self::k_expf128::{k_expf128, k_expm1f128};
+        use self::k_lgammaf128::{k_lgammaf128, k_sinpif128};
+        use self::k_logf128::{k_log1pf128, k_logf128};
+        use self::k_sinf128::k_sinf128;
+        use self::rem_pio2f128::rem_pio2f128;
     }
 }
 
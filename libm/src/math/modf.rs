@@ -1,35 +1,33 @@
+/// Split `x` into its fractional and integral parts (f16).
+///
+/// Both parts have the same sign as `x`, and are returned as `(fractional, integral)`.
+#[cfg(f16_enabled)]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
-pub fn modf(x: f64) -> (f64, f64) {
-    let rv2: f64;
-    let mut u = x.to_bits();
-    let mask: u64;
-    let e = (((u >> 52) & 0x7ff) as i32) - 0x3ff;
+pub fn modff16(x: f16) -> (f16, f16) {
+    super::generic::modf(x)
+}
 
-    /* no fractional part */
-    if e >= 52 {
-        rv2 = x;
-        if e == 0x400 && (u << 12) != 0 {
-            /* nan */
-            return (x, rv2);
-        }
-        u &= 1 << 63;
-        return (f64::from_bits(u), rv2);
-    }
+/// Split `x` into its fractional and integral parts (f32).
+///
+/// Both parts have the same sign as `x`, and are returned as `(fractional, integral)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn modff(x: f32) -> (f32, f32) {
+    super::generic::modf(x)
+}
 
-    /* no integral part*/
-    if e < 0 {
-        u &= 1 << 63;
-        rv2 = f64::from_bits(u);
-        return (x, rv2);
-    }
+/// Split `x` into its fractional and integral parts (f64).
+///
+/// Both parts have the same sign as `x`, and are returned as `(fractional, integral)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn modf(x: f64) -> (f64, f64) {
+    super::generic::modf(x)
+}
 
-    mask = ((!0) >> 12) >> e;
-    if (u & mask) == 0 {
-        rv2 = x;
-        u &= 1 << 63;
-        return (f64::from_bits(u), rv2);
-    }
-    u &= !mask;
-    rv2 = f64::from_bits(u);
-    return (x - rv2, rv2);
+/// Split `x` into its fractional and integral parts (f128).
+///
+/// Both parts have the same sign as `x`, and are returned as `(fractional, integral)`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn modff128(x: f128) -> (f128, f128) {
+    super::generic::modf(x)
 }
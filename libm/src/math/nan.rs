@@ -0,0 +1,41 @@
+/// Return a quiet NaN with a payload given by `tagp` (f16).
+///
+/// The payload is read as a C integer constant: decimal, octal with a leading `0` or hexadecimal
+/// with a leading `0x`. Only the bits that fit in the payload are kept, and if `tagp` is not a
+/// valid integer then the default NaN is returned.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nanf16(tagp: &str) -> f16 {
+    super::generic::nan(tagp)
+}
+
+/// Return a quiet NaN with a payload given by `tagp` (f32).
+///
+/// The payload is read as a C integer constant: decimal, octal with a leading `0` or hexadecimal
+/// with a leading `0x`. Only the bits that fit in the payload are kept, and if `tagp` is not a
+/// valid integer then the default NaN is returned.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nanf(tagp: &str) -> f32 {
+    super::generic::nan(tagp)
+}
+
+/// Return a quiet NaN with a payload given by `tagp` (f64).
+///
+/// The payload is read as a C integer constant: decimal, octal with a leading `0` or hexadecimal
+/// with a leading `0x`. Only the bits that fit in the payload are kept, and if `tagp` is not a
+/// valid integer then the default NaN is returned.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nan(tagp: &str) -> f64 {
+    super::generic::nan(tagp)
+}
+
+/// Return a quiet NaN with a payload given by `tagp` (f128).
+///
+/// The payload is read as a C integer constant: decimal, octal with a leading `0` or hexadecimal
+/// with a leading `0x`. Only the bits that fit in the payload are kept, and if `tagp` is not a
+/// valid integer then the default NaN is returned.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nanf128(tagp: &str) -> f128 {
+    super::generic::nan(tagp)
+}
@@ -0,0 +1,39 @@
+use super::support::Round;
+
+/// Round `x` to the nearest integer, breaking ties toward even, without raising inexact.
+///
+/// Since the floating point environment is not accessible from Rust, this is the same as
+/// [`rint`](crate::rint).
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyintf16(x: f16) -> f16 {
+    super::generic::rint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, without raising inexact.
+///
+/// Since the floating point environment is not accessible from Rust, this is the same as
+/// [`rint`](crate::rint).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyintf(x: f32) -> f32 {
+    super::generic::rint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, without raising inexact.
+///
+/// Since the floating point environment is not accessible from Rust, this is the same as
+/// [`rint`](crate::rint).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyint(x: f64) -> f64 {
+    super::generic::rint_round(x, Round::Nearest).val
+}
+
+/// Round `x` to the nearest integer, breaking ties toward even, without raising inexact.
+///
+/// Since the floating point environment is not accessible from Rust, this is the same as
+/// [`rint`](crate::rint).
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyintf128(x: f128) -> f128 {
+    super::generic::rint_round(x, Round::Nearest).val
+}
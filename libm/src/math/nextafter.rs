@@ -1,37 +1,25 @@
+/// Return the next representable value after `x` in the direction of `y` (f16).
+#[cfg(f16_enabled)]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
-pub fn nextafter(x: f64, y: f64) -> f64 {
-    if x.is_nan() || y.is_nan() {
-        return x + y;
-    }
+pub fn nextafterf16(x: f16, y: f16) -> f16 {
+    super::generic::nextafter(x, y)
+}
 
-    let mut ux_i = x.to_bits();
-    let uy_i = y.to_bits();
-    if ux_i == uy_i {
-        return y;
-    }
+/// Return the next representable value after `x` in the direction of `y` (f32).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextafterf(x: f32, y: f32) -> f32 {
+    super::generic::nextafter(x, y)
+}
 
-    let ax = ux_i & (!1_u64 / 2);
-    let ay = uy_i & (!1_u64 / 2);
-    if ax == 0 {
-        if ay == 0 {
-            return y;
-        }
-        ux_i = (uy_i & (1_u64 << 63)) | 1;
-    } else if ax > ay || ((ux_i ^ uy_i) & (1_u64 << 63)) != 0 {
-        ux_i -= 1;
-    } else {
-        ux_i += 1;
-    }
+/// Return the next representable value after `x` in the direction of `y` (f64).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextafter(x: f64, y: f64) -> f64 {
+    super::generic::nextafter(x, y)
+}
 
-    let e = (ux_i >> 52) & 0x7ff;
-    // raise overflow if ux.f is infinite and x is finite
-    if e == 0x7ff {
-        force_eval!(x + x);
-    }
-    let ux_f = f64::from_bits(ux_i);
-    // raise underflow if ux.f is subnormal or zero
-    if e == 0 {
-        force_eval!(x * x + ux_f * ux_f);
-    }
-    ux_f
+/// Return the next representable value after `x` in the direction of `y` (f128).
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextafterf128(x: f128, y: f128) -> f128 {
+    super::generic::nextafter(x, y)
 }
@@ -0,0 +1,17 @@
+/// Return the next representable value after `x` in the direction of `y` (f32).
+///
+/// C takes `y` as a `long double`, which Rust does not have, so `y` is an `f64`. This means
+/// it can specify directions that are between two `f32` values.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nexttowardf(x: f32, y: f64) -> f32 {
+    super::generic::nexttoward(x, y)
+}
+
+/// Return the next representable value after `x` in the direction of `y` (f64).
+///
+/// C takes `y` as a `long double`, which Rust does not have, so `y` is an `f64`. This means
+/// `nexttoward` is the same as `nextafter`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nexttoward(x: f64, y: f64) -> f64 {
+    super::generic::nexttoward(x, y)
+}
@@ -0,0 +1,67 @@
+/// Return the least value greater than `x` (f16).
+///
+/// This is `nextafter(x, INFINITY)`, except that no overflow or underflow is raised.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextupf16(x: f16) -> f16 {
+    super::generic::nextup(x)
+}
+
+/// Return the least value greater than `x` (f32).
+///
+/// This is `nextafter(x, INFINITY)`, except that no overflow or underflow is raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextupf(x: f32) -> f32 {
+    super::generic::nextup(x)
+}
+
+/// Return the least value greater than `x` (f64).
+///
+/// This is `nextafter(x, INFINITY)`, except that no overflow or underflow is raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextup(x: f64) -> f64 {
+    super::generic::nextup(x)
+}
+
+/// Return the least value greater than `x` (f128).
+///
+/// This is `nextafter(x, INFINITY)`, except that no overflow or underflow is raised.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextupf128(x: f128) -> f128 {
+    super::generic::nextup(x)
+}
+
+/// Return the greatest value less than `x` (f16).
+///
+/// This is `nextafter(x, NEG_INFINITY)`, except that no overflow or underflow is raised.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdownf16(x: f16) -> f16 {
+    super::generic::nextdown(x)
+}
+
+/// Return the greatest value less than `x` (f32).
+///
+/// This is `nextafter(x, NEG_INFINITY)`, except that no overflow or underflow is raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdownf(x: f32) -> f32 {
+    super::generic::nextdown(x)
+}
+
+/// Return the greatest value less than `x` (f64).
+///
+/// This is `nextafter(x, NEG_INFINITY)`, except that no overflow or underflow is raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdown(x: f64) -> f64 {
+    super::generic::nextdown(x)
+}
+
+/// Return the greatest value less than `x` (f128).
+///
+/// This is `nextafter(x, NEG_INFINITY)`, except that no overflow or underflow is raised.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdownf128(x: f128) -> f128 {
+    super::generic::nextdown(x)
+}
@@ -0,0 +1,101 @@
+/// Return the payload of the NaN `x` as an integer value, or -1.0 if `x` is not a NaN.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn getpayloadf16(x: f16) -> f16 {
+    super::generic::getpayload(x)
+}
+
+/// Return the payload of the NaN `x` as an integer value, or -1.0 if `x` is not a NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn getpayloadf(x: f32) -> f32 {
+    super::generic::getpayload(x)
+}
+
+/// Return the payload of the NaN `x` as an integer value, or -1.0 if `x` is not a NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn getpayload(x: f64) -> f64 {
+    super::generic::getpayload(x)
+}
+
+/// Return the payload of the NaN `x` as an integer value, or -1.0 if `x` is not a NaN.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn getpayloadf128(x: f128) -> f128 {
+    super::generic::getpayload(x)
+}
+
+/// Return a quiet NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a nonnegative integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadf16(pl: f16) -> f16 {
+    super::generic::setpayload(pl)
+}
+
+/// Return a quiet NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a nonnegative integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadf(pl: f32) -> f32 {
+    super::generic::setpayload(pl)
+}
+
+/// Return a quiet NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a nonnegative integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayload(pl: f64) -> f64 {
+    super::generic::setpayload(pl)
+}
+
+/// Return a quiet NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a nonnegative integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadf128(pl: f128) -> f128 {
+    super::generic::setpayload(pl)
+}
+
+/// Return a signaling NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a positive integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsigf16(pl: f16) -> f16 {
+    super::generic::setpayloadsig(pl)
+}
+
+/// Return a signaling NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a positive integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsigf(pl: f32) -> f32 {
+    super::generic::setpayloadsig(pl)
+}
+
+/// Return a signaling NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a positive integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsig(pl: f64) -> f64 {
+    super::generic::setpayloadsig(pl)
+}
+
+/// Return a signaling NaN with the payload `pl`.
+///
+/// If `pl` is not a valid payload, i.e. a positive integer that fits in the significand
+/// excluding the quiet bit, +0.0 is returned.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsigf128(pl: f128) -> f128 {
+    super::generic::setpayloadsig(pl)
+}
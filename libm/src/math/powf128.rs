@@ -0,0 +1,85 @@
+use super::support::WideFloat;
+use super::{k_expf128, k_logf128};
+
+/// Returns `x` to the power of `y` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powf128(x: f128, y: f128) -> f128 {
+    /* x**0 = 1, even if x is NaN; 1**y = 1, even if y is NaN */
+    if y == 0.0 || x == 1.0 {
+        return 1.0;
+    }
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    let yint = checkint(y);
+
+    if y.is_infinite() {
+        if x == -1.0 {
+            return 1.0; /* (-1)**+-inf is 1 */
+        }
+        /* (|x|>1)**+inf = inf, (|x|<1)**-inf = inf, zero otherwise */
+        return if (x.abs() > 1.0) == (y > 0.0) { f128::INFINITY } else { 0.0 };
+    }
+
+    if x.is_infinite() || x == 0.0 {
+        /* the result is zero or infinite, negative only for a negative x and an odd integer y */
+        let r = if (x == 0.0) == (y < 0.0) { f128::INFINITY } else { 0.0 };
+        return if x.is_sign_negative() && yint == 1 { -r } else { r };
+    }
+
+    if x < 0.0 && yint == 0 {
+        return (x - x) / (x - x); /* (x<0)**(non-int) is NaN */
+    }
+
+    let ax = WideFloat::from_f128(x).abs();
+    let r = if yint != 0 && y.abs() <= 64.0 {
+        // Small integer powers by repeated squaring. This is exact whenever the result fits in
+        // the working precision, which covers the cases where `x**y` lies halfway between two
+        // representable values.
+        let n = y as i32;
+        let mut base = ax;
+        let mut r = WideFloat::ONE;
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                r = r * base;
+            }
+            base = base * base;
+            k >>= 1;
+        }
+        if n < 0 { WideFloat::ONE / r } else { r }.to_f128()
+    } else {
+        let (e, t) = k_logf128(ax);
+        let z = (WideFloat::from_i32(e) * WideFloat::LN2 + t) * WideFloat::from_f128(y);
+
+        /* e^z overflows above log(MAX) ~= 11356.5 and underflows below ~= -11433.5 */
+        if z > WideFloat::from_i32(11357) {
+            f128::INFINITY
+        } else if z < WideFloat::from_i32(-11434) {
+            0.0
+        } else {
+            k_expf128(z).to_f128()
+        }
+    };
+
+    if x < 0.0 && yint == 1 { -r } else { r }
+}
+
+/// Returns 0 if `y` is not an integer, 1 if it is an odd integer and 2 if it is an even integer.
+fn checkint(y: f128) -> i32 {
+    let bits = y.to_bits();
+    let e = ((bits >> 112) & 0x7fff) as i32 - 0x3fff;
+    if e < 0 {
+        return 0;
+    }
+    if e > 112 {
+        return 2;
+    }
+
+    let frac_bits = 112 - e;
+    if bits & ((1 << frac_bits) - 1) != 0 {
+        return 0;
+    }
+    if (bits >> frac_bits) & 1 == 1 { 1 } else { 2 }
+}
@@ -0,0 +1,5 @@
+/// Returns `x` to the power of `y` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powf16(x: f16, y: f16) -> f16 {
+    super::pow(x as f64, y as f64) as f16
+}
@@ -0,0 +1,139 @@
+use super::{expm1, fabs, fma, frexp, log2, round, scalbn};
+
+const HUGE: f64 = 1.0e300;
+const TINY: f64 = 1.0e-300;
+const IVLN2: f64 = core::f64::consts::LOG2_E;
+const LN2_HI: f64 = 6.93147180369123816490e-01; /* 0x3fe62e42, 0xfee00000 */
+const LN2_LO: f64 = 1.90821492927058770002e-10; /* 0x3dea39ef, 0x35793c76 */
+
+/// Multiply two double-doubles scaled by powers of two, `(ah + al) * 2^ae` and
+/// `(bh + bl) * 2^be`. The result is renormalized so that its high part is in `[0.5, 1)`.
+fn mul_dd(a: (f64, f64, i64), b: (f64, f64, i64)) -> (f64, f64, i64) {
+    let (ah, al, ae) = a;
+    let (bh, bl, be) = b;
+
+    let p = ah * bh;
+    let pl = fma(ah, bh, -p) + (ah * bl + al * bh);
+    let s = p + pl;
+    let sl = pl - (s - p);
+
+    /* s is in [0.25, 1), so scaling it and its tail is exact */
+    let (m, k) = frexp(s);
+    (m, scalbn(sl, -k), ae + be + k as i64)
+}
+
+/// Raise the double-double `hi + lo` to the power of `n`.
+///
+/// Returns `(h, l, e)` such that `(hi + lo)^n` is approximately `(h + l) * 2^e`, with a relative
+/// error of a few parts in 2^100. `hi` must be finite, positive and larger than `lo` in
+/// magnitude. The caller is responsible for making sure the exponent of the result is
+/// reasonably bounded.
+pub(crate) fn pow_dd(hi: f64, lo: f64, n: i64) -> (f64, f64, i64) {
+    let (bh, be) = frexp(hi);
+    let mut b = (bh, scalbn(lo, -be), be as i64);
+    let mut r = (1.0, 0.0, 0);
+    let mut k = n.unsigned_abs();
+
+    /* binary exponentiation, r = b^|n| */
+    loop {
+        if k & 1 != 0 {
+            r = mul_dd(r, b);
+        }
+        k >>= 1;
+        if k == 0 {
+            break;
+        }
+        b = mul_dd(b, b);
+    }
+
+    if n < 0 {
+        /* 1/(h + l) = q + q*(1 - q*h - q*l) */
+        let (h, l, e) = r;
+        let q = 1.0 / h;
+        let ql = (fma(-h, q, 1.0) - l * q) * q;
+        let (m, k) = frexp(q);
+        r = (m, scalbn(ql, -k), k as i64 - e);
+    }
+
+    r
+}
+
+/// `(1 + t)^n` for `|n| > 2^40` and `|t| < 2^-29`.
+///
+/// Rounding errors in `pow_dd` grow with `n`, so this uses `exp(n * log1p(t))` instead, with
+/// the exponent computed in extra precision.
+pub(crate) fn pow1p_large(t: f64, n: i64) -> f64 {
+    /* log1p(t) = t - t^2/2 + t^3/3 - ..., the next term is below 2^-90 relative to t */
+    let t2 = t * t;
+    let t2l = fma(t, t, -t2);
+    let lh = t - 0.5 * t2;
+    let ll = ((t - lh) - 0.5 * t2) - 0.5 * t2l + t2 * t * (1.0 / 3.0);
+
+    /* n = nh + nl exactly */
+    let nh = n as f64;
+    let nl = (n as i128 - nh as i128) as f64;
+
+    let yh = nh * lh;
+    let yl = fma(nh, lh, -yh) + (nh * ll + nl * lh);
+
+    /* y = k*ln2 + r, with r = rh + rl and |r| <= ln2/2 */
+    let k = round(yh * IVLN2);
+    let hi = yh - k * LN2_HI; /* k*ln2_hi is exact here */
+    let lo = k * LN2_LO;
+    let rh = hi - lo;
+    let rl = ((hi - rh) - lo) + yl;
+
+    /* exp(r) = 1 + expm1(rh) + rl*exp(rh), keeping the low bits of the sum */
+    let e = expm1(rh);
+    let s = 1.0 + e;
+    let sl = ((1.0 - s) + e) + rl * (1.0 + e);
+    scalbn(s + sl, k as i32)
+}
+
+/// `x` raised to the integer power `n` (f32).
+///
+/// `pownf(x, 0)` is 1 for any `x`, including NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pownf(x: f32, n: i64) -> f32 {
+    pown(x as f64, n) as f32
+}
+
+/// `x` raised to the integer power `n` (f64).
+///
+/// `pown(x, 0)` is 1 for any `x`, including NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pown(x: f64, n: i64) -> f64 {
+    if n == 0 {
+        return 1.0;
+    }
+    if x.is_nan() {
+        return x + x;
+    }
+
+    /* the result is negative only for negative x (including -0) and odd n */
+    let s = if x.is_sign_negative() && n & 1 != 0 { -1.0 } else { 1.0 };
+    let ax = fabs(x);
+
+    if ax == 0.0 || ax.is_infinite() {
+        /* pown(±0, n) and pown(±inf, -n) are zero for n > 0, infinite for n < 0 */
+        let r = if (ax == 0.0) == (n > 0) { 0.0 } else { f64::INFINITY };
+        return s * r;
+    }
+
+    /* estimate log2 of the result to catch overflow and underflow early */
+    let lg = log2(ax) * n as f64;
+    if lg > 1100.0 {
+        return s * HUGE * HUGE;
+    }
+    if lg < -1200.0 {
+        return s * TINY * TINY;
+    }
+
+    if n.unsigned_abs() > 1 << 40 {
+        /* |x| is close to 1, so x - 1 is exact */
+        return s * pow1p_large(ax - 1.0, n);
+    }
+
+    let (h, l, e) = pow_dd(ax, 0.0, n);
+    s * scalbn(h + l, e as i32)
+}
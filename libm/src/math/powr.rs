@@ -0,0 +1,39 @@
+use super::{pow, powf};
+
+/// `x` raised to the power of `y`, computed as `exp(y * log(x))` (f32).
+///
+/// Unlike `powf`, this is only defined for `x >= 0`: negative `x` returns NaN, as do
+/// `powrf(0, 0)`, `powrf(inf, 0)` and `powrf(1, ±inf)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powrf(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    /* powr(-0, y) follows powr(+0, y) */
+    let x = if x == 0.0 { 0.0 } else { x };
+    if x < 0.0 || (y == 0.0 && (x == 0.0 || x.is_infinite())) || (x == 1.0 && y.is_infinite()) {
+        return (x - x) / 0.0;
+    }
+
+    powf(x, y)
+}
+
+/// `x` raised to the power of `y`, computed as `exp(y * log(x))` (f64).
+///
+/// Unlike `pow`, this is only defined for `x >= 0`: negative `x` returns NaN, as do
+/// `powr(0, 0)`, `powr(inf, 0)` and `powr(1, ±inf)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powr(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    /* powr(-0, y) follows powr(+0, y) */
+    let x = if x == 0.0 { 0.0 } else { x };
+    if x < 0.0 || (y == 0.0 && (x == 0.0 || x.is_infinite())) || (x == 1.0 && y.is_infinite()) {
+        return (x - x) / 0.0;
+    }
+
+    pow(x, y)
+}
@@ -26,7 +26,9 @@ const INIT_JK: [usize; 4] = [3, 4, 4, 6];
 //
 // NB: This table must have at least (e0-3)/24 + jk terms.
 //     For quad precision (e0 <= 16360, jk = 6), this is 686.
-#[cfg(any(target_pointer_width = "32", target_pointer_width = "16"))]
+//     `rem_pio2f128` reads a 512-bit window past the largest
+//     `f128` exponent, which needs 700.
+#[cfg(all(any(target_pointer_width = "32", target_pointer_width = "16"), not(f128_enabled)))]
 const IPIO2: [i32; 66] = [
     0xA2F983, 0x6E4E44, 0x1529FC, 0x2757D1, 0xF534DD, 0xC0DB62, 0x95993C, 0x439041, 0xFE5163,
     0xABDEBB, 0xC561B7, 0x246E3A, 0x424DD2, 0xE00649, 0x2EEA09, 0xD1921C, 0xFE1DEB, 0x1CB129,
@@ -38,8 +40,8 @@ const IPIO2: [i32; 66] = [
     0x73A8C9, 0x60E27B, 0xC08C6B,
 ];
 
-#[cfg(target_pointer_width = "64")]
-const IPIO2: [i32; 690] = [
+#[cfg(any(target_pointer_width = "64", f128_enabled))]
+pub(crate) const IPIO2: [i32; 704] = [
     0xA2F983, 0x6E4E44, 0x1529FC, 0x2757D1, 0xF534DD, 0xC0DB62, 0x95993C, 0x439041, 0xFE5163,
     0xABDEBB, 0xC561B7, 0x246E3A, 0x424DD2, 0xE00649, 0x2EEA09, 0xD1921C, 0xFE1DEB, 0x1CB129,
     0xA73EE8, 0x8235F5, 0x2EBB44, 0x84E99C, 0x7026B4, 0x5F7E41, 0x3991D6, 0x398353, 0x39F49C,
@@ -116,7 +118,9 @@ const IPIO2: [i32; 690] = [
     0x63DD27, 0xDDE169, 0x19949A, 0x9529A8, 0x28CE68, 0xB4ED09, 0x209F44, 0xCA984E, 0x638270,
     0x237C7E, 0x32B90F, 0x8EF5A7, 0xE75614, 0x08F121, 0x2A9DB5, 0x4D7E6F, 0x5119A5, 0xABF9B5,
     0xD6DF82, 0x61DD96, 0x023616, 0x9F3AC4, 0xA1A283, 0x6DED72, 0x7A8D39, 0xA9B882, 0x5C326B,
-    0x5B2746, 0xED3400, 0x7700D2, 0x55F4FC, 0x4D5901, 0x8071E0,
+    0x5B2746, 0xED3400, 0x7700D2, 0x55F4FC, 0x4D5901, 0x8071E0, 0xE13F89, 0xB295F3, 0x64A8F1,
+    0xAEA74B, 0x38FC4C, 0xEAB2BB, 0x47270B, 0xABC3A7, 0x34BA60, 0x52DD34, 0xF8563A, 0xEB7E8A,
+    0x31BB36, 0x5895B7,
 ];
 
 const PIO2: [f64; 8] = [
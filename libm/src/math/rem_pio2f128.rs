@@ -0,0 +1,162 @@
+use super::rem_pio2_large::IPIO2;
+use super::support::{Float, WideFloat, u256};
+
+/// Number of 64-bit words of 2/π multiplied with the significand of `x`.
+const WINDOW_WORDS: usize = 8;
+
+/// Reduce `x` by multiples of π/2, returning `(n, r)` with `x = n * π/2 + r` and `|r| <= π/4`.
+///
+/// Only the low two bits of `n` are meaningful. This uses Payne–Hanek reduction so that every
+/// finite `f128` reduces correctly, including arguments up to `2^16383` and those close to
+/// multiples of π/2.
+pub(crate) fn rem_pio2f128(x: f128) -> (i32, WideFloat) {
+    debug_assert!(x.is_finite());
+
+    if x.abs() <= hf128!("0x1.921fb54442d18469898cc51701b8p-1") {
+        return (0, WideFloat::from_f128(x));
+    }
+
+    // |x| = m * 2^e with `m` an integer. `x` is normal at this point.
+    let m = (x.to_bits() & f128::SIG_MASK) | (1 << f128::SIG_BITS);
+    let e = x.ex() as i32 - f128::EXP_BIAS as i32 - f128::SIG_BITS as i32;
+
+    // Bits of 2/π with weight above `2^(1 - e)` only contribute multiples of 4 to `x * 2/π`, so
+    // start the window at the bit with weight `2^(1 - e)` (1-based bit index `e - 1`). Bits
+    // past the window contribute less than `m * 2^(2 - 64 * WINDOW_WORDS)`, which is far below
+    // the closest any `f128` gets to a multiple of π/2.
+    let start = (e - 2).max(0) as usize;
+    let mut c = [0u64; WINDOW_WORDS];
+    for (i, ci) in c.iter_mut().rev().enumerate() {
+        *ci = two_over_pi_bits(start + 64 * i);
+    }
+
+    // p = m * c, with limbs in little-endian order
+    let mut p = [0u64; WINDOW_WORDS + 2];
+    for (j, mj) in [m as u64, (m >> 64) as u64].into_iter().enumerate() {
+        let mut carry = 0u128;
+        for (i, ci) in c.into_iter().enumerate() {
+            let t = u128::from(ci) * u128::from(mj) + u128::from(p[i + j]) + carry;
+            p[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        p[WINDOW_WORDS + j] = carry as u64;
+    }
+
+    // `p` is `x * 2/π` scaled by `2^frac_bits`. The two bits above the fraction are the quadrant.
+    let frac_bits = (start as i32 + 64 * WINDOW_WORDS as i32 - e) as usize;
+    let mut n = (bits_at(&p, frac_bits).lo & 3) as i32;
+    mask_low(&mut p, frac_bits);
+
+    // Round to the nearest quadrant, leaving a fraction in [-1/2, 1/2]
+    let mut neg = false;
+    if bits_at(&p, frac_bits - 1).lo & 1 != 0 {
+        n += 1;
+        neg = true;
+        negate(&mut p);
+        mask_low(&mut p, frac_bits);
+    }
+
+    let r = match p.iter().rposition(|&w| w != 0) {
+        Some(top) => {
+            let top_bit = top * 64 + 63 - p[top].leading_zeros() as usize;
+            let lo_bit = top_bit.saturating_sub(255);
+            let f =
+                WideFloat::from_u256(neg, bits_at(&p, lo_bit), lo_bit as i32 - frac_bits as i32);
+            f * WideFloat::FRAC_PI_2
+        }
+        None => WideFloat::ZERO,
+    };
+
+    if x.is_sign_negative() { (-n, -r) } else { (n, r) }
+}
+
+/// 64 fractional bits of 2/π, starting at the bit with weight `2^-(bit + 1)`.
+fn two_over_pi_bits(bit: usize) -> u64 {
+    // `IPIO2` holds 24 bits per entry, so four entries cover any 64-bit window.
+    let i = bit / 24;
+    let mut acc = 0u128;
+    for k in 0..4 {
+        acc = (acc << 24) | IPIO2[i + k] as u128;
+    }
+    (acc >> (32 - bit % 24)) as u64
+}
+
+/// The 256 bits of `p` starting at bit `lo_bit`.
+fn bits_at(p: &[u64], lo_bit: usize) -> u256 {
+    let mut words = [0u64; 4];
+    for (k, w) in words.iter_mut().enumerate() {
+        let bit = lo_bit + 64 * k;
+        let (i, shift) = (bit / 64, bit % 64);
+        let lo = p.get(i).copied().unwrap_or(0);
+        let hi = p.get(i + 1).copied().unwrap_or(0);
+        *w = lo >> shift;
+        if shift != 0 {
+            *w |= hi << (64 - shift);
+        }
+    }
+
+    u256 {
+        lo: u128::from(words[0]) | (u128::from(words[1]) << 64),
+        hi: u128::from(words[2]) | (u128::from(words[3]) << 64),
+    }
+}
+
+/// Clear all bits of `p` at position `bits` and above.
+fn mask_low(p: &mut [u64], bits: usize) {
+    for (i, w) in p.iter_mut().enumerate() {
+        if i * 64 >= bits {
+            *w = 0;
+        } else if (i + 1) * 64 > bits {
+            *w &= (1 << (bits - i * 64)) - 1;
+        }
+    }
+}
+
+/// Two's complement negation of a little-endian integer.
+fn negate(p: &mut [u64]) {
+    let mut carry = true;
+    for w in p.iter_mut() {
+        let (v, c) = (!*w).overflowing_add(carry as u64);
+        *w = v;
+        carry = c;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rem_pio2f128;
+
+    #[test]
+    fn reduce() {
+        let check = [
+            (
+                hf128!("0x1.921fb54442d18469898cc51701b8p+0"),
+                1,
+                hf128!("-0x1.cd129024e088a67cc74020bbea64p-115"),
+            ),
+            (
+                hf128!("0x1.921fb54442d18469898cc51701b8p+1"),
+                2,
+                hf128!("-0x1.cd129024e088a67cc74020bbea64p-114"),
+            ),
+            (
+                hf128!("-0x1.2d97c7f3321d234f272993d1414ap+2"),
+                1,
+                hf128!("0x1.59cdec1ba8667cdd9570188cefcbp-113"),
+            ),
+            (hf128!("0x1p+100"), 3, hf128!("0x1.05b529eee170c6fc5f517c7eb9d3p-1")),
+            (
+                hf128!("0x1.a3750647fcab18c21ab905450cc3p+13287"),
+                2,
+                hf128!("-0x1.75c26cd6d55bf37e0b0935ebfe96p-2"),
+            ),
+            (f128::MAX, 1, hf128!("0x1.3ed7af4d4347cc5dddbed0cb999fp-2")),
+            (0.5, 0, 0.5),
+        ];
+
+        for (x, n, r) in check {
+            let (rn, rr) = rem_pio2f128(x);
+            assert_eq!((rn & 3, rr.to_f128()), (n, r), "{x:?}");
+        }
+    }
+}
@@ -1,5 +1,29 @@
+/// Compute the IEEE remainder of `x / y`, which is `x - n * y` with `n` the integer nearest
+/// to `x / y` (f16).
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn remainderf16(x: f16, y: f16) -> f16 {
+    super::generic::remquo(x, y).0
+}
+
+/// Compute the IEEE remainder of `x / y`, which is `x - n * y` with `n` the integer nearest
+/// to `x / y` (f32).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn remainderf(x: f32, y: f32) -> f32 {
+    super::generic::remquo(x, y).0
+}
+
+/// Compute the IEEE remainder of `x / y`, which is `x - n * y` with `n` the integer nearest
+/// to `x / y` (f64).
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn remainder(x: f64, y: f64) -> f64 {
-    let (result, _) = super::remquo(x, y);
-    result
+    super::generic::remquo(x, y).0
+}
+
+/// Compute the IEEE remainder of `x / y`, which is `x - n * y` with `n` the integer nearest
+/// to `x / y` (f128).
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn remainderf128(x: f128, y: f128) -> f128 {
+    super::generic::remquo(x, y).0
 }
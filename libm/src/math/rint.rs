@@ -1,4 +1,4 @@
-use super::support::Round;
+use super::support::{FpResult, Round};
 
 /// Round `x` to the nearest integer, breaking ties toward even.
 #[cfg(f16_enabled)]
@@ -13,6 +13,13 @@ pub fn rintf16(x: f16) -> f16 {
     super::generic::rint_round(x, Round::Nearest).val
 }
 
+/// Round `x` to an integer in the direction given by `round` (f16).
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf16_round(x: f16, round: Round) -> FpResult<f16> {
+    super::generic::rint_round(x, round)
+}
+
 /// Round `x` to the nearest integer, breaking ties toward even.
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn rintf(x: f32) -> f32 {
@@ -28,6 +35,12 @@ pub fn rintf(x: f32) -> f32 {
     super::generic::rint_round(x, Round::Nearest).val
 }
 
+/// Round `x` to an integer in the direction given by `round` (f32).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf_round(x: f32, round: Round) -> FpResult<f32> {
+    super::generic::rint_round(x, round)
+}
+
 /// Round `x` to the nearest integer, breaking ties toward even.
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn rint(x: f64) -> f64 {
@@ -43,9 +56,22 @@ pub fn rint(x: f64) -> f64 {
     super::generic::rint_round(x, Round::Nearest).val
 }
 
+/// Round `x` to an integer in the direction given by `round` (f64).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rint_round(x: f64, round: Round) -> FpResult<f64> {
+    super::generic::rint_round(x, round)
+}
+
 /// Round `x` to the nearest integer, breaking ties toward even.
 #[cfg(f128_enabled)]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn rintf128(x: f128) -> f128 {
     super::generic::rint_round(x, Round::Nearest).val
 }
+
+/// Round `x` to an integer in the direction given by `round` (f128).
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf128_round(x: f128, round: Round) -> FpResult<f128> {
+    super::generic::rint_round(x, round)
+}
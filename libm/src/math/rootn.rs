@@ -0,0 +1,55 @@
+use super::pown::pow_dd;
+use super::{exp, fabs, fma, log, pow, rsqrt, scalbn, sqrt};
+
+/// The `n`th root of `x` (f32).
+///
+/// Negative `x` has a real root only for odd `n`; otherwise, and for `n == 0`, this returns NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootnf(x: f32, n: i64) -> f32 {
+    rootn(x as f64, n) as f32
+}
+
+/// The `n`th root of `x` (f64).
+///
+/// Negative `x` has a real root only for odd `n`; otherwise, and for `n == 0`, this returns NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootn(x: f64, n: i64) -> f64 {
+    let odd = n & 1 != 0;
+    if n == 0 || x.is_nan() || (x < 0.0 && !odd) {
+        return (x - x) / 0.0;
+    }
+
+    let ax = fabs(x);
+    if ax == 0.0 || ax.is_infinite() {
+        /* the sign of ±0 and ±inf is only kept for odd n */
+        let y = if odd { x } else { ax };
+        return if n > 0 { y } else { 1.0 / y };
+    }
+
+    match n {
+        1 => return x,
+        -1 => return 1.0 / x,
+        2 => return sqrt(x),
+        -2 => return rsqrt(x),
+        _ => {}
+    }
+
+    let y = if n.unsigned_abs() <= 1 << 30 {
+        /*
+         * Start from |x|^(1/n) and take one Newton step on y^n - |x|:
+         *   y' = y * (1 - d/n), d = y^n/|x| - 1
+         * computing y^n in extra precision.
+         */
+        let y0 = pow(ax, 1.0 / n as f64);
+        let (h, l, e) = pow_dd(y0, 0.0, n);
+        /* y0^n is close to |x|, so h - s is exact */
+        let s = scalbn(ax, -e as i32);
+        let d = ((h - s) + l) / s;
+        fma(-y0, d / n as f64, y0)
+    } else {
+        /* |log(|x|)/n| < 2^-20, so the result is close to 1 */
+        exp(log(ax) / n as f64)
+    };
+
+    if x < 0.0 { -y } else { y }
+}
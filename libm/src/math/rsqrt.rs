@@ -0,0 +1,46 @@
+use super::{fma, scalbn, sqrt};
+
+/// The reciprocal of the square root of `x` (f32).
+///
+/// `rsqrtf(±0)` is ±inf.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrtf(x: f32) -> f32 {
+    rsqrt(x as f64) as f32
+}
+
+/// The reciprocal of the square root of `x` (f64).
+///
+/// `rsqrt(±0)` is ±inf.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrt(x: f64) -> f64 {
+    if x == 0.0 {
+        return 1.0 / x;
+    }
+    if x.is_nan() {
+        return x + x;
+    }
+    if x < 0.0 {
+        return (x - x) / 0.0;
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+
+    /* scale subnormals up by 2^108 */
+    let (x, adj) = if x.is_subnormal() { (x * hf64!("0x1p108"), 54) } else { (x, 0) };
+
+    /* x = m * 2^(2k) with m in [1, 4) */
+    let ix = x.to_bits();
+    let e = ((ix >> 52) & 0x7ff) as i32 - 0x3ff;
+    let k = e >> 1;
+    let m = f64::from_bits((ix & ((1 << 52) - 1)) | (((0x3ff + e - 2 * k) as u64) << 52));
+
+    /* one Newton step with the residual 1 - m*r^2 computed in extra precision */
+    let r = 1.0 / sqrt(m);
+    let h = r * r;
+    let hl = fma(r, r, -h);
+    let d = fma(-m, h, 1.0) - m * hl;
+    let r = fma(r * 0.5, d, r);
+
+    scalbn(r, adj - k)
+}
@@ -0,0 +1,43 @@
+/// Calculate `x * 2^n` with a 64-bit exponent (f16).
+///
+/// This is the same as `scalbn`, since any exponent out of the `i32` range gives the same result
+/// as `i32::MIN` or `i32::MAX`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalblnf16(x: f16, n: i64) -> f16 {
+    super::generic::scalbn(x, clamp_exp(n))
+}
+
+/// Calculate `x * 2^n` with a 64-bit exponent (f32).
+///
+/// This is the same as `scalbn`, since any exponent out of the `i32` range gives the same result
+/// as `i32::MIN` or `i32::MAX`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalblnf(x: f32, n: i64) -> f32 {
+    super::generic::scalbn(x, clamp_exp(n))
+}
+
+/// Calculate `x * 2^n` with a 64-bit exponent (f64).
+///
+/// This is the same as `scalbn`, since any exponent out of the `i32` range gives the same result
+/// as `i32::MIN` or `i32::MAX`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbln(x: f64, n: i64) -> f64 {
+    super::generic::scalbn(x, clamp_exp(n))
+}
+
+/// Calculate `x * 2^n` with a 64-bit exponent (f128).
+///
+/// This is the same as `scalbn`, since any exponent out of the `i32` range gives the same result
+/// as `i32::MIN` or `i32::MAX`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalblnf128(x: f128, n: i64) -> f128 {
+    super::generic::scalbn(x, clamp_exp(n))
+}
+
+/// Saturate `n` to the range of an `i32`. Exponents this large already overflow or underflow
+/// every float type, so this doesn't change the result.
+fn clamp_exp(n: i64) -> i32 {
+    n.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+}
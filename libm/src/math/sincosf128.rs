@@ -0,0 +1,23 @@
+use super::{k_cosf128, k_sinf128, rem_pio2f128};
+
+/// Both the sine and cosine of `x` (f128).
+///
+/// `x` is specified in radians and the return value is (sin(x), cos(x)).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincosf128(x: f128) -> (f128, f128) {
+    /* sin(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        let nan = x - x;
+        return (nan, nan);
+    }
+
+    let (n, r) = rem_pio2f128(x);
+    let (s, c) = (k_sinf128(r), k_cosf128(r));
+    let (s, c) = match n & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    (s.to_f128(), c.to_f128())
+}
@@ -0,0 +1,8 @@
+/// Both the sine and cosine of `x` (f16).
+///
+/// `x` is specified in radians and the return value is (sin(x), cos(x)).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincosf16(x: f16) -> (f16, f16) {
+    let (s, c) = super::sincos(x as f64);
+    (s as f16, c as f16)
+}
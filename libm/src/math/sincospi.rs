@@ -0,0 +1,114 @@
+use super::generic::rem_half;
+use super::sinpi::mul_pi;
+use super::{copysign, copysignf, k_cos, k_cosf, k_sin, k_sinf};
+
+/// Both the sine and cosine of `πx` (f16).
+///
+/// The return value is `(sinpi(x), cospi(x))`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincospif16(x: f16) -> (f16, f16) {
+    let (s, c) = sincospi(x as f64);
+    (s as f16, c as f16)
+}
+
+/// Both the sine and cosine of `πx` (f32).
+///
+/// The return value is `(sinpi(x), cospi(x))`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincospif(x: f32) -> (f32, f32) {
+    /* sincospi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        let nan = x - x;
+        return (nan, nan);
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        let z = copysignf(0.0, x);
+        return match n {
+            0 => (z, 1.0),
+            1 => (1.0, 0.0),
+            2 => (z, -1.0),
+            _ => (-1.0, 0.0),
+        };
+    }
+
+    let y = core::f64::consts::PI * r as f64;
+    let (s, c) = (k_sinf(y), k_cosf(y));
+    match n {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Both the sine and cosine of `πx` (f64).
+///
+/// The return value is `(sinpi(x), cospi(x))`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincospi(x: f64) -> (f64, f64) {
+    /* sincospi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        let nan = x - x;
+        return (nan, nan);
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        let z = copysign(0.0, x);
+        return match n {
+            0 => (z, 1.0),
+            1 => (1.0, 0.0),
+            2 => (z, -1.0),
+            _ => (-1.0, 0.0),
+        };
+    }
+
+    let (y0, y1) = mul_pi(r);
+    let (s, c) = (k_sin(y0, y1, 1), k_cos(y0, y1));
+    match n {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Both the sine and cosine of `πx` (f128).
+///
+/// The return value is `(sinpi(x), cospi(x))`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincospif128(x: f128) -> (f128, f128) {
+    use super::support::WideFloat;
+    use super::{copysignf128, k_cosf128, k_sinf128};
+
+    /* sincospi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        let nan = x - x;
+        return (nan, nan);
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        let z = copysignf128(0.0, x);
+        return match n {
+            0 => (z, 1.0),
+            1 => (1.0, 0.0),
+            2 => (z, -1.0),
+            _ => (-1.0, 0.0),
+        };
+    }
+
+    let y = WideFloat::PI * WideFloat::from_f128(r);
+    let (s, c) = (k_sinf128(y), k_cosf128(y));
+    let (s, c) = match n {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    (s.to_f128(), c.to_f128())
+}
@@ -0,0 +1,21 @@
+use super::{k_cosf128, k_sinf128, rem_pio2f128};
+
+/// The sine of `x` (f128).
+///
+/// `x` is specified in radians.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf128(x: f128) -> f128 {
+    /* sin(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_pio2f128(x);
+    let res = match n & 3 {
+        0 => k_sinf128(r),
+        1 => k_cosf128(r),
+        2 => -k_sinf128(r),
+        _ => -k_cosf128(r),
+    };
+    res.to_f128()
+}
@@ -0,0 +1,7 @@
+/// The sine of `x` (f16).
+///
+/// `x` is specified in radians.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf16(x: f16) -> f16 {
+    super::sin(x as f64) as f16
+}
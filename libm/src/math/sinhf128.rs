@@ -0,0 +1,28 @@
+use super::support::WideFloat;
+use super::{k_expf128, k_expm1f128};
+
+/// The hyperbolic sine of `x` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinhf128(x: f128) -> f128 {
+    if x.is_nan() || x.is_infinite() || x == 0.0 {
+        return x + x;
+    }
+
+    /* sinh(x) overflows for |x| > log(2 * MAX) ~= 11357.2 */
+    if x.abs() > 11358.0 {
+        return if x > 0.0 { f128::INFINITY } else { f128::NEG_INFINITY };
+    }
+
+    let w = WideFloat::from_f128(x).abs();
+    let r = if w.exp() < 0 {
+        // sinh(x) = (t + t/(t+1)) / 2 with t = expm1(|x|), avoiding cancellation
+        let t = k_expm1f128(w);
+        (t + t / (t + WideFloat::ONE)).mul_pow2(-1)
+    } else {
+        let e = k_expf128(w);
+        (e - WideFloat::ONE / e).mul_pow2(-1)
+    };
+
+    let r = r.to_f128();
+    if x < 0.0 { -r } else { r }
+}
@@ -0,0 +1,5 @@
+/// The hyperbolic sine of `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinhf16(x: f16) -> f16 {
+    super::sinh(x as f64) as f16
+}
@@ -0,0 +1,112 @@
+use super::generic::rem_half;
+use super::{copysign, copysignf, fma, k_cos, k_cosf, k_sin, k_sinf};
+
+/// π split into a head and a tail, so that `PI_HI * r + PI_LO * r` is accurate to well beyond
+/// `f64` precision.
+const PI_HI: f64 = core::f64::consts::PI; /* 0x400921FB, 0x54442D18 */
+const PI_LO: f64 = 1.2246467991473532e-16; /* 0x3CA1A626, 0x33145C07 */
+
+/// Compute `πr` as a double-double `(hi, lo)` for use with the `f64` trig kernels.
+pub(crate) fn mul_pi(r: f64) -> (f64, f64) {
+    let hi = PI_HI * r;
+    let lo = fma(PI_HI, r, -hi) + PI_LO * r;
+    (hi, lo)
+}
+
+/// The sine of `πx` (f16).
+///
+/// This is exact at integers, where the result is zero with the sign of `x`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpif16(x: f16) -> f16 {
+    sinpi(x as f64) as f16
+}
+
+/// The sine of `πx` (f32).
+///
+/// This is exact at integers, where the result is zero with the sign of `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpif(x: f32) -> f32 {
+    /* sinpi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 | 2 => copysignf(0.0, x),
+            1 => 1.0,
+            _ => -1.0,
+        };
+    }
+
+    let y = core::f64::consts::PI * r as f64;
+    match n {
+        0 => k_sinf(y),
+        1 => k_cosf(y),
+        2 => -k_sinf(y),
+        _ => -k_cosf(y),
+    }
+}
+
+/// The sine of `πx` (f64).
+///
+/// This is exact at integers, where the result is zero with the sign of `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpi(x: f64) -> f64 {
+    /* sinpi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 | 2 => copysign(0.0, x),
+            1 => 1.0,
+            _ => -1.0,
+        };
+    }
+
+    let (y0, y1) = mul_pi(r);
+    match n {
+        0 => k_sin(y0, y1, 1),
+        1 => k_cos(y0, y1),
+        2 => -k_sin(y0, y1, 1),
+        _ => -k_cos(y0, y1),
+    }
+}
+
+/// The sine of `πx` (f128).
+///
+/// This is exact at integers, where the result is zero with the sign of `x`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpif128(x: f128) -> f128 {
+    use super::support::WideFloat;
+    use super::{copysignf128, k_cosf128, k_sinf128};
+
+    /* sinpi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 | 2 => copysignf128(0.0, x),
+            1 => 1.0,
+            _ => -1.0,
+        };
+    }
+
+    let y = WideFloat::PI * WideFloat::from_f128(r);
+    let res = match n {
+        0 => k_sinf128(y),
+        1 => k_cosf128(y),
+        2 => -k_sinf128(y),
+        _ => -k_cosf128(y),
+    };
+    res.to_f128()
+}
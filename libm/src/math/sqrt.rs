@@ -1,3 +1,5 @@
+use super::support::{FpResult, Round};
+
 /// The square root of `x` (f16).
 #[cfg(f16_enabled)]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
@@ -11,6 +13,13 @@ pub fn sqrtf16(x: f16) -> f16 {
     return super::generic::sqrt(x);
 }
 
+/// The square root of `x` (f16), rounded in the direction given by `round`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf16_round(x: f16, round: Round) -> FpResult<f16> {
+    super::generic::sqrt_round(x, round)
+}
+
 /// The square root of `x` (f32).
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn sqrtf(x: f32) -> f32 {
@@ -27,6 +36,12 @@ pub fn sqrtf(x: f32) -> f32 {
     super::generic::sqrt(x)
 }
 
+/// The square root of `x` (f32), rounded in the direction given by `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf_round(x: f32, round: Round) -> FpResult<f32> {
+    super::generic::sqrt_round(x, round)
+}
+
 /// The square root of `x` (f64).
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn sqrt(x: f64) -> f64 {
@@ -43,9 +58,22 @@ pub fn sqrt(x: f64) -> f64 {
     super::generic::sqrt(x)
 }
 
+/// The square root of `x` (f64), rounded in the direction given by `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrt_round(x: f64, round: Round) -> FpResult<f64> {
+    super::generic::sqrt_round(x, round)
+}
+
 /// The square root of `x` (f128).
 #[cfg(f128_enabled)]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn sqrtf128(x: f128) -> f128 {
     return super::generic::sqrt(x);
 }
+
+/// The square root of `x` (f128), rounded in the direction given by `round`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf128_round(x: f128, round: Round) -> FpResult<f128> {
+    super::generic::sqrt_round(x, round)
+}
@@ -0,0 +1,1138 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! Exception flags for the results of the public functions.
+//!
+//! Most functions are classified after the fact, from their arguments and result. `INVALID`,
+//! `DIVIDE_BY_ZERO` and `OVERFLOW` only depend on whether the arguments and results are finite,
+//! and on where each function has poles. `INEXACT` (and with it, `UNDERFLOW`) needs to know
+//! whether the result is exact, which is the case at a handful of points for the transcendental
+//! functions. Where the exact points are not a short list, such as powers and roots, the result
+//! is checked by redoing the computation with exact integer arithmetic.
+//!
+//! Underflow is reported when an inexact result is tiny before rounding, which is the rule used by
+//! the operations that round with status tracking. A result below the smallest normal number is
+//! always tiny. A result equal to it is tiny if it was rounded up, which classifiers check with
+//! [`rounded_up`] where the result can land there.
+
+use core::cmp::Ordering;
+
+use super::super::generic::{self, NarrowHelper};
+use super::super::support::{FpResult, Round, Status};
+use super::super::{CastFrom, CastInto, Float, HInt};
+
+// The complex types are not available when `math` is vendored into compiler-builtins.
+#[cfg(not(feature = "compiler-builtins"))]
+mod complex;
+#[cfg(not(feature = "compiler-builtins"))]
+pub use complex::*;
+
+/// Summary of the floating point arguments of a function.
+#[derive(Clone, Copy)]
+pub struct Args {
+    nan: bool,
+    snan: bool,
+    finite: bool,
+}
+
+impl Args {
+    pub fn new<F: Float>(xs: &[F]) -> Self {
+        Self { nan: false, snan: false, finite: true }.and(xs)
+    }
+
+    /// Include arguments of another type.
+    pub fn and<F: Float>(mut self, xs: &[F]) -> Self {
+        for &x in xs {
+            self.nan |= x.is_nan();
+            self.snan |= generic::issignaling(x);
+            self.finite &= !x.is_nan() && !x.is_infinite();
+        }
+        self
+    }
+
+    /// Signaling NaNs raise invalid in every computational operation.
+    fn signaling(self) -> Status {
+        if self.snan { Status::INVALID } else { Status::OK }
+    }
+}
+
+/// Flags for a result `r` computed from `args`. An infinite result from finite arguments is a
+/// division by zero if `pole` is set, and an overflow otherwise. Any other result is inexact
+/// unless `exact` is set.
+fn flags<F: Float>(args: Args, r: F, pole: bool, exact: bool) -> Status {
+    let status = args.signaling();
+
+    if r.is_nan() {
+        return if args.nan { status } else { Status::INVALID };
+    }
+
+    if r.is_infinite() && args.finite {
+        let raised =
+            if pole { Status::DIVIDE_BY_ZERO } else { Status::OVERFLOW.with(Status::INEXACT) };
+        return status.with(raised);
+    }
+
+    if exact {
+        return status;
+    }
+
+    let mut status = status.with(Status::INEXACT);
+    status.set_underflow(r.abs() < F::MIN_POSITIVE_NORMAL);
+    status
+}
+
+/// Add underflow for an inexact result `r` that was rounded up to the smallest normal number,
+/// which `flags` cannot tell from the result alone. `below` is whether the exact value is smaller
+/// in magnitude than `r`.
+fn rounded_up<F: Float>(mut status: Status, r: F, below: bool) -> Status {
+    if status.inexact() && r.abs() == F::MIN_POSITIVE_NORMAL && below {
+        status.set_underflow(true);
+    }
+    status
+}
+
+/// Flags for a function of one argument.
+fn unary<F: Float>(x: F, r: F, pole: bool, exact: bool) -> Status {
+    flags(Args::new(&[x]), r, pole, exact)
+}
+
+/// Flags for a function of two arguments.
+fn binary<F: Float>(x: F, y: F, r: F, pole: bool, exact: bool) -> Status {
+    flags(Args::new(&[x, y]), r, pole, exact)
+}
+
+/// Flags for the narrowing operations, which are rounded with status tracking. Only `INVALID`
+/// for signaling NaNs needs to be added.
+fn narrowed<F: Float>(res: FpResult<F>, args: Args) -> FpResult<F> {
+    FpResult::new(res.val, res.status.with(args.signaling()))
+}
+
+/* Helpers */
+
+/// True if `x` is a finite integer.
+fn is_int<F: Float>(x: F) -> bool {
+    !x.is_nan() && !x.is_infinite() && generic::trunc(x) == x
+}
+
+/// True if `x * 2^k` is a finite integer, for small `k`.
+fn is_int_scaled<F: Float>(x: F, k: u32) -> bool {
+    // Values where the multiplication could overflow are already integers.
+    let mut s = x;
+    for _ in 0..k {
+        s = s + s;
+    }
+    is_int(x) || is_int(s)
+}
+
+/// `x` as an integer, if it is one and it fits.
+fn to_int<F: Float>(x: F) -> Option<i64>
+where
+    F::Int: CastInto<u128>,
+{
+    if x == F::ZERO { Some(0) } else { Dyadic::new(x)?.to_int() }
+}
+
+/// `1 + x`, which is needed exactly by a few functions.
+fn one_plus<F: Float>(x: F) -> Option<Dyadic>
+where
+    F::Int: CastInto<u128>,
+{
+    Dyadic::new(F::ONE)?.add(Dyadic::new(x)?)
+}
+
+/// True if `x = 2^n - 1`, or `x = 10^n - 1` if `ten` is set, for an integer `n`.
+fn is_pow_minus_one<F: Float>(x: F, ten: bool) -> bool
+where
+    F::Int: CastInto<u128>,
+{
+    if x == F::ZERO {
+        return true;
+    }
+
+    match one_plus(x) {
+        Some(s) if ten => s.e >= 0 && pow_u128(5, s.e as u64) == Some(s.m),
+        Some(s) => s.m == 1,
+        None => false,
+    }
+}
+
+/// `b^n`, if it fits.
+fn pow_u128(b: u128, n: u64) -> Option<u128> {
+    let mut r: u128 = 1;
+    for _ in 0..n {
+        r = r.checked_mul(b)?;
+    }
+    Some(r)
+}
+
+/// Number of significant bits in `m`.
+fn bit_len(m: u128) -> u32 {
+    128 - m.leading_zeros()
+}
+
+/// A nonzero finite value `(-1)^neg * m * 2^e`, where `m` is odd.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Dyadic {
+    neg: bool,
+    m: u128,
+    e: i64,
+}
+
+impl Dyadic {
+    const ONE: Self = Self { neg: false, m: 1, e: 0 };
+
+    /// The exact value of `x`, or `None` for zero, infinity and NaN.
+    fn new<F: Float>(x: F) -> Option<Self>
+    where
+        F::Int: CastInto<u128>,
+    {
+        if x.is_nan() || x.is_infinite() || x == F::ZERO {
+            return None;
+        }
+
+        let (m, e) = if x.ex() == 0 {
+            (x.frac(), F::EXP_MIN_SUBNORM)
+        } else {
+            (x.frac() | F::IMPLICIT_BIT, x.exp_unbiased() - F::SIG_BITS as i32)
+        };
+        Some(Self::normalize(x.is_sign_negative(), m.cast(), e.into()))
+    }
+
+    fn normalize(neg: bool, m: u128, e: i64) -> Self {
+        let tz = m.trailing_zeros();
+        Self { neg, m: m >> tz, e: e + i64::from(tz) }
+    }
+
+    /// True if the value is exactly representable in `F`.
+    fn fits<F: Float>(self) -> bool {
+        let len = i64::from(bit_len(self.m));
+        len <= i64::from(F::SIG_BITS + 1)
+            && self.e >= i64::from(F::EXP_MIN_SUBNORM)
+            && self.e <= i64::from(F::EXP_MAX) - (len - 1)
+    }
+
+    /// The value as an integer, if it is one and it fits.
+    fn to_int(self) -> Option<i64> {
+        let e = u32::try_from(self.e).ok()?;
+        if bit_len(self.m).saturating_add(e) > 63 {
+            return None;
+        }
+        let m = (self.m << e) as i64;
+        Some(if self.neg { -m } else { m })
+    }
+
+    /// Compare `|a * b|` with `|c * d|`. The products are not limited to the integer
+    /// representation.
+    fn cmp_mul(a: Self, b: Self, c: Self, d: Self) -> Ordering {
+        let (p, ep) = (a.m.widen_mul(b.m), a.e + b.e);
+        let (q, eq) = (c.m.widen_mul(d.m), c.e + d.e);
+        let (lp, lq) = (256 - p.leading_zeros(), 256 - q.leading_zeros());
+
+        // Compare the positions of the leading bits, then the significands aligned to the same
+        // length.
+        let top = (i64::from(lp) + ep).cmp(&(i64::from(lq) + eq));
+        if top != Ordering::Equal {
+            return top;
+        }
+        let (p, q) = if lp >= lq { (p, q << (lp - lq)) } else { (p << (lq - lp), q) };
+        (p.hi, p.lo).cmp(&(q.hi, q.lo))
+    }
+
+    /// The value as an `f64`, which is exact if `m` has at most 53 bits and the exponent is in
+    /// range.
+    fn to_f64(self) -> f64 {
+        let v = super::super::scalbn(self.m as f64, self.e as i32);
+        if self.neg { -v } else { v }
+    }
+
+    fn mul(self, rhs: Self) -> Option<Self> {
+        Some(Self { neg: self.neg != rhs.neg, m: self.m.checked_mul(rhs.m)?, e: self.e + rhs.e })
+    }
+
+    /// `self^n`, if the result fits in the integer representation.
+    fn powi(self, n: u64) -> Option<Self> {
+        let e = if self.e == 0 { 0 } else { self.e.checked_mul(i64::try_from(n).ok()?)? };
+        let m = if self.m == 1 { 1 } else { pow_u128(self.m, n)? };
+        Some(Self { neg: self.neg && n % 2 == 1, m, e })
+    }
+
+    /// `1 / self`, which is only a dyadic value for powers of two.
+    fn recip(self) -> Option<Self> {
+        if self.m == 1 { Some(Self { e: self.e.checked_neg()?, ..self }) } else { None }
+    }
+
+    /// `self^n` for any integer `n`.
+    fn pown(self, n: i64) -> Option<Self> {
+        let p = self.powi(n.unsigned_abs())?;
+        if n < 0 { p.recip() } else { Some(p) }
+    }
+
+    /// The square root, if it is exact.
+    fn sqrt(self) -> Option<Self> {
+        if self.neg || self.e % 2 != 0 {
+            return None;
+        }
+        let s = isqrt(self.m);
+        (s * s == self.m).then_some(Self { neg: false, m: s, e: self.e / 2 })
+    }
+
+    /// The exact sum, if it fits in the integer representation. `None` for a zero sum.
+    fn add(self, rhs: Self) -> Option<Self> {
+        let (hi, lo) = if self.e >= rhs.e { (self, rhs) } else { (rhs, self) };
+        let shift = u32::try_from(hi.e - lo.e).ok()?;
+        if shift + bit_len(hi.m) >= 127 {
+            return None;
+        }
+
+        let a = hi.m << shift;
+        let (neg, m) = if hi.neg == lo.neg {
+            (hi.neg, a + lo.m)
+        } else if a >= lo.m {
+            (hi.neg, a - lo.m)
+        } else {
+            (lo.neg, lo.m - a)
+        };
+
+        (m != 0).then(|| Self::normalize(neg, m, lo.e))
+    }
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u128) -> u128 {
+    let mut rem = n;
+    let mut root = 0u128;
+    let mut bit = 1u128 << 126;
+
+    while bit > n {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+/// True if `x^y` is exactly representable, for finite nonzero `x` and `y`.
+fn pow_exact<F: Float>(x: F, y: F) -> bool
+where
+    F::Int: CastInto<u128>,
+{
+    let (mut base, dy) = match (Dyadic::new(x), Dyadic::new(y)) {
+        (Some(base), Some(dy)) => (base, dy),
+        _ => return false,
+    };
+
+    // Powers of -1 and 1 are exact wherever they are defined.
+    base.neg = false;
+    if base.m == 1 && base.e == 0 {
+        return true;
+    }
+
+    // `y = my * 2^ey`. Negative exponents take roots of `x`, which must all be exact.
+    let mut ey = dy.e;
+    while ey < 0 {
+        match base.sqrt() {
+            Some(s) => base = s,
+            None => return false,
+        }
+        ey += 1;
+    }
+
+    let n = Dyadic { neg: dy.neg, m: dy.m, e: ey }.to_int();
+    n.and_then(|n| base.pown(n)).map_or(false, Dyadic::fits::<F>)
+}
+
+/// True if `|x^y| < |r|`, for finite nonzero values. Types narrower than `f64` can tell from the
+/// `f64` power, which is far more precise than `r`. Wider types assume it is not.
+fn pow_below<F: Float>(x: F, y: F, r: F) -> bool
+where
+    F::Int: CastInto<u128>,
+{
+    if F::BITS >= 64 {
+        return false;
+    }
+    match (Dyadic::new(x), Dyadic::new(y), Dyadic::new(r)) {
+        (Some(x), Some(y), Some(r)) => {
+            super::super::fabs(super::super::pow(x.to_f64(), y.to_f64())) < r.to_f64().abs()
+        }
+        _ => false,
+    }
+}
+
+/* Classifiers, named after the functions they are used for */
+
+pub fn acos<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ONE)
+}
+
+pub fn acosh<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ONE || x == F::INFINITY)
+}
+
+pub fn acospi<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.abs() == F::ONE)
+}
+
+pub fn asin<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO)
+}
+
+pub fn asinh<F: Float>(x: F, r: F) -> Status {
+    // `|asinh(x)| < |x|`, so a result equal to `x` was rounded up.
+    rounded_up(unary(x, r, false, x == F::ZERO || x.is_infinite()), r, r == x)
+}
+
+pub fn asinpi<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.abs() == F::ONE)
+}
+
+pub fn atan<F: Float>(x: F, r: F) -> Status {
+    // `|atan(x)| < |x|`, so a result equal to `x` was rounded up.
+    rounded_up(unary(x, r, false, x == F::ZERO), r, r == x)
+}
+
+pub fn atan2<F: Float>(y: F, x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // Results of zero, the other special values are multiples of pi.
+    let exact = (y == F::ZERO && x.is_sign_positive()) || (!y.is_infinite() && x == F::INFINITY);
+
+    // Tiny results are `atan(y / x) < y / x`, so the result was rounded up if `|r * x| >= |y|`.
+    let below = match (Dyadic::new(y), Dyadic::new(x), Dyadic::new(r)) {
+        (Some(dy), Some(dx), Some(dr)) => Dyadic::cmp_mul(dr, dx, dy, Dyadic::ONE).is_ge(),
+        _ => false,
+    };
+    rounded_up(binary(y, x, r, false, exact), r, below)
+}
+
+pub fn atan2pi<F: Float>(y: F, x: F, r: F) -> Status {
+    // Multiples of 1/4.
+    let exact =
+        y == F::ZERO || x == F::ZERO || x.abs() == y.abs() || x.is_infinite() || y.is_infinite();
+    binary(y, x, r, false, exact)
+}
+
+pub fn atanh<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, x.abs() == F::ONE, x == F::ZERO)
+}
+
+pub fn atanpi<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.abs() == F::ONE || x.is_infinite())
+}
+
+pub fn cbrt<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = match (Dyadic::new(x), Dyadic::new(r)) {
+        (Some(x), Some(r)) => r.powi(3) == Some(x),
+        _ => true,
+    };
+    unary(x, r, false, exact)
+}
+
+pub fn ceil<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, true)
+}
+
+pub fn compoundn<F: Float>(x: F, n: i64, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = n == 0
+        || x.is_infinite()
+        || x == F::ZERO
+        || x == F::NEG_ONE
+        || one_plus(x).and_then(|s| s.pown(n)).map_or(false, Dyadic::fits::<F>);
+    unary(x, r, x == F::NEG_ONE && n < 0, exact)
+}
+
+pub fn copysign<F: Float>(_x: F, _y: F, _r: F) -> Status {
+    Status::OK
+}
+
+pub fn cos<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO)
+}
+
+pub fn cosh<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn cospi<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, is_int_scaled(x, 1))
+}
+
+pub fn erf<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn erfc<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn exp<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn exp10<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // `10^n = 5^n * 2^n` is exact if `5^n` fits in the significand.
+    let exact = x.is_infinite()
+        || to_int(x)
+            .and_then(|n| pow_u128(5, u64::try_from(n).ok()?))
+            .map_or(false, |m| bit_len(m) <= F::SIG_BITS + 1);
+    unary(x, r, false, exact)
+}
+
+pub fn exp10m1<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = x.is_infinite()
+        || to_int(x)
+            .and_then(|n| pow_u128(10, u64::try_from(n).ok()?))
+            .map_or(false, |m| bit_len(m - 1) <= F::SIG_BITS + 1);
+    unary(x, r, false, exact)
+}
+
+pub fn exp2<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x.is_infinite() || (is_int(x) && r != F::ZERO))
+}
+
+pub fn exp2m1<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // `2^n - 1` and `2^-n - 1` both need `n` bits.
+    let exact = x.is_infinite()
+        || to_int(x).map_or(false, |n| n.unsigned_abs() <= u64::from(F::SIG_BITS + 1));
+    unary(x, r, false, exact)
+}
+
+pub fn expm1<F: Float>(x: F, r: F) -> Status {
+    // `|expm1(x)| < |x|` for negative `x`, so a result equal to `x` was rounded up.
+    let status = unary(x, r, false, x == F::ZERO || x.is_infinite());
+    rounded_up(status, r, r == x && x < F::ZERO)
+}
+
+pub fn fabs<F: Float>(_x: F, _r: F) -> Status {
+    Status::OK
+}
+
+pub fn fdim<F: Float>(x: F, y: F, r: F) -> Status {
+    // The difference is exact if the rounding error is zero.
+    let exact = x <= y || x.is_infinite() || y.is_infinite() || {
+        let s = x - y;
+        let bb = s - x;
+        let err = (x - (s - bb)) + (-y - bb);
+        err == F::ZERO
+    };
+    binary(x, y, r, false, exact)
+}
+
+pub fn floor<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, true)
+}
+
+pub fn fma<F>(x: F, y: F, z: F, _r: F) -> Status
+where
+    F: Float,
+    F: CastFrom<F::SignedInt>,
+    F: CastFrom<i8>,
+    F::Int: HInt,
+    u32: CastInto<F::Int>,
+{
+    // The rounding already tracks its status, including tininess before rounding.
+    let status = generic::fma_round(x, y, z, Round::Nearest).status;
+    status.with(Args::new(&[x, y, z]).signaling())
+}
+
+pub fn fmax<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fmaximum<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fmaximum_mag<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fmaximum_mag_num<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fmaximum_num<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fmin<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fminimum<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fminimum_mag<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fminimum_mag_num<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fminimum_num<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn fmod<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn frexp<F: Float>(x: F, r: (F, i32)) -> Status {
+    unary(x, r.0, false, true)
+}
+
+pub fn getpayload<F: Float>(_x: F, _r: F) -> Status {
+    Status::OK
+}
+
+pub fn hypot<F: Float>(x: F, y: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let (big, small) = if x.abs() >= y.abs() { (x.abs(), y.abs()) } else { (y.abs(), x.abs()) };
+
+    // Compare `r^2 - big^2 = (r - big) * (r + big)` with `small^2`. `r - big` is exact because
+    // `big <= r <= 2 * big`, and `r + big` does not need many more bits than `r`.
+    let ord = match (Dyadic::new(small), Dyadic::new(r)) {
+        (Some(s), Some(dr)) => {
+            let diff = Dyadic::new(r - big);
+            let sum = Dyadic::new(big).and_then(|b| dr.add(b));
+            match (diff, sum) {
+                (Some(a), Some(b)) => Some(Dyadic::cmp_mul(a, b, s, s)),
+                // `r = big` is below the exact result.
+                (None, _) => Some(Ordering::Less),
+                _ => None,
+            }
+        }
+        // A zero or infinite argument gives a result that is one of the arguments.
+        _ => Some(Ordering::Equal),
+    };
+
+    let status = binary(x, y, r, false, ord == Some(Ordering::Equal));
+    rounded_up(status, r, ord == Some(Ordering::Greater))
+}
+
+pub fn ilogb<F: Float>(x: F, _r: i32) -> Status {
+    // There is no integer result for these.
+    let invalid = x.is_nan() || x.is_infinite() || x == F::ZERO;
+    if invalid { Status::INVALID } else { Status::OK }
+}
+
+pub fn issignaling<F: Float>(_x: F, _r: i32) -> Status {
+    Status::OK
+}
+
+pub fn j0<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn j1<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn jn<F: Float>(_n: i32, x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn ldexp<F: Float>(x: F, n: i32, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    scalbln(x, n.into(), r)
+}
+
+pub fn lgamma<F: Float>(x: F, r: F) -> Status {
+    let pole = x <= F::ZERO && is_int(x);
+    unary(x, r, pole, x == F::ONE || x == F::ONE + F::ONE || x.is_infinite())
+}
+
+pub fn lgamma_r<F: Float>(x: F, r: (F, i32)) -> Status {
+    lgamma(x, r.0)
+}
+
+pub fn llogb<F: Float>(x: F, _r: i64) -> Status {
+    ilogb(x, 0)
+}
+
+pub fn log<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, x == F::ZERO, x == F::ONE || x == F::INFINITY)
+}
+
+pub fn log10<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = x == F::INFINITY
+        || Dyadic::new(x)
+            .map_or(false, |d| !d.neg && d.e >= 0 && pow_u128(5, d.e as u64) == Some(d.m));
+    unary(x, r, x == F::ZERO, exact)
+}
+
+pub fn log10p1<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = x == F::INFINITY || (x > F::NEG_ONE && is_pow_minus_one(x, true));
+    unary(x, r, x == F::NEG_ONE, exact)
+}
+
+pub fn log1p<F: Float>(x: F, r: F) -> Status {
+    // `|log1p(x)| < |x|` for positive `x`, so a result equal to `x` was rounded up.
+    let status = unary(x, r, x == F::NEG_ONE, x == F::ZERO || x == F::INFINITY);
+    rounded_up(status, r, r == x && x > F::ZERO)
+}
+
+pub fn log2<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = x == F::INFINITY || Dyadic::new(x).map_or(false, |d| !d.neg && d.m == 1);
+    unary(x, r, x == F::ZERO, exact)
+}
+
+pub fn log2p1<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = x == F::INFINITY || (x > F::NEG_ONE && is_pow_minus_one(x, false));
+    unary(x, r, x == F::NEG_ONE, exact)
+}
+
+pub fn logb<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, x == F::ZERO, true)
+}
+
+pub fn logp1<F: Float>(x: F, r: F) -> Status {
+    log1p(x, r)
+}
+
+pub fn modf<F: Float>(x: F, r: (F, F)) -> Status {
+    unary(x, r.0, false, true)
+}
+
+pub fn nan<F: Float>(_tagp: &str, _r: F) -> Status {
+    Status::OK
+}
+
+pub fn nearbyint<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, true)
+}
+
+pub fn nextafter<F: Float>(x: F, y: F, r: F) -> Status {
+    nexttoward(x, y, r)
+}
+
+/// Flags for `nextafter` and `nexttoward`, which follow C rather than IEEE 754 `nextUp` and
+/// `nextDown`: moving from a finite value to infinity overflows, and moving to a subnormal or
+/// zero underflows.
+pub fn nexttoward<F: Float, G: Float>(x: F, y: G, r: F) -> Status {
+    let args = Args::new(&[x]).and(&[y]);
+    let status = args.signaling();
+
+    // The result only differs from `x` if it moved toward `y`.
+    if args.nan || r == x {
+        return status;
+    }
+
+    if r.is_infinite() {
+        status.with(Status::OVERFLOW).with(Status::INEXACT)
+    } else if r.abs() < F::MIN_POSITIVE_NORMAL {
+        status.with(Status::UNDERFLOW).with(Status::INEXACT)
+    } else {
+        status
+    }
+}
+
+pub fn nextdown<F: Float>(x: F, _r: F) -> Status {
+    Args::new(&[x]).signaling()
+}
+
+pub fn nextup<F: Float>(x: F, _r: F) -> Status {
+    Args::new(&[x]).signaling()
+}
+
+pub fn pow<F: Float>(x: F, y: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // `pow` is 1 for these even if the other argument is NaN.
+    if y == F::ZERO || x == F::ONE {
+        return Args::new(&[x, y]).signaling();
+    }
+
+    let pole = x == F::ZERO && y < F::ZERO;
+    let exact = x == F::ZERO || x.is_infinite() || y.is_infinite() || pow_exact(x, y);
+    rounded_up(binary(x, y, r, pole, exact), r, pow_below(x, y, r))
+}
+
+pub fn pown<F: Float>(x: F, n: i64, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = n == 0
+        || x == F::ZERO
+        || x.is_infinite()
+        || Dyadic::new(x).and_then(|d| d.pown(n)).map_or(false, Dyadic::fits::<F>);
+    unary(x, r, x == F::ZERO && n < 0, exact)
+}
+
+pub fn powr<F: Float>(x: F, y: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let pole = x == F::ZERO && y < F::ZERO;
+    let exact = x == F::ZERO
+        || x.is_infinite()
+        || y.is_infinite()
+        || y == F::ZERO
+        || x == F::ONE
+        || pow_exact(x, y);
+    rounded_up(binary(x, y, r, pole, exact), r, pow_below(x, y, r))
+}
+
+pub fn remainder<F: Float>(x: F, y: F, r: F) -> Status {
+    binary(x, y, r, false, true)
+}
+
+pub fn remquo<F: Float>(x: F, y: F, r: (F, i32)) -> Status {
+    binary(x, y, r.0, false, true)
+}
+
+pub fn rint<F: Float>(x: F, r: F) -> Status {
+    // Integers are never tiny, even if they are zero.
+    let mut status = unary(x, r, false, r == x);
+    status.set_underflow(false);
+    status
+}
+
+pub fn rootn<F: Float>(x: F, n: i64, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // Check the result by raising it back to the power `n`.
+    let exact = match (Dyadic::new(x), Dyadic::new(r)) {
+        (Some(dx), Some(dr)) => dr.pown(n) == Some(dx),
+        _ => true,
+    };
+    unary(x, r, x == F::ZERO && n < 0, exact)
+}
+
+pub fn round<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, true)
+}
+
+pub fn roundeven<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, true)
+}
+
+pub fn rsqrt<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact =
+        x == F::INFINITY || Dyadic::new(x).map_or(false, |d| !d.neg && d.m == 1 && d.e % 2 == 0);
+    unary(x, r, x == F::ZERO, exact)
+}
+
+pub fn scalbln<F: Float>(x: F, n: i64, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // Scaling only loses bits if the result is subnormal.
+    let exact = match (Dyadic::new(x), Dyadic::new(r)) {
+        (Some(dx), Some(dr)) => {
+            dr.m == dx.m && i128::from(dr.e) == i128::from(dx.e) + i128::from(n)
+        }
+        (None, _) => true,
+        (Some(_), None) => false,
+    };
+
+    let below = match (Dyadic::new(x), Dyadic::new(r)) {
+        (Some(dx), Some(dr)) => dx.e.checked_add(n).map_or(false, |e| {
+            Dyadic::cmp_mul(dr, Dyadic::ONE, Dyadic { e, ..dx }, Dyadic::ONE).is_gt()
+        }),
+        _ => false,
+    };
+    rounded_up(unary(x, r, false, exact), r, below)
+}
+
+pub fn scalbn<F: Float>(x: F, n: i32, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    scalbln(x, n.into(), r)
+}
+
+pub fn setpayload<F: Float>(_pl: F, _r: F) -> Status {
+    Status::OK
+}
+
+pub fn setpayloadsig<F: Float>(_pl: F, _r: F) -> Status {
+    Status::OK
+}
+
+pub fn sin<F: Float>(x: F, r: F) -> Status {
+    // `|sin(x)| < |x|`, so a result equal to `x` was rounded up.
+    rounded_up(unary(x, r, false, x == F::ZERO), r, r == x)
+}
+
+pub fn sincos<F: Float>(x: F, r: (F, F)) -> Status {
+    sin(x, r.0).with(cos(x, r.1))
+}
+
+pub fn sincospi<F: Float>(x: F, r: (F, F)) -> Status {
+    sinpi(x, r.0).with(cospi(x, r.1))
+}
+
+pub fn sinh<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO || x.is_infinite())
+}
+
+pub fn sinpi<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, is_int_scaled(x, 1))
+}
+
+pub fn sqrt<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    let exact = match (Dyadic::new(x), Dyadic::new(r)) {
+        (Some(dx), Some(dr)) => dr.powi(2) == Some(dx),
+        _ => true,
+    };
+    unary(x, r, false, exact)
+}
+
+pub fn tan<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, x == F::ZERO)
+}
+
+pub fn tanh<F: Float>(x: F, r: F) -> Status {
+    // `|tanh(x)| < |x|`, so a result equal to `x` was rounded up.
+    rounded_up(unary(x, r, false, x == F::ZERO || x.is_infinite()), r, r == x)
+}
+
+pub fn tanpi<F: Float>(x: F, r: F) -> Status {
+    let pole = is_int_scaled(x, 1) && !is_int(x);
+    unary(x, r, pole, is_int_scaled(x, 2))
+}
+
+pub fn tgamma<F: Float>(x: F, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // `tgamma(n) = (n - 1)!`, which is exact until its odd part no longer fits.
+    let factorial_exact = || {
+        let mut acc = Dyadic { neg: false, m: 1, e: 0 };
+        let mut k = F::ONE + F::ONE;
+        while k < x {
+            acc = match Dyadic::new(k).and_then(|d| acc.mul(d)) {
+                Some(acc) if acc.fits::<F>() => acc,
+                _ => return false,
+            };
+            k = k + F::ONE;
+        }
+        true
+    };
+
+    let exact = x == F::INFINITY || (x > F::ZERO && is_int(x) && factorial_exact());
+    unary(x, r, x == F::ZERO, exact)
+}
+
+pub fn totalorder<F: Float>(_x: F, _y: F, _r: i32) -> Status {
+    Status::OK
+}
+
+pub fn totalordermag<F: Float>(_x: F, _y: F, _r: i32) -> Status {
+    Status::OK
+}
+
+pub fn trunc<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, false, true)
+}
+
+pub fn y0<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, x == F::ZERO, x == F::INFINITY)
+}
+
+pub fn y1<F: Float>(x: F, r: F) -> Status {
+    unary(x, r, x == F::ZERO, x == F::INFINITY)
+}
+
+pub fn yn<F: Float>(_n: i32, x: F, r: F) -> Status {
+    unary(x, r, x == F::ZERO, x == F::INFINITY)
+}
+
+/* Functions that already track their status */
+
+pub fn canonicalize<F: Float>(x: F) -> FpResult<F> {
+    generic::canonicalize(x)
+}
+
+pub fn fadd<B: NarrowHelper>(x: B, y: B) -> FpResult<B::H> {
+    narrowed(generic::fadd_round(x, y, Round::Nearest), Args::new(&[x, y]))
+}
+
+pub fn fdiv<B: NarrowHelper>(x: B, y: B) -> FpResult<B::H> {
+    narrowed(generic::fdiv_round(x, y, Round::Nearest), Args::new(&[x, y]))
+}
+
+pub fn ffma<B: NarrowHelper>(x: B, y: B, z: B) -> FpResult<B::H> {
+    narrowed(generic::ffma_round(x, y, z, Round::Nearest), Args::new(&[x, y, z]))
+}
+
+pub fn fmul<B: NarrowHelper>(x: B, y: B) -> FpResult<B::H> {
+    narrowed(generic::fmul_round(x, y, Round::Nearest), Args::new(&[x, y]))
+}
+
+pub fn fsqrt<B: NarrowHelper>(x: B) -> FpResult<B::H> {
+    narrowed(generic::fsqrt_round(x, Round::Nearest), Args::new(&[x]))
+}
+
+pub fn fsub<B: NarrowHelper>(x: B, y: B) -> FpResult<B::H> {
+    narrowed(generic::fsub_round(x, y, Round::Nearest), Args::new(&[x, y]))
+}
+
+pub fn fromfp<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    generic::fromfp(x, round, width)
+}
+
+pub fn fromfpx<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    generic::fromfpx(x, round, width)
+}
+
+pub fn llrint<F: Float>(x: F) -> FpResult<i64>
+where
+    F::Int: CastInto<u64>,
+{
+    generic::lrint_round(x, Round::Nearest)
+}
+
+pub fn llround<F: Float>(x: F) -> FpResult<i64>
+where
+    F::Int: CastInto<u64>,
+{
+    generic::lround(x)
+}
+
+pub fn lrint<F: Float>(x: F) -> FpResult<i64>
+where
+    F::Int: CastInto<u64>,
+{
+    generic::lrint_round(x, Round::Nearest)
+}
+
+pub fn lround<F: Float>(x: F) -> FpResult<i64>
+where
+    F::Int: CastInto<u64>,
+{
+    generic::lround(x)
+}
+
+pub fn ufromfp<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    generic::ufromfp(x, round, width)
+}
+
+pub fn ufromfpx<F: Float>(x: F, round: Round, width: u32) -> FpResult<F> {
+    generic::ufromfpx(x, round, width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dyadic_arithmetic() {
+        let d = |x: f64| Dyadic::new(x).unwrap();
+
+        assert_eq!(d(12.0), Dyadic { neg: false, m: 3, e: 2 });
+        assert_eq!(d(-0.375), Dyadic { neg: true, m: 3, e: -3 });
+        assert_eq!(Dyadic::new(f64::from_bits(1)), Some(Dyadic { neg: false, m: 1, e: -1074 }));
+        assert_eq!(Dyadic::new(0.0f64), None);
+
+        assert_eq!(d(3.0).powi(3), Some(d(27.0)));
+        assert_eq!(d(0.5).pown(-3), Some(d(8.0)));
+        assert_eq!(d(3.0).pown(-1), None);
+        assert_eq!(d(2.25).sqrt(), Some(d(1.5)));
+        assert_eq!(d(2.0).sqrt(), None);
+        assert_eq!(d(1.0).add(d(f64::EPSILON)), Some(d(1.0 + f64::EPSILON)));
+        assert_eq!(d(1.0).add(d(-1.0)), None);
+        assert_eq!(d(1.0).add(d(-0.75)), Some(d(0.25)));
+        assert_eq!(d(1.0).add(d(1e-300)), None);
+
+        assert!(d(f64::MAX).fits::<f64>());
+        assert!(!d(f64::MAX).fits::<f32>());
+        assert!(!d(1.0 + f64::EPSILON).fits::<f32>());
+        assert!(d(f64::from_bits(1)).fits::<f64>());
+
+        assert_eq!(Dyadic::cmp_mul(d(3.0), d(0.5), d(1.5), Dyadic::ONE), Ordering::Equal);
+        assert_eq!(Dyadic::cmp_mul(d(3.0), d(-5.0), d(16.0), Dyadic::ONE), Ordering::Less);
+        assert_eq!(
+            Dyadic::cmp_mul(d(f64::MAX), d(f64::MAX), d(1e300), d(1e300)),
+            Ordering::Greater
+        );
+        assert_eq!(d(f64::MIN_POSITIVE).to_f64(), f64::MIN_POSITIVE);
+    }
+
+    #[test]
+    fn isqrt_values() {
+        for n in [0u128, 1, 2, 3, 4, 15, 16, 17, u64::MAX as u128, u128::MAX >> 2] {
+            let s = isqrt(n);
+            assert!(s * s <= n && (s + 1) * (s + 1) > n, "{n}");
+        }
+    }
+
+    #[test]
+    fn exact_powers() {
+        assert!(pow_exact(4.0f64, 0.5));
+        assert!(pow_exact(4.0f64, 1.5));
+        assert!(!pow_exact(8.0f64, 1.5));
+        assert!(pow_exact(3.0f64, 3.0));
+        assert!(pow_exact(2.0f64, -1074.0));
+        assert!(pow_exact(-3.0f64, 2.0));
+        assert!(!pow_exact(2.0f64, -1075.0));
+        assert!(!pow_exact(2.0f64, 0.5));
+        assert!(!pow_exact(3.0f64, -1.0));
+        assert!(!pow_exact(3.0f64, 40.0));
+        assert!(!pow_exact(10.0f64, 1e300));
+    }
+
+    #[test]
+    fn huge_negative_powers() {
+        // `0.25^(2^62)` has an exponent of exactly `i64::MIN`, which cannot be negated.
+        let d = Dyadic::new(0.25f64).unwrap();
+        assert_eq!(d.pown(-(1 << 62)), None);
+
+        let r = super::super::pow(0.25, -2f64.powi(62));
+        assert_eq!(r.val, f64::INFINITY);
+        assert!(r.status.overflow());
+        let r = super::super::pown(0.25, -(1 << 62));
+        assert_eq!(r.val, f64::INFINITY);
+        assert!(r.status.overflow());
+        let r = super::super::compoundn(-0.75, -(1 << 62));
+        assert_eq!(r.val, f64::INFINITY);
+        assert!(r.status.overflow());
+    }
+}
@@ -0,0 +1,175 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! Exception flags for the complex functions.
+
+use super::*;
+use crate::Complex;
+
+/// Flags for a complex result `r` computed from `args`, with the exactness of each part given
+/// separately. Parts that are zero or infinite from infinite arguments are the exact limits of
+/// Annex G, and a NaN part from non-NaN arguments is invalid.
+fn complex<F: Float>(args: Args, r: Complex<F>, pole: bool, exact: (bool, bool)) -> Status {
+    complex_part(args, r.re, pole, exact.0).with(complex_part(args, r.im, pole, exact.1))
+}
+
+fn complex_part<F: Float>(args: Args, r: F, pole: bool, exact: bool) -> Status {
+    let limit = !args.finite && (r.is_infinite() || r == F::ZERO);
+    flags(args, r, pole, exact || limit)
+}
+
+pub fn cabs<F: Float>(z: Complex<F>, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    hypot(z.re, z.im, r)
+}
+
+pub fn cacos<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    // Real arguments from the branch cut at `x > 1` down to `x = -1`.
+    let (x, y) = (z.re, z.im);
+    complex(
+        Args::new(&[x, y]),
+        r,
+        false,
+        (y == F::ZERO && x >= F::ONE, y == F::ZERO && x.abs() <= F::ONE),
+    )
+}
+
+pub fn cacosh<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    complex(
+        Args::new(&[x, y]),
+        r,
+        false,
+        (y == F::ZERO && x.abs() <= F::ONE, y == F::ZERO && x >= F::ONE),
+    )
+}
+
+pub fn carg<F: Float>(z: Complex<F>, r: F) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    atan2(z.im, z.re, r)
+}
+
+pub fn casin<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    complex(Args::new(&[x, y]), r, false, (x == F::ZERO, y == F::ZERO && x.abs() <= F::ONE))
+}
+
+pub fn casinh<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    complex(Args::new(&[x, y]), r, false, (x == F::ZERO && y.abs() <= F::ONE, y == F::ZERO))
+}
+
+pub fn catan<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    // Poles at `±i`.
+    let (x, y) = (z.re, z.im);
+    let pole = x == F::ZERO && y.abs() == F::ONE;
+    complex(Args::new(&[x, y]), r, pole, (x == F::ZERO && y.abs() <= F::ONE, y == F::ZERO))
+}
+
+pub fn catanh<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    // Poles at `±1`.
+    let (x, y) = (z.re, z.im);
+    let pole = y == F::ZERO && x.abs() == F::ONE;
+    complex(Args::new(&[x, y]), r, pole, (x == F::ZERO, y == F::ZERO && x.abs() <= F::ONE))
+}
+
+pub fn ccos<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    let exact = (x == F::ZERO && y == F::ZERO, x == F::ZERO || y == F::ZERO);
+    complex(Args::new(&[x, y]), r, false, exact)
+}
+
+pub fn ccosh<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    let exact = (x == F::ZERO && y == F::ZERO, x == F::ZERO || y == F::ZERO);
+    complex(Args::new(&[x, y]), r, false, exact)
+}
+
+pub fn cexp<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    complex(Args::new(&[x, y]), r, false, (x == F::ZERO && y == F::ZERO, y == F::ZERO))
+}
+
+pub fn clog<F: Float>(z: Complex<F>, r: Complex<F>) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // The real part is `log|z|`, with a pole at zero, and the imaginary part is `carg(z)`.
+    let (x, y) = (z.re, z.im);
+    let pole = x == F::ZERO && y == F::ZERO;
+    let unit = (x.abs() == F::ONE && y == F::ZERO) || (x == F::ZERO && y.abs() == F::ONE);
+    complex_part(Args::new(&[x, y]), r.re, pole, unit).with(atan2(y, x, r.im))
+}
+
+pub fn conj<F: Float>(_z: Complex<F>, _r: Complex<F>) -> Status {
+    Status::OK
+}
+
+pub fn cpow<F: Float>(z: Complex<F>, w: Complex<F>, r: Complex<F>) -> Status {
+    // `cexp(w * clog(z))`, which is exact for `z^0` and `1^w`. A positive real number to a real
+    // power has an exact zero imaginary part.
+    let pole = z.re == F::ZERO && z.im == F::ZERO;
+    let exact = (w.re == F::ZERO && w.im == F::ZERO) || (z.re == F::ONE && z.im == F::ZERO);
+    let real = z.re > F::ZERO && z.im == F::ZERO && w.im == F::ZERO;
+    complex(Args::new(&[z.re, z.im, w.re, w.im]), r, pole, (exact, exact || real))
+}
+
+pub fn cproj<F: Float>(_z: Complex<F>, _r: Complex<F>) -> Status {
+    Status::OK
+}
+
+pub fn csin<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    complex(Args::new(&[x, y]), r, false, (x == F::ZERO, y == F::ZERO))
+}
+
+pub fn csinh<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    let (x, y) = (z.re, z.im);
+    complex(Args::new(&[x, y]), r, false, (x == F::ZERO, y == F::ZERO))
+}
+
+pub fn csqrt<F: Float>(z: Complex<F>, r: Complex<F>) -> Status
+where
+    F::Int: CastInto<u128>,
+{
+    // Exact if `r^2 = z`, which is `re^2 - im^2 = x` and `2 * re * im = y`.
+    let (x, y) = (z.re, z.im);
+    if y == F::ZERO {
+        // One part is zero and the other is the real square root of `|x|`.
+        let exact = match (Dyadic::new(x.abs()), Dyadic::new(r.re + r.im)) {
+            (Some(dx), Some(dr)) => dr.powi(2) == Some(dx),
+            _ => true,
+        };
+        let exact = (exact || r.re == F::ZERO, exact || r.im == F::ZERO);
+        return complex(Args::new(&[x, y]), r, false, exact);
+    }
+
+    let exact = match (Dyadic::new(y), Dyadic::new(r.re), Dyadic::new(r.im)) {
+        (Some(dy), Some(a), Some(b)) => {
+            let two = Dyadic { neg: false, m: 1, e: 1 };
+            let im_ok = a.mul(b).and_then(|ab| ab.mul(two)) == Some(dy);
+            let neg_b2 = b.powi(2).map(|b2| Dyadic { neg: !b2.neg, ..b2 });
+            let re_ok = a.powi(2).zip(neg_b2).and_then(|(a2, nb2)| a2.add(nb2)) == Dyadic::new(x);
+            im_ok && re_ok
+        }
+        // A zero part cannot give a nonzero `y`, and other special values are exact limits.
+        _ => false,
+    };
+    complex(Args::new(&[x, y]), r, false, (exact, exact))
+}
+
+pub fn ctan<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    // `-i * ctanh(i * z)`, which approaches `±i` as the imaginary part grows.
+    let (x, y) = (z.re, z.im);
+    let exact = (x == F::ZERO || y.is_infinite(), y == F::ZERO || y.is_infinite());
+    complex(Args::new(&[x, y]), r, false, exact)
+}
+
+pub fn ctanh<F: Float>(z: Complex<F>, r: Complex<F>) -> Status {
+    // Approaches `±1` as the real part grows.
+    let (x, y) = (z.re, z.im);
+    let exact = (x == F::ZERO || x.is_infinite(), y == F::ZERO || x.is_infinite());
+    complex(Args::new(&[x, y]), r, false, exact)
+}
@@ -0,0 +1,682 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0 */
+//! Versions of the libm functions that report IEEE 754 exceptions.
+//!
+//! Rust has no access to the floating point environment, so the functions at the crate root
+//! cannot raise exception flags. Each function here has the same name and arguments as its
+//! counterpart at the root and returns the same value, along with the [`Status`] flags that the
+//! operation signals under the default rounding mode:
+//!
+//! - `INVALID` for a domain error, such as `sqrt(-1.0)` or `log(-1.0)`, or for a signaling NaN
+//!   argument. A quiet NaN argument propagates without raising anything.
+//! - `DIVIDE_BY_ZERO` for an exact infinite result from finite arguments, such as `log(0.0)`.
+//! - `OVERFLOW` and `INEXACT` for a finite result too large for the type, such as `exp(1000.0)`.
+//! - `UNDERFLOW` for an inexact result that is below the smallest normal number before rounding,
+//!   which is how the `*_round` functions detect tininess too. These are also `INEXACT`.
+//! - `INEXACT` for any other result that is not exact.
+//!
+//! `nextafter` and `nexttoward` follow C and report overflow and underflow when stepping to
+//! infinity or to a subnormal result. The functions that convert to integers (`ilogb`, `lrint`,
+//! and so on) report `INVALID` when there is no integer result, as described by C23. The complex
+//! functions classify each part of the result like a real function, and treat the special values
+//! of C99 Annex G as exact.
+//!
+//! ```
+//! use libm::Status;
+//!
+//! assert_eq!(libm::status::log(0.0).status, Status::DIVIDE_BY_ZERO);
+//! assert_eq!(libm::status::sqrt(-1.0).status, Status::INVALID);
+//!
+//! let res = libm::status::exp(1000.0);
+//! assert_eq!(res.val, f64::INFINITY);
+//! assert!(res.status.overflow());
+//!
+//! // Exact results have no flags.
+//! assert_eq!(libm::status::powf(4.0, 0.5).status, Status::OK);
+//! ```
+//!
+//! [`Status`]: crate::Status
+
+mod classify;
+
+use super::support::{FpResult, Round};
+#[cfg(not(feature = "compiler-builtins"))]
+use crate::Complex;
+
+/// Define wrappers for a list of functions. Most compute their value with the function of the
+/// same name and pass it to a classifier from [`classify`] for the flags. Functions marked
+/// with `=>` are implemented by a classifier that computes both. The functions are looked up in
+/// `super` unless another module is given with `in`.
+macro_rules! with_status {
+    ($($entry:tt;)*) => {
+        with_status! { in super; $($entry;)* }
+    };
+
+    (in $m:tt; $($entry:tt;)*) => {
+        $(
+            with_status! { @fn $m $entry }
+        )*
+    };
+
+    (@fn $m:tt (
+        $(#[$meta:meta])*
+        fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty; $classify:ident
+    )) => {
+        $(#[$meta])*
+        #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+        pub fn $name($($arg: $ty),*) -> FpResult<$ret> {
+            let r = $m::$name($($arg),*);
+            FpResult::new(r, classify::$classify($($arg,)* r))
+        }
+    };
+
+    (@fn $m:tt (
+        $(#[$meta:meta])*
+        fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty; => $classify:ident
+    )) => {
+        $(#[$meta])*
+        #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+        pub fn $name($($arg: $ty),*) -> FpResult<$ret> {
+            classify::$classify($($arg),*)
+        }
+    };
+}
+
+// verify-apilist-start
+with_status! {
+    // verify-sorted-start
+    (#[cfg(f16_enabled)] fn f16addf32(x: f32, y: f32) -> f16; => fadd);
+    (#[cfg(f16_enabled)] fn f16divf32(x: f32, y: f32) -> f16; => fdiv);
+    (#[cfg(f16_enabled)] fn f16fmaf32(x: f32, y: f32, z: f32) -> f16; => ffma);
+    (#[cfg(f16_enabled)] fn f16mulf32(x: f32, y: f32) -> f16; => fmul);
+    (#[cfg(f16_enabled)] fn f16sqrtf32(x: f32) -> f16; => fsqrt);
+    (#[cfg(f16_enabled)] fn f16subf32(x: f32, y: f32) -> f16; => fsub);
+    (fn acosf(x: f32) -> f32;                   acos);
+    (fn acoshf(x: f32) -> f32;                  acosh);
+    (fn acospif(x: f32) -> f32;                 acospi);
+    (fn asinf(x: f32) -> f32;                   asin);
+    (fn asinhf(x: f32) -> f32;                  asinh);
+    (fn asinpif(x: f32) -> f32;                 asinpi);
+    (fn atan2f(y: f32, x: f32) -> f32;          atan2);
+    (fn atan2pif(y: f32, x: f32) -> f32;        atan2pi);
+    (fn atanf(x: f32) -> f32;                   atan);
+    (fn atanhf(x: f32) -> f32;                  atanh);
+    (fn atanpif(x: f32) -> f32;                 atanpi);
+    (fn canonicalizef(x: f32) -> f32;           => canonicalize);
+    (fn cbrtf(x: f32) -> f32;                   cbrt);
+    (fn ceilf(x: f32) -> f32;                   ceil);
+    (fn compoundnf(x: f32, n: i64) -> f32;      compoundn);
+    (fn copysignf(x: f32, y: f32) -> f32;       copysign);
+    (fn cosf(x: f32) -> f32;                    cos);
+    (fn coshf(x: f32) -> f32;                   cosh);
+    (fn cospif(x: f32) -> f32;                  cospi);
+    (fn erfcf(x: f32) -> f32;                   erfc);
+    (fn erff(x: f32) -> f32;                    erf);
+    (fn exp10f(x: f32) -> f32;                  exp10);
+    (fn exp10m1f(x: f32) -> f32;                exp10m1);
+    (fn exp2f(x: f32) -> f32;                   exp2);
+    (fn exp2m1f(x: f32) -> f32;                 exp2m1);
+    (fn expf(x: f32) -> f32;                    exp);
+    (fn expm1f(x: f32) -> f32;                  expm1);
+    (fn fabsf(x: f32) -> f32;                   fabs);
+    (fn fdimf(x: f32, y: f32) -> f32;           fdim);
+    (fn floorf(x: f32) -> f32;                  floor);
+    (fn fmaf(x: f32, y: f32, z: f32) -> f32;    fma);
+    (fn fmaxf(x: f32, y: f32) -> f32;           fmax);
+    (fn fmaximum_mag_numf(x: f32, y: f32) -> f32; fmaximum_mag_num);
+    (fn fmaximum_magf(x: f32, y: f32) -> f32;   fmaximum_mag);
+    (fn fmaximum_numf(x: f32, y: f32) -> f32;   fmaximum_num);
+    (fn fmaximumf(x: f32, y: f32) -> f32;       fmaximum);
+    (fn fminf(x: f32, y: f32) -> f32;           fmin);
+    (fn fminimum_mag_numf(x: f32, y: f32) -> f32; fminimum_mag_num);
+    (fn fminimum_magf(x: f32, y: f32) -> f32;   fminimum_mag);
+    (fn fminimum_numf(x: f32, y: f32) -> f32;   fminimum_num);
+    (fn fminimumf(x: f32, y: f32) -> f32;       fminimum);
+    (fn fmodf(x: f32, y: f32) -> f32;           fmod);
+    (fn frexpf(x: f32) -> (f32, i32);           frexp);
+    (fn fromfpf(x: f32, round: Round, width: u32) -> f32; => fromfp);
+    (fn fromfpxf(x: f32, round: Round, width: u32) -> f32; => fromfpx);
+    (fn getpayloadf(x: f32) -> f32;             getpayload);
+    (fn hypotf(x: f32, y: f32) -> f32;          hypot);
+    (fn ilogbf(x: f32) -> i32;                  ilogb);
+    (fn issignalingf(x: f32) -> i32;            issignaling);
+    (fn j0f(x: f32) -> f32;                     j0);
+    (fn j1f(x: f32) -> f32;                     j1);
+    (fn jnf(n: i32, x: f32) -> f32;             jn);
+    (fn ldexpf(x: f32, n: i32) -> f32;          ldexp);
+    (fn lgammaf(x: f32) -> f32;                 lgamma);
+    (fn lgammaf_r(x: f32) -> (f32, i32);        lgamma_r);
+    (fn llogbf(x: f32) -> i64;                  llogb);
+    (fn llrintf(x: f32) -> i64;                 => llrint);
+    (fn llroundf(x: f32) -> i64;                => llround);
+    (fn log10f(x: f32) -> f32;                  log10);
+    (fn log10p1f(x: f32) -> f32;                log10p1);
+    (fn log1pf(x: f32) -> f32;                  log1p);
+    (fn log2f(x: f32) -> f32;                   log2);
+    (fn log2p1f(x: f32) -> f32;                 log2p1);
+    (fn logbf(x: f32) -> f32;                   logb);
+    (fn logf(x: f32) -> f32;                    log);
+    (fn logp1f(x: f32) -> f32;                  logp1);
+    (fn lrintf(x: f32) -> i64;                  => lrint);
+    (fn lroundf(x: f32) -> i64;                 => lround);
+    (fn modff(x: f32) -> (f32, f32);            modf);
+    (fn nanf(tagp: &str) -> f32;                nan);
+    (fn nearbyintf(x: f32) -> f32;              nearbyint);
+    (fn nextafterf(x: f32, y: f32) -> f32;      nextafter);
+    (fn nextdownf(x: f32) -> f32;               nextdown);
+    (fn nexttowardf(x: f32, y: f64) -> f32;     nexttoward);
+    (fn nextupf(x: f32) -> f32;                 nextup);
+    (fn powf(x: f32, y: f32) -> f32;            pow);
+    (fn pownf(x: f32, n: i64) -> f32;           pown);
+    (fn powrf(x: f32, y: f32) -> f32;           powr);
+    (fn remainderf(x: f32, y: f32) -> f32;      remainder);
+    (fn remquof(x: f32, y: f32) -> (f32, i32);  remquo);
+    (fn rintf(x: f32) -> f32;                   rint);
+    (fn rootnf(x: f32, n: i64) -> f32;          rootn);
+    (fn roundevenf(x: f32) -> f32;              roundeven);
+    (fn roundf(x: f32) -> f32;                  round);
+    (fn rsqrtf(x: f32) -> f32;                  rsqrt);
+    (fn scalblnf(x: f32, n: i64) -> f32;        scalbln);
+    (fn scalbnf(x: f32, n: i32) -> f32;         scalbn);
+    (fn setpayloadf(pl: f32) -> f32;            setpayload);
+    (fn setpayloadsigf(pl: f32) -> f32;         setpayloadsig);
+    (fn sincosf(x: f32) -> (f32, f32);          sincos);
+    (fn sincospif(x: f32) -> (f32, f32);        sincospi);
+    (fn sinf(x: f32) -> f32;                    sin);
+    (fn sinhf(x: f32) -> f32;                   sinh);
+    (fn sinpif(x: f32) -> f32;                  sinpi);
+    (fn sqrtf(x: f32) -> f32;                   sqrt);
+    (fn tanf(x: f32) -> f32;                    tan);
+    (fn tanhf(x: f32) -> f32;                   tanh);
+    (fn tanpif(x: f32) -> f32;                  tanpi);
+    (fn tgammaf(x: f32) -> f32;                 tgamma);
+    (fn totalorderf(x: f32, y: f32) -> i32;     totalorder);
+    (fn totalordermagf(x: f32, y: f32) -> i32;  totalordermag);
+    (fn truncf(x: f32) -> f32;                  trunc);
+    (fn ufromfpf(x: f32, round: Round, width: u32) -> f32; => ufromfp);
+    (fn ufromfpxf(x: f32, round: Round, width: u32) -> f32; => ufromfpx);
+    (fn y0f(x: f32) -> f32;                     y0);
+    (fn y1f(x: f32) -> f32;                     y1);
+    (fn ynf(n: i32, x: f32) -> f32;             yn);
+    // verify-sorted-end
+}
+
+with_status! {
+    // verify-sorted-start
+    (fn acos(x: f64) -> f64;                    acos);
+    (fn acosh(x: f64) -> f64;                   acosh);
+    (fn acospi(x: f64) -> f64;                  acospi);
+    (fn asin(x: f64) -> f64;                    asin);
+    (fn asinh(x: f64) -> f64;                   asinh);
+    (fn asinpi(x: f64) -> f64;                  asinpi);
+    (fn atan(x: f64) -> f64;                    atan);
+    (fn atan2(y: f64, x: f64) -> f64;           atan2);
+    (fn atan2pi(y: f64, x: f64) -> f64;         atan2pi);
+    (fn atanh(x: f64) -> f64;                   atanh);
+    (fn atanpi(x: f64) -> f64;                  atanpi);
+    (fn canonicalize(x: f64) -> f64;            => canonicalize);
+    (fn cbrt(x: f64) -> f64;                    cbrt);
+    (fn ceil(x: f64) -> f64;                    ceil);
+    (fn compoundn(x: f64, n: i64) -> f64;       compoundn);
+    (fn copysign(x: f64, y: f64) -> f64;        copysign);
+    (fn cos(x: f64) -> f64;                     cos);
+    (fn cosh(x: f64) -> f64;                    cosh);
+    (fn cospi(x: f64) -> f64;                   cospi);
+    (fn erf(x: f64) -> f64;                     erf);
+    (fn erfc(x: f64) -> f64;                    erfc);
+    (fn exp(x: f64) -> f64;                     exp);
+    (fn exp10(x: f64) -> f64;                   exp10);
+    (fn exp10m1(x: f64) -> f64;                 exp10m1);
+    (fn exp2(x: f64) -> f64;                    exp2);
+    (fn exp2m1(x: f64) -> f64;                  exp2m1);
+    (fn expm1(x: f64) -> f64;                   expm1);
+    (fn fabs(x: f64) -> f64;                    fabs);
+    (fn fadd(x: f64, y: f64) -> f32;            => fadd);
+    (fn fdim(x: f64, y: f64) -> f64;            fdim);
+    (fn fdiv(x: f64, y: f64) -> f32;            => fdiv);
+    (fn ffma(x: f64, y: f64, z: f64) -> f32;    => ffma);
+    (fn floor(x: f64) -> f64;                   floor);
+    (fn fma(x: f64, y: f64, z: f64) -> f64;     fma);
+    (fn fmax(x: f64, y: f64) -> f64;            fmax);
+    (fn fmaximum(x: f64, y: f64) -> f64;        fmaximum);
+    (fn fmaximum_mag(x: f64, y: f64) -> f64;    fmaximum_mag);
+    (fn fmaximum_mag_num(x: f64, y: f64) -> f64; fmaximum_mag_num);
+    (fn fmaximum_num(x: f64, y: f64) -> f64;    fmaximum_num);
+    (fn fmin(x: f64, y: f64) -> f64;            fmin);
+    (fn fminimum(x: f64, y: f64) -> f64;        fminimum);
+    (fn fminimum_mag(x: f64, y: f64) -> f64;    fminimum_mag);
+    (fn fminimum_mag_num(x: f64, y: f64) -> f64; fminimum_mag_num);
+    (fn fminimum_num(x: f64, y: f64) -> f64;    fminimum_num);
+    (fn fmod(x: f64, y: f64) -> f64;            fmod);
+    (fn fmul(x: f64, y: f64) -> f32;            => fmul);
+    (fn frexp(x: f64) -> (f64, i32);            frexp);
+    (fn fromfp(x: f64, round: Round, width: u32) -> f64; => fromfp);
+    (fn fromfpx(x: f64, round: Round, width: u32) -> f64; => fromfpx);
+    (fn fsqrt(x: f64) -> f32;                   => fsqrt);
+    (fn fsub(x: f64, y: f64) -> f32;            => fsub);
+    (fn getpayload(x: f64) -> f64;              getpayload);
+    (fn hypot(x: f64, y: f64) -> f64;           hypot);
+    (fn ilogb(x: f64) -> i32;                   ilogb);
+    (fn issignaling(x: f64) -> i32;             issignaling);
+    (fn j0(x: f64) -> f64;                      j0);
+    (fn j1(x: f64) -> f64;                      j1);
+    (fn jn(n: i32, x: f64) -> f64;              jn);
+    (fn ldexp(x: f64, n: i32) -> f64;           ldexp);
+    (fn lgamma(x: f64) -> f64;                  lgamma);
+    (fn lgamma_r(x: f64) -> (f64, i32);         lgamma_r);
+    (fn llogb(x: f64) -> i64;                   llogb);
+    (fn llrint(x: f64) -> i64;                  => llrint);
+    (fn llround(x: f64) -> i64;                 => llround);
+    (fn log(x: f64) -> f64;                     log);
+    (fn log10(x: f64) -> f64;                   log10);
+    (fn log10p1(x: f64) -> f64;                 log10p1);
+    (fn log1p(x: f64) -> f64;                   log1p);
+    (fn log2(x: f64) -> f64;                    log2);
+    (fn log2p1(x: f64) -> f64;                  log2p1);
+    (fn logb(x: f64) -> f64;                    logb);
+    (fn logp1(x: f64) -> f64;                   logp1);
+    (fn lrint(x: f64) -> i64;                   => lrint);
+    (fn lround(x: f64) -> i64;                  => lround);
+    (fn modf(x: f64) -> (f64, f64);             modf);
+    (fn nan(tagp: &str) -> f64;                 nan);
+    (fn nearbyint(x: f64) -> f64;               nearbyint);
+    (fn nextafter(x: f64, y: f64) -> f64;       nextafter);
+    (fn nextdown(x: f64) -> f64;                nextdown);
+    (fn nexttoward(x: f64, y: f64) -> f64;      nexttoward);
+    (fn nextup(x: f64) -> f64;                  nextup);
+    (fn pow(x: f64, y: f64) -> f64;             pow);
+    (fn pown(x: f64, n: i64) -> f64;            pown);
+    (fn powr(x: f64, y: f64) -> f64;            powr);
+    (fn remainder(x: f64, y: f64) -> f64;       remainder);
+    (fn remquo(x: f64, y: f64) -> (f64, i32);   remquo);
+    (fn rint(x: f64) -> f64;                    rint);
+    (fn rootn(x: f64, n: i64) -> f64;           rootn);
+    (fn round(x: f64) -> f64;                   round);
+    (fn roundeven(x: f64) -> f64;               roundeven);
+    (fn rsqrt(x: f64) -> f64;                   rsqrt);
+    (fn scalbln(x: f64, n: i64) -> f64;         scalbln);
+    (fn scalbn(x: f64, n: i32) -> f64;          scalbn);
+    (fn setpayload(pl: f64) -> f64;             setpayload);
+    (fn setpayloadsig(pl: f64) -> f64;          setpayloadsig);
+    (fn sin(x: f64) -> f64;                     sin);
+    (fn sincos(x: f64) -> (f64, f64);           sincos);
+    (fn sincospi(x: f64) -> (f64, f64);         sincospi);
+    (fn sinh(x: f64) -> f64;                    sinh);
+    (fn sinpi(x: f64) -> f64;                   sinpi);
+    (fn sqrt(x: f64) -> f64;                    sqrt);
+    (fn tan(x: f64) -> f64;                     tan);
+    (fn tanh(x: f64) -> f64;                    tanh);
+    (fn tanpi(x: f64) -> f64;                   tanpi);
+    (fn tgamma(x: f64) -> f64;                  tgamma);
+    (fn totalorder(x: f64, y: f64) -> i32;      totalorder);
+    (fn totalordermag(x: f64, y: f64) -> i32;   totalordermag);
+    (fn trunc(x: f64) -> f64;                   trunc);
+    (fn ufromfp(x: f64, round: Round, width: u32) -> f64; => ufromfp);
+    (fn ufromfpx(x: f64, round: Round, width: u32) -> f64; => ufromfpx);
+    (fn y0(x: f64) -> f64;                      y0);
+    (fn y1(x: f64) -> f64;                      y1);
+    (fn yn(n: i32, x: f64) -> f64;              yn);
+    // verify-sorted-end
+}
+
+#[cfg(f16_enabled)]
+with_status! {
+    // verify-sorted-start
+    (fn acosf16(x: f16) -> f16;                 acos);
+    (fn acoshf16(x: f16) -> f16;                acosh);
+    (fn acospif16(x: f16) -> f16;               acospi);
+    (fn asinf16(x: f16) -> f16;                 asin);
+    (fn asinhf16(x: f16) -> f16;                asinh);
+    (fn asinpif16(x: f16) -> f16;               asinpi);
+    (fn atan2f16(y: f16, x: f16) -> f16;        atan2);
+    (fn atan2pif16(y: f16, x: f16) -> f16;      atan2pi);
+    (fn atanf16(x: f16) -> f16;                 atan);
+    (fn atanhf16(x: f16) -> f16;                atanh);
+    (fn atanpif16(x: f16) -> f16;               atanpi);
+    (fn canonicalizef16(x: f16) -> f16;         => canonicalize);
+    (fn cbrtf16(x: f16) -> f16;                 cbrt);
+    (fn ceilf16(x: f16) -> f16;                 ceil);
+    (fn copysignf16(x: f16, y: f16) -> f16;     copysign);
+    (fn cosf16(x: f16) -> f16;                  cos);
+    (fn coshf16(x: f16) -> f16;                 cosh);
+    (fn cospif16(x: f16) -> f16;                cospi);
+    (fn erfcf16(x: f16) -> f16;                 erfc);
+    (fn erff16(x: f16) -> f16;                  erf);
+    (fn exp10f16(x: f16) -> f16;                exp10);
+    (fn exp2f16(x: f16) -> f16;                 exp2);
+    (fn expf16(x: f16) -> f16;                  exp);
+    (fn expm1f16(x: f16) -> f16;                expm1);
+    (fn fabsf16(x: f16) -> f16;                 fabs);
+    (fn fdimf16(x: f16, y: f16) -> f16;         fdim);
+    (fn floorf16(x: f16) -> f16;                floor);
+    (fn fmaf16(x: f16, y: f16, z: f16) -> f16;  fma);
+    (fn fmaxf16(x: f16, y: f16) -> f16;         fmax);
+    (fn fmaximum_mag_numf16(x: f16, y: f16) -> f16; fmaximum_mag_num);
+    (fn fmaximum_magf16(x: f16, y: f16) -> f16; fmaximum_mag);
+    (fn fmaximum_numf16(x: f16, y: f16) -> f16; fmaximum_num);
+    (fn fmaximumf16(x: f16, y: f16) -> f16;     fmaximum);
+    (fn fminf16(x: f16, y: f16) -> f16;         fmin);
+    (fn fminimum_mag_numf16(x: f16, y: f16) -> f16; fminimum_mag_num);
+    (fn fminimum_magf16(x: f16, y: f16) -> f16; fminimum_mag);
+    (fn fminimum_numf16(x: f16, y: f16) -> f16; fminimum_num);
+    (fn fminimumf16(x: f16, y: f16) -> f16;     fminimum);
+    (fn fmodf16(x: f16, y: f16) -> f16;         fmod);
+    (fn frexpf16(x: f16) -> (f16, i32);         frexp);
+    (fn fromfpf16(x: f16, round: Round, width: u32) -> f16; => fromfp);
+    (fn fromfpxf16(x: f16, round: Round, width: u32) -> f16; => fromfpx);
+    (fn getpayloadf16(x: f16) -> f16;           getpayload);
+    (fn hypotf16(x: f16, y: f16) -> f16;        hypot);
+    (fn ilogbf16(x: f16) -> i32;                ilogb);
+    (fn issignalingf16(x: f16) -> i32;          issignaling);
+    (fn ldexpf16(x: f16, n: i32) -> f16;        ldexp);
+    (fn lgammaf16(x: f16) -> f16;               lgamma);
+    (fn llogbf16(x: f16) -> i64;                llogb);
+    (fn llrintf16(x: f16) -> i64;               => llrint);
+    (fn llroundf16(x: f16) -> i64;              => llround);
+    (fn log10f16(x: f16) -> f16;                log10);
+    (fn log1pf16(x: f16) -> f16;                log1p);
+    (fn log2f16(x: f16) -> f16;                 log2);
+    (fn logbf16(x: f16) -> f16;                 logb);
+    (fn logf16(x: f16) -> f16;                  log);
+    (fn lrintf16(x: f16) -> i64;                => lrint);
+    (fn lroundf16(x: f16) -> i64;               => lround);
+    (fn modff16(x: f16) -> (f16, f16);          modf);
+    (fn nanf16(tagp: &str) -> f16;              nan);
+    (fn nearbyintf16(x: f16) -> f16;            nearbyint);
+    (fn nextafterf16(x: f16, y: f16) -> f16;    nextafter);
+    (fn nextdownf16(x: f16) -> f16;             nextdown);
+    (fn nextupf16(x: f16) -> f16;               nextup);
+    (fn powf16(x: f16, y: f16) -> f16;          pow);
+    (fn remainderf16(x: f16, y: f16) -> f16;    remainder);
+    (fn remquof16(x: f16, y: f16) -> (f16, i32); remquo);
+    (fn rintf16(x: f16) -> f16;                 rint);
+    (fn roundevenf16(x: f16) -> f16;            roundeven);
+    (fn roundf16(x: f16) -> f16;                round);
+    (fn scalblnf16(x: f16, n: i64) -> f16;      scalbln);
+    (fn scalbnf16(x: f16, n: i32) -> f16;       scalbn);
+    (fn setpayloadf16(pl: f16) -> f16;          setpayload);
+    (fn setpayloadsigf16(pl: f16) -> f16;       setpayloadsig);
+    (fn sincosf16(x: f16) -> (f16, f16);        sincos);
+    (fn sincospif16(x: f16) -> (f16, f16);      sincospi);
+    (fn sinf16(x: f16) -> f16;                  sin);
+    (fn sinhf16(x: f16) -> f16;                 sinh);
+    (fn sinpif16(x: f16) -> f16;                sinpi);
+    (fn sqrtf16(x: f16) -> f16;                 sqrt);
+    (fn tanf16(x: f16) -> f16;                  tan);
+    (fn tanhf16(x: f16) -> f16;                 tanh);
+    (fn tanpif16(x: f16) -> f16;                tanpi);
+    (fn tgammaf16(x: f16) -> f16;               tgamma);
+    (fn totalorderf16(x: f16, y: f16) -> i32;   totalorder);
+    (fn totalordermagf16(x: f16, y: f16) -> i32; totalordermag);
+    (fn truncf16(x: f16) -> f16;                trunc);
+    (fn ufromfpf16(x: f16, round: Round, width: u32) -> f16; => ufromfp);
+    (fn ufromfpxf16(x: f16, round: Round, width: u32) -> f16; => ufromfpx);
+    // verify-sorted-end
+}
+
+#[cfg(f128_enabled)]
+with_status! {
+    // verify-sorted-start
+    (fn acosf128(x: f128) -> f128;              acos);
+    (fn acoshf128(x: f128) -> f128;             acosh);
+    (fn acospif128(x: f128) -> f128;            acospi);
+    (fn asinf128(x: f128) -> f128;              asin);
+    (fn asinhf128(x: f128) -> f128;             asinh);
+    (fn asinpif128(x: f128) -> f128;            asinpi);
+    (fn atan2f128(y: f128, x: f128) -> f128;    atan2);
+    (fn atan2pif128(y: f128, x: f128) -> f128;  atan2pi);
+    (fn atanf128(x: f128) -> f128;              atan);
+    (fn atanhf128(x: f128) -> f128;             atanh);
+    (fn atanpif128(x: f128) -> f128;            atanpi);
+    (fn canonicalizef128(x: f128) -> f128;      => canonicalize);
+    (fn cbrtf128(x: f128) -> f128;              cbrt);
+    (fn ceilf128(x: f128) -> f128;              ceil);
+    (fn copysignf128(x: f128, y: f128) -> f128; copysign);
+    (fn cosf128(x: f128) -> f128;               cos);
+    (fn coshf128(x: f128) -> f128;              cosh);
+    (fn cospif128(x: f128) -> f128;             cospi);
+    (fn erfcf128(x: f128) -> f128;              erfc);
+    (fn erff128(x: f128) -> f128;               erf);
+    (fn exp2f128(x: f128) -> f128;              exp2);
+    (fn expf128(x: f128) -> f128;               exp);
+    (fn expm1f128(x: f128) -> f128;             expm1);
+    (fn f64addf128(x: f128, y: f128) -> f64;    => fadd);
+    (fn f64divf128(x: f128, y: f128) -> f64;    => fdiv);
+    (fn f64fmaf128(x: f128, y: f128, z: f128) -> f64; => ffma);
+    (fn f64mulf128(x: f128, y: f128) -> f64;    => fmul);
+    (fn f64sqrtf128(x: f128) -> f64;            => fsqrt);
+    (fn f64subf128(x: f128, y: f128) -> f64;    => fsub);
+    (fn fabsf128(x: f128) -> f128;              fabs);
+    (fn fdimf128(x: f128, y: f128) -> f128;     fdim);
+    (fn floorf128(x: f128) -> f128;             floor);
+    (fn fmaf128(x: f128, y: f128, z: f128) -> f128; fma);
+    (fn fmaxf128(x: f128, y: f128) -> f128;     fmax);
+    (fn fmaximum_mag_numf128(x: f128, y: f128) -> f128; fmaximum_mag_num);
+    (fn fmaximum_magf128(x: f128, y: f128) -> f128; fmaximum_mag);
+    (fn fmaximum_numf128(x: f128, y: f128) -> f128; fmaximum_num);
+    (fn fmaximumf128(x: f128, y: f128) -> f128; fmaximum);
+    (fn fminf128(x: f128, y: f128) -> f128;     fmin);
+    (fn fminimum_mag_numf128(x: f128, y: f128) -> f128; fminimum_mag_num);
+    (fn fminimum_magf128(x: f128, y: f128) -> f128; fminimum_mag);
+    (fn fminimum_numf128(x: f128, y: f128) -> f128; fminimum_num);
+    (fn fminimumf128(x: f128, y: f128) -> f128; fminimum);
+    (fn fmodf128(x: f128, y: f128) -> f128;     fmod);
+    (fn frexpf128(x: f128) -> (f128, i32);      frexp);
+    (fn fromfpf128(x: f128, round: Round, width: u32) -> f128; => fromfp);
+    (fn fromfpxf128(x: f128, round: Round, width: u32) -> f128; => fromfpx);
+    (fn getpayloadf128(x: f128) -> f128;        getpayload);
+    (fn hypotf128(x: f128, y: f128) -> f128;    hypot);
+    (fn ilogbf128(x: f128) -> i32;              ilogb);
+    (fn issignalingf128(x: f128) -> i32;        issignaling);
+    (fn j0f128(x: f128) -> f128;                j0);
+    (fn j1f128(x: f128) -> f128;                j1);
+    (fn jnf128(n: i32, x: f128) -> f128;        jn);
+    (fn ldexpf128(x: f128, n: i32) -> f128;     ldexp);
+    (fn lgammaf128(x: f128) -> f128;            lgamma);
+    (fn lgammaf128_r(x: f128) -> (f128, i32);   lgamma_r);
+    (fn llogbf128(x: f128) -> i64;              llogb);
+    (fn llrintf128(x: f128) -> i64;             => llrint);
+    (fn llroundf128(x: f128) -> i64;            => llround);
+    (fn log10f128(x: f128) -> f128;             log10);
+    (fn log1pf128(x: f128) -> f128;             log1p);
+    (fn log2f128(x: f128) -> f128;              log2);
+    (fn logbf128(x: f128) -> f128;              logb);
+    (fn logf128(x: f128) -> f128;               log);
+    (fn lrintf128(x: f128) -> i64;              => lrint);
+    (fn lroundf128(x: f128) -> i64;             => lround);
+    (fn modff128(x: f128) -> (f128, f128);      modf);
+    (fn nanf128(tagp: &str) -> f128;            nan);
+    (fn nearbyintf128(x: f128) -> f128;         nearbyint);
+    (fn nextafterf128(x: f128, y: f128) -> f128; nextafter);
+    (fn nextdownf128(x: f128) -> f128;          nextdown);
+    (fn nextupf128(x: f128) -> f128;            nextup);
+    (fn powf128(x: f128, y: f128) -> f128;      pow);
+    (fn remainderf128(x: f128, y: f128) -> f128; remainder);
+    (fn remquof128(x: f128, y: f128) -> (f128, i32); remquo);
+    (fn rintf128(x: f128) -> f128;              rint);
+    (fn roundevenf128(x: f128) -> f128;         roundeven);
+    (fn roundf128(x: f128) -> f128;             round);
+    (fn scalblnf128(x: f128, n: i64) -> f128;   scalbln);
+    (fn scalbnf128(x: f128, n: i32) -> f128;    scalbn);
+    (fn setpayloadf128(pl: f128) -> f128;       setpayload);
+    (fn setpayloadsigf128(pl: f128) -> f128;    setpayloadsig);
+    (fn sincosf128(x: f128) -> (f128, f128);    sincos);
+    (fn sincospif128(x: f128) -> (f128, f128);  sincospi);
+    (fn sinf128(x: f128) -> f128;               sin);
+    (fn sinhf128(x: f128) -> f128;              sinh);
+    (fn sinpif128(x: f128) -> f128;             sinpi);
+    (fn sqrtf128(x: f128) -> f128;              sqrt);
+    (fn tanf128(x: f128) -> f128;               tan);
+    (fn tanhf128(x: f128) -> f128;              tanh);
+    (fn tanpif128(x: f128) -> f128;             tanpi);
+    (fn tgammaf128(x: f128) -> f128;            tgamma);
+    (fn totalorderf128(x: f128, y: f128) -> i32; totalorder);
+    (fn totalordermagf128(x: f128, y: f128) -> i32; totalordermag);
+    (fn truncf128(x: f128) -> f128;             trunc);
+    (fn ufromfpf128(x: f128, round: Round, width: u32) -> f128; => ufromfp);
+    (fn ufromfpxf128(x: f128, round: Round, width: u32) -> f128; => ufromfpx);
+    (fn y0f128(x: f128) -> f128;                y0);
+    (fn y1f128(x: f128) -> f128;                y1);
+    (fn ynf128(n: i32, x: f128) -> f128;        yn);
+    // verify-sorted-end
+}
+// verify-apilist-end
+
+#[cfg(not(feature = "compiler-builtins"))]
+with_status! {
+    in crate;
+    // verify-sorted-start
+    (fn cabs(z: Complex<f64>) -> f64;                       cabs);
+    (fn cabsf(z: Complex<f32>) -> f32;                      cabs);
+    (fn cacos(z: Complex<f64>) -> Complex<f64>;             cacos);
+    (fn cacosf(z: Complex<f32>) -> Complex<f32>;            cacos);
+    (fn cacosh(z: Complex<f64>) -> Complex<f64>;            cacosh);
+    (fn cacoshf(z: Complex<f32>) -> Complex<f32>;           cacosh);
+    (fn carg(z: Complex<f64>) -> f64;                       carg);
+    (fn cargf(z: Complex<f32>) -> f32;                      carg);
+    (fn casin(z: Complex<f64>) -> Complex<f64>;             casin);
+    (fn casinf(z: Complex<f32>) -> Complex<f32>;            casin);
+    (fn casinh(z: Complex<f64>) -> Complex<f64>;            casinh);
+    (fn casinhf(z: Complex<f32>) -> Complex<f32>;           casinh);
+    (fn catan(z: Complex<f64>) -> Complex<f64>;             catan);
+    (fn catanf(z: Complex<f32>) -> Complex<f32>;            catan);
+    (fn catanh(z: Complex<f64>) -> Complex<f64>;            catanh);
+    (fn catanhf(z: Complex<f32>) -> Complex<f32>;           catanh);
+    (fn ccos(z: Complex<f64>) -> Complex<f64>;              ccos);
+    (fn ccosf(z: Complex<f32>) -> Complex<f32>;             ccos);
+    (fn ccosh(z: Complex<f64>) -> Complex<f64>;             ccosh);
+    (fn ccoshf(z: Complex<f32>) -> Complex<f32>;            ccosh);
+    (fn cexp(z: Complex<f64>) -> Complex<f64>;              cexp);
+    (fn cexpf(z: Complex<f32>) -> Complex<f32>;             cexp);
+    (fn clog(z: Complex<f64>) -> Complex<f64>;              clog);
+    (fn clogf(z: Complex<f32>) -> Complex<f32>;             clog);
+    (fn conj(z: Complex<f64>) -> Complex<f64>;              conj);
+    (fn conjf(z: Complex<f32>) -> Complex<f32>;             conj);
+    (fn cpow(z: Complex<f64>, w: Complex<f64>) -> Complex<f64>; cpow);
+    (fn cpowf(z: Complex<f32>, w: Complex<f32>) -> Complex<f32>; cpow);
+    (fn cproj(z: Complex<f64>) -> Complex<f64>;             cproj);
+    (fn cprojf(z: Complex<f32>) -> Complex<f32>;            cproj);
+    (fn csin(z: Complex<f64>) -> Complex<f64>;              csin);
+    (fn csinf(z: Complex<f32>) -> Complex<f32>;             csin);
+    (fn csinh(z: Complex<f64>) -> Complex<f64>;             csinh);
+    (fn csinhf(z: Complex<f32>) -> Complex<f32>;            csinh);
+    (fn csqrt(z: Complex<f64>) -> Complex<f64>;             csqrt);
+    (fn csqrtf(z: Complex<f32>) -> Complex<f32>;            csqrt);
+    (fn ctan(z: Complex<f64>) -> Complex<f64>;              ctan);
+    (fn ctanf(z: Complex<f32>) -> Complex<f32>;             ctan);
+    (fn ctanh(z: Complex<f64>) -> Complex<f64>;             ctanh);
+    (fn ctanhf(z: Complex<f32>) -> Complex<f32>;            ctanh);
+    // verify-sorted-end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::Status;
+
+    #[test]
+    fn spot_checks() {
+        assert_eq!(log(0.0).status, Status::DIVIDE_BY_ZERO);
+        assert_eq!(log(-1.0).status, Status::INVALID);
+        assert_eq!(log(1.0).status, Status::OK);
+        assert_eq!(log(2.0).status, Status::INEXACT);
+        assert_eq!(log(f64::NAN).status, Status::OK);
+        assert_eq!(exp(1000.0).status, Status::OVERFLOW.with(Status::INEXACT));
+        assert_eq!(exp(-1000.0).status, Status::UNDERFLOW.with(Status::INEXACT));
+        assert_eq!(sqrt(-1.0).status, Status::INVALID);
+        assert_eq!(sqrt(2.25).status, Status::OK);
+        assert_eq!(sqrtf(2.0).status, Status::INEXACT);
+        assert_eq!(pow(4.0, 0.5).status, Status::OK);
+        assert_eq!(pow(0.0, -1.0).status, Status::DIVIDE_BY_ZERO);
+        assert_eq!(pow(-8.0, 1.0 / 3.0).status, Status::INVALID);
+        assert_eq!(powf(3.0, 3.0).status, Status::OK);
+        assert_eq!(powf(10.0, 0.5).status, Status::INEXACT);
+        assert_eq!(hypot(3.0, 4.0).status, Status::OK);
+        assert_eq!(hypot(1.0, 1.0).status, Status::INEXACT);
+        assert_eq!(cbrt(-27.0).status, Status::OK);
+        assert_eq!(exp10(3.0).status, Status::OK);
+        assert_eq!(exp10f(-1.0).status, Status::INEXACT);
+        assert_eq!(log10(1000.0).status, Status::OK);
+        assert_eq!(log2p1(3.0).status, Status::OK);
+        assert_eq!(compoundn(1.0, i64::MAX).status, Status::OVERFLOW.with(Status::INEXACT));
+        assert_eq!(tgamma(5.0).status, Status::OK);
+        assert_eq!(tgamma(0.0).status, Status::DIVIDE_BY_ZERO);
+        assert_eq!(tanpi(0.5).status, Status::DIVIDE_BY_ZERO);
+        assert_eq!(fmod(1.0, 0.0).status, Status::INVALID);
+        assert_eq!(fma(0.1, 0.1, 0.0).status, Status::INEXACT);
+        assert_eq!(fma(f64::MIN_POSITIVE, 0.5, 0.0).status, Status::OK);
+        assert_eq!(scalbn(1.0, -1074).status, Status::OK);
+        assert_eq!(scalbn(3.0, -1075).status, Status::UNDERFLOW.with(Status::INEXACT));
+        assert_eq!(ilogb(0.0).status, Status::INVALID);
+        assert_eq!(lrint(f64::NAN).status, Status::INVALID);
+        assert_eq!(
+            nextafter(f64::MAX, f64::INFINITY).status,
+            Status::OVERFLOW.with(Status::INEXACT)
+        );
+    }
+
+    #[test]
+    fn tininess_before_rounding() {
+        // Results that round up to the smallest normal number are tiny.
+        let underflow = Status::UNDERFLOW.with(Status::INEXACT);
+        let below = 1.0 - f64::EPSILON / 2.0;
+        assert_eq!(fma(f64::MIN_POSITIVE, below, 0.0).status, underflow);
+        assert_eq!(fmaf(f32::MIN_POSITIVE, 1.0 - f32::EPSILON / 2.0, 0.0).status, underflow);
+        assert_eq!(scalbn(below, -1022).status, underflow);
+        assert_eq!(sin(f64::MIN_POSITIVE).status, underflow);
+        assert_eq!(expm1(-f64::MIN_POSITIVE).status, underflow);
+        assert_eq!(hypotf(f32::from_bits(0xfff), f32::from_bits(0x7fffff)).status, underflow);
+
+        // Results that round down to it are not.
+        assert_eq!(fma(f64::from_bits(0x10000000000001), below, 0.0).status, Status::INEXACT);
+        assert_eq!(tan(f64::MIN_POSITIVE).status, Status::INEXACT);
+        assert_eq!(expm1(f64::MIN_POSITIVE).status, Status::INEXACT);
+    }
+
+    #[test]
+    fn complex() {
+        let c = Complex::new;
+        let inexact = Status::INEXACT;
+
+        assert_eq!(cexp(c(0.0, 0.0)).status, Status::OK);
+        assert_eq!(cexp(c(1.0, 0.0)).status, inexact);
+        assert_eq!(cexp(c(1000.0, 0.0)).status, Status::OVERFLOW.with(inexact));
+        assert_eq!(cexp(c(-1000.0, 1.0)).status, Status::UNDERFLOW.with(inexact));
+        assert_eq!(cexp(c(f64::NEG_INFINITY, 1.0)).status, Status::OK);
+        assert_eq!(cexp(c(1.0, f64::INFINITY)).status, Status::INVALID);
+        assert_eq!(cexp(c(f64::NAN, 1.0)).status, Status::OK);
+        assert_eq!(clog(c(0.0, 0.0)).status, Status::DIVIDE_BY_ZERO);
+        assert_eq!(clog(c(-0.0, 0.0)).status, Status::DIVIDE_BY_ZERO.with(inexact));
+        assert_eq!(clog(c(1.0, 0.0)).status, Status::OK);
+        assert_eq!(clog(c(0.0, 1.0)).status, inexact);
+        assert_eq!(csqrt(c(-4.0, 0.0)).status, Status::OK);
+        assert_eq!(csqrt(c(3.0, 4.0)).status, Status::OK);
+        assert_eq!(csqrtf(Complex::new(2.0, 0.0)).status, inexact);
+        assert_eq!(csqrt(c(1.0, 1.0)).status, inexact);
+        assert_eq!(cabs(c(3.0, 4.0)).status, Status::OK);
+        assert_eq!(cargf(Complex::new(1.0, 1.0)).status, inexact);
+        assert_eq!(catanh(c(1.0, 0.0)).status, Status::DIVIDE_BY_ZERO);
+        assert_eq!(catan(c(0.0, 0.5)).status, inexact);
+        assert_eq!(cacos(c(1.0, 0.0)).status, Status::OK);
+        assert_eq!(casinh(c(0.0, 0.0)).status, Status::OK);
+        assert_eq!(ccosh(c(0.0, 0.0)).status, Status::OK);
+        assert_eq!(ccosh(c(f64::INFINITY, f64::INFINITY)).status, Status::INVALID);
+        assert_eq!(csinh(c(1000.0, 1.0)).status, Status::OVERFLOW.with(inexact));
+        assert_eq!(ctanh(c(f64::INFINITY, 1.0)).status, Status::OK);
+        assert_eq!(ctan(c(1.0, 0.0)).status, inexact);
+        assert_eq!(cpow(c(2.0, 0.0), c(0.0, 0.0)).status, Status::OK);
+        assert_eq!(cpow(c(2.0, 0.0), c(0.5, 0.0)).status, inexact);
+        assert_eq!(conj(c(f64::NAN, 1.0)).status, Status::OK);
+        assert_eq!(cproj(c(f64::INFINITY, f64::NAN)).status, Status::OK);
+    }
+
+    #[test]
+    fn signaling_nan() {
+        let snan = f64::from_bits(0x7ff4000000000000);
+        assert_eq!(sin(snan).status, Status::INVALID);
+        assert_eq!(fmax(snan, 1.0).status, Status::INVALID);
+        assert_eq!(sqrt(snan).status, Status::INVALID);
+        assert_eq!(fadd(snan, 1.0).status, Status::INVALID);
+        assert_eq!(copysign(snan, 1.0).status, Status::OK);
+        assert_eq!(sin(f64::NAN).status, Status::OK);
+    }
+}
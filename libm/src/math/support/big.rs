@@ -25,6 +25,11 @@ impl u256 {
     pub fn signed(self) -> i256 {
         i256 { lo: self.lo, hi: self.hi }
     }
+
+    /// The number of leading zeros in the binary representation.
+    pub fn leading_zeros(self) -> u32 {
+        if self.hi == 0 { 128 + self.lo.leading_zeros() } else { self.hi.leading_zeros() }
+    }
 }
 
 /// A 256-bit signed integer represented as two 128-bit native-endian limbs.
@@ -90,14 +95,6 @@ macro_rules! impl_common {
                 self
             }
         }
-
-        impl ops::Shl<u32> for $ty {
-            type Output = Self;
-
-            fn shl(self, _rhs: u32) -> Self::Output {
-                unimplemented!("only used to meet trait bounds")
-            }
-        }
     };
 }
 
@@ -115,6 +112,55 @@ impl ops::Add<Self> for u256 {
     }
 }
 
+impl ops::Sub<Self> for u256 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let hi = self.hi.wrapping_sub(borrow as u128).wrapping_sub(rhs.hi);
+
+        Self { lo, hi }
+    }
+}
+
+impl ops::Shl<u32> for u256 {
+    type Output = Self;
+
+    fn shl(mut self, rhs: u32) -> Self::Output {
+        debug_assert!(rhs < Self::BITS, "attempted to shift left with overflow");
+        if rhs >= Self::BITS {
+            return Self::ZERO;
+        }
+
+        if rhs == 0 {
+            return self;
+        }
+
+        if rhs < 128 {
+            self.hi <<= rhs;
+            self.hi |= self.lo >> (128 - rhs);
+        } else {
+            self.hi = self.lo << (rhs - 128);
+        }
+
+        if rhs < 128 {
+            self.lo <<= rhs;
+        } else {
+            self.lo = 0;
+        }
+
+        self
+    }
+}
+
+impl ops::Shl<u32> for i256 {
+    type Output = Self;
+
+    fn shl(self, _rhs: u32) -> Self::Output {
+        unimplemented!("only used to meet trait bounds")
+    }
+}
+
 impl ops::Shr<u32> for u256 {
     type Output = Self;
 
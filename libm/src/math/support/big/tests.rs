@@ -147,3 +147,78 @@ fn shr_u256_overflow() {
     assert_eq!(u256::MAX >> 257, u256::ZERO);
     assert_eq!(u256::MAX >> u32::MAX, u256::ZERO);
 }
+
+#[test]
+fn sub_u256() {
+    assert_eq!(u256::ZERO - u256::ONE, u256::MAX);
+    assert_eq!(u256::MAX - u256::MAX, u256::ZERO);
+    assert_eq!(u256 { lo: 0, hi: 1 } - u256::ONE, u256 { lo: u128::MAX, hi: 0 });
+    assert_eq!(
+        u256 { lo: 5, hi: LOHI_SPLIT } - u256 { lo: 7, hi: 1 },
+        u256 { lo: u128::MAX - 1, hi: LOHI_SPLIT - 2 }
+    );
+}
+
+#[test]
+fn shl_u256() {
+    let only_low = [1, u16::MAX.into(), u32::MAX.into(), u64::MAX.into(), u128::MAX];
+    let mut has_errors = false;
+
+    let mut add_error = |a, b, expected, actual| {
+        has_errors = true;
+        eprintln!(
+            "\
+            FAILURE:  {} << {b}\n\
+            expected: {}\n\
+            actual:   {}\
+            ",
+            hexu(a),
+            hexu(expected),
+            hexu(actual),
+        );
+    };
+
+    for a in only_low {
+        for perturb in 0..10 {
+            let a = a.saturating_add(perturb);
+            for shift in 0..128 {
+                let res = a.widen() << shift;
+                let expected = a.widen_mul(1 << shift);
+                if res != expected {
+                    add_error(a.widen(), shift, expected, res);
+                }
+            }
+        }
+    }
+
+    let check = [
+        (u256::MAX, 1, u256 { lo: u128::MAX << 1, hi: u128::MAX }),
+        (u256::MAX, 127, u256 { lo: 1 << 127, hi: u128::MAX }),
+        (u256::MAX, 128, u256 { lo: 0, hi: u128::MAX }),
+        (u256::MAX, 129, u256 { lo: 0, hi: u128::MAX << 1 }),
+        (u256::MAX, 255, u256 { lo: 0, hi: 1 << 127 }),
+        (
+            u256 { hi: 0, lo: LOHI_SPLIT },
+            64,
+            u256 { lo: 0xffffffffffffffff0000000000000000, hi: 0xaaaaaaaaaaaaaaaa },
+        ),
+    ];
+
+    for (input, shift, expected) in check {
+        let res = input << shift;
+        if res != expected {
+            add_error(input, shift, expected, res);
+        }
+    }
+
+    assert!(!has_errors);
+}
+
+#[test]
+fn leading_zeros_u256() {
+    assert_eq!(u256::ZERO.leading_zeros(), 256);
+    assert_eq!(u256::ONE.leading_zeros(), 255);
+    assert_eq!(u256::MAX.leading_zeros(), 0);
+    assert_eq!(u256 { lo: u128::MAX, hi: 0 }.leading_zeros(), 128);
+    assert_eq!(u256 { lo: 0, hi: 1 }.leading_zeros(), 127);
+}
@@ -12,9 +12,11 @@
 //! operations, but there is no plan to add this at the current time.
 
 /// A value combined with a floating point status.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct FpResult<T> {
+    /// The result of the operation.
     pub val: T,
-    #[cfg_attr(not(feature = "unstable-public-internals"), allow(dead_code))]
+    /// Exceptions raised while computing `val`.
     pub status: Status,
 }
 
@@ -29,11 +31,11 @@ impl<T> FpResult<T> {
     }
 }
 
-/// IEEE 754 rounding mode, excluding the optional `roundTiesToAway` version of nearest.
+/// IEEE 754 rounding mode.
 ///
-/// Integer representation comes from what CORE-MATH uses for indexing.
-#[cfg_attr(not(feature = "unstable-public-internals"), allow(dead_code))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// Integer representation of the first four modes comes from what CORE-MATH uses for indexing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Round {
     /// IEEE 754 nearest, `roundTiesToEven`.
     Nearest = 0,
@@ -43,6 +45,8 @@ pub enum Round {
     Positive = 2,
     /// IEEE 754 `roundTowardZero`.
     Zero = 3,
+    /// IEEE 754 nearest, `roundTiesToAway`.
+    NearestAway = 4,
 }
 
 /// IEEE 754 exception status flags.
@@ -70,7 +74,6 @@ impl Status {
     /// The default result for division is +/-inf based on operand sign. For `logB`, the default
     /// result is -inf.
     /// `x / y` when `x != 0.0` and `y == 0.0`,
-    #[cfg_attr(not(feature = "unstable-public-internals"), allow(dead_code))]
     pub const DIVIDE_BY_ZERO: Self = Self(1 << 2);
 
     /// The result exceeds the maximum finite value.
@@ -78,7 +81,6 @@ impl Status {
     /// The default result depends on rounding mode. `Nearest*` rounds to +/- infinity, sign based
     /// on the intermediate result. `Zero` rounds to the signed maximum finite. `Positive` and
     /// `Negative` round to signed maximum finite in one direction, signed infinity in the other.
-    #[cfg_attr(not(feature = "unstable-public-internals"), allow(dead_code))]
     pub const OVERFLOW: Self = Self(1 << 3);
 
     /// The result is subnormal and lost precision.
@@ -88,14 +90,22 @@ impl Status {
     /// is not represented by one of the other flags.
     pub const INEXACT: Self = Self(1 << 5);
 
+    /// True if `INVALID` is set.
+    pub const fn invalid(self) -> bool {
+        self.0 & Self::INVALID.0 != 0
+    }
+
+    /// True if `DIVIDE_BY_ZERO` is set.
+    pub const fn divide_by_zero(self) -> bool {
+        self.0 & Self::DIVIDE_BY_ZERO.0 != 0
+    }
+
     /// True if `UNDERFLOW` is set.
-    #[cfg_attr(not(feature = "unstable-public-internals"), allow(dead_code))]
     pub const fn underflow(self) -> bool {
         self.0 & Self::UNDERFLOW.0 != 0
     }
 
     /// True if `OVERFLOW` is set.
-    #[cfg_attr(not(feature = "unstable-public-internals"), allow(dead_code))]
     pub const fn overflow(self) -> bool {
         self.0 & Self::OVERFLOW.0 != 0
     }
@@ -89,7 +89,7 @@ pub const fn parse_any(
                 (true, Round::Positive) => Round::Negative,
                 (true, Round::Negative) => Round::Positive,
                 // rounding toward nearest or zero are symmetric
-                (true, Round::Nearest | Round::Zero) | (false, _) => round,
+                (true, Round::Nearest | Round::Zero | Round::NearestAway) | (false, _) => round,
             };
             match parse_finite(rest, bits, sig_bits, round) {
                 Err(e) => return Err(e),
@@ -176,7 +176,7 @@ const fn parse_finite(
             // overflow to infinity
             status = status.with(Status::OVERFLOW).with(Status::INEXACT);
             match rounding_mode {
-                Round::Positive | Round::Nearest => inf,
+                Round::Positive | Round::Nearest | Round::NearestAway => inf,
                 Round::Negative | Round::Zero => inf - 1,
             }
         }
@@ -205,6 +205,8 @@ const fn shr2_round(mut x: u128, round: Round) -> u128 {
     match round {
         // Look-up-table on the last three bits for when to round up
         Round::Nearest => x + ((0b11001000_u8 >> t) & 1) as u128,
+        // Round up if the dropped bits are at least half
+        Round::NearestAway => x + (t & 0b10 != 0) as u128,
 
         Round::Negative => x,
         Round::Zero => x,
@@ -443,6 +445,19 @@ impl fmt::LowerHex for Hexf<i32> {
     }
 }
 
+impl fmt::LowerHex for Hexf<i64> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        cfg_if! {
+            if #[cfg(feature = "compiler-builtins")] {
+                let _ = f;
+                unimplemented!()
+            } else {
+                fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+    }
+}
+
 impl<T> fmt::Debug for Hexf<T>
 where
     Hexf<T>: fmt::LowerHex,
@@ -488,6 +503,7 @@ mod parse_tests {
         let (xu, s1) = parse_any(s, 16, 10, Round::Positive)?;
         let (xz, s2) = parse_any(s, 16, 10, Round::Zero)?;
         let (xn, s3) = parse_any(s, 16, 10, Round::Nearest)?;
+        let (xa, s4) = parse_any(s, 16, 10, Round::NearestAway)?;
 
         // FIXME: A value between the least normal and largest subnormal
         // could have underflow status depend on rounding mode.
@@ -497,17 +513,20 @@ mod parse_tests {
             assert_eq!(s0, s1);
             assert_eq!(s0, s2);
             assert_eq!(s0, s3);
+            assert_eq!(s0, s4);
 
             assert_eq!(xd, xu);
             assert_eq!(xd, xz);
             assert_eq!(xd, xn);
+            assert_eq!(xd, xa);
         } else {
-            assert!([s0, s1, s2, s3].into_iter().all(Status::inexact));
+            assert!([s0, s1, s2, s3, s4].into_iter().all(Status::inexact));
 
             let xd = f16::from_bits(xd as u16);
             let xu = f16::from_bits(xu as u16);
             let xz = f16::from_bits(xz as u16);
             let xn = f16::from_bits(xn as u16);
+            let xa = f16::from_bits(xa as u16);
 
             assert_biteq!(xd.next_up(), xu, "s={s}, xd={xd:?}, xu={xu:?}");
 
@@ -523,6 +542,13 @@ mod parse_tests {
             if xn.to_bits() != xd.to_bits() {
                 assert_biteq!(xn, xu);
             }
+
+            // Ties away from zero only differs from ties to even when `xn` was rounded toward
+            // zero, in which case the result is the other neighbor.
+            if xa.to_bits() != xn.to_bits() {
+                assert_biteq!(xa, if xn.to_bits() == xd.to_bits() { xu } else { xd });
+                assert_biteq!(xn, xz);
+            }
         }
         Ok(())
     }
@@ -449,3 +449,10 @@ cast_into_float!(i16);
 cast_into_float!(i32);
 cast_into_float!(i64);
 cast_into_float!(i128);
+
+#[cfg(f16_enabled)]
+cast_into_float!(f16);
+cast_into_float!(f32);
+cast_into_float!(f64);
+#[cfg(f128_enabled)]
+cast_into_float!(f128);
@@ -5,6 +5,8 @@ mod env;
 mod float_traits;
 pub mod hex_float;
 mod int_traits;
+#[cfg(f128_enabled)]
+mod wide_float;
 
 #[allow(unused_imports)]
 pub use big::{i256, u256};
@@ -21,6 +23,8 @@ pub use hex_float::hf128;
 #[allow(unused_imports)]
 pub use hex_float::{Hexf, hf32, hf64};
 pub use int_traits::{CastFrom, CastInto, DInt, HInt, Int, MinInt};
+#[cfg(f128_enabled)]
+pub use wide_float::WideFloat;
 
 /// Hint to the compiler that the current path is cold.
 pub fn cold_path() {
@@ -0,0 +1,496 @@
+//! A software float with a 256-bit significand, used as an intermediate type for `f128`
+//! routines that need more precision than `f128` provides.
+
+#[cfg(test)]
+mod tests;
+
+use core::cmp::Ordering;
+use core::ops;
+
+use super::{Float, HInt, MinInt, u256};
+
+/// A binary float with a 256-bit significand and an `i32` exponent.
+///
+/// The value is `(-1)^neg * sig * 2^(exp - 255)`. Nonzero values are normalized so that the
+/// top bit of `sig` is set, and zero is represented by `sig == 0`. There are no infinities or
+/// NaNs; callers are expected to handle those before converting.
+///
+/// Addition, subtraction and multiplication truncate toward zero so each has an error below one
+/// unit in the last place, about `2^-255` relative. Division is computed from a reciprocal and is
+/// accurate to a few units in the last place. This is far more than needed for results that are
+/// rounded to `f128`, so a handful of operations can be chained without care.
+#[derive(Clone, Copy, Debug)]
+pub struct WideFloat {
+    neg: bool,
+    exp: i32,
+    sig: u256,
+}
+
+impl WideFloat {
+    pub const ZERO: Self = Self { neg: false, exp: 0, sig: u256::ZERO };
+    pub const ONE: Self = Self::from_parts(false, 0, 1 << 127, 0);
+    pub const TWO: Self = Self::from_parts(false, 1, 1 << 127, 0);
+
+    /// `ln(2)`
+    pub const LN2: Self = Self::from_parts(
+        false,
+        -1,
+        0xb17217f7d1cf79abc9e3b39803f2f6af,
+        0x40f343267298b62d8a0d175b8baafa2c,
+    );
+    /// π
+    pub const PI: Self = Self::from_parts(
+        false,
+        1,
+        0xc90fdaa22168c234c4c6628b80dc1cd1,
+        0x29024e088a67cc74020bbea63b139b22,
+    );
+    /// π/2
+    pub const FRAC_PI_2: Self = Self::from_parts(
+        false,
+        0,
+        0xc90fdaa22168c234c4c6628b80dc1cd1,
+        0x29024e088a67cc74020bbea63b139b22,
+    );
+    /// `sqrt(2)`
+    pub const SQRT_2: Self = Self::from_parts(
+        false,
+        0,
+        0xb504f333f9de6484597d89b3754abe9f,
+        0x1d6f60ba893ba84ced17ac8583339915,
+    );
+    /// `log2(e)`
+    pub const LOG2_E: Self = Self::from_parts(
+        false,
+        0,
+        0xb8aa3b295c17f0bbbe87fed0691d3e88,
+        0xeb577aa8dd695a588b25166cd1a13248,
+    );
+    /// `log10(e)`
+    pub const LOG10_E: Self = Self::from_parts(
+        false,
+        -2,
+        0xde5bd8a937287195355baaafad33dc32,
+        0x3ee3460245c9a2023a3f2d44f78ea53c,
+    );
+    /// `log10(2)`
+    pub const LOG10_2: Self = Self::from_parts(
+        false,
+        -2,
+        0x9a209a84fbcff7988f8959ac0b7c9178,
+        0x26ad30c543d1f3498a5e6f26b7cc63cb,
+    );
+
+    /// `2/sqrt(π)`
+    pub const FRAC_2_SQRT_PI: Self = Self::from_parts(
+        false,
+        0,
+        0x906eba8214db688d71d48a7f6bfec344,
+        0x1409a0ebac3e751739a15830cce620b1,
+    );
+    /// 2/π
+    pub const FRAC_2_PI: Self = Self::from_parts(
+        false,
+        -1,
+        0xa2f9836e4e441529fc2757d1f534ddc0,
+        0xdb6295993c439041fe5163abdebbc562,
+    );
+    /// `ln(π)`
+    pub const LN_PI: Self = Self::from_parts(
+        false,
+        0,
+        0x928682473d0de85eafcab635421fa4cc,
+        0x2f36edf9d9f9303719c0176577d49847,
+    );
+    /// The Euler–Mascheroni constant γ
+    pub const EULER_GAMMA: Self = Self::from_parts(
+        false,
+        -1,
+        0x93c467e37db0c7a4d1be3f810152cb56,
+        0xa1cecc3af65cc0190c03df34709affbe,
+    );
+
+    /// Construct from a sign, an unbiased exponent, and the high and low halves of a normalized
+    /// significand.
+    pub const fn from_parts(neg: bool, exp: i32, hi: u128, lo: u128) -> Self {
+        assert!(hi >> 127 == 1, "significand must be normalized");
+        Self { neg, exp, sig: u256 { lo, hi } }
+    }
+
+    /// The value `(-1)^neg * m * 2^e`.
+    fn from_u128(neg: bool, m: u128, e: i32) -> Self {
+        if m == 0 {
+            return Self { neg, ..Self::ZERO };
+        }
+
+        let lz = m.leading_zeros();
+        Self { neg, exp: e + 127 - lz as i32, sig: u256 { lo: 0, hi: m << lz } }
+    }
+
+    /// The value `(-1)^neg * m * 2^e`, truncated to 256 bits if needed.
+    pub fn from_u256(neg: bool, m: u256, e: i32) -> Self {
+        Self { neg, ..Self::normalize(neg, e + 255, m) }
+    }
+
+    /// Exact conversion from an integer.
+    pub fn from_i32(i: i32) -> Self {
+        Self::from_u128(i < 0, i.unsigned_abs().into(), 0)
+    }
+
+    /// Exact conversion from an integer.
+    pub fn from_i128(i: i128) -> Self {
+        Self::from_u128(i < 0, i.unsigned_abs(), 0)
+    }
+
+    /// Exact conversion from a finite `f128`.
+    pub fn from_f128(x: f128) -> Self {
+        debug_assert!(x.is_finite());
+        let bits = x.to_bits();
+        let neg = x.is_sign_negative();
+        let biased = x.ex();
+        let frac = bits & f128::SIG_MASK;
+
+        if biased == 0 {
+            // Subnormal or zero
+            Self::from_u128(neg, frac, f128::EXP_MIN_SUBNORM)
+        } else {
+            let m = frac | (1 << f128::SIG_BITS);
+            Self::from_u128(neg, m, biased as i32 - f128::EXP_BIAS as i32 - f128::SIG_BITS as i32)
+        }
+    }
+
+    /// Exact conversion from a finite `f64`.
+    pub fn from_f64(x: f64) -> Self {
+        debug_assert!(x.is_finite());
+        let neg = x.is_sign_negative();
+        let biased = x.ex();
+        let frac = x.to_bits() & f64::SIG_MASK;
+
+        if biased == 0 {
+            Self::from_u128(neg, frac.into(), f64::EXP_MIN_SUBNORM)
+        } else {
+            let m = frac | (1 << f64::SIG_BITS);
+            Self::from_u128(
+                neg,
+                m.into(),
+                biased as i32 - f64::EXP_BIAS as i32 - f64::SIG_BITS as i32,
+            )
+        }
+    }
+
+    /// Round to the nearest `f128`, ties to even. Values out of range become infinity or zero.
+    pub fn to_f128(self) -> f128 {
+        let sign = if self.neg { <f128 as Float>::SIGN_MASK } else { 0 };
+        if self.is_zero() {
+            return f128::from_bits(sign);
+        }
+
+        if self.exp > f128::EXP_MAX {
+            return f128::from_bits(sign | f128::INFINITY.to_bits());
+        }
+
+        // Number of bits to discard from the 256-bit significand, and the biased exponent minus
+        // one. The implicit bit of `m` carries into the exponent, which also takes care of
+        // rounding up to the next binade or to infinity.
+        let (shift, exp_bits) = if self.exp >= f128::EXP_MIN {
+            (256 - (f128::SIG_BITS + 1), (self.exp + f128::EXP_MAX - 1) as u128)
+        } else {
+            let extra = (f128::EXP_MIN - self.exp) as u32;
+            if extra > f128::SIG_BITS + 1 {
+                // Below half of the smallest subnormal
+                return f128::from_bits(sign);
+            }
+            (256 - (f128::SIG_BITS + 1) + extra, 0)
+        };
+
+        let q = self.sig >> (shift - 1);
+        let round = q.lo & 1 != 0;
+        let sticky = self.sig << (257 - shift) != u256::ZERO;
+        let mut m = (q >> 1).lo;
+        if round && (sticky || m & 1 != 0) {
+            m += 1;
+        }
+
+        f128::from_bits(sign | ((exp_bits << f128::SIG_BITS) + m))
+    }
+
+    /// Round to the nearest integer, ties away from zero. The magnitude must be below `2^30`.
+    pub fn round_int(self) -> i32 {
+        if self.is_zero() || self.exp < -1 {
+            return 0;
+        }
+
+        debug_assert!(self.exp < 30);
+        let half_units = (self.sig >> (254 - self.exp) as u32).lo as i32;
+        let n = (half_units + 1) >> 1;
+        if self.neg { -n } else { n }
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.sig == u256::ZERO
+    }
+
+    /// The unbiased exponent, i.e. `floor(log2(|self|))`. Not meaningful for zero.
+    pub fn exp(self) -> i32 {
+        self.exp
+    }
+
+    pub fn is_sign_negative(self) -> bool {
+        self.neg
+    }
+
+    pub fn abs(self) -> Self {
+        Self { neg: false, ..self }
+    }
+
+    /// Multiply by `2^n`.
+    pub fn mul_pow2(mut self, n: i32) -> Self {
+        if !self.is_zero() {
+            self.exp += n;
+        }
+        self
+    }
+
+    /// Divide by a small integer.
+    pub fn div_small(self, d: u32) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+
+        // Long division one 64-bit limb at a time, with an extra limb of fractional quotient
+        // bits that gets shifted in during normalization.
+        let d = u128::from(d);
+        let limbs = [
+            self.sig.hi >> 64,
+            self.sig.hi as u64 as u128,
+            self.sig.lo >> 64,
+            self.sig.lo as u64 as u128,
+            0,
+        ];
+        let mut q = [0u128; 5];
+        let mut rem = 0u128;
+        for (i, limb) in limbs.into_iter().enumerate() {
+            let cur = (rem << 64) | limb;
+            q[i] = cur / d;
+            rem = cur % d;
+        }
+
+        let hi = (q[0] << 64) | q[1];
+        let lo = (q[2] << 64) | q[3];
+        let lz = u256 { lo, hi }.leading_zeros();
+        let mut sig = u256 { lo, hi } << lz;
+        if lz > 0 {
+            sig.lo |= q[4] >> (64 - lz);
+        }
+
+        Self { neg: self.neg, exp: self.exp - lz as i32, sig }
+    }
+
+    /// The square root. `self` must not be negative.
+    pub fn sqrt(self) -> Self {
+        debug_assert!(!self.neg || self.is_zero());
+        if self.is_zero() {
+            return self;
+        }
+
+        // Seed with the `f64` square root of the top 53 bits, then refine with Newton's method.
+        // Each iteration doubles the number of correct bits: 53, 106, 212, then full precision.
+        let mut e = self.exp;
+        let mut m = (self.sig.hi >> 75) as f64 * f64::EPSILON;
+        if e % 2 != 0 {
+            m *= 2.0;
+            e -= 1;
+        }
+
+        let mut y = Self::from_f64(super::super::sqrt(m)).mul_pow2(e / 2);
+        for _ in 0..3 {
+            y = (y + self / y).mul_pow2(-1);
+        }
+        y
+    }
+
+    /// The cube root. `self` must not be negative.
+    pub fn cbrt(self) -> Self {
+        debug_assert!(!self.neg || self.is_zero());
+        if self.is_zero() {
+            return self;
+        }
+
+        // As with `sqrt`, seed from `f64` and refine with Newton's method. Splitting the exponent
+        // as `3q + r` keeps the seed's argument in `[1, 8)`.
+        let q = self.exp.div_euclid(3);
+        let m = (self.sig.hi >> 75) as f64 * f64::EPSILON * (1 << self.exp.rem_euclid(3)) as f64;
+
+        let mut y = Self::from_f64(super::super::cbrt(m)).mul_pow2(q);
+        for _ in 0..3 {
+            y = (y.mul_pow2(1) + self / (y * y)).div_small(3);
+        }
+        y
+    }
+
+    /// Normalize a nonzero significand that may have leading zeros.
+    fn normalize(neg: bool, exp: i32, sig: u256) -> Self {
+        if sig == u256::ZERO {
+            return Self::ZERO;
+        }
+
+        let lz = sig.leading_zeros();
+        Self { neg, exp: exp - lz as i32, sig: sig << lz }
+    }
+}
+
+impl PartialEq for WideFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for WideFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let ord = match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Some(Ordering::Equal),
+            (true, false) => {
+                return Some(if other.neg { Ordering::Greater } else { Ordering::Less });
+            }
+            (false, true) => {
+                return Some(if self.neg { Ordering::Less } else { Ordering::Greater });
+            }
+            (false, false) if self.neg != other.neg => {
+                return Some(if self.neg { Ordering::Less } else { Ordering::Greater });
+            }
+            (false, false) => {
+                (self.exp, self.sig.hi, self.sig.lo).cmp(&(other.exp, other.sig.hi, other.sig.lo))
+            }
+        };
+
+        Some(if self.neg { ord.reverse() } else { ord })
+    }
+}
+
+impl ops::Neg for WideFloat {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        self.neg = !self.neg;
+        self
+    }
+}
+
+impl ops::Add for WideFloat {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            return self;
+        }
+        if self.is_zero() {
+            return rhs;
+        }
+
+        let (big, small) =
+            if (self.exp, self.sig.hi, self.sig.lo) >= (rhs.exp, rhs.sig.hi, rhs.sig.lo) {
+                (self, rhs)
+            } else {
+                (rhs, self)
+            };
+
+        let d = (big.exp - small.exp) as u32;
+        if d >= 256 {
+            return big;
+        }
+
+        let s = small.sig >> d;
+        if big.neg == small.neg {
+            let (lo, c0) = big.sig.lo.overflowing_add(s.lo);
+            let (hi, c1) = big.sig.hi.overflowing_add(s.hi);
+            let (hi, c2) = hi.overflowing_add(c0 as u128);
+            let sig = u256 { lo, hi };
+            if c1 || c2 {
+                let sig = (sig >> 1) | u256 { lo: 0, hi: 1 << 127 };
+                return Self { neg: big.neg, exp: big.exp + 1, sig };
+            }
+            return Self { neg: big.neg, exp: big.exp, sig };
+        }
+
+        // With a shift of one, the bit shifted out of `small` is half a unit of the difference.
+        // Keep it so that results with heavy cancellation are still exact.
+        let half = d == 1 && small.sig.lo & 1 != 0;
+        let mut diff = big.sig - s;
+        if half {
+            diff = diff - u256::ONE;
+        }
+
+        let mut res = Self::normalize(big.neg, big.exp, diff);
+        let lz = (big.exp - res.exp) as u32;
+        if half && lz > 0 {
+            res.sig = res.sig | (u256::ONE << (lz - 1));
+        }
+        res
+    }
+}
+
+impl ops::Sub for WideFloat {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl ops::Mul for WideFloat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let neg = self.neg ^ rhs.neg;
+        if self.is_zero() || rhs.is_zero() {
+            return Self { neg, ..Self::ZERO };
+        }
+
+        let (a, b) = (self.sig, rhs.sig);
+        let ll = a.lo.widen_mul(b.lo);
+        let lh = a.lo.widen_mul(b.hi);
+        let hl = a.hi.widen_mul(b.lo);
+        let hh = a.hi.widen_mul(b.hi);
+
+        // Sum the 128-bit columns of the 512-bit product, keeping only the top three.
+        let (w1, c1) = ll.hi.overflowing_add(lh.lo);
+        let (w1, c2) = w1.overflowing_add(hl.lo);
+        let (w2, c3) = hh.lo.overflowing_add(lh.hi);
+        let (w2, c4) = w2.overflowing_add(hl.hi);
+        let (w2, c5) = w2.overflowing_add(c1 as u128 + c2 as u128);
+        let w3 = hh.hi + c3 as u128 + c4 as u128 + c5 as u128;
+
+        let exp = self.exp + rhs.exp;
+        let sig = u256 { lo: w2, hi: w3 };
+        if w3 >> 127 != 0 {
+            Self { neg, exp: exp + 1, sig }
+        } else {
+            let mut sig = sig << 1;
+            sig.lo |= w1 >> 127;
+            Self { neg, exp, sig }
+        }
+    }
+}
+
+impl ops::Div for WideFloat {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        debug_assert!(!rhs.is_zero(), "division by zero");
+        let neg = self.neg ^ rhs.neg;
+        if self.is_zero() {
+            return Self { neg, ..Self::ZERO };
+        }
+
+        // Newton's method for the reciprocal of the divisor's significand, seeded from `f64`. Each
+        // iteration doubles the number of correct bits: 53, 106, 212, then full precision.
+        let b = Self { neg: false, exp: 0, sig: rhs.sig };
+        let mut y = Self::from_f64(1.0 / ((b.sig.hi >> 75) as f64 * f64::EPSILON));
+        for _ in 0..3 {
+            y = y * (Self::TWO - b * y);
+        }
+
+        let q = Self { neg, exp: self.exp, sig: self.sig } * y;
+        q.mul_pow2(-rhs.exp)
+    }
+}
@@ -0,0 +1,146 @@
+use super::WideFloat;
+use crate::support::Float;
+
+fn wf(x: f128) -> WideFloat {
+    WideFloat::from_f128(x)
+}
+
+#[test]
+fn f128_roundtrip() {
+    let check = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.5,
+        f128::MAX,
+        f128::MIN,
+        f128::MIN_POSITIVE_NORMAL,
+        f128::EPSILON,
+        f128::from_bits(1),
+        f128::from_bits(0x0000ffffffffffffffffffffffffffff),
+        hf128!("0x1.23456789abcdef0123456789abcdp-100"),
+    ];
+
+    for x in check {
+        let res = wf(x).to_f128();
+        assert_eq!(res.to_bits(), x.to_bits(), "{x:?}");
+    }
+}
+
+#[test]
+fn to_f128_rounding() {
+    let one = WideFloat::ONE;
+    let half_ulp = one.mul_pow2(-113);
+    let tiny = one.mul_pow2(-200);
+
+    // Ties go to even
+    assert_eq!((one + half_ulp).to_f128(), 1.0);
+    assert_eq!((one + half_ulp + tiny).to_f128(), 1.0 + f128::EPSILON);
+    assert_eq!((one + wf(f128::EPSILON) + half_ulp).to_f128(), 1.0 + 2.0 * f128::EPSILON);
+    assert_eq!((one - half_ulp.mul_pow2(-1)).to_f128(), 1.0);
+    assert_eq!((one - half_ulp.mul_pow2(-1) - tiny).to_f128(), 1.0 - f128::EPSILON / 2.0);
+
+    // Overflow and underflow
+    assert_eq!(wf(f128::MAX).mul_pow2(1).to_f128(), f128::INFINITY);
+    assert_eq!((-wf(f128::MAX)).mul_pow2(1).to_f128(), f128::NEG_INFINITY);
+    assert_eq!((wf(f128::MAX) + wf(f128::MAX).mul_pow2(-113)).to_f128(), f128::INFINITY);
+    assert_eq!((wf(f128::MAX) + wf(f128::MAX).mul_pow2(-114)).to_f128(), f128::MAX);
+
+    // Subnormals
+    let min_sub = wf(f128::from_bits(1));
+    assert_eq!(min_sub.mul_pow2(-1).to_f128().to_bits(), 0);
+    assert_eq!((min_sub.mul_pow2(-1) + min_sub.mul_pow2(-200)).to_f128().to_bits(), 1);
+    assert_eq!((-min_sub).mul_pow2(-2).to_f128().to_bits(), <f128 as Float>::SIGN_MASK);
+    assert_eq!((min_sub * wf(1.5)).to_f128().to_bits(), 2);
+    assert_eq!((min_sub * wf(2.5)).to_f128().to_bits(), 2);
+    assert_eq!(
+        (wf(f128::MIN_POSITIVE_NORMAL) - min_sub.mul_pow2(-1)).to_f128(),
+        f128::MIN_POSITIVE_NORMAL
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let third = WideFloat::ONE / WideFloat::from_i32(3);
+    assert_eq!(third.to_f128(), 1.0 / 3.0);
+    assert_eq!(third, WideFloat::ONE.div_small(3));
+    assert_eq!((third * WideFloat::from_i32(3)).to_f128(), 1.0);
+    assert_eq!(WideFloat::ONE.div_small(10).to_f128(), 0.1);
+    assert_eq!((wf(0.1) * wf(3.0)).to_f128(), 0.1 * 3.0);
+    assert_eq!((wf(0.1) + wf(0.2)).to_f128(), 0.1 + 0.2);
+    assert_eq!((wf(0.1) - wf(0.2)).to_f128(), 0.1 - 0.2);
+    assert_eq!((wf(-7.0) / wf(0.25)).to_f128(), -28.0);
+    assert!((wf(1.25) - wf(1.25)).is_zero());
+
+    // Cancellation is exact when the inputs are
+    let x = hf128!("0x1.ffffffffffffffffffffffffffffp-1");
+    assert_eq!((wf(x) - WideFloat::ONE).to_f128(), x - 1.0);
+    assert_eq!((WideFloat::ONE - wf(x)).to_f128(), 1.0 - x);
+    let y = hf128!("0x1.0000000000000000000000000001p+0");
+    assert_eq!((wf(y) - wf(x)).to_f128(), y - x);
+}
+
+#[test]
+fn round_int() {
+    let check = [(0.0, 0), (0.49, 0), (0.5, 1), (-0.5, -1), (1.5, 2), (-2.5, -3), (1e6, 1000000)];
+    for (x, expected) in check {
+        assert_eq!(wf(x).round_int(), expected, "{x:?}");
+    }
+}
+
+#[test]
+fn constants() {
+    assert_eq!(WideFloat::LN2.to_f128(), hf128!("0x1.62e42fefa39ef35793c7673007e6p-1"));
+    assert_eq!((WideFloat::LN2 * WideFloat::LOG2_E).to_f128(), 1.0);
+    assert_eq!((WideFloat::LOG10_2 / WideFloat::LOG10_E / WideFloat::LN2).to_f128(), 1.0);
+    assert_eq!((WideFloat::FRAC_2_SQRT_PI * WideFloat::PI.sqrt()).to_f128(), 2.0);
+    assert_eq!((WideFloat::FRAC_2_PI * WideFloat::PI).to_f128(), 2.0);
+    assert_eq!(WideFloat::EULER_GAMMA.to_f128(), hf128!("0x1.2788cfc6fb618f49a37c7f0202a6p-1"));
+}
+
+#[test]
+fn ordering() {
+    let check = [-2.0, -1.5, -1.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0];
+    for x in check {
+        for y in check {
+            assert_eq!(wf(x).partial_cmp(&wf(y)), x.partial_cmp(&y), "{x:?} {y:?}");
+        }
+    }
+    assert_eq!(wf(0.0), wf(-0.0));
+    assert!(WideFloat::SQRT_2 > wf(1.414) && WideFloat::SQRT_2 < wf(1.415));
+}
+
+#[test]
+fn sqrt() {
+    assert!(WideFloat::ZERO.sqrt().is_zero());
+    assert_eq!(WideFloat::TWO.sqrt().to_f128(), WideFloat::SQRT_2.to_f128());
+    assert_eq!((WideFloat::TWO.sqrt() * WideFloat::TWO.sqrt()).to_f128(), 2.0);
+
+    let check = [0.5, 3.0, 9.0, 1e-4000, 1.2345e4000, f128::from_bits(1), f128::MAX];
+    for x in check {
+        assert_eq!(wf(x).sqrt().to_f128(), crate::math::sqrtf128(x), "{x:?}");
+    }
+}
+
+#[test]
+fn cbrt() {
+    assert!(WideFloat::ZERO.cbrt().is_zero());
+    assert_eq!(wf(8.0).cbrt().to_f128(), 2.0);
+    assert_eq!(wf(0.125).cbrt().to_f128(), 0.5);
+
+    let check = [3.0, 27.0, 1e-4000, 1.2345e4000, f128::from_bits(1), f128::MAX];
+    for x in check {
+        let y = wf(x).cbrt();
+        assert_eq!((y * y * y).to_f128(), x, "{x:?}");
+    }
+}
+
+#[test]
+fn from_f64() {
+    let check = [0.0, -0.0, 1.0, -2.5, f64::MAX, f64::MIN_POSITIVE, f64::from_bits(1), 0.1];
+    for x in check {
+        let res = WideFloat::from_f64(x).to_f128();
+        assert_eq!(res, x as f128, "{x:?}");
+        assert_eq!(res.is_sign_negative(), x.is_sign_negative(), "{x:?}");
+    }
+}
@@ -0,0 +1,17 @@
+use super::{k_cosf128, k_sinf128, rem_pio2f128};
+
+/// The tangent of `x` (f128).
+///
+/// `x` is specified in radians.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanf128(x: f128) -> f128 {
+    /* tan(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_pio2f128(x);
+    let (s, c) = (k_sinf128(r), k_cosf128(r));
+    let res = if n & 1 == 0 { s / c } else { -(c / s) };
+    res.to_f128()
+}
@@ -0,0 +1,27 @@
+use super::support::WideFloat;
+use super::{k_expf128, k_expm1f128};
+
+/// The hyperbolic tangent of `x` (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanhf128(x: f128) -> f128 {
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+
+    /* 1 - tanh(x) ~= 2e^(-2|x|) is below half an ulp of 1 for |x| > 40 */
+    if x.abs() > 40.0 {
+        return x.signum();
+    }
+
+    let w = WideFloat::from_f128(x).abs().mul_pow2(1);
+    let r = if w.exp() < 0 {
+        // tanh(x) = t / (t+2) with t = expm1(2|x|)
+        let t = k_expm1f128(w);
+        t / (t + WideFloat::TWO)
+    } else {
+        WideFloat::ONE - WideFloat::TWO / (k_expf128(w) + WideFloat::ONE)
+    };
+
+    let r = r.to_f128();
+    if x < 0.0 { -r } else { r }
+}
@@ -0,0 +1,5 @@
+/// The hyperbolic tangent of `x` (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanhf16(x: f16) -> f16 {
+    super::tanh(x as f64) as f16
+}
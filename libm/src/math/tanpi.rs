@@ -0,0 +1,93 @@
+use super::generic::rem_half;
+use super::sinpi::mul_pi;
+use super::{copysign, copysignf, k_tan, k_tanf};
+
+/// The tangent of `πx` (f16).
+///
+/// At integers this returns `+0` when `x` is positive and even or negative and odd, and `-0`
+/// otherwise. At `n + 1/2` the result is `+inf` for even `n` and `-inf` for odd `n`.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpif16(x: f16) -> f16 {
+    tanpi(x as f64) as f16
+}
+
+/// The tangent of `πx` (f32).
+///
+/// At integers this returns `+0` when `x` is positive and even or negative and odd, and `-0`
+/// otherwise. At `n + 1/2` the result is `+inf` for even `n` and `-inf` for odd `n`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpif(x: f32) -> f32 {
+    /* tanpi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 => copysignf(0.0, x),
+            1 => f32::INFINITY,
+            2 => copysignf(0.0, -x),
+            _ => f32::NEG_INFINITY,
+        };
+    }
+
+    k_tanf(core::f64::consts::PI * r as f64, n & 1 == 1)
+}
+
+/// The tangent of `πx` (f64).
+///
+/// At integers this returns `+0` when `x` is positive and even or negative and odd, and `-0`
+/// otherwise. At `n + 1/2` the result is `+inf` for even `n` and `-inf` for odd `n`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpi(x: f64) -> f64 {
+    /* tanpi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 => copysign(0.0, x),
+            1 => f64::INFINITY,
+            2 => copysign(0.0, -x),
+            _ => f64::NEG_INFINITY,
+        };
+    }
+
+    let (y0, y1) = mul_pi(r);
+    k_tan(y0, y1, (n & 1) as i32)
+}
+
+/// The tangent of `πx` (f128).
+///
+/// At integers this returns `+0` when `x` is positive and even or negative and odd, and `-0`
+/// otherwise. At `n + 1/2` the result is `+inf` for even `n` and `-inf` for odd `n`.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpif128(x: f128) -> f128 {
+    use super::support::WideFloat;
+    use super::{copysignf128, k_cosf128, k_sinf128};
+
+    /* tanpi(Inf or NaN) is NaN */
+    if !x.is_finite() {
+        return x - x;
+    }
+
+    let (n, r) = rem_half(x);
+    if r == 0.0 {
+        return match n {
+            0 => copysignf128(0.0, x),
+            1 => f128::INFINITY,
+            2 => copysignf128(0.0, -x),
+            _ => f128::NEG_INFINITY,
+        };
+    }
+
+    let y = WideFloat::PI * WideFloat::from_f128(r);
+    let (s, c) = (k_sinf128(y), k_cosf128(y));
+    let res = if n & 1 == 0 { s / c } else { -(c / s) };
+    res.to_f128()
+}
@@ -0,0 +1,36 @@
+use super::support::WideFloat;
+use super::{k_expf128, k_lgammaf128, k_sinpif128, truncf128};
+
+/// The [Gamma function](https://en.wikipedia.org/wiki/Gamma_function) (f128).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tgammaf128(x: f128) -> f128 {
+    if x.is_nan() || x == f128::INFINITY {
+        return x + x;
+    }
+    if x == 0.0 {
+        return 1.0 / x; /* tgamma(+-0)=+-inf */
+    }
+    if x < 0.0 && x == truncf128(x) {
+        return (x - x) / (x - x); /* tgamma(-integer) = tgamma(-inf) = NaN */
+    }
+
+    /* tgamma(x) overflows for x > 1755.5 */
+    if x > 1800.0 {
+        return f128::INFINITY;
+    }
+
+    if x > 0.0 {
+        return k_expf128(k_lgammaf128(WideFloat::from_f128(x))).to_f128();
+    }
+
+    // Reflection: Γ(x) = π / (sin(πx) * Γ(1 - x))
+    let s = k_sinpif128(x);
+
+    /* tgamma(x) underflows for x < -1770, but keeps the sign of sin(πx) */
+    if x < -1800.0 {
+        return if s.is_sign_negative() { -0.0 } else { 0.0 };
+    }
+
+    let g = k_expf128(k_lgammaf128(WideFloat::ONE - WideFloat::from_f128(x)));
+    (WideFloat::PI / (s * g)).to_f128()
+}
@@ -0,0 +1,5 @@
+/// The [Gamma function](https://en.wikipedia.org/wiki/Gamma_function) (f16).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tgammaf16(x: f16) -> f16 {
+    super::tgamma(x as f64) as f16
+}
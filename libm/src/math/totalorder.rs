@@ -0,0 +1,67 @@
+/// Return 1 if `x` is ordered before or equal to `y` in the IEEE 754 total order, 0 otherwise.
+///
+/// Unlike `<=`, this orders `-0.0` before `+0.0` and places NaNs at either end, depending on
+/// their sign.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorderf16(x: f16, y: f16) -> i32 {
+    super::generic::totalorder(x, y).into()
+}
+
+/// Return 1 if `x` is ordered before or equal to `y` in the IEEE 754 total order, 0 otherwise.
+///
+/// Unlike `<=`, this orders `-0.0` before `+0.0` and places NaNs at either end, depending on
+/// their sign.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorderf(x: f32, y: f32) -> i32 {
+    super::generic::totalorder(x, y).into()
+}
+
+/// Return 1 if `x` is ordered before or equal to `y` in the IEEE 754 total order, 0 otherwise.
+///
+/// Unlike `<=`, this orders `-0.0` before `+0.0` and places NaNs at either end, depending on
+/// their sign.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorder(x: f64, y: f64) -> i32 {
+    super::generic::totalorder(x, y).into()
+}
+
+/// Return 1 if `x` is ordered before or equal to `y` in the IEEE 754 total order, 0 otherwise.
+///
+/// Unlike `<=`, this orders `-0.0` before `+0.0` and places NaNs at either end, depending on
+/// their sign.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorderf128(x: f128, y: f128) -> i32 {
+    super::generic::totalorder(x, y).into()
+}
+
+/// Return 1 if `|x|` is ordered before or equal to `|y|` in the IEEE 754 total order, 0
+/// otherwise.
+#[cfg(f16_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermagf16(x: f16, y: f16) -> i32 {
+    super::generic::totalordermag(x, y).into()
+}
+
+/// Return 1 if `|x|` is ordered before or equal to `|y|` in the IEEE 754 total order, 0
+/// otherwise.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermagf(x: f32, y: f32) -> i32 {
+    super::generic::totalordermag(x, y).into()
+}
+
+/// Return 1 if `|x|` is ordered before or equal to `|y|` in the IEEE 754 total order, 0
+/// otherwise.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermag(x: f64, y: f64) -> i32 {
+    super::generic::totalordermag(x, y).into()
+}
+
+/// Return 1 if `|x|` is ordered before or equal to `|y|` in the IEEE 754 total order, 0
+/// otherwise.
+#[cfg(f128_enabled)]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermagf128(x: f128, y: f128) -> i32 {
+    super::generic::totalordermag(x, y).into()
+}